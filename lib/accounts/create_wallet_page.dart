@@ -2,7 +2,7 @@ import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
 import 'package:rust_plugin/src/rust/api/nostr.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
-import 'package:path_provider/path_provider.dart';
+import '../wallet/services/wallet_service.dart';
 import 'package:flutter_secure_storage/flutter_secure_storage.dart';
 import 'package:dart:math';
 
@@ -114,11 +114,8 @@ class _CreateWalletPageState extends State<CreateWalletPage> {
       // Save seed hex to secure storage
       await _secureStorage.write(key: _seedKey, value: _generatedSeedHex!);
 
-      // Initialize MultiMintWallet
-      final documentsDir = await getApplicationDocumentsDirectory();
-      final databaseDir = documentsDir.path;
-
-      final initResult = initMultiMintWallet(databaseDir: databaseDir, seedHex: _generatedSeedHex!);
+      // Open the wallet
+      await WalletService.initializeWallet(_generatedSeedHex!);
 
       if (mounted) {
         Navigator.pushReplacementNamed(context, '/main');
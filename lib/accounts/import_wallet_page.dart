@@ -1,6 +1,6 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_secure_storage/flutter_secure_storage.dart';
-import 'package:path_provider/path_provider.dart';
+import '../wallet/services/wallet_service.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
 
 /// Import wallet page - Import wallet using seed phrase
@@ -72,11 +72,8 @@ class _ImportWalletPageState extends State<ImportWalletPage> {
       // Save seed hex to secure storage
       await _secureStorage.write(key: _seedKey, value: seedHex);
 
-      // Initialize MultiMintWallet with imported seed
-      final documentsDir = await getApplicationDocumentsDirectory();
-      final databaseDir = documentsDir.path;
-
-      final initResult = initMultiMintWallet(databaseDir: databaseDir, seedHex: seedHex);
+      // Open the wallet with the imported seed
+      await WalletService.initializeWallet(seedHex);
 
       if (mounted) {
         Navigator.pushReplacementNamed(context, '/main');
@@ -503,7 +503,7 @@ class _MainAppPageState extends State<MainAppPage> {
 
     try {
       // Call receiveTokens API
-      final receivedAmount = await receiveTokens(token: token.trim());
+      final receivedAmount = await receiveTokens(handle: WalletService.handle, token: token.trim());
 
       // Show success message
       if (!mounted) return;
@@ -575,7 +575,7 @@ class _MainAppPageState extends State<MainAppPage> {
       String? selectedMintUrl = mintUrl;
 
       if (selectedMintUrl == null) {
-        final mints = await listMints();
+        final mints = await listMints(handle: WalletService.handle);
         if (mints.isEmpty) {
           Navigator.of(context).pop(); // Close loading dialog
           ScaffoldMessenger.of(context).showSnackBar(
@@ -605,6 +605,7 @@ class _MainAppPageState extends State<MainAppPage> {
 
       // Create mint quote using new simplified API
       final quote = await createMintQuote(
+        handle: WalletService.handle,
         mintUrl: selectedMintUrl,
         amount: BigInt.from(amount),
       );
@@ -808,6 +809,7 @@ class _MainAppPageState extends State<MainAppPage> {
 
       // Call sendTokens API
       final token = await sendTokens(
+        handle: WalletService.handle,
         mintUrl: parsedMintUrl,
         amount: BigInt.from(parsedAmount),
         memo: memo.isNotEmpty ? memo : null,
@@ -1003,6 +1005,7 @@ class _MainAppPageState extends State<MainAppPage> {
       }
 
       final token = await sendP2PkTokens(
+        handle: WalletService.handle,
         mintUrl: parsedMintUrl,
         amount: BigInt.from(parsedAmount),
         memo: request.memo.trim().isNotEmpty ? request.memo : null,
@@ -1082,8 +1085,8 @@ class _MainAppPageState extends State<MainAppPage> {
   Future<void> _refreshWalletData() async {
     try {
       // Use new bulk methods to get all data at once
-      final allBalances = await getAllBalances();
-      final allTransactions = await getAllTransactions();
+      final allBalances = await getAllBalances(handle: WalletService.handle);
+      final allTransactions = await getAllTransactions(handle: WalletService.handle);
 
       // Calculate total balance from all mints
       BigInt totalBalance = BigInt.zero;
@@ -1156,7 +1159,7 @@ class _MainAppPageState extends State<MainAppPage> {
       );
 
       // Get the first mint URL
-      final mints = await listMints();
+      final mints = await listMints(handle: WalletService.handle);
       if (mints.isEmpty) {
         Navigator.of(context).pop(); // Close loading dialog
         ScaffoldMessenger.of(context).showSnackBar(
@@ -1182,6 +1185,7 @@ class _MainAppPageState extends State<MainAppPage> {
 
       // Pay lightning invoice using new API
       final paymentStatus = await payInvoiceForWallet(
+        handle: WalletService.handle,
         mintUrl: mintUrl,
         bolt11Invoice: invoice,
         maxFeeSats: BigInt.from(100), // Max fee of 100 sats
@@ -829,7 +829,7 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
     );
 
     try {
-      final receivedAmount = await receiveTokens(token: token.trim());
+      final receivedAmount = await receiveTokens(handle: WalletService.handle, token: token.trim());
 
       if (!mounted) return;
       ScaffoldMessenger.of(context).showSnackBar(
@@ -896,7 +896,7 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
       String? selectedMintUrl = mintUrl;
 
       if (selectedMintUrl == null) {
-        final mints = await listMints();
+        final mints = await listMints(handle: WalletService.handle);
         if (mints.isEmpty) {
           Navigator.of(context).pop();
           ScaffoldMessenger.of(context).showSnackBar(
@@ -924,6 +924,7 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
       }
 
       final quote = await createMintQuote(
+        handle: WalletService.handle,
         mintUrl: selectedMintUrl,
         amount: BigInt.from(amount),
       );
@@ -1107,6 +1108,7 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
       }
 
       final token = await sendTokens(
+        handle: WalletService.handle,
         mintUrl: parsedMintUrl,
         amount: BigInt.from(parsedAmount),
         memo: memo.isNotEmpty ? memo : null,
@@ -1298,6 +1300,7 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
       }
 
       final token = await sendP2PkTokens(
+        handle: WalletService.handle,
         mintUrl: parsedMintUrl,
         amount: BigInt.from(parsedAmount),
         memo: request.memo.trim().isNotEmpty ? request.memo : null,
@@ -1370,8 +1373,8 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
 
   Future<void> _refreshWalletData() async {
     try {
-      final allBalances = await getAllBalances();
-      final allTransactions = await getAllTransactions();
+      final allBalances = await getAllBalances(handle: WalletService.handle);
+      final allTransactions = await getAllTransactions(handle: WalletService.handle);
 
       BigInt totalBalance = BigInt.zero;
       for (final balance in allBalances.values) {
@@ -1438,7 +1441,7 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
         },
       );
 
-      final mints = await listMints();
+      final mints = await listMints(handle: WalletService.handle);
       if (mints.isEmpty) {
         Navigator.of(context).pop();
         ScaffoldMessenger.of(context).showSnackBar(
@@ -1462,6 +1465,7 @@ class _MainAppPageAdaptiveState extends State<MainAppPageAdaptive> {
               : mintString;
 
       final paymentStatus = await payInvoiceForWallet(
+        handle: WalletService.handle,
         mintUrl: mintUrl,
         bolt11Invoice: invoice,
         maxFeeSats: BigInt.from(100),
@@ -582,6 +582,12 @@ class SettingsPage extends StatelessWidget {
   Future<void> _setTorPreference(bool enabled) async {
     final prefs = await SharedPreferences.getInstance();
     await prefs.setBool(_torPrefKey, enabled);
-    await setTorConfig(policy: enabled);
+    final needsReopen = await setTorConfig(
+      handle: WalletService.handle,
+      policy: enabled ? 'always' : 'onion_only',
+    );
+    if (needsReopen) {
+      await WalletService.reinitializeWalletWithTorConfig();
+    }
   }
 }
\ No newline at end of file
@@ -261,7 +261,7 @@ class _MintDetailPageState extends State<MintDetailPage> {
     );
 
     try {
-      final restoredAmount = await restoreMint(mintUrl: widget.mintUrl);
+      final restoredAmount = await restoreMint(handle: WalletService.handle, mintUrl: widget.mintUrl);
 
       if (!mounted) return;
       Navigator.of(context, rootNavigator: true).pop();
@@ -495,7 +495,7 @@ class _MintDetailPageState extends State<MintDetailPage> {
   void _loadBalance() async {
     try {
       // Get balance for this specific mint
-      final balances = await getAllBalances();
+      final balances = await getAllBalances(handle: WalletService.handle);
       
       // Find balance for this mint URL
       int totalBalance = 0;
@@ -781,7 +781,7 @@ class _MintDetailPageState extends State<MintDetailPage> {
   void _confirmDeleteMint() async {
     try {
       // Wait for the mint to be removed
-      await removeMint(mintUrl: widget.mintUrl);
+      await removeMint(handle: WalletService.handle, mintUrl: widget.mintUrl);
       
       if (mounted) {
         ScaffoldMessenger.of(context).showSnackBar(
@@ -1,6 +1,7 @@
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
+import '../wallet/services/wallet_service.dart';
 
 /// Mint info page displaying detailed mint information according to NUT-06
 class MintInfoPage extends StatefulWidget {
@@ -452,6 +453,7 @@ class _MintInfoPageState extends State<MintInfoPage> {
     try {
       // Fetch mint info using Rust API (NUT-06)
       final mintInfoData = await getMintInfo(
+        handle: WalletService.handle,
         mintUrl: widget.mintUrl,
       );
       
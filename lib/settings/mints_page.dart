@@ -26,7 +26,7 @@ class _MintsPageState extends State<MintsPage> {
   @override
   Widget build(BuildContext context) {
     final body = FutureBuilder<List<String>>(
-        future: Future(() => listMints()),
+        future: Future(() => listMints(handle: WalletService.handle)),
         builder: (context, snapshot) {
           if (snapshot.connectionState == ConnectionState.waiting) {
             return const Center(
@@ -614,7 +614,7 @@ class _MintsPageState extends State<MintsPage> {
       // Use the exact URL as stored (no processing needed)
       
       // Remove the mint using the Rust API
-      removeMint(mintUrl: mintUrl);
+      removeMint(handle: WalletService.handle, mintUrl: mintUrl);
 
       // Show success message
       if (mounted) {
@@ -49,7 +49,13 @@ class _SecurityPageState extends State<SecurityPage> {
     try {
       final prefs = await SharedPreferences.getInstance();
       await prefs.setBool(_torPrefKey, value);
-      await setTorConfig(policy: value);
+      final needsReopen = await setTorConfig(
+        handle: WalletService.handle,
+        policy: value ? 'always' : 'onion_only',
+      );
+      if (needsReopen) {
+        await WalletService.reinitializeWalletWithTorConfig();
+      }
       if (mounted) {
         ScaffoldMessenger.of(context).showSnackBar(
           SnackBar(
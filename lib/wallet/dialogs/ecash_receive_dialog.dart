@@ -1,6 +1,7 @@
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
+import '../services/wallet_service.dart';
 
 /// Show ecash receive dialog
 Future<void> showEcashReceiveDialog({
@@ -177,7 +178,7 @@ Future<void> showEcashReceiveDialog({
                 }
 
                 // Call receiveTokens API
-                final receivedAmount = await receiveTokens(token: token.trim());
+                final receivedAmount = await receiveTokens(handle: WalletService.handle, token: token.trim());
 
                 // Close loading dialog
                 if (context.mounted) {
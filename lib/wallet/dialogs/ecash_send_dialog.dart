@@ -1,5 +1,6 @@
 import 'package:flutter/material.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
+import '../services/wallet_service.dart';
 import 'add_mint_dialog.dart';
 
 /// Show ecash send dialog
@@ -11,7 +12,7 @@ Future<void> showEcashSendDialog({
   // Load available mints first
   List<String> mints = [];
   try {
-    mints = await listMints();
+    mints = await listMints(handle: WalletService.handle);
   } catch (e) {
     // Error loading mints
   }
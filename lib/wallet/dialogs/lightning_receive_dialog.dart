@@ -1,5 +1,6 @@
 import 'package:flutter/material.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
+import '../services/wallet_service.dart';
 import 'add_mint_dialog.dart';
 
 /// Show lightning receive dialog
@@ -9,7 +10,7 @@ Future<void> showLightningReceiveDialog({
   required VoidCallback onRefresh,
 }) async {
   // Check if there are any mints available first
-  final mints = await listMints();
+  final mints = await listMints(handle: WalletService.handle);
   if (mints.isEmpty) {
     // Show dialog to prompt user to add a mint
     return showDialog(
@@ -1,5 +1,6 @@
 import 'package:flutter/material.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
+import '../services/wallet_service.dart';
 
 import 'add_mint_dialog.dart';
 
@@ -36,7 +37,7 @@ Future<void> showP2pkSendDialog({
 }) async {
   List<String> mints = [];
   try {
-    mints = await listMints();
+    mints = await listMints(handle: WalletService.handle);
   } catch (_) {}
 
   if (mints.isEmpty) {
@@ -1,6 +1,7 @@
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
+import '../services/wallet_service.dart';
 
 /// Show transaction detail dialog
 Future<void> showTransactionDetailDialog({
@@ -158,7 +159,7 @@ Future<void> showTransactionDetailDialog({
                         );
                         
                         try {
-                          final amount = await receiveTokens(token: tx.ecashToken!);
+                          final amount = await receiveTokens(handle: WalletService.handle, token: tx.ecashToken!);
                           
                           // Close loading dialog
                           if (context.mounted) {
@@ -1,6 +1,5 @@
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
-import 'package:path_provider/path_provider.dart';
 import 'package:flutter_secure_storage/flutter_secure_storage.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
 
@@ -112,11 +111,8 @@ class _CreateWalletPageState extends State<CreateWalletPage> {
       await _secureStorage.write(key: _seedKey, value: _generatedSeedHex!);
       await WalletService.storeMnemonic(_generatedMnemonic!);
 
-      // Initialize MultiMintWallet
-      final documentsDir = await getApplicationDocumentsDirectory();
-      final databaseDir = documentsDir.path;
-
-      await initMultiMintWallet(databaseDir: databaseDir, seedHex: _generatedSeedHex!);
+      // Open the wallet
+      await WalletService.initializeWallet(_generatedSeedHex!);
 
       if (mounted) {
         Navigator.pushReplacementNamed(context, '/main');
@@ -1,6 +1,5 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_secure_storage/flutter_secure_storage.dart';
-import 'package:path_provider/path_provider.dart';
 import 'package:rust_plugin/src/rust/api/cashu.dart';
 
 import '../services/wallet_service.dart';
@@ -97,11 +96,8 @@ class _ImportWalletPageState extends State<ImportWalletPage> {
       await _secureStorage.write(key: _seedKey, value: seedHex);
       await WalletService.storeMnemonic(mnemonicPhrase);
 
-      // Initialize MultiMintWallet with imported seed
-      final documentsDir = await getApplicationDocumentsDirectory();
-      final databaseDir = documentsDir.path;
-
-      await initMultiMintWallet(databaseDir: databaseDir, seedHex: seedHex);
+      // Open the wallet with the imported seed
+      await WalletService.initializeWallet(seedHex);
       await WalletService.restoreMintsFromBackup();
 
       if (mounted) {
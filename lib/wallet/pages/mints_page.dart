@@ -22,7 +22,7 @@ class _MintsPageState extends State<MintsPage> {
 
   Future<void> _loadMints() async {
     try {
-      final mints = await listMints();
+      final mints = await listMints(handle: WalletService.handle);
       if (mounted) {
         setState(() {
           _mints = mints;
@@ -578,7 +578,7 @@ class _MintsPageState extends State<MintsPage> {
   void _deleteMint(String mintUrl) async {
     try {
       // Call the API to remove the mint
-      await removeMint(mintUrl: mintUrl);
+      await removeMint(handle: WalletService.handle, mintUrl: mintUrl);
       
       // Reload mints list
       await _loadMints();
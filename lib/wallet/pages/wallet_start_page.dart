@@ -5,6 +5,7 @@ import 'package:path_provider/path_provider.dart';
 import 'dart:io';
 import '../../accounts/login_home_page.dart';
 import '../../main_app_page_adaptive.dart';
+import '../services/wallet_service.dart';
 
 /// Wallet start page that checks for existing wallet and initializes it
 class WalletStartPage extends StatefulWidget {
@@ -43,8 +44,8 @@ class _WalletStartPageState extends State<WalletStartPage> {
         final seedHex = await storage.read(key: 'cashu_wallet_seed');
         
         if (seedHex != null) {
-          // Initialize MultiMintWallet with existing seed
-          initMultiMintWallet(databaseDir: databaseDir, seedHex: seedHex);
+          // Open the wallet with the existing seed
+          await WalletService.initializeWallet(seedHex);
 
           // Mints are now empty by default - users can add their own mints
 
@@ -13,6 +13,10 @@ class WalletService {
   static const String _seedKey = 'cashu_wallet_seed';
   static const String _mnemonicKey = 'cashu_wallet_mnemonic';
   static const String _mintListKeyPrefix = 'cashu_wallet_mints';
+  static const String _profileId = 'default';
+
+  // Handle to the open wallet profile
+  static WalletHandle? _handle;
 
   // Global monitoring timer
   static Timer? _globalMonitorTimer;  // Check all wallets every 1 minute
@@ -34,6 +38,15 @@ class WalletService {
   static Function(Map<String, String>)? onMeltedAmountReceived;
   static VoidCallback? onWalletUpdated;
 
+  /// Handle to the open wallet, passed to every wallet API
+  static WalletHandle get handle {
+    final handle = _handle;
+    if (handle == null) {
+      throw StateError('Wallet is not open');
+    }
+    return handle;
+  }
+
   static void notifyWalletUpdated() {
     try {
       onWalletUpdated?.call();
@@ -53,15 +66,19 @@ class WalletService {
       // Load Tor configuration
       _loadAndApplyTorConfig();
 
-      // Initialize MultiMintWallet
-      final initResult = await initMultiMintWallet(databaseDir: databaseDir, seedHex: seedHex);
+      // Open the wallet profile
+      _handle = await openWallet(
+        profileId: _profileId,
+        databaseDir: databaseDir,
+        seedHex: seedHex,
+      );
 
       await _refreshMintListBackup();
 
       // Start global monitoring after wallet is initialized
       startGlobalMonitoring();
 
-      return initResult;
+      return 'Wallet opened successfully';
     } catch (e) {
       throw Exception('Failed to initialize wallet: $e');
     }
@@ -82,10 +99,19 @@ class WalletService {
       // Load and apply Tor configuration
       await _loadAndApplyTorConfig();
 
-      // Reinitialize MultiMintWallet with Tor config
-      final initResult = reinitializeWithTorConfig(databaseDir: databaseDir, seedHex: seedHex);
+      // Reopen the wallet so the saved Tor policy is applied
+      final handle = _handle;
+      if (handle != null) {
+        await closeWallet(handle: handle);
+        _handle = null;
+      }
+      _handle = await openWallet(
+        profileId: _profileId,
+        databaseDir: databaseDir,
+        seedHex: seedHex,
+      );
 
-      return initResult;
+      return 'Wallet reopened successfully';
     } catch (e) {
       throw Exception('Failed to reinitialize wallet with Tor config: $e');
     }
@@ -144,7 +170,7 @@ class WalletService {
           ? const Duration(seconds: 120) 
           : const Duration(seconds: 60);
       
-      final result = await addMint(mintUrl: mintUrl)
+      final result = await addMint(handle: handle, mintUrl: mintUrl)
           .timeout(timeoutDuration, onTimeout: () {
             throw Exception('Adding mint timed out. Please check your network connection and try again.');
           });
@@ -162,7 +188,7 @@ class WalletService {
         // For .onion addresses, check if Tor is ready and provide helpful message
         if (mintUrl.contains('.onion')) {
           try {
-            final isReady = await isTorReady();
+            final isReady = await isTorReady(handle: handle);
             if (!isReady) {
               throw Exception('Tor network is still connecting. Please wait a moment and try again.\n\n⏱️ This usually takes 30-60 seconds on first connection.\n\n💡 Tip: The connection is happening in the background. Try again in a few moments.');
             }
@@ -180,7 +206,7 @@ class WalletService {
   /// Remove a mint from the wallet
   static Future<String> removeMintService(String mintUrl, String unit) async {
     try {
-      final result = removeMint(mintUrl: mintUrl);
+      final result = await removeMint(handle: handle, mintUrl: mintUrl);
       await _refreshMintListBackup();
       return result;
     } catch (e) {
@@ -191,7 +217,7 @@ class WalletService {
   /// Get wallet info
   static Future<WalletInfo?> getWalletInfoService(String mintUrl, String unit) async {
     try {
-      return await getWalletInfo(mintUrl: mintUrl);
+      return await getWalletInfo(handle: handle, mintUrl: mintUrl);
     } catch (e) {
       String errorMsg = e.toString();
       
@@ -199,7 +225,7 @@ class WalletService {
       if (errorMsg.contains('Tor is not initialized') && mintUrl.contains('.onion')) {
         // Check if Tor is ready
         try {
-          await isTorReady();
+          await isTorReady(handle: handle);
           // Tor still connecting, mint info will be available once Tor is ready
         } catch (_) {
           // Ignore error checking status
@@ -214,10 +240,9 @@ class WalletService {
   /// Get wallet balance for a specific mint URL
   static Future<BigInt> getWalletBalanceService(String mintUrl, String unit) async {
     try {
-      final allBalances = await getAllBalances();
+      final allBalances = await getAllBalances(handle: handle);
       final key = '$mintUrl:$unit';
-      final balance = allBalances[key] ?? 0;
-      return BigInt.from(balance as int);
+      return allBalances[key] ?? BigInt.zero;
     } catch (e) {
       return BigInt.zero;
     }
@@ -226,7 +251,7 @@ class WalletService {
   /// Get wallet transactions for a specific mint URL
   static Future<List<TransactionInfo>> getWalletTransactionsService(String mintUrl, String unit) async {
     try {
-      final allTransactions = await getAllTransactions();
+      final allTransactions = await getAllTransactions(handle: handle);
       // Filter transactions for the specific mint URL
       return allTransactions.where((tx) {
         return true;
@@ -239,6 +264,12 @@ class WalletService {
   /// Clear wallet data (for logout)
   static Future<void> clearWalletData() async {
     try {
+      stopAllMonitoring();
+      final handle = _handle;
+      if (handle != null) {
+        _handle = null;
+        await closeWallet(handle: handle);
+      }
       final seedHex = await getStoredSeed();
       if (seedHex != null) {
         final mintKey = _mintListStorageKey(seedHex);
@@ -261,13 +292,13 @@ class WalletService {
     for (final mintUrl in backupMints) {
       if (mintUrl.isEmpty) continue;
       try {
-        await addMint(mintUrl: mintUrl);
+        await addMint(handle: handle, mintUrl: mintUrl);
       } catch (_) {
         // Ignore failures (already exists, offline, etc.)
       }
 
       try {
-        await restoreMint(mintUrl: mintUrl);
+        await restoreMint(handle: handle, mintUrl: mintUrl);
       } catch (_) {
         // Ignore failures (already exists, offline, etc.)
       }
@@ -381,7 +412,7 @@ class WalletService {
   static Future<void> _checkAllMeltQuotes() async {
     try {
       // Check all melt quotes across all wallets
-      final result = await checkAllMeltQuotes();
+      final result = await checkAllMeltQuotes(handle: handle);
       final totalCompleted = int.parse(result);
 
       if (totalCompleted > 0) {
@@ -403,7 +434,7 @@ class WalletService {
   static Future<void> _checkAllMintQuotes() async {
     try {
       // Use the new checkAllMintQuotes API
-      final result = await checkAllMintQuotes();
+      final result = await checkAllMintQuotes(handle: handle);
       final totalMinted = int.parse(result['total_minted'] ?? '0');
 
       if (totalMinted > 0) {
@@ -430,6 +461,7 @@ class WalletService {
         try {
           // Check mint quote status for this specific mint
           final result = await checkMintQuoteStatus(
+            handle: handle,
             mintUrl: mintUrl,
           );
           
@@ -468,7 +500,7 @@ class WalletService {
     try {
       
       // Check all melt quotes for this mint URL
-      final result = await checkMeltQuoteStatus(mintUrl: mintUrl);
+      final result = await checkMeltQuoteStatus(handle: handle, mintUrl: mintUrl);
       final completedCount = int.parse(result);
       
       if (completedCount > 0) {
@@ -493,7 +525,7 @@ class WalletService {
 
   static Future<void> _refreshMintListBackup() async {
     try {
-      final mintEntries = await listMints();
+      final mintEntries = await listMints(handle: handle);
       final urls = mintEntries
           .map(_extractMintUrl)
           .where((url) => url.isNotEmpty)
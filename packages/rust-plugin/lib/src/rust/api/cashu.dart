@@ -4,12 +4,109 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../network.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `build_p2pk_spending_conditions`, `extract_supported_nuts`, `get_database_path`, `load_wallets_from_database`, `parse_pubkey_list`, `parse_public_key`, `parse_seed_from_hex`, `parse_sigflag`, `wallet_database_exists`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `try_from`
+// These functions are ignored because they are not marked as `pub`: `apply_mint_transports`, `begin_wallet_operation`, `build_p2pk_spending_conditions`, `cached_mint_info`, `check_method_limits`, `deliver_payment_over_http`, `deliver_payment_over_nostr`, `encoded_token`, `ensure_mint`, `ensure_no_running_operations`, `ensure_online`, `extract_supported_nuts`, `get_database_path`, `get_multi_mint_database_path`, `get_multi_mint_wallet`, `get_profile_dir`, `get_profile_dir_for_handle`, `has_keysets`, `import_mint_proofs`, `import_mint_proofs_inner`, `is_plaintext_database`, `keyset_counters`, `load_wallets_from_database`, `lock_profile`, `mint_auth_settings`, `mint_connection`, `mint_connector`, `mint_settings`, `mint_wallet_config`, `open_wallet_store`, `open_wallet_with_database_key`, `parse_pubkey_list`, `parse_public_key`, `parse_seed_from_hex`, `parse_sigflag`, `parse_token_encoding`, `parse_transport`, `payment_request_conditions`, `prepare_mint_connection`, `proxied_connector`, `redeem_lnurl_withdraw_inner`, `reencode_token`, `refresh_mint_cache`, `register_mint`, `release_reserved_proofs`, `require_capabilities`, `reserve_selected_proofs`, `send_locked_tokens`, `send_reserved_proofs`, `send_token`, `service_http_client`, `shutdown_profile`, `spawn_mint_cache_refresh`, `standalone_mint_client`, `token_proofs`, `update_network_settings`, `validate_profile_id`, `validate_proof_secret`, `verify_proofs_with_keys`, `wallet_database_exists`, `wire_string`, `with_open_wallet`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `try_from`
 
-/// Initialize MultiMintWallet
+/// Open a wallet profile and return its handle
+/// Every profile has its own database, spending policy and Tor state under database_dir.
+/// Opening a profile that is already open returns the existing handle.
+Future<WalletHandle> openWallet({
+  required String profileId,
+  required String databaseDir,
+  required String seedHex,
+}) => RustLib.instance.api.crateApiCashuOpenWallet(
+  profileId: profileId,
+  databaseDir: databaseDir,
+  seedHex: seedHex,
+);
+
+/// Open a wallet profile with an encrypted database
+/// key_material comes from the user's PIN or the OS keystore. An existing plaintext
+/// database is encrypted in place on first use.
+Future<WalletHandle> openEncryptedWallet({
+  required String profileId,
+  required String databaseDir,
+  required String seedHex,
+  required String keyMaterial,
+}) => RustLib.instance.api.crateApiCashuOpenEncryptedWallet(
+  profileId: profileId,
+  databaseDir: databaseDir,
+  seedHex: seedHex,
+  keyMaterial: keyMaterial,
+);
+
+/// Close a wallet profile, the handle stops working until the profile is opened again
+Future<void> closeWallet({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuCloseWallet(handle: handle);
+
+/// Shut a wallet profile down cleanly
+/// New operations are refused, background tasks such as LNURL-withdraw polling are
+/// cancelled and running sends and melts get up to timeout_secs (default 30) to finish.
+/// Sends and melts still running after that are returned; they stay in the operation
+/// journal and show up in get_interrupted_operations the next time the profile is opened.
+/// The SQLite store and any Tor client are released once the last task lets go.
+Future<List<InterruptedOperation>> shutdownWallet({
+  required WalletHandle handle,
+  BigInt? timeoutSecs,
+}) => RustLib.instance.api.crateApiCashuShutdownWallet(
+  handle: handle,
+  timeoutSecs: timeoutSecs,
+);
+
+/// Sends and melts from earlier sessions that never finished
+/// The proofs involved may be reserved or spent, check_melt_quote_status and
+/// restore_mint recover them.
+Future<List<InterruptedOperation>> getInterruptedOperations({
+  required WalletHandle handle,
+}) => RustLib.instance.api.crateApiCashuGetInterruptedOperations(
+  handle: handle,
+);
+
+/// Forget interrupted operations once the app has dealt with them
+Future<void> clearInterruptedOperations({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuClearInterruptedOperations(
+      handle: handle,
+    );
+
+/// Switch offline mode for a profile
+/// While offline, mint info and keysets are served from the cache only and calls
+/// that need the mint (receiving, minting, melting, quote checks) fail right away.
+/// Sends that do not need a swap keep working.
+Future<void> setOfflineMode({
+  required WalletHandle handle,
+  required bool offline,
+}) => RustLib.instance.api.crateApiCashuSetOfflineMode(
+  handle: handle,
+  offline: offline,
+);
+
+Future<bool> isOfflineMode({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuIsOfflineMode(handle: handle);
+
+/// Delete a wallet profile from disk, shutting it down first if it is open
+/// This destroys the ecash in it, the app must confirm with the user first.
+/// Sends and melts still running after the shutdown make this fail unless force is set.
+/// For the default profile only the wallet files are removed, as its directory
+/// also holds the other profiles.
+Future<void> deleteWallet({
+  required String profileId,
+  required String databaseDir,
+  required bool force,
+}) => RustLib.instance.api.crateApiCashuDeleteWallet(
+  profileId: profileId,
+  databaseDir: databaseDir,
+  force: force,
+);
+
+/// List the wallet profiles stored under database_dir
+Future<List<WalletProfile>> listProfiles({required String databaseDir}) =>
+    RustLib.instance.api.crateApiCashuListProfiles(databaseDir: databaseDir);
+
+/// Initialize MultiMintWallet (deprecated - use open_wallet)
+/// Opens the default profile, later calls return "already initialized".
 Future<String> initMultiMintWallet({
   required String databaseDir,
   required String seedHex,
@@ -18,17 +115,53 @@ Future<String> initMultiMintWallet({
   seedHex: seedHex,
 );
 
+/// Change the key of a profile's encrypted wallet database
+/// The profile is closed first and has to be opened again with the new key.
+/// Sends and melts still running after the shutdown make this fail unless force is set.
+Future<String> rotateWalletDatabaseKey({
+  required String profileId,
+  required String databaseDir,
+  required String oldKeyMaterial,
+  required String newKeyMaterial,
+  required bool force,
+}) => RustLib.instance.api.crateApiCashuRotateWalletDatabaseKey(
+  profileId: profileId,
+  databaseDir: databaseDir,
+  oldKeyMaterial: oldKeyMaterial,
+  newKeyMaterial: newKeyMaterial,
+  force: force,
+);
+
+/// Check whether a profile's wallet database is encrypted
+Future<bool> isWalletDatabaseEncrypted({
+  required String profileId,
+  required String databaseDir,
+}) => RustLib.instance.api.crateApiCashuIsWalletDatabaseEncrypted(
+  profileId: profileId,
+  databaseDir: databaseDir,
+);
+
 /// Add a mint to MultiMintWallet - defaults to sat unit
-Future<String> addMint({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuAddMint(mintUrl: mintUrl);
+Future<String> addMint({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuAddMint(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// Remove a mint from MultiMintWallet - defaults to sat unit
-Future<String> removeMint({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuRemoveMint(mintUrl: mintUrl);
+Future<String> removeMint({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuRemoveMint(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// List all mints in MultiMintWallet
-Future<List<String>> listMints() =>
-    RustLib.instance.api.crateApiCashuListMints();
+Future<List<String>> listMints({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuListMints(handle: handle);
 
 /// Check if wallet exists
 Future<bool> walletExists({
@@ -39,34 +172,90 @@ Future<bool> walletExists({
   databaseDir: databaseDir,
 );
 
-/// Get wallet information (may make network requests for keyset info) - defaults to sat unit
-Future<WalletInfo> getWalletInfo({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuGetWalletInfo(mintUrl: mintUrl);
+/// Get wallet information - defaults to sat unit
+/// Keysets come from the local cache when there is one, stale keysets are refreshed
+/// in the background.
+Future<WalletInfo> getWalletInfo({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuGetWalletInfo(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// Get all transactions from all mints (fast, no network requests)
-Future<List<TransactionInfo>> getAllTransactions() =>
-    RustLib.instance.api.crateApiCashuGetAllTransactions();
+Future<List<TransactionInfo>> getAllTransactions({
+  required WalletHandle handle,
+}) => RustLib.instance.api.crateApiCashuGetAllTransactions(handle: handle);
 
 /// Get all wallet balances from all mints (fast, no network requests)
-Future<Map<String, BigInt>> getAllBalances() =>
-    RustLib.instance.api.crateApiCashuGetAllBalances();
+Future<Map<String, BigInt>> getAllBalances({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuGetAllBalances(handle: handle);
 
 /// Get mint information from NUT-06 endpoint - defaults to sat unit
-Future<MintInfo> getMintInfo({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuGetMintInfo(mintUrl: mintUrl);
+Future<MintInfo> getMintInfo({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuGetMintInfo(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// Send tokens using CDK MultiMintWallet API directly - defaults to sat unit
 Future<String> sendTokens({
+  required WalletHandle handle,
   required String mintUrl,
   required BigInt amount,
   String? memo,
 }) => RustLib.instance.api.crateApiCashuSendTokens(
+  handle: handle,
+  mintUrl: mintUrl,
+  amount: amount,
+  memo: memo,
+);
+
+/// Send tokens with an explicit encoding ("v3" for cashuA, "v4" for cashuB) - defaults to sat unit
+Future<EncodedToken> sendTokensWithEncoding({
+  required WalletHandle handle,
+  required String mintUrl,
+  required BigInt amount,
+  String? memo,
+  required String encoding,
+}) => RustLib.instance.api.crateApiCashuSendTokensWithEncoding(
+  handle: handle,
   mintUrl: mintUrl,
   amount: amount,
   memo: memo,
+  encoding: encoding,
+);
+
+/// Re-encode an existing token as V3 (cashuA) or V4 (cashuB)
+Future<EncodedToken> encodeToken({
+  required WalletHandle handle,
+  required String token,
+  required String encoding,
+}) => RustLib.instance.api.crateApiCashuEncodeToken(
+  handle: handle,
+  token: token,
+  encoding: encoding,
+);
+
+/// Bundle several tokens into one token
+/// Tokens from more than one mint can only be combined into a V3 (cashuA) token.
+Future<EncodedToken> combineTokens({
+  required WalletHandle handle,
+  required List<String> tokens,
+  String? memo,
+  required String encoding,
+}) => RustLib.instance.api.crateApiCashuCombineTokens(
+  handle: handle,
+  tokens: tokens,
+  memo: memo,
+  encoding: encoding,
 );
 
 Future<String> sendP2PkTokens({
+  required WalletHandle handle,
   required String mintUrl,
   required BigInt amount,
   String? memo,
@@ -78,6 +267,7 @@ Future<String> sendP2PkTokens({
   String? sigflag,
   BigInt? refundRequiredSigs,
 }) => RustLib.instance.api.crateApiCashuSendP2PkTokens(
+  handle: handle,
   mintUrl: mintUrl,
   amount: amount,
   memo: memo,
@@ -90,28 +280,213 @@ Future<String> sendP2PkTokens({
   refundRequiredSigs: refundRequiredSigs,
 );
 
+/// Send exactly the chosen proofs as a token (coin control)
+/// proof_ys are the `y` values from get_wallet_proofs, every proof has to be unspent.
+/// Without swap the token holds those proofs and they are marked pending until
+/// the receiver claims them. With swap they are exchanged at the mint, the send part
+/// goes into the token and is marked pending, change stays in the wallet.
+Future<String> sendSelectedProofs({
+  required WalletHandle handle,
+  required String mintUrl,
+  required List<String> proofYs,
+  required SelectedProofsSendOptions options,
+}) => RustLib.instance.api.crateApiCashuSendSelectedProofs(
+  handle: handle,
+  mintUrl: mintUrl,
+  proofYs: proofYs,
+  options: options,
+);
+
+/// Create a NUT-18 payment request (creqA...) - defaults to sat unit
+Future<String> createPaymentRequest({
+  BigInt? amount,
+  List<String>? mints,
+  String? description,
+  bool? singleUse,
+  String? p2PkPubkey,
+  required List<PaymentRequestTransport> transports,
+}) => RustLib.instance.api.crateApiCashuCreatePaymentRequest(
+  amount: amount,
+  mints: mints,
+  description: description,
+  singleUse: singleUse,
+  p2PkPubkey: p2PkPubkey,
+  transports: transports,
+);
+
+/// Decode a NUT-18 payment request
+Future<PaymentRequestInfo> decodePaymentRequest({required String request}) =>
+    RustLib.instance.api.crateApiCashuDecodePaymentRequest(request: request);
+
+/// Pay a NUT-18 payment request - defaults to sat unit
+/// The token is delivered through the first of the request's HTTP POST or Nostr
+/// transports that accepts it. If the request has no transport the token is returned for
+/// the caller to hand over in-band.
+/// amount is only used when the request does not specify one.
+Future<Map<String, String>> payPaymentRequest({
+  required WalletHandle handle,
+  required String request,
+  String? mintUrl,
+  BigInt? amount,
+}) => RustLib.instance.api.crateApiCashuPayPaymentRequest(
+  handle: handle,
+  request: request,
+  mintUrl: mintUrl,
+  amount: amount,
+);
+
 /// Receive tokens using CDK MultiMintWallet API directly - auto-detects mint URL from token
-Future<BigInt> receiveTokens({required String token}) =>
-    RustLib.instance.api.crateApiCashuReceiveTokens(token: token);
+Future<BigInt> receiveTokens({
+  required WalletHandle handle,
+  required String token,
+}) => RustLib.instance.api.crateApiCashuReceiveTokens(
+  handle: handle,
+  token: token,
+);
 
 /// Restore wallet balance for a specific mint
-Future<BigInt> restoreMint({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuRestoreMint(mintUrl: mintUrl);
+Future<BigInt> restoreMint({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuRestoreMint(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// Create mint quote using CDK MultiMintWallet API directly - defaults to sat unit
 Future<Map<String, String>> createMintQuote({
+  required WalletHandle handle,
   required String mintUrl,
   required BigInt amount,
   String? description,
 }) => RustLib.instance.api.crateApiCashuCreateMintQuote(
+  handle: handle,
+  mintUrl: mintUrl,
+  amount: amount,
+  description: description,
+);
+
+/// Redeem an LNURL-withdraw code into a mint quote - defaults to sat unit
+/// Progress is reported through the wallet event stream. If amount is None the
+/// maximum withdrawable amount is requested.
+Future<BigInt> redeemLnurlWithdraw({
+  required WalletHandle handle,
+  required String lnurl,
+  required String mintUrl,
+  BigInt? amount,
+}) => RustLib.instance.api.crateApiCashuRedeemLnurlWithdraw(
+  handle: handle,
+  lnurl: lnurl,
+  mintUrl: mintUrl,
+  amount: amount,
+);
+
+/// Recover the typed error from a wallet API error message
+/// Returns None for any other error.
+UnsupportedByMint? parseUnsupportedByMint({required String error}) =>
+    RustLib.instance.api.crateApiCashuParseUnsupportedByMint(error: error);
+
+/// Check a capability by name before starting a flow that needs it
+/// Names: bolt11_mint, bolt11_melt, bolt12_mint, bolt12_melt, token_state, restore,
+/// spending_conditions, p2pk, dleq, htlc, mpp, websocket.
+Future<void> checkMintCapability({
+  required WalletHandle handle,
+  required String mintUrl,
+  required String capability,
+}) => RustLib.instance.api.crateApiCashuCheckMintCapability(
+  handle: handle,
+  mintUrl: mintUrl,
+  capability: capability,
+);
+
+/// Create a reusable BOLT12 mint quote (offer) - defaults to sat unit
+/// If amount is None the offer is amountless and can be paid any number of times.
+Future<Map<String, String>> createBolt12MintQuote({
+  required WalletHandle handle,
+  required String mintUrl,
+  BigInt? amount,
+  String? description,
+}) => RustLib.instance.api.crateApiCashuCreateBolt12MintQuote(
+  handle: handle,
   mintUrl: mintUrl,
   amount: amount,
   description: description,
 );
 
-/// Get wallet proofs - defaults to sat unit
-Future<List<CashuProof>> getWalletProofs({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuGetWalletProofs(mintUrl: mintUrl);
+/// Mint whatever has been paid to a BOLT12 quote but not yet issued
+/// Offers can be paid repeatedly, so this can be called again after each payment.
+Future<Map<String, String>> mintBolt12Quote({
+  required WalletHandle handle,
+  required String mintUrl,
+  required String quoteId,
+}) => RustLib.instance.api.crateApiCashuMintBolt12Quote(
+  handle: handle,
+  mintUrl: mintUrl,
+  quoteId: quoteId,
+);
+
+/// Import proofs and tokens from another wallet's backup
+/// Accepts nutshell exports, cashu.me backups, JSON proof dumps and lists of tokens.
+/// Proofs are checked with their mints, unspent ones are swapped into this wallet.
+/// Mints in the backup are added to the wallet. A failing mint does not stop the others.
+Future<ImportReport> importWalletBackup({
+  required WalletHandle handle,
+  required String backup,
+}) => RustLib.instance.api.crateApiCashuImportWalletBackup(
+  handle: handle,
+  backup: backup,
+);
+
+/// Open the seed in another wallet's backup as a new profile and restore its mints
+/// Every mint the backup mentions is added to the profile and restored with NUT-09,
+/// which also moves the keyset counters past the ones the other wallet used.
+/// The mnemonic is only returned here, import_wallet_backup never reports it.
+Future<RestoredProfile> restoreBackupProfile({
+  required String profileId,
+  required String databaseDir,
+  required String backup,
+}) => RustLib.instance.api.crateApiCashuRestoreBackupProfile(
+  profileId: profileId,
+  databaseDir: databaseDir,
+  backup: backup,
+);
+
+/// Verify a token without the mint: DLEQ proofs against the keyset keys, keyset ids
+/// against their keys, and NUT-10 secret structure
+/// Works for mints that are not in the wallet. Keys that are not cached are fetched from
+/// the mint only when allow_fetch is set and the wallet is not in offline mode.
+/// Says nothing about whether the proofs are already spent.
+Future<TokenVerification> verifyTokenOffline({
+  required WalletHandle handle,
+  required String token,
+  required bool allowFetch,
+}) => RustLib.instance.api.crateApiCashuVerifyTokenOffline(
+  handle: handle,
+  token: token,
+  allowFetch: allowFetch,
+);
+
+/// Verify proofs of a mint without the mint, same checks as verify_token_offline
+Future<TokenVerification> verifyProofsOffline({
+  required WalletHandle handle,
+  required String mintUrl,
+  required List<CashuProof> proofs,
+  required bool allowFetch,
+}) => RustLib.instance.api.crateApiCashuVerifyProofsOffline(
+  handle: handle,
+  mintUrl: mintUrl,
+  proofs: proofs,
+  allowFetch: allowFetch,
+);
+
+/// Get wallet proofs that are not spent yet, with their state - defaults to sat unit
+Future<List<CashuProof>> getWalletProofs({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuGetWalletProofs(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// Parse Cashu token string
 Future<Map<String, String>> parseCashuToken({required String token}) =>
@@ -135,21 +510,64 @@ Future<String> seedHexToMnemonic({required String seedHex}) =>
 
 /// Pay lightning invoice using wallet tokens - defaults to sat unit
 Future<String> payInvoiceForWallet({
+  required WalletHandle handle,
   required String mintUrl,
   required String bolt11Invoice,
   BigInt? maxFeeSats,
 }) => RustLib.instance.api.crateApiCashuPayInvoiceForWallet(
+  handle: handle,
   mintUrl: mintUrl,
   bolt11Invoice: bolt11Invoice,
   maxFeeSats: maxFeeSats,
 );
 
+/// Pay a BOLT12 offer using wallet tokens - defaults to sat unit
+/// amount_sats is required when the offer does not specify an amount.
+Future<String> payBolt12Offer({
+  required WalletHandle handle,
+  required String mintUrl,
+  required String offer,
+  BigInt? amountSats,
+}) => RustLib.instance.api.crateApiCashuPayBolt12Offer(
+  handle: handle,
+  mintUrl: mintUrl,
+  offer: offer,
+  amountSats: amountSats,
+);
+
+/// Resolve a lightning address (name@domain) or LNURL into its pay request
+/// The request goes through the wallet's proxy or Tor like mint connections do.
+Future<LnurlPayInfo> resolveLnurlPay({
+  required WalletHandle handle,
+  required String lnurl,
+}) => RustLib.instance.api.crateApiCashuResolveLnurlPay(
+  handle: handle,
+  lnurl: lnurl,
+);
+
+/// Pay a lightning address or LNURL-pay code using wallet tokens - defaults to sat unit
+Future<String> payLnurl({
+  required WalletHandle handle,
+  required String mintUrl,
+  required String lnurl,
+  required BigInt amountSats,
+  String? comment,
+}) => RustLib.instance.api.crateApiCashuPayLnurl(
+  handle: handle,
+  mintUrl: mintUrl,
+  lnurl: lnurl,
+  amountSats: amountSats,
+  comment: comment,
+);
+
 /// Verify token matches p2pk conditions - defaults to sat unit
 Future<bool> verifyTokenP2Pk({
+  required WalletHandle handle,
   required String mintUrl,
   required String token,
   required String conditions,
 }) => RustLib.instance.api.crateApiCashuVerifyTokenP2Pk(
+  handle: handle,
   mintUrl: mintUrl,
   token: token,
   conditions: conditions,
@@ -157,28 +575,41 @@ Future<bool> verifyTokenP2Pk({
 
 /// Verify all proofs in token have valid dleq proof - defaults to sat unit
 Future<bool> verifyTokenDleq({
+  required WalletHandle handle,
   required String mintUrl,
   required String token,
 }) => RustLib.instance.api.crateApiCashuVerifyTokenDleq(
+  handle: handle,
   mintUrl: mintUrl,
   token: token,
 );
 
 /// Check all mint quotes and automatically mint if paid - defaults to sat unit
-Future<String> checkMintQuoteStatus({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuCheckMintQuoteStatus(mintUrl: mintUrl);
+Future<String> checkMintQuoteStatus({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuCheckMintQuoteStatus(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// Check all mint quotes across all wallets and automatically mint if paid
-Future<Map<String, String>> checkAllMintQuotes() =>
-    RustLib.instance.api.crateApiCashuCheckAllMintQuotes();
+Future<Map<String, String>> checkAllMintQuotes({
+  required WalletHandle handle,
+}) => RustLib.instance.api.crateApiCashuCheckAllMintQuotes(handle: handle);
 
 /// Check melt quote status for a specific mint URL - automatically checks all melt quotes
-Future<String> checkMeltQuoteStatus({required String mintUrl}) =>
-    RustLib.instance.api.crateApiCashuCheckMeltQuoteStatus(mintUrl: mintUrl);
+Future<String> checkMeltQuoteStatus({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuCheckMeltQuoteStatus(
+  handle: handle,
+  mintUrl: mintUrl,
+);
 
 /// Check all melt quotes across all wallets and return completed count
-Future<String> checkAllMeltQuotes() =>
-    RustLib.instance.api.crateApiCashuCheckAllMeltQuotes();
+Future<String> checkAllMeltQuotes({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuCheckAllMeltQuotes(handle: handle);
 
 /// Validate a mnemonic phrase
 Future<bool> validateMnemonicPhrase({required String mnemonicPhrase}) => RustLib
@@ -186,23 +617,59 @@ Future<bool> validateMnemonicPhrase({required String mnemonicPhrase}) => RustLib
     .api
     .crateApiCashuValidateMnemonicPhrase(mnemonicPhrase: mnemonicPhrase);
 
-/// Set Tor configuration (deprecated - Tor is now auto-detected for .onion addresses)
-/// This function is kept for FFI compatibility but does nothing.
-/// The policy parameter is ignored.
-Future<void> setTorConfig({required bool policy}) =>
-    RustLib.instance.api.crateApiCashuSetTorConfig(policy: policy);
+/// Set the Tor policy: "always", "onion_only" or "never"
+/// The policy is saved with the profile and applies when the wallet is next opened.
+/// Returns true when the wallet has to be reopened for the change to take effect.
+Future<bool> setTorConfig({
+  required WalletHandle handle,
+  required String policy,
+}) => RustLib.instance.api.crateApiCashuSetTorConfig(
+  handle: handle,
+  policy: policy,
+);
+
+/// Get the saved Tor policy
+Future<String> getTorConfig({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuGetTorConfig(handle: handle);
+
+/// Check if the open wallet may use Tor
+Future<bool> isTorEnabled({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuIsTorEnabled(handle: handle);
+
+/// Check if Tor has finished bootstrapping for the wallet
+Future<bool> isTorReady({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuIsTorReady(handle: handle);
 
-/// Get current Tor configuration (deprecated)
-Future<void> getTorConfig() => RustLib.instance.api.crateApiCashuGetTorConfig();
+/// Current Tor bootstrap progress for the wallet
+Future<TorBootstrapStatus> getTorBootstrapStatus({
+  required WalletHandle handle,
+}) => RustLib.instance.api.crateApiCashuGetTorBootstrapStatus(handle: handle);
 
-/// Check if Tor is currently enabled (deprecated - always returns true if tor feature enabled)
-Future<bool> isTorEnabled() => RustLib.instance.api.crateApiCashuIsTorEnabled();
+/// Stream Tor bootstrap progress until Tor is ready or fails
+/// Starts the bootstrap unless the policy is "never".
+Stream<TorBootstrapStatus> subscribeTorBootstrap({
+  required WalletHandle handle,
+}) => RustLib.instance.api.crateApiCashuSubscribeTorBootstrap(handle: handle);
 
-/// Check if Tor is ready (deprecated - not available in new implementation)
-Future<bool> isTorReady() => RustLib.instance.api.crateApiCashuIsTorReady();
+/// Give every mint reached over Tor its own circuits, so mints cannot be correlated
+/// Applies right away to the wallet's mints.
+Future<void> setTorIsolation({
+  required WalletHandle handle,
+  required bool enabled,
+}) => RustLib.instance.api.crateApiCashuSetTorIsolation(
+  handle: handle,
+  enabled: enabled,
+);
+
+Future<bool> getTorIsolation({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuGetTorIsolation(handle: handle);
 
-/// Reinitialize MultiMintWallet with current Tor configuration (deprecated)
-/// Tor is now automatically used for .onion addresses, no reinitialization needed.
+/// Move every mint reached over Tor onto fresh circuits
+/// Returns the number of mints that got a new transport.
+Future<int> newTorIdentity({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuNewTorIdentity(handle: handle);
+
+/// Reinitialize MultiMintWallet with the saved Tor policy (deprecated, use close_wallet and open_wallet)
 Future<String> reinitializeWithTorConfig({
   required String databaseDir,
   required String seedHex,
@@ -211,9 +678,8 @@ Future<String> reinitializeWithTorConfig({
   seedHex: seedHex,
 );
 
-/// Initialize MultiMintWallet with Tor configuration (deprecated)
-/// Tor is now automatically used for .onion addresses, no special initialization needed.
-/// The tor_config parameter is ignored.
+/// Initialize MultiMintWallet with a Tor policy (deprecated, use set_tor_config and open_wallet)
+/// A tor_config of "always", "onion_only" or "never" is saved for the default profile first.
 Future<String> initMultiMintWalletWithTor({
   required String databaseDir,
   required String seedHex,
@@ -224,27 +690,240 @@ Future<String> initMultiMintWalletWithTor({
   torConfig: torConfig,
 );
 
+/// Set the proxy for every mint of the wallet, None connects directly
+/// Applies right away to the mints without a proxy of their own.
+/// The password is stored encrypted with a key derived from the wallet seed.
+Future<void> setWalletProxy({
+  required WalletHandle handle,
+  ProxyConfig? proxy,
+}) => RustLib.instance.api.crateApiCashuSetWalletProxy(
+  handle: handle,
+  proxy: proxy,
+);
+
+Future<ProxyConfig?> getWalletProxy({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiCashuGetWalletProxy(handle: handle);
+
+/// Set the proxy for one mint, overriding the wallet proxy. None falls back to the wallet proxy.
+/// Applies right away if the mint is in the wallet, otherwise when it is added.
+Future<void> setMintProxy({
+  required WalletHandle handle,
+  required String mintUrl,
+  ProxyConfig? proxy,
+}) => RustLib.instance.api.crateApiCashuSetMintProxy(
+  handle: handle,
+  mintUrl: mintUrl,
+  proxy: proxy,
+);
+
+/// The proxy a mint's requests go through, its own or the wallet's
+Future<ProxyConfig?> getMintProxy({
+  required WalletHandle handle,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuGetMintProxy(
+  handle: handle,
+  mintUrl: mintUrl,
+);
+
+/// Check that a mint's info endpoint can be reached through the proxy
+Future<ProxyTestResult> testProxy({
+  required ProxyConfig proxy,
+  required String mintUrl,
+}) => RustLib.instance.api.crateApiCashuTestProxy(
+  proxy: proxy,
+  mintUrl: mintUrl,
+);
+
 /// Decode a bolt11 lightning invoice to extract amount and other info
 Future<String> decodeBolt11Invoice({required String invoice}) =>
     RustLib.instance.api.crateApiCashuDecodeBolt11Invoice(invoice: invoice);
 
-/// Cashu proof structure for FFI
+/// Decode a BOLT12 offer (lno...) into its fields
+Future<Bolt12OfferInfo> decodeBolt12Offer({required String offer}) =>
+    RustLib.instance.api.crateApiCashuDecodeBolt12Offer(offer: offer);
+
+/// Decode a hex encoded BOLT12 invoice request into its fields
+Future<Bolt12InvoiceRequestInfo> decodeBolt12InvoiceRequest({
+  required String invoiceRequestHex,
+}) => RustLib.instance.api.crateApiCashuDecodeBolt12InvoiceRequest(
+  invoiceRequestHex: invoiceRequestHex,
+);
+
+/// Decoded BOLT12 invoice request
+class Bolt12InvoiceRequestInfo {
+  final String payerPubkey;
+  final BigInt? amountMsat;
+  final BigInt? quantity;
+  final String? payerNote;
+  final String chain;
+  final String? offerDescription;
+  final String? offerIssuer;
+
+  const Bolt12InvoiceRequestInfo({
+    required this.payerPubkey,
+    this.amountMsat,
+    this.quantity,
+    this.payerNote,
+    required this.chain,
+    this.offerDescription,
+    this.offerIssuer,
+  });
+
+  @override
+  int get hashCode =>
+      payerPubkey.hashCode ^
+      amountMsat.hashCode ^
+      quantity.hashCode ^
+      payerNote.hashCode ^
+      chain.hashCode ^
+      offerDescription.hashCode ^
+      offerIssuer.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Bolt12InvoiceRequestInfo &&
+          runtimeType == other.runtimeType &&
+          payerPubkey == other.payerPubkey &&
+          amountMsat == other.amountMsat &&
+          quantity == other.quantity &&
+          payerNote == other.payerNote &&
+          chain == other.chain &&
+          offerDescription == other.offerDescription &&
+          offerIssuer == other.offerIssuer;
+}
+
+/// Decoded BOLT12 offer
+class Bolt12OfferInfo {
+  final String offerId;
+  final BigInt? amountMsat;
+  final String? currency;
+  final BigInt? currencyAmount;
+  final String? description;
+  final String? issuer;
+  final String? issuerPubkey;
+  final BigInt? absoluteExpiry;
+  final List<String> chains;
+  final bool isExpired;
+
+  const Bolt12OfferInfo({
+    required this.offerId,
+    this.amountMsat,
+    this.currency,
+    this.currencyAmount,
+    this.description,
+    this.issuer,
+    this.issuerPubkey,
+    this.absoluteExpiry,
+    required this.chains,
+    required this.isExpired,
+  });
+
+  @override
+  int get hashCode =>
+      offerId.hashCode ^
+      amountMsat.hashCode ^
+      currency.hashCode ^
+      currencyAmount.hashCode ^
+      description.hashCode ^
+      issuer.hashCode ^
+      issuerPubkey.hashCode ^
+      absoluteExpiry.hashCode ^
+      chains.hashCode ^
+      isExpired.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Bolt12OfferInfo &&
+          runtimeType == other.runtimeType &&
+          offerId == other.offerId &&
+          amountMsat == other.amountMsat &&
+          currency == other.currency &&
+          currencyAmount == other.currencyAmount &&
+          description == other.description &&
+          issuer == other.issuer &&
+          issuerPubkey == other.issuerPubkey &&
+          absoluteExpiry == other.absoluteExpiry &&
+          chains == other.chains &&
+          isExpired == other.isExpired;
+}
+
+/// NUT-19 endpoint whose responses the mint caches
+class CachedEndpoint {
+  final String method;
+  final String path;
+
+  const CachedEndpoint({required this.method, required this.path});
+
+  @override
+  int get hashCode => method.hashCode ^ path.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CachedEndpoint &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          path == other.path;
+}
+
+/// NUT-12 DLEQ proof, hex encoded
+class CashuDleq {
+  final String e;
+  final String s;
+  final String r;
+
+  const CashuDleq({required this.e, required this.s, required this.r});
+
+  @override
+  int get hashCode => e.hashCode ^ s.hashCode ^ r.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CashuDleq &&
+          runtimeType == other.runtimeType &&
+          e == other.e &&
+          s == other.s &&
+          r == other.r;
+}
+
+/// Cashu proof structure for FFI, converts back to the exact CDK proof
 class CashuProof {
   final String id;
   final BigInt amount;
-  final String secret;
+  final CashuSecret secret;
   final String c;
+  final CashuWitness? witness;
+  final CashuDleq? dleq;
+  final String y;
+  final String? state;
+  final bool reserved;
 
   const CashuProof({
     required this.id,
     required this.amount,
     required this.secret,
     required this.c,
+    this.witness,
+    this.dleq,
+    required this.y,
+    this.state,
+    required this.reserved,
   });
 
   @override
   int get hashCode =>
-      id.hashCode ^ amount.hashCode ^ secret.hashCode ^ c.hashCode;
+      id.hashCode ^
+      amount.hashCode ^
+      secret.hashCode ^
+      c.hashCode ^
+      witness.hashCode ^
+      dleq.hashCode ^
+      y.hashCode ^
+      state.hashCode ^
+      reserved.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -254,7 +933,67 @@ class CashuProof {
           id == other.id &&
           amount == other.amount &&
           secret == other.secret &&
-          c == other.c;
+          c == other.c &&
+          witness == other.witness &&
+          dleq == other.dleq &&
+          y == other.y &&
+          state == other.state &&
+          reserved == other.reserved;
+}
+
+/// Proof secret, either a plain random string or a NUT-10 well-known secret
+class CashuSecret {
+  final String raw;
+  final String? kind;
+  final String? nonce;
+  final String? data;
+  final List<List<String>> tags;
+
+  const CashuSecret({
+    required this.raw,
+    this.kind,
+    this.nonce,
+    this.data,
+    required this.tags,
+  });
+
+  @override
+  int get hashCode =>
+      raw.hashCode ^
+      kind.hashCode ^
+      nonce.hashCode ^
+      data.hashCode ^
+      tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CashuSecret &&
+          runtimeType == other.runtimeType &&
+          raw == other.raw &&
+          kind == other.kind &&
+          nonce == other.nonce &&
+          data == other.data &&
+          tags == other.tags;
+}
+
+/// Witness unlocking a NUT-10 secret
+class CashuWitness {
+  final String? preimage;
+  final List<String>? signatures;
+
+  const CashuWitness({this.preimage, this.signatures});
+
+  @override
+  int get hashCode => preimage.hashCode ^ signatures.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CashuWitness &&
+          runtimeType == other.runtimeType &&
+          preimage == other.preimage &&
+          signatures == other.signatures;
 }
 
 /// Contact information structure
@@ -276,32 +1015,297 @@ class ContactInfo {
           info == other.info;
 }
 
-/// Mint information structure for NUT-06
-class MintInfo {
-  final String? name;
-  final String? version;
-  final String? description;
-  final String? descriptionLong;
-  final List<ContactInfo>? contact;
-  final String? motd;
-  final String? iconUrl;
-  final List<String>? urls;
-  final List<String>? nuts;
-  final String? publicKey;
-  final String? additionalInfo;
+/// Encoded token along with what callers need to pick a QR strategy
+class EncodedToken {
+  final String token;
+  final String encoding;
+  final BigInt byteSize;
+  final BigInt amount;
+  final BigInt proofsCount;
+  final List<String> mintUrls;
 
-  const MintInfo({
-    this.name,
-    this.version,
-    this.description,
-    this.descriptionLong,
-    this.contact,
-    this.motd,
+  const EncodedToken({
+    required this.token,
+    required this.encoding,
+    required this.byteSize,
+    required this.amount,
+    required this.proofsCount,
+    required this.mintUrls,
+  });
+
+  @override
+  int get hashCode =>
+      token.hashCode ^
+      encoding.hashCode ^
+      byteSize.hashCode ^
+      amount.hashCode ^
+      proofsCount.hashCode ^
+      mintUrls.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EncodedToken &&
+          runtimeType == other.runtimeType &&
+          token == other.token &&
+          encoding == other.encoding &&
+          byteSize == other.byteSize &&
+          amount == other.amount &&
+          proofsCount == other.proofsCount &&
+          mintUrls == other.mintUrls;
+}
+
+/// Report of a backup import
+class ImportReport {
+  final String format;
+  final List<ImportedMint> mints;
+  final BigInt importedAmount;
+  final BigInt unassignedProofs;
+  final bool hasMnemonic;
+  final List<KeysetCounter> keysetCounters;
+
+  const ImportReport({
+    required this.format,
+    required this.mints,
+    required this.importedAmount,
+    required this.unassignedProofs,
+    required this.hasMnemonic,
+    required this.keysetCounters,
+  });
+
+  @override
+  int get hashCode =>
+      format.hashCode ^
+      mints.hashCode ^
+      importedAmount.hashCode ^
+      unassignedProofs.hashCode ^
+      hasMnemonic.hashCode ^
+      keysetCounters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportReport &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          mints == other.mints &&
+          importedAmount == other.importedAmount &&
+          unassignedProofs == other.unassignedProofs &&
+          hasMnemonic == other.hasMnemonic &&
+          keysetCounters == other.keysetCounters;
+}
+
+/// What happened to one mint's proofs during a backup import
+class ImportedMint {
+  final String mintUrl;
+  final BigInt proofsFound;
+  final BigInt amountFound;
+  final BigInt spentAmount;
+  final BigInt importedAmount;
+  final String? error;
+
+  const ImportedMint({
+    required this.mintUrl,
+    required this.proofsFound,
+    required this.amountFound,
+    required this.spentAmount,
+    required this.importedAmount,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      mintUrl.hashCode ^
+      proofsFound.hashCode ^
+      amountFound.hashCode ^
+      spentAmount.hashCode ^
+      importedAmount.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportedMint &&
+          runtimeType == other.runtimeType &&
+          mintUrl == other.mintUrl &&
+          proofsFound == other.proofsFound &&
+          amountFound == other.amountFound &&
+          spentAmount == other.spentAmount &&
+          importedAmount == other.importedAmount &&
+          error == other.error;
+}
+
+/// Send or melt that had not finished when the wallet was shut down or the app died
+class InterruptedOperation {
+  final String id;
+  final String kind;
+  final String mintUrl;
+  final BigInt amount;
+  final BigInt startedAt;
+
+  const InterruptedOperation({
+    required this.id,
+    required this.kind,
+    required this.mintUrl,
+    required this.amount,
+    required this.startedAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      kind.hashCode ^
+      mintUrl.hashCode ^
+      amount.hashCode ^
+      startedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InterruptedOperation &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          kind == other.kind &&
+          mintUrl == other.mintUrl &&
+          amount == other.amount &&
+          startedAt == other.startedAt;
+}
+
+/// Deterministic secret counter of a keyset in the imported wallet
+class KeysetCounter {
+  final String keysetId;
+  final int counter;
+
+  const KeysetCounter({required this.keysetId, required this.counter});
+
+  @override
+  int get hashCode => keysetId.hashCode ^ counter.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeysetCounter &&
+          runtimeType == other.runtimeType &&
+          keysetId == other.keysetId &&
+          counter == other.counter;
+}
+
+/// Resolved LNURL-pay request (LUD-06 / LUD-16)
+class LnurlPayInfo {
+  final String callback;
+  final BigInt minSendableSats;
+  final BigInt maxSendableSats;
+  final BigInt commentAllowed;
+  final String? description;
+  final String metadata;
+  final String domain;
+
+  const LnurlPayInfo({
+    required this.callback,
+    required this.minSendableSats,
+    required this.maxSendableSats,
+    required this.commentAllowed,
+    this.description,
+    required this.metadata,
+    required this.domain,
+  });
+
+  @override
+  int get hashCode =>
+      callback.hashCode ^
+      minSendableSats.hashCode ^
+      maxSendableSats.hashCode ^
+      commentAllowed.hashCode ^
+      description.hashCode ^
+      metadata.hashCode ^
+      domain.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnurlPayInfo &&
+          runtimeType == other.runtimeType &&
+          callback == other.callback &&
+          minSendableSats == other.minSendableSats &&
+          maxSendableSats == other.maxSendableSats &&
+          commentAllowed == other.commentAllowed &&
+          description == other.description &&
+          metadata == other.metadata &&
+          domain == other.domain;
+}
+
+/// NUT-21 / NUT-22 authentication the mint requires
+class MintAuthSettings {
+  final String? openidDiscovery;
+  final String? clientId;
+  final List<String> clearAuthEndpoints;
+  final BigInt? blindAuthMaxMint;
+  final List<String> blindAuthEndpoints;
+
+  const MintAuthSettings({
+    this.openidDiscovery,
+    this.clientId,
+    required this.clearAuthEndpoints,
+    this.blindAuthMaxMint,
+    required this.blindAuthEndpoints,
+  });
+
+  @override
+  int get hashCode =>
+      openidDiscovery.hashCode ^
+      clientId.hashCode ^
+      clearAuthEndpoints.hashCode ^
+      blindAuthMaxMint.hashCode ^
+      blindAuthEndpoints.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MintAuthSettings &&
+          runtimeType == other.runtimeType &&
+          openidDiscovery == other.openidDiscovery &&
+          clientId == other.clientId &&
+          clearAuthEndpoints == other.clearAuthEndpoints &&
+          blindAuthMaxMint == other.blindAuthMaxMint &&
+          blindAuthEndpoints == other.blindAuthEndpoints;
+}
+
+/// Mint information structure for NUT-06
+class MintInfo {
+  final String? name;
+  final String? version;
+  final String? description;
+  final String? descriptionLong;
+  final List<ContactInfo>? contact;
+  final String? motd;
+  final String? iconUrl;
+  final List<String>? urls;
+  final List<String>? nuts;
+  final String? publicKey;
+  final String? additionalInfo;
+  final String? tosUrl;
+  final BigInt? time;
+  final MintSettings settings;
+  final BigInt? fetchedAt;
+  final bool isStale;
+
+  const MintInfo({
+    this.name,
+    this.version,
+    this.description,
+    this.descriptionLong,
+    this.contact,
+    this.motd,
     this.iconUrl,
     this.urls,
     this.nuts,
     this.publicKey,
     this.additionalInfo,
+    this.tosUrl,
+    this.time,
+    required this.settings,
+    this.fetchedAt,
+    required this.isStale,
   });
 
   @override
@@ -316,7 +1320,12 @@ class MintInfo {
       urls.hashCode ^
       nuts.hashCode ^
       publicKey.hashCode ^
-      additionalInfo.hashCode;
+      additionalInfo.hashCode ^
+      tosUrl.hashCode ^
+      time.hashCode ^
+      settings.hashCode ^
+      fetchedAt.hashCode ^
+      isStale.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -333,7 +1342,409 @@ class MintInfo {
           urls == other.urls &&
           nuts == other.nuts &&
           publicKey == other.publicKey &&
-          additionalInfo == other.additionalInfo;
+          additionalInfo == other.additionalInfo &&
+          tosUrl == other.tosUrl &&
+          time == other.time &&
+          settings == other.settings &&
+          fetchedAt == other.fetchedAt &&
+          isStale == other.isStale;
+}
+
+/// Typed NUT-06 settings
+class MintSettings {
+  final List<PaymentMethodSettings> mintMethods;
+  final bool mintingDisabled;
+  final List<PaymentMethodSettings> meltMethods;
+  final bool meltingDisabled;
+  final List<int> supportedNuts;
+  final List<PaymentMethodSettings> mppMethods;
+  final List<WebSocketSettings> websocket;
+  final BigInt? cacheTtl;
+  final List<CachedEndpoint> cachedEndpoints;
+  final MintAuthSettings? auth;
+
+  const MintSettings({
+    required this.mintMethods,
+    required this.mintingDisabled,
+    required this.meltMethods,
+    required this.meltingDisabled,
+    required this.supportedNuts,
+    required this.mppMethods,
+    required this.websocket,
+    this.cacheTtl,
+    required this.cachedEndpoints,
+    this.auth,
+  });
+
+  @override
+  int get hashCode =>
+      mintMethods.hashCode ^
+      mintingDisabled.hashCode ^
+      meltMethods.hashCode ^
+      meltingDisabled.hashCode ^
+      supportedNuts.hashCode ^
+      mppMethods.hashCode ^
+      websocket.hashCode ^
+      cacheTtl.hashCode ^
+      cachedEndpoints.hashCode ^
+      auth.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MintSettings &&
+          runtimeType == other.runtimeType &&
+          mintMethods == other.mintMethods &&
+          mintingDisabled == other.mintingDisabled &&
+          meltMethods == other.meltMethods &&
+          meltingDisabled == other.meltingDisabled &&
+          supportedNuts == other.supportedNuts &&
+          mppMethods == other.mppMethods &&
+          websocket == other.websocket &&
+          cacheTtl == other.cacheTtl &&
+          cachedEndpoints == other.cachedEndpoints &&
+          auth == other.auth;
+}
+
+/// NUT-04 / NUT-05 settings for one payment method and unit
+class PaymentMethodSettings {
+  final String method;
+  final String unit;
+  final BigInt? minAmount;
+  final BigInt? maxAmount;
+  final bool description;
+  final bool amountless;
+
+  const PaymentMethodSettings({
+    required this.method,
+    required this.unit,
+    this.minAmount,
+    this.maxAmount,
+    required this.description,
+    required this.amountless,
+  });
+
+  @override
+  int get hashCode =>
+      method.hashCode ^
+      unit.hashCode ^
+      minAmount.hashCode ^
+      maxAmount.hashCode ^
+      description.hashCode ^
+      amountless.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentMethodSettings &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          unit == other.unit &&
+          minAmount == other.minAmount &&
+          maxAmount == other.maxAmount &&
+          description == other.description &&
+          amountless == other.amountless;
+}
+
+/// Decoded NUT-18 payment request
+class PaymentRequestInfo {
+  final String? paymentId;
+  final BigInt? amount;
+  final String? unit;
+  final bool? singleUse;
+  final List<String> mints;
+  final String? description;
+  final List<PaymentRequestTransport> transports;
+  final String? lockKind;
+  final String? lockData;
+
+  const PaymentRequestInfo({
+    this.paymentId,
+    this.amount,
+    this.unit,
+    this.singleUse,
+    required this.mints,
+    this.description,
+    required this.transports,
+    this.lockKind,
+    this.lockData,
+  });
+
+  @override
+  int get hashCode =>
+      paymentId.hashCode ^
+      amount.hashCode ^
+      unit.hashCode ^
+      singleUse.hashCode ^
+      mints.hashCode ^
+      description.hashCode ^
+      transports.hashCode ^
+      lockKind.hashCode ^
+      lockData.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentRequestInfo &&
+          runtimeType == other.runtimeType &&
+          paymentId == other.paymentId &&
+          amount == other.amount &&
+          unit == other.unit &&
+          singleUse == other.singleUse &&
+          mints == other.mints &&
+          description == other.description &&
+          transports == other.transports &&
+          lockKind == other.lockKind &&
+          lockData == other.lockData;
+}
+
+/// NUT-18 payment request transport
+class PaymentRequestTransport {
+  final String transportType;
+  final String target;
+  final List<List<String>> tags;
+
+  const PaymentRequestTransport({
+    required this.transportType,
+    required this.target,
+    required this.tags,
+  });
+
+  @override
+  int get hashCode => transportType.hashCode ^ target.hashCode ^ tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentRequestTransport &&
+          runtimeType == other.runtimeType &&
+          transportType == other.transportType &&
+          target == other.target &&
+          tags == other.tags;
+}
+
+/// Offline verification result for one proof
+class ProofVerification {
+  final String y;
+  final BigInt amount;
+  final String keysetId;
+  final bool? dleqValid;
+  final bool secretValid;
+  final String? error;
+
+  const ProofVerification({
+    required this.y,
+    required this.amount,
+    required this.keysetId,
+    this.dleqValid,
+    required this.secretValid,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      y.hashCode ^
+      amount.hashCode ^
+      keysetId.hashCode ^
+      dleqValid.hashCode ^
+      secretValid.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProofVerification &&
+          runtimeType == other.runtimeType &&
+          y == other.y &&
+          amount == other.amount &&
+          keysetId == other.keysetId &&
+          dleqValid == other.dleqValid &&
+          secretValid == other.secretValid &&
+          error == other.error;
+}
+
+/// Result of a proxy connectivity test
+class ProxyTestResult {
+  final bool success;
+  final BigInt latencyMs;
+  final String? error;
+
+  const ProxyTestResult({
+    required this.success,
+    required this.latencyMs,
+    this.error,
+  });
+
+  @override
+  int get hashCode => success.hashCode ^ latencyMs.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxyTestResult &&
+          runtimeType == other.runtimeType &&
+          success == other.success &&
+          latencyMs == other.latencyMs &&
+          error == other.error;
+}
+
+/// What NUT-09 restore recovered from one mint of a backup's seed
+class RestoredMint {
+  final String mintUrl;
+  final BigInt restoredAmount;
+  final String? error;
+
+  const RestoredMint({
+    required this.mintUrl,
+    required this.restoredAmount,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      mintUrl.hashCode ^ restoredAmount.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestoredMint &&
+          runtimeType == other.runtimeType &&
+          mintUrl == other.mintUrl &&
+          restoredAmount == other.restoredAmount &&
+          error == other.error;
+}
+
+/// Profile opened with the seed of another wallet's backup
+class RestoredProfile {
+  final WalletHandle handle;
+  final String mnemonic;
+  final List<RestoredMint> mints;
+  final BigInt restoredAmount;
+  final List<KeysetCounter> keysetCounters;
+
+  const RestoredProfile({
+    required this.handle,
+    required this.mnemonic,
+    required this.mints,
+    required this.restoredAmount,
+    required this.keysetCounters,
+  });
+
+  @override
+  int get hashCode =>
+      handle.hashCode ^
+      mnemonic.hashCode ^
+      mints.hashCode ^
+      restoredAmount.hashCode ^
+      keysetCounters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestoredProfile &&
+          runtimeType == other.runtimeType &&
+          handle == other.handle &&
+          mnemonic == other.mnemonic &&
+          mints == other.mints &&
+          restoredAmount == other.restoredAmount &&
+          keysetCounters == other.keysetCounters;
+}
+
+/// How send_selected_proofs turns the chosen proofs into a token
+class SelectedProofsSendOptions {
+  final bool swap;
+  final BigInt? amount;
+  final String? memo;
+
+  const SelectedProofsSendOptions({required this.swap, this.amount, this.memo});
+
+  @override
+  int get hashCode => swap.hashCode ^ amount.hashCode ^ memo.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SelectedProofsSendOptions &&
+          runtimeType == other.runtimeType &&
+          swap == other.swap &&
+          amount == other.amount &&
+          memo == other.memo;
+}
+
+/// Offline verification of a token or proofs against the mint's keysets
+class TokenVerification {
+  final String mintUrl;
+  final BigInt amount;
+  final bool keysetsValid;
+  final bool fetchedKeys;
+  final bool valid;
+  final List<ProofVerification> proofs;
+
+  const TokenVerification({
+    required this.mintUrl,
+    required this.amount,
+    required this.keysetsValid,
+    required this.fetchedKeys,
+    required this.valid,
+    required this.proofs,
+  });
+
+  @override
+  int get hashCode =>
+      mintUrl.hashCode ^
+      amount.hashCode ^
+      keysetsValid.hashCode ^
+      fetchedKeys.hashCode ^
+      valid.hashCode ^
+      proofs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TokenVerification &&
+          runtimeType == other.runtimeType &&
+          mintUrl == other.mintUrl &&
+          amount == other.amount &&
+          keysetsValid == other.keysetsValid &&
+          fetchedKeys == other.fetchedKeys &&
+          valid == other.valid &&
+          proofs == other.proofs;
+}
+
+/// Progress of the Tor bootstrap for a wallet profile
+class TorBootstrapStatus {
+  final bool started;
+  final double progress;
+  final bool ready;
+  final String message;
+  final String? error;
+
+  const TorBootstrapStatus({
+    required this.started,
+    required this.progress,
+    required this.ready,
+    required this.message,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      started.hashCode ^
+      progress.hashCode ^
+      ready.hashCode ^
+      message.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TorBootstrapStatus &&
+          runtimeType == other.runtimeType &&
+          started == other.started &&
+          progress == other.progress &&
+          ready == other.ready &&
+          message == other.message &&
+          error == other.error;
 }
 
 /// Transaction information structure
@@ -396,18 +1807,66 @@ class TransactionInfo {
           metadata == other.metadata;
 }
 
+/// Error for an operation the mint does not support
+/// Wallet APIs return it as a string, parse_unsupported_by_mint turns it back into
+/// this struct.
+class UnsupportedByMint {
+  final String mintUrl;
+  final int nut;
+  final String capability;
+
+  const UnsupportedByMint({
+    required this.mintUrl,
+    required this.nut,
+    required this.capability,
+  });
+
+  @override
+  int get hashCode => mintUrl.hashCode ^ nut.hashCode ^ capability.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UnsupportedByMint &&
+          runtimeType == other.runtimeType &&
+          mintUrl == other.mintUrl &&
+          nut == other.nut &&
+          capability == other.capability;
+}
+
+/// Handle to an open wallet profile, passed to every wallet API
+class WalletHandle {
+  final String profileId;
+
+  const WalletHandle({required this.profileId});
+
+  @override
+  int get hashCode => profileId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletHandle &&
+          runtimeType == other.runtimeType &&
+          profileId == other.profileId;
+}
+
 /// Wallet information structure
 class WalletInfo {
   final String mintUrl;
   final String unit;
   final BigInt balance;
   final String activeKeysetId;
+  final BigInt? fetchedAt;
+  final bool isStale;
 
   const WalletInfo({
     required this.mintUrl,
     required this.unit,
     required this.balance,
     required this.activeKeysetId,
+    this.fetchedAt,
+    required this.isStale,
   });
 
   @override
@@ -415,7 +1874,9 @@ class WalletInfo {
       mintUrl.hashCode ^
       unit.hashCode ^
       balance.hashCode ^
-      activeKeysetId.hashCode;
+      activeKeysetId.hashCode ^
+      fetchedAt.hashCode ^
+      isStale.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -425,5 +1886,64 @@ class WalletInfo {
           mintUrl == other.mintUrl &&
           unit == other.unit &&
           balance == other.balance &&
-          activeKeysetId == other.activeKeysetId;
+          activeKeysetId == other.activeKeysetId &&
+          fetchedAt == other.fetchedAt &&
+          isStale == other.isStale;
+}
+
+/// Wallet profile found on disk
+class WalletProfile {
+  final String profileId;
+  final String profileDir;
+  final bool isOpen;
+  final bool isEncrypted;
+
+  const WalletProfile({
+    required this.profileId,
+    required this.profileDir,
+    required this.isOpen,
+    required this.isEncrypted,
+  });
+
+  @override
+  int get hashCode =>
+      profileId.hashCode ^
+      profileDir.hashCode ^
+      isOpen.hashCode ^
+      isEncrypted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletProfile &&
+          runtimeType == other.runtimeType &&
+          profileId == other.profileId &&
+          profileDir == other.profileDir &&
+          isOpen == other.isOpen &&
+          isEncrypted == other.isEncrypted;
+}
+
+/// NUT-17 WebSocket subscriptions supported for a method and unit
+class WebSocketSettings {
+  final String method;
+  final String unit;
+  final List<String> commands;
+
+  const WebSocketSettings({
+    required this.method,
+    required this.unit,
+    required this.commands,
+  });
+
+  @override
+  int get hashCode => method.hashCode ^ unit.hashCode ^ commands.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebSocketSettings &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          unit == other.unit &&
+          commands == other.commands;
 }
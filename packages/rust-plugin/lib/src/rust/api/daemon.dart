@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cashu.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `authorize`, `create_client`, `hash_token`, `load_clients`, `save_clients`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Create a client for the daemon with permissions from read, receive, send, pay and events
+/// Returns the bearer token, it is only shown once.
+Future<String> addDaemonClient({
+  required WalletHandle handle,
+  required String name,
+  required List<String> permissions,
+}) => RustLib.instance.api.crateApiDaemonAddDaemonClient(
+  handle: handle,
+  name: name,
+  permissions: permissions,
+);
+
+/// Revoke a client, its token stops working immediately
+Future<void> removeDaemonClient({
+  required WalletHandle handle,
+  required String name,
+}) => RustLib.instance.api.crateApiDaemonRemoveDaemonClient(
+  handle: handle,
+  name: name,
+);
+
+Future<List<DaemonClient>> listDaemonClients({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiDaemonListDaemonClients(handle: handle);
+
+/// Serve the wallet over JSON-RPC on `listen`, either "127.0.0.1:<port>" or "unix:<path>"
+/// Requests go to POST /rpc, events stream from the /events WebSocket. Both need
+/// "Authorization: Bearer <token>", /events also accepts ?token= for browsers.
+/// Returns the address the daemon listens on.
+Future<String> startDaemon({
+  required WalletHandle handle,
+  required String listen,
+}) => RustLib.instance.api.crateApiDaemonStartDaemon(
+  handle: handle,
+  listen: listen,
+);
+
+Future<void> stopDaemon({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiDaemonStopDaemon(handle: handle);
+
+/// Address the daemon listens on, None when it is not running
+Future<String?> getDaemonAddress({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiDaemonGetDaemonAddress(handle: handle);
+
+/// Client allowed to call the daemon
+class DaemonClient {
+  final String name;
+  final List<String> permissions;
+  final BigInt createdAt;
+
+  const DaemonClient({
+    required this.name,
+    required this.permissions,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ permissions.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DaemonClient &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          permissions == other.permissions &&
+          createdAt == other.createdAt;
+}
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `emit_wallet_event`, `event_sender`, `subscribe`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Stream wallet events to Dart until the stream is closed on the Dart side
+Stream<WalletEvent> subscribeWalletEvents() =>
+    RustLib.instance.api.crateApiEventsSubscribeWalletEvents();
+
+/// Wallet event delivered to Dart
+class WalletEvent {
+  final String kind;
+  final String? profileId;
+  final String? mintUrl;
+  final String? quoteId;
+  final BigInt? amount;
+  final String? message;
+  final BigInt timestamp;
+  final Map<String, String> data;
+
+  const WalletEvent({
+    required this.kind,
+    this.profileId,
+    this.mintUrl,
+    this.quoteId,
+    this.amount,
+    this.message,
+    required this.timestamp,
+    required this.data,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      profileId.hashCode ^
+      mintUrl.hashCode ^
+      quoteId.hashCode ^
+      amount.hashCode ^
+      message.hashCode ^
+      timestamp.hashCode ^
+      data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          profileId == other.profileId &&
+          mintUrl == other.mintUrl &&
+          quoteId == other.quoteId &&
+          amount == other.amount &&
+          message == other.message &&
+          timestamp == other.timestamp &&
+          data == other.data;
+}
@@ -0,0 +1,151 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cashu.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_spend`, `hash_pin`, `load_spending_policy`, `normalize_mint_url`, `now_secs`, `policy_state`, `reserved_amount`, `save_policy`, `spent_since`, `unload_spending_policy`, `verify_pin`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `fmt`, `fmt`
+
+/// Add or replace the spending limit for a mint (or globally when mint_url is None)
+/// Requires the spending PIN once one is set.
+Future<void> setSpendingLimit({
+  required WalletHandle handle,
+  required SpendingLimit limit,
+  String? pin,
+}) => RustLib.instance.api.crateApiLimitsSetSpendingLimit(
+  handle: handle,
+  limit: limit,
+  pin: pin,
+);
+
+/// Remove the spending limit for a mint (or the global one when mint_url is None)
+Future<void> removeSpendingLimit({
+  required WalletHandle handle,
+  String? mintUrl,
+  String? pin,
+}) => RustLib.instance.api.crateApiLimitsRemoveSpendingLimit(
+  handle: handle,
+  mintUrl: mintUrl,
+  pin: pin,
+);
+
+/// List configured spending limits
+Future<List<SpendingLimit>> getSpendingLimits({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiLimitsGetSpendingLimits(handle: handle);
+
+/// Remaining allowance for every configured limit
+Future<List<SpendingAllowance>> getSpendingAllowances({
+  required WalletHandle handle,
+}) => RustLib.instance.api.crateApiLimitsGetSpendingAllowances(handle: handle);
+
+/// Set or change the spending PIN guarding limit changes and unlocks
+/// current_pin is required when a PIN is already set. An empty new_pin removes it.
+/// After five wrong PINs every PIN check is refused for a minute, doubling with each
+/// further wrong PIN.
+Future<void> setSpendingPin({
+  required WalletHandle handle,
+  String? currentPin,
+  required String newPin,
+}) => RustLib.instance.api.crateApiLimitsSetSpendingPin(
+  handle: handle,
+  currentPin: currentPin,
+  newPin: newPin,
+);
+
+/// Allow the next spend of up to amount sats past an "unlock" limit
+/// The unlock is single use and expires after two minutes.
+Future<void> unlockSpending({
+  required WalletHandle handle,
+  required String pin,
+  String? mintUrl,
+  required BigInt amount,
+}) => RustLib.instance.api.crateApiLimitsUnlockSpending(
+  handle: handle,
+  pin: pin,
+  mintUrl: mintUrl,
+  amount: amount,
+);
+
+/// What is left of a spending limit right now
+class SpendingAllowance {
+  final String? mintUrl;
+  final BigInt? perTransaction;
+  final BigInt dailySpent;
+  final BigInt? dailyRemaining;
+  final BigInt weeklySpent;
+  final BigInt? weeklyRemaining;
+  final String action;
+
+  const SpendingAllowance({
+    this.mintUrl,
+    this.perTransaction,
+    required this.dailySpent,
+    this.dailyRemaining,
+    required this.weeklySpent,
+    this.weeklyRemaining,
+    required this.action,
+  });
+
+  @override
+  int get hashCode =>
+      mintUrl.hashCode ^
+      perTransaction.hashCode ^
+      dailySpent.hashCode ^
+      dailyRemaining.hashCode ^
+      weeklySpent.hashCode ^
+      weeklyRemaining.hashCode ^
+      action.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SpendingAllowance &&
+          runtimeType == other.runtimeType &&
+          mintUrl == other.mintUrl &&
+          perTransaction == other.perTransaction &&
+          dailySpent == other.dailySpent &&
+          dailyRemaining == other.dailyRemaining &&
+          weeklySpent == other.weeklySpent &&
+          weeklyRemaining == other.weeklyRemaining &&
+          action == other.action;
+}
+
+/// Spending limit for one mint, or for all mints together when mint_url is None
+class SpendingLimit {
+  final String? mintUrl;
+  final BigInt? perTransaction;
+  final BigInt? daily;
+  final BigInt? weekly;
+  final String action;
+
+  const SpendingLimit({
+    this.mintUrl,
+    this.perTransaction,
+    this.daily,
+    this.weekly,
+    required this.action,
+  });
+
+  @override
+  int get hashCode =>
+      mintUrl.hashCode ^
+      perTransaction.hashCode ^
+      daily.hashCode ^
+      weekly.hashCode ^
+      action.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SpendingLimit &&
+          runtimeType == other.runtimeType &&
+          mintUrl == other.mintUrl &&
+          perTransaction == other.perTransaction &&
+          daily == other.daily &&
+          weekly == other.weekly &&
+          action == other.action;
+}
@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `after_key`, `flush_word`, `install_logger`, `is_sensitive_key`, `is_sensitive_word`, `is_word_char`, `logger`, `module_of`, `now_ms`, `redact`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `enabled`, `flush`, `fmt`, `fmt`, `log`
+
+/// Set the level for a module: cdk, arti, purrwallet or other
+/// Levels are off, error, warn, info, debug and trace.
+void setLogLevel({required String module, required String level}) => RustLib
+    .instance
+    .api
+    .crateApiLoggingSetLogLevel(module: module, level: level);
+
+/// Stream log records to Dart until the stream is closed on the Dart side
+Stream<LogRecord> subscribeLogs() =>
+    RustLib.instance.api.crateApiLoggingSubscribeLogs();
+
+/// Redacted copy of the buffered logs, ready to attach to a bug report
+DiagnosticsBundle exportDiagnostics() =>
+    RustLib.instance.api.crateApiLoggingExportDiagnostics();
+
+/// Forget the buffered logs, e.g. after a bundle was sent
+void clearLogBuffer() => RustLib.instance.api.crateApiLoggingClearLogBuffer();
+
+/// Write a record from Dart into the same buffer, so bundles show both sides
+void logFromDart({required String level, required String message}) => RustLib
+    .instance
+    .api
+    .crateApiLoggingLogFromDart(level: level, message: message);
+
+/// Redacted logs and environment details for a bug report
+class DiagnosticsBundle {
+  final BigInt createdAt;
+  final String crateVersion;
+  final String os;
+  final String arch;
+  final Map<String, String> logLevels;
+  final List<LogRecord> records;
+
+  const DiagnosticsBundle({
+    required this.createdAt,
+    required this.crateVersion,
+    required this.os,
+    required this.arch,
+    required this.logLevels,
+    required this.records,
+  });
+
+  @override
+  int get hashCode =>
+      createdAt.hashCode ^
+      crateVersion.hashCode ^
+      os.hashCode ^
+      arch.hashCode ^
+      logLevels.hashCode ^
+      records.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiagnosticsBundle &&
+          runtimeType == other.runtimeType &&
+          createdAt == other.createdAt &&
+          crateVersion == other.crateVersion &&
+          os == other.os &&
+          arch == other.arch &&
+          logLevels == other.logLevels &&
+          records == other.records;
+}
+
+/// Log record delivered to Dart, already redacted
+class LogRecord {
+  final String level;
+  final String module;
+  final String target;
+  final String message;
+  final BigInt timestampMs;
+
+  const LogRecord({
+    required this.level,
+    required this.module,
+    required this.target,
+    required this.message,
+    required this.timestampMs,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      module.hashCode ^
+      target.hashCode ^
+      message.hashCode ^
+      timestampMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogRecord &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          module == other.module &&
+          target == other.target &&
+          message == other.message &&
+          timestampMs == other.timestampMs;
+}
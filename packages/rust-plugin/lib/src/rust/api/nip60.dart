@@ -0,0 +1,120 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cashu.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `parse_keys`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `default`, `fmt`, `fmt`
+
+/// Keep the wallet's proofs in sync with NIP-60 events on the relays
+/// Publishes the kind 17375 wallet event and kind 7375 token events (NIP-44 encrypted
+/// to the key), merges events from other devices and deletes spent token events.
+/// Runs in the background until disabled or the wallet is closed. The key stays in
+/// memory only, call again after opening the wallet. relays None reuses the saved ones.
+Future<void> enableNip60Sync({
+  required WalletHandle handle,
+  required String privateKey,
+  List<String>? relays,
+}) => RustLib.instance.api.crateApiNip60EnableNip60Sync(
+  handle: handle,
+  privateKey: privateKey,
+  relays: relays,
+);
+
+Future<void> disableNip60Sync({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiNip60DisableNip60Sync(handle: handle);
+
+/// Sync right away instead of waiting for the next background round
+Future<Nip60SyncReport> syncNip60Wallet({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiNip60SyncNip60Wallet(handle: handle);
+
+Future<Nip60SyncStatus> getNip60SyncStatus({required WalletHandle handle}) =>
+    RustLib.instance.api.crateApiNip60GetNip60SyncStatus(handle: handle);
+
+/// Outcome of one NIP-60 sync
+class Nip60SyncReport {
+  final BigInt importedProofs;
+  final BigInt importedAmount;
+  final BigInt removedProofs;
+  final List<String> mintsAdded;
+  final BigInt publishedEvents;
+  final BigInt deletedEvents;
+  final List<String> errors;
+  final BigInt syncedAt;
+
+  const Nip60SyncReport({
+    required this.importedProofs,
+    required this.importedAmount,
+    required this.removedProofs,
+    required this.mintsAdded,
+    required this.publishedEvents,
+    required this.deletedEvents,
+    required this.errors,
+    required this.syncedAt,
+  });
+
+  @override
+  int get hashCode =>
+      importedProofs.hashCode ^
+      importedAmount.hashCode ^
+      removedProofs.hashCode ^
+      mintsAdded.hashCode ^
+      publishedEvents.hashCode ^
+      deletedEvents.hashCode ^
+      errors.hashCode ^
+      syncedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Nip60SyncReport &&
+          runtimeType == other.runtimeType &&
+          importedProofs == other.importedProofs &&
+          importedAmount == other.importedAmount &&
+          removedProofs == other.removedProofs &&
+          mintsAdded == other.mintsAdded &&
+          publishedEvents == other.publishedEvents &&
+          deletedEvents == other.deletedEvents &&
+          errors == other.errors &&
+          syncedAt == other.syncedAt;
+}
+
+/// State of the background sync for a wallet profile
+class Nip60SyncStatus {
+  final bool enabled;
+  final List<String> relays;
+  final String? publicKey;
+  final Nip60SyncReport? lastReport;
+  final String? lastError;
+
+  const Nip60SyncStatus({
+    required this.enabled,
+    required this.relays,
+    this.publicKey,
+    this.lastReport,
+    this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      relays.hashCode ^
+      publicKey.hashCode ^
+      lastReport.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Nip60SyncStatus &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          relays == other.relays &&
+          publicKey == other.publicKey &&
+          lastReport == other.lastReport &&
+          lastError == other.lastError;
+}
@@ -0,0 +1,101 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cbor_unwrap_bytes`, `cbor_wrap_bytes`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `default`, `fmt`, `fmt`
+
+/// Split a payload (e.g. a token from send_tokens) into animated QR frames
+/// Frames use the multi-part UR format with fountain codes, so the scanner can start
+/// at any frame and missed frames are recovered from later ones.
+AnimatedQrFrames encodeAnimatedQr({
+  required String payload,
+  int? maxFragmentLength,
+  int? frameCount,
+}) => RustLib.instance.api.crateApiQrEncodeAnimatedQr(
+  payload: payload,
+  maxFragmentLength: maxFragmentLength,
+  frameCount: frameCount,
+);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>
+/// Stateful decoder for animated QR frames, accepts frames in any order
+abstract class AnimatedQrDecoder implements RustOpaqueInterface {
+  factory AnimatedQrDecoder() =>
+      RustLib.instance.api.crateApiQrAnimatedQrDecoderNew();
+
+  /// Current progress without feeding a frame
+  QrDecodeProgress progress();
+
+  /// Feed one scanned frame and get the updated progress
+  QrDecodeProgress receive({required String frame});
+
+  /// Forget all received frames to scan a new code
+  void reset();
+}
+
+/// Frames for an animated QR code
+class AnimatedQrFrames {
+  final List<String> frames;
+  final int fragmentCount;
+  final bool isAnimated;
+
+  const AnimatedQrFrames({
+    required this.frames,
+    required this.fragmentCount,
+    required this.isAnimated,
+  });
+
+  @override
+  int get hashCode =>
+      frames.hashCode ^ fragmentCount.hashCode ^ isAnimated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AnimatedQrFrames &&
+          runtimeType == other.runtimeType &&
+          frames == other.frames &&
+          fragmentCount == other.fragmentCount &&
+          isAnimated == other.isAnimated;
+}
+
+/// Decoding progress after a frame was received
+class QrDecodeProgress {
+  final bool complete;
+  final double progress;
+  final int receivedFrames;
+  final int expectedFrames;
+  final String? payload;
+
+  const QrDecodeProgress({
+    required this.complete,
+    required this.progress,
+    required this.receivedFrames,
+    required this.expectedFrames,
+    this.payload,
+  });
+
+  @override
+  int get hashCode =>
+      complete.hashCode ^
+      progress.hashCode ^
+      receivedFrames.hashCode ^
+      expectedFrames.hashCode ^
+      payload.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QrDecodeProgress &&
+          runtimeType == other.runtimeType &&
+          complete == other.complete &&
+          progress == other.progress &&
+          receivedFrames == other.receivedFrames &&
+          expectedFrames == other.expectedFrames &&
+          payload == other.payload;
+}
@@ -4,12 +4,19 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/cashu.dart';
+import 'api/daemon.dart';
+import 'api/events.dart';
+import 'api/limits.dart';
+import 'api/logging.dart';
+import 'api/nip60.dart';
 import 'api/nostr.dart';
+import 'api/qr.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'network.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 /// Main entrypoint of the Rust API
@@ -65,7 +72,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.7.0';
 
   @override
-  int get rustContentHash => 1346198312;
+  int get rustContentHash => -1017403726;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -76,48 +83,209 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  Future<String> crateApiCashuAddMint({required String mintUrl});
+  AnimatedQrDecoder crateApiQrAnimatedQrDecoderNew();
 
-  Future<String> crateApiCashuCheckAllMeltQuotes();
+  QrDecodeProgress crateApiQrAnimatedQrDecoderProgress({
+    required AnimatedQrDecoder that,
+  });
+
+  QrDecodeProgress crateApiQrAnimatedQrDecoderReceive({
+    required AnimatedQrDecoder that,
+    required String frame,
+  });
+
+  void crateApiQrAnimatedQrDecoderReset({required AnimatedQrDecoder that});
+
+  Future<String> crateApiDaemonAddDaemonClient({
+    required WalletHandle handle,
+    required String name,
+    required List<String> permissions,
+  });
+
+  Future<String> crateApiCashuAddMint({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
+
+  Future<String> crateApiCashuCheckAllMeltQuotes({
+    required WalletHandle handle,
+  });
+
+  Future<Map<String, String>> crateApiCashuCheckAllMintQuotes({
+    required WalletHandle handle,
+  });
+
+  Future<String> crateApiCashuCheckMeltQuoteStatus({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
 
-  Future<Map<String, String>> crateApiCashuCheckAllMintQuotes();
+  Future<void> crateApiCashuCheckMintCapability({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String capability,
+  });
+
+  Future<String> crateApiCashuCheckMintQuoteStatus({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
+
+  Future<void> crateApiCashuClearInterruptedOperations({
+    required WalletHandle handle,
+  });
+
+  void crateApiLoggingClearLogBuffer();
 
-  Future<String> crateApiCashuCheckMeltQuoteStatus({required String mintUrl});
+  Future<void> crateApiCashuCloseWallet({required WalletHandle handle});
 
-  Future<String> crateApiCashuCheckMintQuoteStatus({required String mintUrl});
+  Future<EncodedToken> crateApiCashuCombineTokens({
+    required WalletHandle handle,
+    required List<String> tokens,
+    String? memo,
+    required String encoding,
+  });
+
+  Future<Map<String, String>> crateApiCashuCreateBolt12MintQuote({
+    required WalletHandle handle,
+    required String mintUrl,
+    BigInt? amount,
+    String? description,
+  });
 
   Future<Map<String, String>> crateApiCashuCreateMintQuote({
+    required WalletHandle handle,
     required String mintUrl,
     required BigInt amount,
     String? description,
   });
 
+  Future<String> crateApiCashuCreatePaymentRequest({
+    BigInt? amount,
+    List<String>? mints,
+    String? description,
+    bool? singleUse,
+    String? p2PkPubkey,
+    required List<PaymentRequestTransport> transports,
+  });
+
   Future<String> crateApiCashuDecodeBolt11Invoice({required String invoice});
 
+  Future<Bolt12InvoiceRequestInfo> crateApiCashuDecodeBolt12InvoiceRequest({
+    required String invoiceRequestHex,
+  });
+
+  Future<Bolt12OfferInfo> crateApiCashuDecodeBolt12Offer({
+    required String offer,
+  });
+
+  Future<PaymentRequestInfo> crateApiCashuDecodePaymentRequest({
+    required String request,
+  });
+
+  Future<void> crateApiCashuDeleteWallet({
+    required String profileId,
+    required String databaseDir,
+    required bool force,
+  });
+
+  Future<void> crateApiNip60DisableNip60Sync({required WalletHandle handle});
+
+  Future<void> crateApiNip60EnableNip60Sync({
+    required WalletHandle handle,
+    required String privateKey,
+    List<String>? relays,
+  });
+
+  AnimatedQrFrames crateApiQrEncodeAnimatedQr({
+    required String payload,
+    int? maxFragmentLength,
+    int? frameCount,
+  });
+
+  Future<EncodedToken> crateApiCashuEncodeToken({
+    required WalletHandle handle,
+    required String token,
+    required String encoding,
+  });
+
+  DiagnosticsBundle crateApiLoggingExportDiagnostics();
+
   NostrKeys crateApiNostrGenerateKeys();
 
   NostrKeysWithBech32 crateApiNostrGenerateKeysWithBech32();
 
   Future<String> crateApiCashuGenerateMnemonicPhrase({required int wordCount});
 
-  Future<Map<String, BigInt>> crateApiCashuGetAllBalances();
+  Future<Map<String, BigInt>> crateApiCashuGetAllBalances({
+    required WalletHandle handle,
+  });
+
+  Future<List<TransactionInfo>> crateApiCashuGetAllTransactions({
+    required WalletHandle handle,
+  });
+
+  Future<String?> crateApiDaemonGetDaemonAddress({
+    required WalletHandle handle,
+  });
+
+  Future<List<InterruptedOperation>> crateApiCashuGetInterruptedOperations({
+    required WalletHandle handle,
+  });
+
+  Future<MintInfo> crateApiCashuGetMintInfo({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
 
-  Future<List<TransactionInfo>> crateApiCashuGetAllTransactions();
+  Future<ProxyConfig?> crateApiCashuGetMintProxy({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
 
-  Future<MintInfo> crateApiCashuGetMintInfo({required String mintUrl});
+  Future<Nip60SyncStatus> crateApiNip60GetNip60SyncStatus({
+    required WalletHandle handle,
+  });
 
   String crateApiNostrGetPublicKeyFromPrivate({required String privateKey});
 
-  Future<void> crateApiCashuGetTorConfig();
+  Future<List<SpendingAllowance>> crateApiLimitsGetSpendingAllowances({
+    required WalletHandle handle,
+  });
+
+  Future<List<SpendingLimit>> crateApiLimitsGetSpendingLimits({
+    required WalletHandle handle,
+  });
+
+  Future<TorBootstrapStatus> crateApiCashuGetTorBootstrapStatus({
+    required WalletHandle handle,
+  });
+
+  Future<String> crateApiCashuGetTorConfig({required WalletHandle handle});
+
+  Future<bool> crateApiCashuGetTorIsolation({required WalletHandle handle});
 
-  Future<WalletInfo> crateApiCashuGetWalletInfo({required String mintUrl});
+  Future<WalletInfo> crateApiCashuGetWalletInfo({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
 
   Future<List<CashuProof>> crateApiCashuGetWalletProofs({
+    required WalletHandle handle,
     required String mintUrl,
   });
 
+  Future<ProxyConfig?> crateApiCashuGetWalletProxy({
+    required WalletHandle handle,
+  });
+
   String crateApiNostrGreet({required String name});
 
+  Future<ImportReport> crateApiCashuImportWalletBackup({
+    required WalletHandle handle,
+    required String backup,
+  });
+
   Future<void> crateApiNostrInitApp();
 
   Future<String> crateApiCashuInitMultiMintWallet({
@@ -131,16 +299,44 @@ abstract class RustLibApi extends BaseApi {
     String? torConfig,
   });
 
-  Future<bool> crateApiCashuIsTorEnabled();
+  Future<bool> crateApiCashuIsOfflineMode({required WalletHandle handle});
+
+  Future<bool> crateApiCashuIsTorEnabled({required WalletHandle handle});
+
+  Future<bool> crateApiCashuIsTorReady({required WalletHandle handle});
+
+  Future<bool> crateApiCashuIsWalletDatabaseEncrypted({
+    required String profileId,
+    required String databaseDir,
+  });
+
+  Future<List<DaemonClient>> crateApiDaemonListDaemonClients({
+    required WalletHandle handle,
+  });
+
+  Future<List<String>> crateApiCashuListMints({required WalletHandle handle});
+
+  Future<List<WalletProfile>> crateApiCashuListProfiles({
+    required String databaseDir,
+  });
 
-  Future<bool> crateApiCashuIsTorReady();
+  void crateApiLoggingLogFromDart({
+    required String level,
+    required String message,
+  });
 
-  Future<List<String>> crateApiCashuListMints();
+  Future<Map<String, String>> crateApiCashuMintBolt12Quote({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String quoteId,
+  });
 
   Future<String> crateApiCashuMnemonicToSeedHex({
     required String mnemonicPhrase,
   });
 
+  Future<int> crateApiCashuNewTorIdentity({required WalletHandle handle});
+
   String crateApiNostrNip04Decrypt({
     required String ciphertext,
     required String publicKey,
@@ -169,34 +365,121 @@ abstract class RustLibApi extends BaseApi {
 
   String crateApiNostrNsecToSecretKey({required String nsec});
 
+  Future<WalletHandle> crateApiCashuOpenEncryptedWallet({
+    required String profileId,
+    required String databaseDir,
+    required String seedHex,
+    required String keyMaterial,
+  });
+
+  Future<WalletHandle> crateApiCashuOpenWallet({
+    required String profileId,
+    required String databaseDir,
+    required String seedHex,
+  });
+
   Future<Map<String, String>> crateApiCashuParseCashuToken({
     required String token,
   });
 
+  UnsupportedByMint? crateApiCashuParseUnsupportedByMint({
+    required String error,
+  });
+
+  Future<String> crateApiCashuPayBolt12Offer({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String offer,
+    BigInt? amountSats,
+  });
+
   Future<String> crateApiCashuPayInvoiceForWallet({
+    required WalletHandle handle,
     required String mintUrl,
     required String bolt11Invoice,
     BigInt? maxFeeSats,
   });
 
+  Future<String> crateApiCashuPayLnurl({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String lnurl,
+    required BigInt amountSats,
+    String? comment,
+  });
+
+  Future<Map<String, String>> crateApiCashuPayPaymentRequest({
+    required WalletHandle handle,
+    required String request,
+    String? mintUrl,
+    BigInt? amount,
+  });
+
   String crateApiNostrPublicKeyToNpub({required String publicKey});
 
-  Future<BigInt> crateApiCashuReceiveTokens({required String token});
+  Future<BigInt> crateApiCashuReceiveTokens({
+    required WalletHandle handle,
+    required String token,
+  });
+
+  Future<BigInt> crateApiCashuRedeemLnurlWithdraw({
+    required WalletHandle handle,
+    required String lnurl,
+    required String mintUrl,
+    BigInt? amount,
+  });
 
   Future<String> crateApiCashuReinitializeWithTorConfig({
     required String databaseDir,
     required String seedHex,
   });
 
-  Future<String> crateApiCashuRemoveMint({required String mintUrl});
+  Future<void> crateApiDaemonRemoveDaemonClient({
+    required WalletHandle handle,
+    required String name,
+  });
+
+  Future<String> crateApiCashuRemoveMint({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
+
+  Future<void> crateApiLimitsRemoveSpendingLimit({
+    required WalletHandle handle,
+    String? mintUrl,
+    String? pin,
+  });
+
+  Future<LnurlPayInfo> crateApiCashuResolveLnurlPay({
+    required WalletHandle handle,
+    required String lnurl,
+  });
+
+  Future<RestoredProfile> crateApiCashuRestoreBackupProfile({
+    required String profileId,
+    required String databaseDir,
+    required String backup,
+  });
+
+  Future<BigInt> crateApiCashuRestoreMint({
+    required WalletHandle handle,
+    required String mintUrl,
+  });
 
-  Future<BigInt> crateApiCashuRestoreMint({required String mintUrl});
+  Future<String> crateApiCashuRotateWalletDatabaseKey({
+    required String profileId,
+    required String databaseDir,
+    required String oldKeyMaterial,
+    required String newKeyMaterial,
+    required bool force,
+  });
 
   String crateApiNostrSecretKeyToNsec({required String secretKey});
 
   Future<String> crateApiCashuSeedHexToMnemonic({required String seedHex});
 
   Future<String> crateApiCashuSendP2PkTokens({
+    required WalletHandle handle,
     required String mintUrl,
     required BigInt amount,
     String? memo,
@@ -209,31 +492,139 @@ abstract class RustLibApi extends BaseApi {
     BigInt? refundRequiredSigs,
   });
 
+  Future<String> crateApiCashuSendSelectedProofs({
+    required WalletHandle handle,
+    required String mintUrl,
+    required List<String> proofYs,
+    required SelectedProofsSendOptions options,
+  });
+
   Future<String> crateApiCashuSendTokens({
+    required WalletHandle handle,
+    required String mintUrl,
+    required BigInt amount,
+    String? memo,
+  });
+
+  Future<EncodedToken> crateApiCashuSendTokensWithEncoding({
+    required WalletHandle handle,
     required String mintUrl,
     required BigInt amount,
     String? memo,
+    required String encoding,
+  });
+
+  void crateApiLoggingSetLogLevel({
+    required String module,
+    required String level,
+  });
+
+  Future<void> crateApiCashuSetMintProxy({
+    required WalletHandle handle,
+    required String mintUrl,
+    ProxyConfig? proxy,
+  });
+
+  Future<void> crateApiCashuSetOfflineMode({
+    required WalletHandle handle,
+    required bool offline,
+  });
+
+  Future<void> crateApiLimitsSetSpendingLimit({
+    required WalletHandle handle,
+    required SpendingLimit limit,
+    String? pin,
+  });
+
+  Future<void> crateApiLimitsSetSpendingPin({
+    required WalletHandle handle,
+    String? currentPin,
+    required String newPin,
+  });
+
+  Future<bool> crateApiCashuSetTorConfig({
+    required WalletHandle handle,
+    required String policy,
+  });
+
+  Future<void> crateApiCashuSetTorIsolation({
+    required WalletHandle handle,
+    required bool enabled,
+  });
+
+  Future<void> crateApiCashuSetWalletProxy({
+    required WalletHandle handle,
+    ProxyConfig? proxy,
   });
 
-  Future<void> crateApiCashuSetTorConfig({required bool policy});
+  Future<List<InterruptedOperation>> crateApiCashuShutdownWallet({
+    required WalletHandle handle,
+    BigInt? timeoutSecs,
+  });
 
   String crateApiNostrSignEvent({
     required String eventJson,
     required String privateKey,
   });
 
+  Future<String> crateApiDaemonStartDaemon({
+    required WalletHandle handle,
+    required String listen,
+  });
+
+  Future<void> crateApiDaemonStopDaemon({required WalletHandle handle});
+
+  Stream<LogRecord> crateApiLoggingSubscribeLogs();
+
+  Stream<TorBootstrapStatus> crateApiCashuSubscribeTorBootstrap({
+    required WalletHandle handle,
+  });
+
+  Stream<WalletEvent> crateApiEventsSubscribeWalletEvents();
+
+  Future<Nip60SyncReport> crateApiNip60SyncNip60Wallet({
+    required WalletHandle handle,
+  });
+
+  Future<ProxyTestResult> crateApiCashuTestProxy({
+    required ProxyConfig proxy,
+    required String mintUrl,
+  });
+
+  Future<void> crateApiLimitsUnlockSpending({
+    required WalletHandle handle,
+    required String pin,
+    String? mintUrl,
+    required BigInt amount,
+  });
+
   Future<bool> crateApiCashuValidateMnemonicPhrase({
     required String mnemonicPhrase,
   });
 
   bool crateApiNostrVerifyEvent({required NostrEvent event});
 
+  Future<TokenVerification> crateApiCashuVerifyProofsOffline({
+    required WalletHandle handle,
+    required String mintUrl,
+    required List<CashuProof> proofs,
+    required bool allowFetch,
+  });
+
   Future<bool> crateApiCashuVerifyTokenDleq({
+    required WalletHandle handle,
     required String mintUrl,
     required String token,
   });
 
+  Future<TokenVerification> crateApiCashuVerifyTokenOffline({
+    required WalletHandle handle,
+    required String token,
+    required bool allowFetch,
+  });
+
   Future<bool> crateApiCashuVerifyTokenP2Pk({
+    required WalletHandle handle,
     required String mintUrl,
     required String token,
     required String conditions,
@@ -243,6 +634,14 @@ abstract class RustLibApi extends BaseApi {
     required String mintUrl,
     required String databaseDir,
   });
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_AnimatedQrDecoder;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_AnimatedQrDecoder;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AnimatedQrDecoderPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -254,125 +653,133 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<String> crateApiCashuAddMint({required String mintUrl}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  AnimatedQrDecoder crateApiQrAnimatedQrDecoderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCashuAddMintConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiQrAnimatedQrDecoderNewConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuAddMintConstMeta =>
-      const TaskConstMeta(debugName: "add_mint", argNames: ["mintUrl"]);
+  TaskConstMeta get kCrateApiQrAnimatedQrDecoderNewConstMeta =>
+      const TaskConstMeta(debugName: "AnimatedQrDecoder_new", argNames: []);
 
   @override
-  Future<String> crateApiCashuCheckAllMeltQuotes() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  QrDecodeProgress crateApiQrAnimatedQrDecoderProgress({
+    required AnimatedQrDecoder that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+            that,
             serializer,
-            funcId: 2,
-            port: port_,
           );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_qr_decode_progress,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCashuCheckAllMeltQuotesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiQrAnimatedQrDecoderProgressConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuCheckAllMeltQuotesConstMeta =>
-      const TaskConstMeta(debugName: "check_all_melt_quotes", argNames: []);
+  TaskConstMeta get kCrateApiQrAnimatedQrDecoderProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "AnimatedQrDecoder_progress",
+        argNames: ["that"],
+      );
 
   @override
-  Future<Map<String, String>> crateApiCashuCheckAllMintQuotes() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  QrDecodeProgress crateApiQrAnimatedQrDecoderReceive({
+    required AnimatedQrDecoder that,
+    required String frame,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+            that,
             serializer,
-            funcId: 3,
-            port: port_,
           );
+          sse_encode_String(frame, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Map_String_String,
+          decodeSuccessData: sse_decode_qr_decode_progress,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuCheckAllMintQuotesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiQrAnimatedQrDecoderReceiveConstMeta,
+        argValues: [that, frame],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuCheckAllMintQuotesConstMeta =>
-      const TaskConstMeta(debugName: "check_all_mint_quotes", argNames: []);
+  TaskConstMeta get kCrateApiQrAnimatedQrDecoderReceiveConstMeta =>
+      const TaskConstMeta(
+        debugName: "AnimatedQrDecoder_receive",
+        argNames: ["that", "frame"],
+      );
 
   @override
-  Future<String> crateApiCashuCheckMeltQuoteStatus({required String mintUrl}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiQrAnimatedQrDecoderReset({required AnimatedQrDecoder that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+            that,
             serializer,
-            funcId: 4,
-            port: port_,
           );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCashuCheckMeltQuoteStatusConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiQrAnimatedQrDecoderResetConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuCheckMeltQuoteStatusConstMeta =>
+  TaskConstMeta get kCrateApiQrAnimatedQrDecoderResetConstMeta =>
       const TaskConstMeta(
-        debugName: "check_melt_quote_status",
-        argNames: ["mintUrl"],
+        debugName: "AnimatedQrDecoder_reset",
+        argNames: ["that"],
       );
 
   @override
-  Future<String> crateApiCashuCheckMintQuoteStatus({required String mintUrl}) {
+  Future<String> crateApiDaemonAddDaemonClient({
+    required WalletHandle handle,
+    required String name,
+    required List<String> permissions,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_list_String(permissions, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -384,32 +791,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuCheckMintQuoteStatusConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiDaemonAddDaemonClientConstMeta,
+        argValues: [handle, name, permissions],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuCheckMintQuoteStatusConstMeta =>
+  TaskConstMeta get kCrateApiDaemonAddDaemonClientConstMeta =>
       const TaskConstMeta(
-        debugName: "check_mint_quote_status",
-        argNames: ["mintUrl"],
+        debugName: "add_daemon_client",
+        argNames: ["handle", "name", "permissions"],
       );
 
   @override
-  Future<Map<String, String>> crateApiCashuCreateMintQuote({
+  Future<String> crateApiCashuAddMint({
+    required WalletHandle handle,
     required String mintUrl,
-    required BigInt amount,
-    String? description,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           sse_encode_String(mintUrl, serializer);
-          sse_encode_u_64(amount, serializer);
-          sse_encode_opt_String(description, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -418,29 +823,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Map_String_String,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuCreateMintQuoteConstMeta,
-        argValues: [mintUrl, amount, description],
+        constMeta: kCrateApiCashuAddMintConstMeta,
+        argValues: [handle, mintUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuCreateMintQuoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_mint_quote",
-        argNames: ["mintUrl", "amount", "description"],
-      );
+  TaskConstMeta get kCrateApiCashuAddMintConstMeta => const TaskConstMeta(
+    debugName: "add_mint",
+    argNames: ["handle", "mintUrl"],
+  );
 
   @override
-  Future<String> crateApiCashuDecodeBolt11Invoice({required String invoice}) {
+  Future<String> crateApiCashuCheckAllMeltQuotes({
+    required WalletHandle handle,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(invoice, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -452,70 +858,100 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuDecodeBolt11InvoiceConstMeta,
-        argValues: [invoice],
+        constMeta: kCrateApiCashuCheckAllMeltQuotesConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuDecodeBolt11InvoiceConstMeta =>
+  TaskConstMeta get kCrateApiCashuCheckAllMeltQuotesConstMeta =>
       const TaskConstMeta(
-        debugName: "decode_bolt11_invoice",
-        argNames: ["invoice"],
+        debugName: "check_all_melt_quotes",
+        argNames: ["handle"],
       );
 
   @override
-  NostrKeys crateApiNostrGenerateKeys() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<Map<String, String>> crateApiCashuCheckAllMintQuotes({
+    required WalletHandle handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_nostr_keys,
+          decodeSuccessData: sse_decode_Map_String_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrGenerateKeysConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuCheckAllMintQuotesConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrGenerateKeysConstMeta =>
-      const TaskConstMeta(debugName: "generate_keys", argNames: []);
+  TaskConstMeta get kCrateApiCashuCheckAllMintQuotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_all_mint_quotes",
+        argNames: ["handle"],
+      );
 
   @override
-  NostrKeysWithBech32 crateApiNostrGenerateKeysWithBech32() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<String> crateApiCashuCheckMeltQuoteStatus({
+    required WalletHandle handle,
+    required String mintUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_nostr_keys_with_bech_32,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrGenerateKeysWithBech32ConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuCheckMeltQuoteStatusConstMeta,
+        argValues: [handle, mintUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrGenerateKeysWithBech32ConstMeta =>
-      const TaskConstMeta(debugName: "generate_keys_with_bech32", argNames: []);
+  TaskConstMeta get kCrateApiCashuCheckMeltQuoteStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_melt_quote_status",
+        argNames: ["handle", "mintUrl"],
+      );
 
   @override
-  Future<String> crateApiCashuGenerateMnemonicPhrase({required int wordCount}) {
+  Future<void> crateApiCashuCheckMintCapability({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String capability,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(wordCount, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(capability, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -524,28 +960,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuGenerateMnemonicPhraseConstMeta,
-        argValues: [wordCount],
+        constMeta: kCrateApiCashuCheckMintCapabilityConstMeta,
+        argValues: [handle, mintUrl, capability],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuGenerateMnemonicPhraseConstMeta =>
+  TaskConstMeta get kCrateApiCashuCheckMintCapabilityConstMeta =>
       const TaskConstMeta(
-        debugName: "generate_mnemonic_phrase",
-        argNames: ["wordCount"],
+        debugName: "check_mint_capability",
+        argNames: ["handle", "mintUrl", "capability"],
       );
 
   @override
-  Future<Map<String, BigInt>> crateApiCashuGetAllBalances() {
+  Future<String> crateApiCashuCheckMintQuoteStatus({
+    required WalletHandle handle,
+    required String mintUrl,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -554,25 +995,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Map_String_u_64,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuGetAllBalancesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuCheckMintQuoteStatusConstMeta,
+        argValues: [handle, mintUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuGetAllBalancesConstMeta =>
-      const TaskConstMeta(debugName: "get_all_balances", argNames: []);
+  TaskConstMeta get kCrateApiCashuCheckMintQuoteStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_mint_quote_status",
+        argNames: ["handle", "mintUrl"],
+      );
 
   @override
-  Future<List<TransactionInfo>> crateApiCashuGetAllTransactions() {
+  Future<void> crateApiCashuClearInterruptedOperations({
+    required WalletHandle handle,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -581,79 +1028,87 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_transaction_info,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuGetAllTransactionsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuClearInterruptedOperationsConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuGetAllTransactionsConstMeta =>
-      const TaskConstMeta(debugName: "get_all_transactions", argNames: []);
+  TaskConstMeta get kCrateApiCashuClearInterruptedOperationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_interrupted_operations",
+        argNames: ["handle"],
+      );
 
   @override
-  Future<MintInfo> crateApiCashuGetMintInfo({required String mintUrl}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiLoggingClearLogBuffer() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_mint_info,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuGetMintInfoConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiLoggingClearLogBufferConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuGetMintInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_mint_info", argNames: ["mintUrl"]);
+  TaskConstMeta get kCrateApiLoggingClearLogBufferConstMeta =>
+      const TaskConstMeta(debugName: "clear_log_buffer", argNames: []);
 
   @override
-  String crateApiNostrGetPublicKeyFromPrivate({required String privateKey}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateApiCashuCloseWallet({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(privateKey, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrGetPublicKeyFromPrivateConstMeta,
-        argValues: [privateKey],
+        constMeta: kCrateApiCashuCloseWalletConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrGetPublicKeyFromPrivateConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_public_key_from_private",
-        argNames: ["privateKey"],
-      );
+  TaskConstMeta get kCrateApiCashuCloseWalletConstMeta =>
+      const TaskConstMeta(debugName: "close_wallet", argNames: ["handle"]);
 
   @override
-  Future<void> crateApiCashuGetTorConfig() {
+  Future<EncodedToken> crateApiCashuCombineTokens({
+    required WalletHandle handle,
+    required List<String> tokens,
+    String? memo,
+    required String encoding,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_list_String(tokens, serializer);
+          sse_encode_opt_String(memo, serializer);
+          sse_encode_String(encoding, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -662,26 +1117,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_encoded_token,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuGetTorConfigConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuCombineTokensConstMeta,
+        argValues: [handle, tokens, memo, encoding],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuGetTorConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_tor_config", argNames: []);
+  TaskConstMeta get kCrateApiCashuCombineTokensConstMeta => const TaskConstMeta(
+    debugName: "combine_tokens",
+    argNames: ["handle", "tokens", "memo", "encoding"],
+  );
 
   @override
-  Future<WalletInfo> crateApiCashuGetWalletInfo({required String mintUrl}) {
+  Future<Map<String, String>> crateApiCashuCreateBolt12MintQuote({
+    required WalletHandle handle,
+    required String mintUrl,
+    BigInt? amount,
+    String? description,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           sse_encode_String(mintUrl, serializer);
+          sse_encode_opt_box_autoadd_u_64(amount, serializer);
+          sse_encode_opt_String(description, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -690,28 +1155,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_info,
+          decodeSuccessData: sse_decode_Map_String_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuGetWalletInfoConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiCashuCreateBolt12MintQuoteConstMeta,
+        argValues: [handle, mintUrl, amount, description],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuGetWalletInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_wallet_info", argNames: ["mintUrl"]);
+  TaskConstMeta get kCrateApiCashuCreateBolt12MintQuoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_bolt12_mint_quote",
+        argNames: ["handle", "mintUrl", "amount", "description"],
+      );
 
   @override
-  Future<List<CashuProof>> crateApiCashuGetWalletProofs({
+  Future<Map<String, String>> crateApiCashuCreateMintQuote({
+    required WalletHandle handle,
     required String mintUrl,
+    required BigInt amount,
+    String? description,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           sse_encode_String(mintUrl, serializer);
+          sse_encode_u_64(amount, serializer);
+          sse_encode_opt_String(description, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -720,51 +1194,86 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_cashu_proof,
+          decodeSuccessData: sse_decode_Map_String_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuGetWalletProofsConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiCashuCreateMintQuoteConstMeta,
+        argValues: [handle, mintUrl, amount, description],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuGetWalletProofsConstMeta =>
+  TaskConstMeta get kCrateApiCashuCreateMintQuoteConstMeta =>
       const TaskConstMeta(
-        debugName: "get_wallet_proofs",
-        argNames: ["mintUrl"],
+        debugName: "create_mint_quote",
+        argNames: ["handle", "mintUrl", "amount", "description"],
       );
 
   @override
-  String crateApiNostrGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<String> crateApiCashuCreatePaymentRequest({
+    BigInt? amount,
+    List<String>? mints,
+    String? description,
+    bool? singleUse,
+    String? p2PkPubkey,
+    required List<PaymentRequestTransport> transports,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          sse_encode_opt_box_autoadd_u_64(amount, serializer);
+          sse_encode_opt_list_String(mints, serializer);
+          sse_encode_opt_String(description, serializer);
+          sse_encode_opt_box_autoadd_bool(singleUse, serializer);
+          sse_encode_opt_String(p2PkPubkey, serializer);
+          sse_encode_list_payment_request_transport(transports, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiCashuCreatePaymentRequestConstMeta,
+        argValues: [
+          amount,
+          mints,
+          description,
+          singleUse,
+          p2PkPubkey,
+          transports,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiCashuCreatePaymentRequestConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_payment_request",
+        argNames: [
+          "amount",
+          "mints",
+          "description",
+          "singleUse",
+          "p2PkPubkey",
+          "transports",
+        ],
+      );
 
   @override
-  Future<void> crateApiNostrInitApp() {
+  Future<String> crateApiCashuDecodeBolt11Invoice({required String invoice}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(invoice, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -773,30 +1282,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuDecodeBolt11InvoiceConstMeta,
+        argValues: [invoice],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiCashuDecodeBolt11InvoiceConstMeta =>
+      const TaskConstMeta(
+        debugName: "decode_bolt11_invoice",
+        argNames: ["invoice"],
+      );
 
   @override
-  Future<String> crateApiCashuInitMultiMintWallet({
-    required String databaseDir,
-    required String seedHex,
+  Future<Bolt12InvoiceRequestInfo> crateApiCashuDecodeBolt12InvoiceRequest({
+    required String invoiceRequestHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(databaseDir, serializer);
-          sse_encode_String(seedHex, serializer);
+          sse_encode_String(invoiceRequestHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -805,35 +1315,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bolt_12_invoice_request_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuInitMultiMintWalletConstMeta,
-        argValues: [databaseDir, seedHex],
+        constMeta: kCrateApiCashuDecodeBolt12InvoiceRequestConstMeta,
+        argValues: [invoiceRequestHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuInitMultiMintWalletConstMeta =>
+  TaskConstMeta get kCrateApiCashuDecodeBolt12InvoiceRequestConstMeta =>
       const TaskConstMeta(
-        debugName: "init_multi_mint_wallet",
-        argNames: ["databaseDir", "seedHex"],
+        debugName: "decode_bolt12_invoice_request",
+        argNames: ["invoiceRequestHex"],
       );
 
   @override
-  Future<String> crateApiCashuInitMultiMintWalletWithTor({
-    required String databaseDir,
-    required String seedHex,
-    String? torConfig,
+  Future<Bolt12OfferInfo> crateApiCashuDecodeBolt12Offer({
+    required String offer,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(databaseDir, serializer);
-          sse_encode_String(seedHex, serializer);
-          sse_encode_opt_String(torConfig, serializer);
+          sse_encode_String(offer, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -842,28 +1348,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bolt_12_offer_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuInitMultiMintWalletWithTorConstMeta,
-        argValues: [databaseDir, seedHex, torConfig],
+        constMeta: kCrateApiCashuDecodeBolt12OfferConstMeta,
+        argValues: [offer],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuInitMultiMintWalletWithTorConstMeta =>
+  TaskConstMeta get kCrateApiCashuDecodeBolt12OfferConstMeta =>
       const TaskConstMeta(
-        debugName: "init_multi_mint_wallet_with_tor",
-        argNames: ["databaseDir", "seedHex", "torConfig"],
+        debugName: "decode_bolt12_offer",
+        argNames: ["offer"],
       );
 
   @override
-  Future<bool> crateApiCashuIsTorEnabled() {
+  Future<PaymentRequestInfo> crateApiCashuDecodePaymentRequest({
+    required String request,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(request, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -872,25 +1381,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_payment_request_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuIsTorEnabledConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuDecodePaymentRequestConstMeta,
+        argValues: [request],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuIsTorEnabledConstMeta =>
-      const TaskConstMeta(debugName: "is_tor_enabled", argNames: []);
+  TaskConstMeta get kCrateApiCashuDecodePaymentRequestConstMeta =>
+      const TaskConstMeta(
+        debugName: "decode_payment_request",
+        argNames: ["request"],
+      );
 
   @override
-  Future<bool> crateApiCashuIsTorReady() {
+  Future<void> crateApiCashuDeleteWallet({
+    required String profileId,
+    required String databaseDir,
+    required bool force,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(profileId, serializer);
+          sse_encode_String(databaseDir, serializer);
+          sse_encode_bool(force, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -899,25 +1418,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuIsTorReadyConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCashuDeleteWalletConstMeta,
+        argValues: [profileId, databaseDir, force],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuIsTorReadyConstMeta =>
-      const TaskConstMeta(debugName: "is_tor_ready", argNames: []);
+  TaskConstMeta get kCrateApiCashuDeleteWalletConstMeta => const TaskConstMeta(
+    debugName: "delete_wallet",
+    argNames: ["profileId", "databaseDir", "force"],
+  );
 
   @override
-  Future<List<String>> crateApiCashuListMints() {
+  Future<void> crateApiNip60DisableNip60Sync({required WalletHandle handle}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -926,28 +1448,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuListMintsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiNip60DisableNip60SyncConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuListMintsConstMeta =>
-      const TaskConstMeta(debugName: "list_mints", argNames: []);
+  TaskConstMeta get kCrateApiNip60DisableNip60SyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "disable_nip60_sync",
+        argNames: ["handle"],
+      );
 
   @override
-  Future<String> crateApiCashuMnemonicToSeedHex({
-    required String mnemonicPhrase,
+  Future<void> crateApiNip60EnableNip60Sync({
+    required WalletHandle handle,
+    required String privateKey,
+    List<String>? relays,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mnemonicPhrase, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(privateKey, serializer);
+          sse_encode_opt_list_String(relays, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -956,201 +1485,195 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuMnemonicToSeedHexConstMeta,
-        argValues: [mnemonicPhrase],
+        constMeta: kCrateApiNip60EnableNip60SyncConstMeta,
+        argValues: [handle, privateKey, relays],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuMnemonicToSeedHexConstMeta =>
+  TaskConstMeta get kCrateApiNip60EnableNip60SyncConstMeta =>
       const TaskConstMeta(
-        debugName: "mnemonic_to_seed_hex",
-        argNames: ["mnemonicPhrase"],
+        debugName: "enable_nip60_sync",
+        argNames: ["handle", "privateKey", "relays"],
       );
 
   @override
-  String crateApiNostrNip04Decrypt({
-    required String ciphertext,
-    required String publicKey,
-    required String privateKey,
+  AnimatedQrFrames crateApiQrEncodeAnimatedQr({
+    required String payload,
+    int? maxFragmentLength,
+    int? frameCount,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ciphertext, serializer);
-          sse_encode_String(publicKey, serializer);
-          sse_encode_String(privateKey, serializer);
+          sse_encode_String(payload, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxFragmentLength, serializer);
+          sse_encode_opt_box_autoadd_u_32(frameCount, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_animated_qr_frames,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrNip04DecryptConstMeta,
-        argValues: [ciphertext, publicKey, privateKey],
+        constMeta: kCrateApiQrEncodeAnimatedQrConstMeta,
+        argValues: [payload, maxFragmentLength, frameCount],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrNip04DecryptConstMeta => const TaskConstMeta(
-    debugName: "nip04_decrypt",
-    argNames: ["ciphertext", "publicKey", "privateKey"],
+  TaskConstMeta get kCrateApiQrEncodeAnimatedQrConstMeta => const TaskConstMeta(
+    debugName: "encode_animated_qr",
+    argNames: ["payload", "maxFragmentLength", "frameCount"],
   );
 
   @override
-  String crateApiNostrNip04Encrypt({
-    required String plaintext,
-    required String publicKey,
-    required String privateKey,
+  Future<EncodedToken> crateApiCashuEncodeToken({
+    required WalletHandle handle,
+    required String token,
+    required String encoding,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(plaintext, serializer);
-          sse_encode_String(publicKey, serializer);
-          sse_encode_String(privateKey, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(token, serializer);
+          sse_encode_String(encoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_encoded_token,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrNip04EncryptConstMeta,
-        argValues: [plaintext, publicKey, privateKey],
+        constMeta: kCrateApiCashuEncodeTokenConstMeta,
+        argValues: [handle, token, encoding],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrNip04EncryptConstMeta => const TaskConstMeta(
-    debugName: "nip04_encrypt",
-    argNames: ["plaintext", "publicKey", "privateKey"],
+  TaskConstMeta get kCrateApiCashuEncodeTokenConstMeta => const TaskConstMeta(
+    debugName: "encode_token",
+    argNames: ["handle", "token", "encoding"],
   );
 
   @override
-  String crateApiNostrNip44Decrypt({
-    required String ciphertext,
-    required String publicKey,
-    required String privateKey,
-  }) {
+  DiagnosticsBundle crateApiLoggingExportDiagnostics() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ciphertext, serializer);
-          sse_encode_String(publicKey, serializer);
-          sse_encode_String(privateKey, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_diagnostics_bundle,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrNip44DecryptConstMeta,
-        argValues: [ciphertext, publicKey, privateKey],
+        constMeta: kCrateApiLoggingExportDiagnosticsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrNip44DecryptConstMeta => const TaskConstMeta(
-    debugName: "nip44_decrypt",
-    argNames: ["ciphertext", "publicKey", "privateKey"],
-  );
+  TaskConstMeta get kCrateApiLoggingExportDiagnosticsConstMeta =>
+      const TaskConstMeta(debugName: "export_diagnostics", argNames: []);
 
   @override
-  String crateApiNostrNip44Encrypt({
-    required String plaintext,
-    required String publicKey,
-    required String privateKey,
-  }) {
+  NostrKeys crateApiNostrGenerateKeys() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(plaintext, serializer);
-          sse_encode_String(publicKey, serializer);
-          sse_encode_String(privateKey, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_nostr_keys,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrNip44EncryptConstMeta,
-        argValues: [plaintext, publicKey, privateKey],
+        constMeta: kCrateApiNostrGenerateKeysConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrNip44EncryptConstMeta => const TaskConstMeta(
-    debugName: "nip44_encrypt",
-    argNames: ["plaintext", "publicKey", "privateKey"],
-  );
+  TaskConstMeta get kCrateApiNostrGenerateKeysConstMeta =>
+      const TaskConstMeta(debugName: "generate_keys", argNames: []);
 
   @override
-  String crateApiNostrNpubToPublicKey({required String npub}) {
+  NostrKeysWithBech32 crateApiNostrGenerateKeysWithBech32() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(npub, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_nostr_keys_with_bech_32,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrNpubToPublicKeyConstMeta,
-        argValues: [npub],
+        constMeta: kCrateApiNostrGenerateKeysWithBech32ConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrNpubToPublicKeyConstMeta =>
-      const TaskConstMeta(debugName: "npub_to_public_key", argNames: ["npub"]);
+  TaskConstMeta get kCrateApiNostrGenerateKeysWithBech32ConstMeta =>
+      const TaskConstMeta(debugName: "generate_keys_with_bech32", argNames: []);
 
   @override
-  String crateApiNostrNsecToSecretKey({required String nsec}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<String> crateApiCashuGenerateMnemonicPhrase({required int wordCount}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nsec, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+          sse_encode_u_32(wordCount, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrNsecToSecretKeyConstMeta,
-        argValues: [nsec],
+        constMeta: kCrateApiCashuGenerateMnemonicPhraseConstMeta,
+        argValues: [wordCount],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrNsecToSecretKeyConstMeta =>
-      const TaskConstMeta(debugName: "nsec_to_secret_key", argNames: ["nsec"]);
+  TaskConstMeta get kCrateApiCashuGenerateMnemonicPhraseConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_mnemonic_phrase",
+        argNames: ["wordCount"],
+      );
 
   @override
-  Future<Map<String, String>> crateApiCashuParseCashuToken({
-    required String token,
+  Future<Map<String, BigInt>> crateApiCashuGetAllBalances({
+    required WalletHandle handle,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(token, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1159,32 +1682,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Map_String_String,
+          decodeSuccessData: sse_decode_Map_String_u_64,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuParseCashuTokenConstMeta,
-        argValues: [token],
+        constMeta: kCrateApiCashuGetAllBalancesConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuParseCashuTokenConstMeta =>
-      const TaskConstMeta(debugName: "parse_cashu_token", argNames: ["token"]);
+  TaskConstMeta get kCrateApiCashuGetAllBalancesConstMeta =>
+      const TaskConstMeta(debugName: "get_all_balances", argNames: ["handle"]);
 
   @override
-  Future<String> crateApiCashuPayInvoiceForWallet({
-    required String mintUrl,
-    required String bolt11Invoice,
-    BigInt? maxFeeSats,
+  Future<List<TransactionInfo>> crateApiCashuGetAllTransactions({
+    required WalletHandle handle,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          sse_encode_String(bolt11Invoice, serializer);
-          sse_encode_opt_box_autoadd_u_64(maxFeeSats, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1193,55 +1712,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_transaction_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuPayInvoiceForWalletConstMeta,
-        argValues: [mintUrl, bolt11Invoice, maxFeeSats],
+        constMeta: kCrateApiCashuGetAllTransactionsConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuPayInvoiceForWalletConstMeta =>
+  TaskConstMeta get kCrateApiCashuGetAllTransactionsConstMeta =>
       const TaskConstMeta(
-        debugName: "pay_invoice_for_wallet",
-        argNames: ["mintUrl", "bolt11Invoice", "maxFeeSats"],
+        debugName: "get_all_transactions",
+        argNames: ["handle"],
       );
 
   @override
-  String crateApiNostrPublicKeyToNpub({required String publicKey}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<String?> crateApiDaemonGetDaemonAddress({
+    required WalletHandle handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publicKey, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrPublicKeyToNpubConstMeta,
-        argValues: [publicKey],
+        constMeta: kCrateApiDaemonGetDaemonAddressConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrPublicKeyToNpubConstMeta =>
+  TaskConstMeta get kCrateApiDaemonGetDaemonAddressConstMeta =>
       const TaskConstMeta(
-        debugName: "public_key_to_npub",
-        argNames: ["publicKey"],
+        debugName: "get_daemon_address",
+        argNames: ["handle"],
       );
 
   @override
-  Future<BigInt> crateApiCashuReceiveTokens({required String token}) {
+  Future<List<InterruptedOperation>> crateApiCashuGetInterruptedOperations({
+    required WalletHandle handle,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(token, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1250,30 +1778,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
+          decodeSuccessData: sse_decode_list_interrupted_operation,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuReceiveTokensConstMeta,
-        argValues: [token],
+        constMeta: kCrateApiCashuGetInterruptedOperationsConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuReceiveTokensConstMeta =>
-      const TaskConstMeta(debugName: "receive_tokens", argNames: ["token"]);
+  TaskConstMeta get kCrateApiCashuGetInterruptedOperationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_interrupted_operations",
+        argNames: ["handle"],
+      );
 
   @override
-  Future<String> crateApiCashuReinitializeWithTorConfig({
-    required String databaseDir,
-    required String seedHex,
+  Future<MintInfo> crateApiCashuGetMintInfo({
+    required WalletHandle handle,
+    required String mintUrl,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(databaseDir, serializer);
-          sse_encode_String(seedHex, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1282,28 +1813,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_mint_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuReinitializeWithTorConfigConstMeta,
-        argValues: [databaseDir, seedHex],
+        constMeta: kCrateApiCashuGetMintInfoConstMeta,
+        argValues: [handle, mintUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuReinitializeWithTorConfigConstMeta =>
-      const TaskConstMeta(
-        debugName: "reinitialize_with_tor_config",
-        argNames: ["databaseDir", "seedHex"],
-      );
+  TaskConstMeta get kCrateApiCashuGetMintInfoConstMeta => const TaskConstMeta(
+    debugName: "get_mint_info",
+    argNames: ["handle", "mintUrl"],
+  );
 
   @override
-  Future<String> crateApiCashuRemoveMint({required String mintUrl}) {
+  Future<ProxyConfig?> crateApiCashuGetMintProxy({
+    required WalletHandle handle,
+    required String mintUrl,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           sse_encode_String(mintUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
@@ -1313,26 +1847,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_opt_box_autoadd_proxy_config,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuRemoveMintConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiCashuGetMintProxyConstMeta,
+        argValues: [handle, mintUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuRemoveMintConstMeta =>
-      const TaskConstMeta(debugName: "remove_mint", argNames: ["mintUrl"]);
+  TaskConstMeta get kCrateApiCashuGetMintProxyConstMeta => const TaskConstMeta(
+    debugName: "get_mint_proxy",
+    argNames: ["handle", "mintUrl"],
+  );
 
   @override
-  Future<BigInt> crateApiCashuRestoreMint({required String mintUrl}) {
+  Future<Nip60SyncStatus> crateApiNip60GetNip60SyncStatus({
+    required WalletHandle handle,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1341,52 +1879,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
+          decodeSuccessData: sse_decode_nip_60_sync_status,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuRestoreMintConstMeta,
-        argValues: [mintUrl],
+        constMeta: kCrateApiNip60GetNip60SyncStatusConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuRestoreMintConstMeta =>
-      const TaskConstMeta(debugName: "restore_mint", argNames: ["mintUrl"]);
+  TaskConstMeta get kCrateApiNip60GetNip60SyncStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_nip60_sync_status",
+        argNames: ["handle"],
+      );
 
   @override
-  String crateApiNostrSecretKeyToNsec({required String secretKey}) {
+  String crateApiNostrGetPublicKeyFromPrivate({required String privateKey}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(secretKey, serializer);
+          sse_encode_String(privateKey, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrSecretKeyToNsecConstMeta,
-        argValues: [secretKey],
+        constMeta: kCrateApiNostrGetPublicKeyFromPrivateConstMeta,
+        argValues: [privateKey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrSecretKeyToNsecConstMeta =>
+  TaskConstMeta get kCrateApiNostrGetPublicKeyFromPrivateConstMeta =>
       const TaskConstMeta(
-        debugName: "secret_key_to_nsec",
-        argNames: ["secretKey"],
+        debugName: "get_public_key_from_private",
+        argNames: ["privateKey"],
       );
 
   @override
-  Future<String> crateApiCashuSeedHexToMnemonic({required String seedHex}) {
+  Future<List<SpendingAllowance>> crateApiLimitsGetSpendingAllowances({
+    required WalletHandle handle,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(seedHex, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1395,49 +1938,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_spending_allowance,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuSeedHexToMnemonicConstMeta,
-        argValues: [seedHex],
+        constMeta: kCrateApiLimitsGetSpendingAllowancesConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuSeedHexToMnemonicConstMeta =>
+  TaskConstMeta get kCrateApiLimitsGetSpendingAllowancesConstMeta =>
       const TaskConstMeta(
-        debugName: "seed_hex_to_mnemonic",
-        argNames: ["seedHex"],
+        debugName: "get_spending_allowances",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiCashuSendP2PkTokens({
-    required String mintUrl,
-    required BigInt amount,
-    String? memo,
-    required String recipientPubkey,
-    List<String>? additionalPubkeys,
-    List<String>? refundPubkeys,
-    BigInt? requiredSigs,
-    BigInt? locktime,
-    String? sigflag,
-    BigInt? refundRequiredSigs,
+  Future<List<SpendingLimit>> crateApiLimitsGetSpendingLimits({
+    required WalletHandle handle,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          sse_encode_u_64(amount, serializer);
-          sse_encode_opt_String(memo, serializer);
-          sse_encode_String(recipientPubkey, serializer);
-          sse_encode_opt_list_String(additionalPubkeys, serializer);
-          sse_encode_opt_list_String(refundPubkeys, serializer);
-          sse_encode_opt_box_autoadd_u_64(requiredSigs, serializer);
-          sse_encode_opt_box_autoadd_u_64(locktime, serializer);
-          sse_encode_opt_String(sigflag, serializer);
-          sse_encode_opt_box_autoadd_u_64(refundRequiredSigs, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1446,57 +1971,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_spending_limit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuSendP2PkTokensConstMeta,
-        argValues: [
-          mintUrl,
-          amount,
-          memo,
-          recipientPubkey,
-          additionalPubkeys,
-          refundPubkeys,
-          requiredSigs,
-          locktime,
-          sigflag,
-          refundRequiredSigs,
-        ],
+        constMeta: kCrateApiLimitsGetSpendingLimitsConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuSendP2PkTokensConstMeta =>
+  TaskConstMeta get kCrateApiLimitsGetSpendingLimitsConstMeta =>
       const TaskConstMeta(
-        debugName: "send_p2pk_tokens",
-        argNames: [
-          "mintUrl",
-          "amount",
-          "memo",
-          "recipientPubkey",
-          "additionalPubkeys",
-          "refundPubkeys",
-          "requiredSigs",
-          "locktime",
-          "sigflag",
-          "refundRequiredSigs",
-        ],
+        debugName: "get_spending_limits",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiCashuSendTokens({
-    required String mintUrl,
-    required BigInt amount,
-    String? memo,
+  Future<TorBootstrapStatus> crateApiCashuGetTorBootstrapStatus({
+    required WalletHandle handle,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          sse_encode_u_64(amount, serializer);
-          sse_encode_opt_String(memo, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1505,28 +2004,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_tor_bootstrap_status,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuSendTokensConstMeta,
-        argValues: [mintUrl, amount, memo],
+        constMeta: kCrateApiCashuGetTorBootstrapStatusConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuSendTokensConstMeta => const TaskConstMeta(
-    debugName: "send_tokens",
-    argNames: ["mintUrl", "amount", "memo"],
-  );
+  TaskConstMeta get kCrateApiCashuGetTorBootstrapStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_tor_bootstrap_status",
+        argNames: ["handle"],
+      );
 
   @override
-  Future<void> crateApiCashuSetTorConfig({required bool policy}) {
+  Future<String> crateApiCashuGetTorConfig({required WalletHandle handle}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(policy, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1535,1022 +2035,6089 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuSetTorConfigConstMeta,
-        argValues: [policy],
+        constMeta: kCrateApiCashuGetTorConfigConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuSetTorConfigConstMeta =>
-      const TaskConstMeta(debugName: "set_tor_config", argNames: ["policy"]);
+  TaskConstMeta get kCrateApiCashuGetTorConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_tor_config", argNames: ["handle"]);
 
   @override
-  String crateApiNostrSignEvent({
-    required String eventJson,
-    required String privateKey,
+  Future<bool> crateApiCashuGetTorIsolation({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuGetTorIsolationConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuGetTorIsolationConstMeta =>
+      const TaskConstMeta(debugName: "get_tor_isolation", argNames: ["handle"]);
+
+  @override
+  Future<WalletInfo> crateApiCashuGetWalletInfo({
+    required WalletHandle handle,
+    required String mintUrl,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(eventJson, serializer);
-          sse_encode_String(privateKey, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_wallet_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNostrSignEventConstMeta,
-        argValues: [eventJson, privateKey],
+        constMeta: kCrateApiCashuGetWalletInfoConstMeta,
+        argValues: [handle, mintUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrSignEventConstMeta => const TaskConstMeta(
-    debugName: "sign_event",
-    argNames: ["eventJson", "privateKey"],
+  TaskConstMeta get kCrateApiCashuGetWalletInfoConstMeta => const TaskConstMeta(
+    debugName: "get_wallet_info",
+    argNames: ["handle", "mintUrl"],
   );
 
   @override
-  Future<bool> crateApiCashuValidateMnemonicPhrase({
-    required String mnemonicPhrase,
+  Future<List<CashuProof>> crateApiCashuGetWalletProofs({
+    required WalletHandle handle,
+    required String mintUrl,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mnemonicPhrase, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 46,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_list_cashu_proof,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuValidateMnemonicPhraseConstMeta,
-        argValues: [mnemonicPhrase],
+        constMeta: kCrateApiCashuGetWalletProofsConstMeta,
+        argValues: [handle, mintUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuValidateMnemonicPhraseConstMeta =>
+  TaskConstMeta get kCrateApiCashuGetWalletProofsConstMeta =>
       const TaskConstMeta(
-        debugName: "validate_mnemonic_phrase",
-        argNames: ["mnemonicPhrase"],
+        debugName: "get_wallet_proofs",
+        argNames: ["handle", "mintUrl"],
       );
 
   @override
-  bool crateApiNostrVerifyEvent({required NostrEvent event}) {
+  Future<ProxyConfig?> crateApiCashuGetWalletProxy({
+    required WalletHandle handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_proxy_config,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuGetWalletProxyConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuGetWalletProxyConstMeta =>
+      const TaskConstMeta(debugName: "get_wallet_proxy", argNames: ["handle"]);
+
+  @override
+  String crateApiNostrGreet({required String name}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_nostr_event(event, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiNostrVerifyEventConstMeta,
-        argValues: [event],
+        constMeta: kCrateApiNostrGreetConstMeta,
+        argValues: [name],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNostrVerifyEventConstMeta =>
-      const TaskConstMeta(debugName: "verify_event", argNames: ["event"]);
+  TaskConstMeta get kCrateApiNostrGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
 
   @override
-  Future<bool> crateApiCashuVerifyTokenDleq({
-    required String mintUrl,
-    required String token,
+  Future<ImportReport> crateApiCashuImportWalletBackup({
+    required WalletHandle handle,
+    required String backup,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          sse_encode_String(token, serializer);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(backup, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 49,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_import_report,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuVerifyTokenDleqConstMeta,
-        argValues: [mintUrl, token],
+        constMeta: kCrateApiCashuImportWalletBackupConstMeta,
+        argValues: [handle, backup],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuVerifyTokenDleqConstMeta =>
+  TaskConstMeta get kCrateApiCashuImportWalletBackupConstMeta =>
       const TaskConstMeta(
-        debugName: "verify_token_dleq",
-        argNames: ["mintUrl", "token"],
+        debugName: "import_wallet_backup",
+        argNames: ["handle", "backup"],
       );
 
   @override
-  Future<bool> crateApiCashuVerifyTokenP2Pk({
-    required String mintUrl,
-    required String token,
-    required String conditions,
+  Future<void> crateApiNostrInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNostrInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<String> crateApiCashuInitMultiMintWallet({
+    required String databaseDir,
+    required String seedHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
-          sse_encode_String(token, serializer);
-          sse_encode_String(conditions, serializer);
+          sse_encode_String(databaseDir, serializer);
+          sse_encode_String(seedHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 51,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuVerifyTokenP2PkConstMeta,
-        argValues: [mintUrl, token, conditions],
+        constMeta: kCrateApiCashuInitMultiMintWalletConstMeta,
+        argValues: [databaseDir, seedHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuVerifyTokenP2PkConstMeta =>
+  TaskConstMeta get kCrateApiCashuInitMultiMintWalletConstMeta =>
       const TaskConstMeta(
-        debugName: "verify_token_p2pk",
-        argNames: ["mintUrl", "token", "conditions"],
+        debugName: "init_multi_mint_wallet",
+        argNames: ["databaseDir", "seedHex"],
       );
 
   @override
-  Future<bool> crateApiCashuWalletExists({
-    required String mintUrl,
+  Future<String> crateApiCashuInitMultiMintWalletWithTor({
     required String databaseDir,
+    required String seedHex,
+    String? torConfig,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mintUrl, serializer);
           sse_encode_String(databaseDir, serializer);
+          sse_encode_String(seedHex, serializer);
+          sse_encode_opt_String(torConfig, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 52,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCashuWalletExistsConstMeta,
-        argValues: [mintUrl, databaseDir],
+        constMeta: kCrateApiCashuInitMultiMintWalletWithTorConstMeta,
+        argValues: [databaseDir, seedHex, torConfig],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCashuWalletExistsConstMeta => const TaskConstMeta(
-    debugName: "wallet_exists",
-    argNames: ["mintUrl", "databaseDir"],
-  );
-
-  @protected
-  Map<String, String> dco_decode_Map_String_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
+  TaskConstMeta get kCrateApiCashuInitMultiMintWalletWithTorConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_multi_mint_wallet_with_tor",
+        argNames: ["databaseDir", "seedHex", "torConfig"],
+      );
 
-  @protected
+  @override
+  Future<bool> crateApiCashuIsOfflineMode({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuIsOfflineModeConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuIsOfflineModeConstMeta =>
+      const TaskConstMeta(debugName: "is_offline_mode", argNames: ["handle"]);
+
+  @override
+  Future<bool> crateApiCashuIsTorEnabled({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuIsTorEnabledConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuIsTorEnabledConstMeta =>
+      const TaskConstMeta(debugName: "is_tor_enabled", argNames: ["handle"]);
+
+  @override
+  Future<bool> crateApiCashuIsTorReady({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuIsTorReadyConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuIsTorReadyConstMeta =>
+      const TaskConstMeta(debugName: "is_tor_ready", argNames: ["handle"]);
+
+  @override
+  Future<bool> crateApiCashuIsWalletDatabaseEncrypted({
+    required String profileId,
+    required String databaseDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(profileId, serializer);
+          sse_encode_String(databaseDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuIsWalletDatabaseEncryptedConstMeta,
+        argValues: [profileId, databaseDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuIsWalletDatabaseEncryptedConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_wallet_database_encrypted",
+        argNames: ["profileId", "databaseDir"],
+      );
+
+  @override
+  Future<List<DaemonClient>> crateApiDaemonListDaemonClients({
+    required WalletHandle handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_daemon_client,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDaemonListDaemonClientsConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDaemonListDaemonClientsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_daemon_clients",
+        argNames: ["handle"],
+      );
+
+  @override
+  Future<List<String>> crateApiCashuListMints({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuListMintsConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuListMintsConstMeta =>
+      const TaskConstMeta(debugName: "list_mints", argNames: ["handle"]);
+
+  @override
+  Future<List<WalletProfile>> crateApiCashuListProfiles({
+    required String databaseDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(databaseDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_wallet_profile,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuListProfilesConstMeta,
+        argValues: [databaseDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuListProfilesConstMeta => const TaskConstMeta(
+    debugName: "list_profiles",
+    argNames: ["databaseDir"],
+  );
+
+  @override
+  void crateApiLoggingLogFromDart({
+    required String level,
+    required String message,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          sse_encode_String(message, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLoggingLogFromDartConstMeta,
+        argValues: [level, message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingLogFromDartConstMeta => const TaskConstMeta(
+    debugName: "log_from_dart",
+    argNames: ["level", "message"],
+  );
+
+  @override
+  Future<Map<String, String>> crateApiCashuMintBolt12Quote({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String quoteId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(quoteId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Map_String_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuMintBolt12QuoteConstMeta,
+        argValues: [handle, mintUrl, quoteId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuMintBolt12QuoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "mint_bolt12_quote",
+        argNames: ["handle", "mintUrl", "quoteId"],
+      );
+
+  @override
+  Future<String> crateApiCashuMnemonicToSeedHex({
+    required String mnemonicPhrase,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mnemonicPhrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuMnemonicToSeedHexConstMeta,
+        argValues: [mnemonicPhrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuMnemonicToSeedHexConstMeta =>
+      const TaskConstMeta(
+        debugName: "mnemonic_to_seed_hex",
+        argNames: ["mnemonicPhrase"],
+      );
+
+  @override
+  Future<int> crateApiCashuNewTorIdentity({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuNewTorIdentityConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuNewTorIdentityConstMeta =>
+      const TaskConstMeta(debugName: "new_tor_identity", argNames: ["handle"]);
+
+  @override
+  String crateApiNostrNip04Decrypt({
+    required String ciphertext,
+    required String publicKey,
+    required String privateKey,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ciphertext, serializer);
+          sse_encode_String(publicKey, serializer);
+          sse_encode_String(privateKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrNip04DecryptConstMeta,
+        argValues: [ciphertext, publicKey, privateKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrNip04DecryptConstMeta => const TaskConstMeta(
+    debugName: "nip04_decrypt",
+    argNames: ["ciphertext", "publicKey", "privateKey"],
+  );
+
+  @override
+  String crateApiNostrNip04Encrypt({
+    required String plaintext,
+    required String publicKey,
+    required String privateKey,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(plaintext, serializer);
+          sse_encode_String(publicKey, serializer);
+          sse_encode_String(privateKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrNip04EncryptConstMeta,
+        argValues: [plaintext, publicKey, privateKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrNip04EncryptConstMeta => const TaskConstMeta(
+    debugName: "nip04_encrypt",
+    argNames: ["plaintext", "publicKey", "privateKey"],
+  );
+
+  @override
+  String crateApiNostrNip44Decrypt({
+    required String ciphertext,
+    required String publicKey,
+    required String privateKey,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ciphertext, serializer);
+          sse_encode_String(publicKey, serializer);
+          sse_encode_String(privateKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrNip44DecryptConstMeta,
+        argValues: [ciphertext, publicKey, privateKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrNip44DecryptConstMeta => const TaskConstMeta(
+    debugName: "nip44_decrypt",
+    argNames: ["ciphertext", "publicKey", "privateKey"],
+  );
+
+  @override
+  String crateApiNostrNip44Encrypt({
+    required String plaintext,
+    required String publicKey,
+    required String privateKey,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(plaintext, serializer);
+          sse_encode_String(publicKey, serializer);
+          sse_encode_String(privateKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrNip44EncryptConstMeta,
+        argValues: [plaintext, publicKey, privateKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrNip44EncryptConstMeta => const TaskConstMeta(
+    debugName: "nip44_encrypt",
+    argNames: ["plaintext", "publicKey", "privateKey"],
+  );
+
+  @override
+  String crateApiNostrNpubToPublicKey({required String npub}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(npub, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrNpubToPublicKeyConstMeta,
+        argValues: [npub],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrNpubToPublicKeyConstMeta =>
+      const TaskConstMeta(debugName: "npub_to_public_key", argNames: ["npub"]);
+
+  @override
+  String crateApiNostrNsecToSecretKey({required String nsec}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nsec, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrNsecToSecretKeyConstMeta,
+        argValues: [nsec],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrNsecToSecretKeyConstMeta =>
+      const TaskConstMeta(debugName: "nsec_to_secret_key", argNames: ["nsec"]);
+
+  @override
+  Future<WalletHandle> crateApiCashuOpenEncryptedWallet({
+    required String profileId,
+    required String databaseDir,
+    required String seedHex,
+    required String keyMaterial,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(profileId, serializer);
+          sse_encode_String(databaseDir, serializer);
+          sse_encode_String(seedHex, serializer);
+          sse_encode_String(keyMaterial, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_handle,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuOpenEncryptedWalletConstMeta,
+        argValues: [profileId, databaseDir, seedHex, keyMaterial],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuOpenEncryptedWalletConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_encrypted_wallet",
+        argNames: ["profileId", "databaseDir", "seedHex", "keyMaterial"],
+      );
+
+  @override
+  Future<WalletHandle> crateApiCashuOpenWallet({
+    required String profileId,
+    required String databaseDir,
+    required String seedHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(profileId, serializer);
+          sse_encode_String(databaseDir, serializer);
+          sse_encode_String(seedHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_handle,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuOpenWalletConstMeta,
+        argValues: [profileId, databaseDir, seedHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuOpenWalletConstMeta => const TaskConstMeta(
+    debugName: "open_wallet",
+    argNames: ["profileId", "databaseDir", "seedHex"],
+  );
+
+  @override
+  Future<Map<String, String>> crateApiCashuParseCashuToken({
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Map_String_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuParseCashuTokenConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuParseCashuTokenConstMeta =>
+      const TaskConstMeta(debugName: "parse_cashu_token", argNames: ["token"]);
+
+  @override
+  UnsupportedByMint? crateApiCashuParseUnsupportedByMint({
+    required String error,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(error, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_unsupported_by_mint,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCashuParseUnsupportedByMintConstMeta,
+        argValues: [error],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuParseUnsupportedByMintConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_unsupported_by_mint",
+        argNames: ["error"],
+      );
+
+  @override
+  Future<String> crateApiCashuPayBolt12Offer({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String offer,
+    BigInt? amountSats,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(offer, serializer);
+          sse_encode_opt_box_autoadd_u_64(amountSats, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuPayBolt12OfferConstMeta,
+        argValues: [handle, mintUrl, offer, amountSats],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuPayBolt12OfferConstMeta =>
+      const TaskConstMeta(
+        debugName: "pay_bolt12_offer",
+        argNames: ["handle", "mintUrl", "offer", "amountSats"],
+      );
+
+  @override
+  Future<String> crateApiCashuPayInvoiceForWallet({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String bolt11Invoice,
+    BigInt? maxFeeSats,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(bolt11Invoice, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxFeeSats, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuPayInvoiceForWalletConstMeta,
+        argValues: [handle, mintUrl, bolt11Invoice, maxFeeSats],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuPayInvoiceForWalletConstMeta =>
+      const TaskConstMeta(
+        debugName: "pay_invoice_for_wallet",
+        argNames: ["handle", "mintUrl", "bolt11Invoice", "maxFeeSats"],
+      );
+
+  @override
+  Future<String> crateApiCashuPayLnurl({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String lnurl,
+    required BigInt amountSats,
+    String? comment,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(lnurl, serializer);
+          sse_encode_u_64(amountSats, serializer);
+          sse_encode_opt_String(comment, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuPayLnurlConstMeta,
+        argValues: [handle, mintUrl, lnurl, amountSats, comment],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuPayLnurlConstMeta => const TaskConstMeta(
+    debugName: "pay_lnurl",
+    argNames: ["handle", "mintUrl", "lnurl", "amountSats", "comment"],
+  );
+
+  @override
+  Future<Map<String, String>> crateApiCashuPayPaymentRequest({
+    required WalletHandle handle,
+    required String request,
+    String? mintUrl,
+    BigInt? amount,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(request, serializer);
+          sse_encode_opt_String(mintUrl, serializer);
+          sse_encode_opt_box_autoadd_u_64(amount, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Map_String_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuPayPaymentRequestConstMeta,
+        argValues: [handle, request, mintUrl, amount],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuPayPaymentRequestConstMeta =>
+      const TaskConstMeta(
+        debugName: "pay_payment_request",
+        argNames: ["handle", "request", "mintUrl", "amount"],
+      );
+
+  @override
+  String crateApiNostrPublicKeyToNpub({required String publicKey}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(publicKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrPublicKeyToNpubConstMeta,
+        argValues: [publicKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrPublicKeyToNpubConstMeta =>
+      const TaskConstMeta(
+        debugName: "public_key_to_npub",
+        argNames: ["publicKey"],
+      );
+
+  @override
+  Future<BigInt> crateApiCashuReceiveTokens({
+    required WalletHandle handle,
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuReceiveTokensConstMeta,
+        argValues: [handle, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuReceiveTokensConstMeta => const TaskConstMeta(
+    debugName: "receive_tokens",
+    argNames: ["handle", "token"],
+  );
+
+  @override
+  Future<BigInt> crateApiCashuRedeemLnurlWithdraw({
+    required WalletHandle handle,
+    required String lnurl,
+    required String mintUrl,
+    BigInt? amount,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(lnurl, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_opt_box_autoadd_u_64(amount, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuRedeemLnurlWithdrawConstMeta,
+        argValues: [handle, lnurl, mintUrl, amount],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuRedeemLnurlWithdrawConstMeta =>
+      const TaskConstMeta(
+        debugName: "redeem_lnurl_withdraw",
+        argNames: ["handle", "lnurl", "mintUrl", "amount"],
+      );
+
+  @override
+  Future<String> crateApiCashuReinitializeWithTorConfig({
+    required String databaseDir,
+    required String seedHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(databaseDir, serializer);
+          sse_encode_String(seedHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuReinitializeWithTorConfigConstMeta,
+        argValues: [databaseDir, seedHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuReinitializeWithTorConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "reinitialize_with_tor_config",
+        argNames: ["databaseDir", "seedHex"],
+      );
+
+  @override
+  Future<void> crateApiDaemonRemoveDaemonClient({
+    required WalletHandle handle,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDaemonRemoveDaemonClientConstMeta,
+        argValues: [handle, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDaemonRemoveDaemonClientConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_daemon_client",
+        argNames: ["handle", "name"],
+      );
+
+  @override
+  Future<String> crateApiCashuRemoveMint({
+    required WalletHandle handle,
+    required String mintUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuRemoveMintConstMeta,
+        argValues: [handle, mintUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuRemoveMintConstMeta => const TaskConstMeta(
+    debugName: "remove_mint",
+    argNames: ["handle", "mintUrl"],
+  );
+
+  @override
+  Future<void> crateApiLimitsRemoveSpendingLimit({
+    required WalletHandle handle,
+    String? mintUrl,
+    String? pin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_opt_String(mintUrl, serializer);
+          sse_encode_opt_String(pin, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLimitsRemoveSpendingLimitConstMeta,
+        argValues: [handle, mintUrl, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLimitsRemoveSpendingLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_spending_limit",
+        argNames: ["handle", "mintUrl", "pin"],
+      );
+
+  @override
+  Future<LnurlPayInfo> crateApiCashuResolveLnurlPay({
+    required WalletHandle handle,
+    required String lnurl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(lnurl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_lnurl_pay_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuResolveLnurlPayConstMeta,
+        argValues: [handle, lnurl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuResolveLnurlPayConstMeta =>
+      const TaskConstMeta(
+        debugName: "resolve_lnurl_pay",
+        argNames: ["handle", "lnurl"],
+      );
+
+  @override
+  Future<RestoredProfile> crateApiCashuRestoreBackupProfile({
+    required String profileId,
+    required String databaseDir,
+    required String backup,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(profileId, serializer);
+          sse_encode_String(databaseDir, serializer);
+          sse_encode_String(backup, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_restored_profile,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuRestoreBackupProfileConstMeta,
+        argValues: [profileId, databaseDir, backup],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuRestoreBackupProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_backup_profile",
+        argNames: ["profileId", "databaseDir", "backup"],
+      );
+
+  @override
+  Future<BigInt> crateApiCashuRestoreMint({
+    required WalletHandle handle,
+    required String mintUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuRestoreMintConstMeta,
+        argValues: [handle, mintUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuRestoreMintConstMeta => const TaskConstMeta(
+    debugName: "restore_mint",
+    argNames: ["handle", "mintUrl"],
+  );
+
+  @override
+  Future<String> crateApiCashuRotateWalletDatabaseKey({
+    required String profileId,
+    required String databaseDir,
+    required String oldKeyMaterial,
+    required String newKeyMaterial,
+    required bool force,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(profileId, serializer);
+          sse_encode_String(databaseDir, serializer);
+          sse_encode_String(oldKeyMaterial, serializer);
+          sse_encode_String(newKeyMaterial, serializer);
+          sse_encode_bool(force, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuRotateWalletDatabaseKeyConstMeta,
+        argValues: [
+          profileId,
+          databaseDir,
+          oldKeyMaterial,
+          newKeyMaterial,
+          force,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuRotateWalletDatabaseKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "rotate_wallet_database_key",
+        argNames: [
+          "profileId",
+          "databaseDir",
+          "oldKeyMaterial",
+          "newKeyMaterial",
+          "force",
+        ],
+      );
+
+  @override
+  String crateApiNostrSecretKeyToNsec({required String secretKey}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(secretKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrSecretKeyToNsecConstMeta,
+        argValues: [secretKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrSecretKeyToNsecConstMeta =>
+      const TaskConstMeta(
+        debugName: "secret_key_to_nsec",
+        argNames: ["secretKey"],
+      );
+
+  @override
+  Future<String> crateApiCashuSeedHexToMnemonic({required String seedHex}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(seedHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSeedHexToMnemonicConstMeta,
+        argValues: [seedHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSeedHexToMnemonicConstMeta =>
+      const TaskConstMeta(
+        debugName: "seed_hex_to_mnemonic",
+        argNames: ["seedHex"],
+      );
+
+  @override
+  Future<String> crateApiCashuSendP2PkTokens({
+    required WalletHandle handle,
+    required String mintUrl,
+    required BigInt amount,
+    String? memo,
+    required String recipientPubkey,
+    List<String>? additionalPubkeys,
+    List<String>? refundPubkeys,
+    BigInt? requiredSigs,
+    BigInt? locktime,
+    String? sigflag,
+    BigInt? refundRequiredSigs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_u_64(amount, serializer);
+          sse_encode_opt_String(memo, serializer);
+          sse_encode_String(recipientPubkey, serializer);
+          sse_encode_opt_list_String(additionalPubkeys, serializer);
+          sse_encode_opt_list_String(refundPubkeys, serializer);
+          sse_encode_opt_box_autoadd_u_64(requiredSigs, serializer);
+          sse_encode_opt_box_autoadd_u_64(locktime, serializer);
+          sse_encode_opt_String(sigflag, serializer);
+          sse_encode_opt_box_autoadd_u_64(refundRequiredSigs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSendP2PkTokensConstMeta,
+        argValues: [
+          handle,
+          mintUrl,
+          amount,
+          memo,
+          recipientPubkey,
+          additionalPubkeys,
+          refundPubkeys,
+          requiredSigs,
+          locktime,
+          sigflag,
+          refundRequiredSigs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSendP2PkTokensConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_p2pk_tokens",
+        argNames: [
+          "handle",
+          "mintUrl",
+          "amount",
+          "memo",
+          "recipientPubkey",
+          "additionalPubkeys",
+          "refundPubkeys",
+          "requiredSigs",
+          "locktime",
+          "sigflag",
+          "refundRequiredSigs",
+        ],
+      );
+
+  @override
+  Future<String> crateApiCashuSendSelectedProofs({
+    required WalletHandle handle,
+    required String mintUrl,
+    required List<String> proofYs,
+    required SelectedProofsSendOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_list_String(proofYs, serializer);
+          sse_encode_box_autoadd_selected_proofs_send_options(
+            options,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSendSelectedProofsConstMeta,
+        argValues: [handle, mintUrl, proofYs, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSendSelectedProofsConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_selected_proofs",
+        argNames: ["handle", "mintUrl", "proofYs", "options"],
+      );
+
+  @override
+  Future<String> crateApiCashuSendTokens({
+    required WalletHandle handle,
+    required String mintUrl,
+    required BigInt amount,
+    String? memo,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_u_64(amount, serializer);
+          sse_encode_opt_String(memo, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSendTokensConstMeta,
+        argValues: [handle, mintUrl, amount, memo],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSendTokensConstMeta => const TaskConstMeta(
+    debugName: "send_tokens",
+    argNames: ["handle", "mintUrl", "amount", "memo"],
+  );
+
+  @override
+  Future<EncodedToken> crateApiCashuSendTokensWithEncoding({
+    required WalletHandle handle,
+    required String mintUrl,
+    required BigInt amount,
+    String? memo,
+    required String encoding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_u_64(amount, serializer);
+          sse_encode_opt_String(memo, serializer);
+          sse_encode_String(encoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_encoded_token,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSendTokensWithEncodingConstMeta,
+        argValues: [handle, mintUrl, amount, memo, encoding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSendTokensWithEncodingConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_tokens_with_encoding",
+        argNames: ["handle", "mintUrl", "amount", "memo", "encoding"],
+      );
+
+  @override
+  void crateApiLoggingSetLogLevel({
+    required String module,
+    required String level,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(module, serializer);
+          sse_encode_String(level, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLoggingSetLogLevelConstMeta,
+        argValues: [module, level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingSetLogLevelConstMeta => const TaskConstMeta(
+    debugName: "set_log_level",
+    argNames: ["module", "level"],
+  );
+
+  @override
+  Future<void> crateApiCashuSetMintProxy({
+    required WalletHandle handle,
+    required String mintUrl,
+    ProxyConfig? proxy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_opt_box_autoadd_proxy_config(proxy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSetMintProxyConstMeta,
+        argValues: [handle, mintUrl, proxy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSetMintProxyConstMeta => const TaskConstMeta(
+    debugName: "set_mint_proxy",
+    argNames: ["handle", "mintUrl", "proxy"],
+  );
+
+  @override
+  Future<void> crateApiCashuSetOfflineMode({
+    required WalletHandle handle,
+    required bool offline,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_bool(offline, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSetOfflineModeConstMeta,
+        argValues: [handle, offline],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSetOfflineModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_offline_mode",
+        argNames: ["handle", "offline"],
+      );
+
+  @override
+  Future<void> crateApiLimitsSetSpendingLimit({
+    required WalletHandle handle,
+    required SpendingLimit limit,
+    String? pin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_box_autoadd_spending_limit(limit, serializer);
+          sse_encode_opt_String(pin, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLimitsSetSpendingLimitConstMeta,
+        argValues: [handle, limit, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLimitsSetSpendingLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_spending_limit",
+        argNames: ["handle", "limit", "pin"],
+      );
+
+  @override
+  Future<void> crateApiLimitsSetSpendingPin({
+    required WalletHandle handle,
+    String? currentPin,
+    required String newPin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_opt_String(currentPin, serializer);
+          sse_encode_String(newPin, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLimitsSetSpendingPinConstMeta,
+        argValues: [handle, currentPin, newPin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLimitsSetSpendingPinConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_spending_pin",
+        argNames: ["handle", "currentPin", "newPin"],
+      );
+
+  @override
+  Future<bool> crateApiCashuSetTorConfig({
+    required WalletHandle handle,
+    required String policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSetTorConfigConstMeta,
+        argValues: [handle, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSetTorConfigConstMeta => const TaskConstMeta(
+    debugName: "set_tor_config",
+    argNames: ["handle", "policy"],
+  );
+
+  @override
+  Future<void> crateApiCashuSetTorIsolation({
+    required WalletHandle handle,
+    required bool enabled,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSetTorIsolationConstMeta,
+        argValues: [handle, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSetTorIsolationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_tor_isolation",
+        argNames: ["handle", "enabled"],
+      );
+
+  @override
+  Future<void> crateApiCashuSetWalletProxy({
+    required WalletHandle handle,
+    ProxyConfig? proxy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_opt_box_autoadd_proxy_config(proxy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuSetWalletProxyConstMeta,
+        argValues: [handle, proxy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuSetWalletProxyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_wallet_proxy",
+        argNames: ["handle", "proxy"],
+      );
+
+  @override
+  Future<List<InterruptedOperation>> crateApiCashuShutdownWallet({
+    required WalletHandle handle,
+    BigInt? timeoutSecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_opt_box_autoadd_u_64(timeoutSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_interrupted_operation,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuShutdownWalletConstMeta,
+        argValues: [handle, timeoutSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuShutdownWalletConstMeta =>
+      const TaskConstMeta(
+        debugName: "shutdown_wallet",
+        argNames: ["handle", "timeoutSecs"],
+      );
+
+  @override
+  String crateApiNostrSignEvent({
+    required String eventJson,
+    required String privateKey,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(eventJson, serializer);
+          sse_encode_String(privateKey, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrSignEventConstMeta,
+        argValues: [eventJson, privateKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrSignEventConstMeta => const TaskConstMeta(
+    debugName: "sign_event",
+    argNames: ["eventJson", "privateKey"],
+  );
+
+  @override
+  Future<String> crateApiDaemonStartDaemon({
+    required WalletHandle handle,
+    required String listen,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(listen, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDaemonStartDaemonConstMeta,
+        argValues: [handle, listen],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDaemonStartDaemonConstMeta => const TaskConstMeta(
+    debugName: "start_daemon",
+    argNames: ["handle", "listen"],
+  );
+
+  @override
+  Future<void> crateApiDaemonStopDaemon({required WalletHandle handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDaemonStopDaemonConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDaemonStopDaemonConstMeta =>
+      const TaskConstMeta(debugName: "stop_daemon", argNames: ["handle"]);
+
+  @override
+  Stream<LogRecord> crateApiLoggingSubscribeLogs() {
+    final sink = RustStreamSink<LogRecord>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_log_record_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 107,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiLoggingSubscribeLogsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLoggingSubscribeLogsConstMeta =>
+      const TaskConstMeta(debugName: "subscribe_logs", argNames: ["sink"]);
+
+  @override
+  Stream<TorBootstrapStatus> crateApiCashuSubscribeTorBootstrap({
+    required WalletHandle handle,
+  }) {
+    final sink = RustStreamSink<TorBootstrapStatus>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_box_autoadd_wallet_handle(handle, serializer);
+            sse_encode_StreamSink_tor_bootstrap_status_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 108,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiCashuSubscribeTorBootstrapConstMeta,
+          argValues: [handle, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCashuSubscribeTorBootstrapConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_tor_bootstrap",
+        argNames: ["handle", "sink"],
+      );
+
+  @override
+  Stream<WalletEvent> crateApiEventsSubscribeWalletEvents() {
+    final sink = RustStreamSink<WalletEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_wallet_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 109,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiEventsSubscribeWalletEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiEventsSubscribeWalletEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_wallet_events",
+        argNames: ["sink"],
+      );
+
+  @override
+  Future<Nip60SyncReport> crateApiNip60SyncNip60Wallet({
+    required WalletHandle handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_nip_60_sync_report,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNip60SyncNip60WalletConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNip60SyncNip60WalletConstMeta =>
+      const TaskConstMeta(debugName: "sync_nip60_wallet", argNames: ["handle"]);
+
+  @override
+  Future<ProxyTestResult> crateApiCashuTestProxy({
+    required ProxyConfig proxy,
+    required String mintUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_proxy_config(proxy, serializer);
+          sse_encode_String(mintUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_proxy_test_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuTestProxyConstMeta,
+        argValues: [proxy, mintUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuTestProxyConstMeta => const TaskConstMeta(
+    debugName: "test_proxy",
+    argNames: ["proxy", "mintUrl"],
+  );
+
+  @override
+  Future<void> crateApiLimitsUnlockSpending({
+    required WalletHandle handle,
+    required String pin,
+    String? mintUrl,
+    required BigInt amount,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(pin, serializer);
+          sse_encode_opt_String(mintUrl, serializer);
+          sse_encode_u_64(amount, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLimitsUnlockSpendingConstMeta,
+        argValues: [handle, pin, mintUrl, amount],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLimitsUnlockSpendingConstMeta =>
+      const TaskConstMeta(
+        debugName: "unlock_spending",
+        argNames: ["handle", "pin", "mintUrl", "amount"],
+      );
+
+  @override
+  Future<bool> crateApiCashuValidateMnemonicPhrase({
+    required String mnemonicPhrase,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mnemonicPhrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuValidateMnemonicPhraseConstMeta,
+        argValues: [mnemonicPhrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuValidateMnemonicPhraseConstMeta =>
+      const TaskConstMeta(
+        debugName: "validate_mnemonic_phrase",
+        argNames: ["mnemonicPhrase"],
+      );
+
+  @override
+  bool crateApiNostrVerifyEvent({required NostrEvent event}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_nostr_event(event, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNostrVerifyEventConstMeta,
+        argValues: [event],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNostrVerifyEventConstMeta =>
+      const TaskConstMeta(debugName: "verify_event", argNames: ["event"]);
+
+  @override
+  Future<TokenVerification> crateApiCashuVerifyProofsOffline({
+    required WalletHandle handle,
+    required String mintUrl,
+    required List<CashuProof> proofs,
+    required bool allowFetch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_list_cashu_proof(proofs, serializer);
+          sse_encode_bool(allowFetch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_token_verification,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuVerifyProofsOfflineConstMeta,
+        argValues: [handle, mintUrl, proofs, allowFetch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuVerifyProofsOfflineConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_proofs_offline",
+        argNames: ["handle", "mintUrl", "proofs", "allowFetch"],
+      );
+
+  @override
+  Future<bool> crateApiCashuVerifyTokenDleq({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuVerifyTokenDleqConstMeta,
+        argValues: [handle, mintUrl, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuVerifyTokenDleqConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_token_dleq",
+        argNames: ["handle", "mintUrl", "token"],
+      );
+
+  @override
+  Future<TokenVerification> crateApiCashuVerifyTokenOffline({
+    required WalletHandle handle,
+    required String token,
+    required bool allowFetch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(token, serializer);
+          sse_encode_bool(allowFetch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_token_verification,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuVerifyTokenOfflineConstMeta,
+        argValues: [handle, token, allowFetch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuVerifyTokenOfflineConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_token_offline",
+        argNames: ["handle", "token", "allowFetch"],
+      );
+
+  @override
+  Future<bool> crateApiCashuVerifyTokenP2Pk({
+    required WalletHandle handle,
+    required String mintUrl,
+    required String token,
+    required String conditions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_handle(handle, serializer);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(token, serializer);
+          sse_encode_String(conditions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCashuVerifyTokenP2PkConstMeta,
+        argValues: [handle, mintUrl, token, conditions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuVerifyTokenP2PkConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_token_p2pk",
+        argNames: ["handle", "mintUrl", "token", "conditions"],
+      );
+
+  @override
+  Future<bool> crateApiCashuWalletExists({
+    required String mintUrl,
+    required String databaseDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mintUrl, serializer);
+          sse_encode_String(databaseDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCashuWalletExistsConstMeta,
+        argValues: [mintUrl, databaseDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCashuWalletExistsConstMeta => const TaskConstMeta(
+    debugName: "wallet_exists",
+    argNames: ["mintUrl", "databaseDir"],
+  );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_AnimatedQrDecoder =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_AnimatedQrDecoder =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  AnimatedQrDecoder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnimatedQrDecoderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  AnimatedQrDecoder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnimatedQrDecoderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  AnimatedQrDecoder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnimatedQrDecoderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
   Map<String, BigInt> dco_decode_Map_String_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_u_64(raw).map((e) => MapEntry(e.$1, e.$2)),
+    return Map.fromEntries(
+      dco_decode_list_record_string_u_64(raw).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  AnimatedQrDecoder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnimatedQrDecoderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<TorBootstrapStatus> dco_decode_StreamSink_tor_bootstrap_status_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WalletEvent> dco_decode_StreamSink_wallet_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AnimatedQrFrames dco_decode_animated_qr_frames(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AnimatedQrFrames(
+      frames: dco_decode_list_String(arr[0]),
+      fragmentCount: dco_decode_u_32(arr[1]),
+      isAnimated: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  Bolt12InvoiceRequestInfo dco_decode_bolt_12_invoice_request_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Bolt12InvoiceRequestInfo(
+      payerPubkey: dco_decode_String(arr[0]),
+      amountMsat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      quantity: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      payerNote: dco_decode_opt_String(arr[3]),
+      chain: dco_decode_String(arr[4]),
+      offerDescription: dco_decode_opt_String(arr[5]),
+      offerIssuer: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  Bolt12OfferInfo dco_decode_bolt_12_offer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return Bolt12OfferInfo(
+      offerId: dco_decode_String(arr[0]),
+      amountMsat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      currency: dco_decode_opt_String(arr[2]),
+      currencyAmount: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      description: dco_decode_opt_String(arr[4]),
+      issuer: dco_decode_opt_String(arr[5]),
+      issuerPubkey: dco_decode_opt_String(arr[6]),
+      absoluteExpiry: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      chains: dco_decode_list_String(arr[8]),
+      isExpired: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bool(raw);
+  }
+
+  @protected
+  CashuDleq dco_decode_box_autoadd_cashu_dleq(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cashu_dleq(raw);
+  }
+
+  @protected
+  CashuWitness dco_decode_box_autoadd_cashu_witness(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cashu_witness(raw);
+  }
+
+  @protected
+  MintAuthSettings dco_decode_box_autoadd_mint_auth_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mint_auth_settings(raw);
+  }
+
+  @protected
+  Nip60SyncReport dco_decode_box_autoadd_nip_60_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_nip_60_sync_report(raw);
+  }
+
+  @protected
+  NostrEvent dco_decode_box_autoadd_nostr_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_nostr_event(raw);
+  }
+
+  @protected
+  ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proxy_config(raw);
+  }
+
+  @protected
+  SelectedProofsSendOptions dco_decode_box_autoadd_selected_proofs_send_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_selected_proofs_send_options(raw);
+  }
+
+  @protected
+  SpendingLimit dco_decode_box_autoadd_spending_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_spending_limit(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_32(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  UnsupportedByMint dco_decode_box_autoadd_unsupported_by_mint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_unsupported_by_mint(raw);
+  }
+
+  @protected
+  WalletHandle dco_decode_box_autoadd_wallet_handle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_handle(raw);
+  }
+
+  @protected
+  CachedEndpoint dco_decode_cached_endpoint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CachedEndpoint(
+      method: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  CashuDleq dco_decode_cashu_dleq(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CashuDleq(
+      e: dco_decode_String(arr[0]),
+      s: dco_decode_String(arr[1]),
+      r: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  CashuProof dco_decode_cashu_proof(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return CashuProof(
+      id: dco_decode_String(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      secret: dco_decode_cashu_secret(arr[2]),
+      c: dco_decode_String(arr[3]),
+      witness: dco_decode_opt_box_autoadd_cashu_witness(arr[4]),
+      dleq: dco_decode_opt_box_autoadd_cashu_dleq(arr[5]),
+      y: dco_decode_String(arr[6]),
+      state: dco_decode_opt_String(arr[7]),
+      reserved: dco_decode_bool(arr[8]),
+    );
+  }
+
+  @protected
+  CashuSecret dco_decode_cashu_secret(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CashuSecret(
+      raw: dco_decode_String(arr[0]),
+      kind: dco_decode_opt_String(arr[1]),
+      nonce: dco_decode_opt_String(arr[2]),
+      data: dco_decode_opt_String(arr[3]),
+      tags: dco_decode_list_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  CashuWitness dco_decode_cashu_witness(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CashuWitness(
+      preimage: dco_decode_opt_String(arr[0]),
+      signatures: dco_decode_opt_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  ContactInfo dco_decode_contact_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ContactInfo(
+      method: dco_decode_String(arr[0]),
+      info: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  DaemonClient dco_decode_daemon_client(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DaemonClient(
+      name: dco_decode_String(arr[0]),
+      permissions: dco_decode_list_String(arr[1]),
+      createdAt: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  DiagnosticsBundle dco_decode_diagnostics_bundle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DiagnosticsBundle(
+      createdAt: dco_decode_u_64(arr[0]),
+      crateVersion: dco_decode_String(arr[1]),
+      os: dco_decode_String(arr[2]),
+      arch: dco_decode_String(arr[3]),
+      logLevels: dco_decode_Map_String_String(arr[4]),
+      records: dco_decode_list_log_record(arr[5]),
+    );
+  }
+
+  @protected
+  EncodedToken dco_decode_encoded_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return EncodedToken(
+      token: dco_decode_String(arr[0]),
+      encoding: dco_decode_String(arr[1]),
+      byteSize: dco_decode_u_64(arr[2]),
+      amount: dco_decode_u_64(arr[3]),
+      proofsCount: dco_decode_u_64(arr[4]),
+      mintUrls: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  ImportReport dco_decode_import_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ImportReport(
+      format: dco_decode_String(arr[0]),
+      mints: dco_decode_list_imported_mint(arr[1]),
+      importedAmount: dco_decode_u_64(arr[2]),
+      unassignedProofs: dco_decode_u_64(arr[3]),
+      hasMnemonic: dco_decode_bool(arr[4]),
+      keysetCounters: dco_decode_list_keyset_counter(arr[5]),
+    );
+  }
+
+  @protected
+  ImportedMint dco_decode_imported_mint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ImportedMint(
+      mintUrl: dco_decode_String(arr[0]),
+      proofsFound: dco_decode_u_64(arr[1]),
+      amountFound: dco_decode_u_64(arr[2]),
+      spentAmount: dco_decode_u_64(arr[3]),
+      importedAmount: dco_decode_u_64(arr[4]),
+      error: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  InterruptedOperation dco_decode_interrupted_operation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return InterruptedOperation(
+      id: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      mintUrl: dco_decode_String(arr[2]),
+      amount: dco_decode_u_64(arr[3]),
+      startedAt: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  KeysetCounter dco_decode_keyset_counter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KeysetCounter(
+      keysetId: dco_decode_String(arr[0]),
+      counter: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<CachedEndpoint> dco_decode_list_cached_endpoint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cached_endpoint).toList();
+  }
+
+  @protected
+  List<CashuProof> dco_decode_list_cashu_proof(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cashu_proof).toList();
+  }
+
+  @protected
+  List<ContactInfo> dco_decode_list_contact_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_contact_info).toList();
+  }
+
+  @protected
+  List<DaemonClient> dco_decode_list_daemon_client(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_daemon_client).toList();
+  }
+
+  @protected
+  List<ImportedMint> dco_decode_list_imported_mint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_imported_mint).toList();
+  }
+
+  @protected
+  List<InterruptedOperation> dco_decode_list_interrupted_operation(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(
+      dco_decode_interrupted_operation,
+    ).toList();
+  }
+
+  @protected
+  List<KeysetCounter> dco_decode_list_keyset_counter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_keyset_counter).toList();
+  }
+
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_String).toList();
+  }
+
+  @protected
+  List<LogRecord> dco_decode_list_log_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_log_record).toList();
+  }
+
+  @protected
+  List<PaymentMethodSettings> dco_decode_list_payment_method_settings(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(
+      dco_decode_payment_method_settings,
+    ).toList();
+  }
+
+  @protected
+  List<PaymentRequestTransport> dco_decode_list_payment_request_transport(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(
+      dco_decode_payment_request_transport,
+    ).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ProofVerification> dco_decode_list_proof_verification(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_proof_verification).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_u_64).toList();
+  }
+
+  @protected
+  List<RestoredMint> dco_decode_list_restored_mint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_restored_mint).toList();
+  }
+
+  @protected
+  List<SpendingAllowance> dco_decode_list_spending_allowance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_spending_allowance).toList();
+  }
+
+  @protected
+  List<SpendingLimit> dco_decode_list_spending_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_spending_limit).toList();
+  }
+
+  @protected
+  List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transaction_info).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_u_32).toList();
+  }
+
+  @protected
+  List<WalletProfile> dco_decode_list_wallet_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_wallet_profile).toList();
+  }
+
+  @protected
+  List<WebSocketSettings> dco_decode_list_web_socket_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_web_socket_settings).toList();
+  }
+
+  @protected
+  LnurlPayInfo dco_decode_lnurl_pay_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return LnurlPayInfo(
+      callback: dco_decode_String(arr[0]),
+      minSendableSats: dco_decode_u_64(arr[1]),
+      maxSendableSats: dco_decode_u_64(arr[2]),
+      commentAllowed: dco_decode_u_64(arr[3]),
+      description: dco_decode_opt_String(arr[4]),
+      metadata: dco_decode_String(arr[5]),
+      domain: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LogRecord(
+      level: dco_decode_String(arr[0]),
+      module: dco_decode_String(arr[1]),
+      target: dco_decode_String(arr[2]),
+      message: dco_decode_String(arr[3]),
+      timestampMs: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  MintAuthSettings dco_decode_mint_auth_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MintAuthSettings(
+      openidDiscovery: dco_decode_opt_String(arr[0]),
+      clientId: dco_decode_opt_String(arr[1]),
+      clearAuthEndpoints: dco_decode_list_String(arr[2]),
+      blindAuthMaxMint: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      blindAuthEndpoints: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  MintInfo dco_decode_mint_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return MintInfo(
+      name: dco_decode_opt_String(arr[0]),
+      version: dco_decode_opt_String(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+      descriptionLong: dco_decode_opt_String(arr[3]),
+      contact: dco_decode_opt_list_contact_info(arr[4]),
+      motd: dco_decode_opt_String(arr[5]),
+      iconUrl: dco_decode_opt_String(arr[6]),
+      urls: dco_decode_opt_list_String(arr[7]),
+      nuts: dco_decode_opt_list_String(arr[8]),
+      publicKey: dco_decode_opt_String(arr[9]),
+      additionalInfo: dco_decode_opt_String(arr[10]),
+      tosUrl: dco_decode_opt_String(arr[11]),
+      time: dco_decode_opt_box_autoadd_u_64(arr[12]),
+      settings: dco_decode_mint_settings(arr[13]),
+      fetchedAt: dco_decode_opt_box_autoadd_u_64(arr[14]),
+      isStale: dco_decode_bool(arr[15]),
+    );
+  }
+
+  @protected
+  MintSettings dco_decode_mint_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return MintSettings(
+      mintMethods: dco_decode_list_payment_method_settings(arr[0]),
+      mintingDisabled: dco_decode_bool(arr[1]),
+      meltMethods: dco_decode_list_payment_method_settings(arr[2]),
+      meltingDisabled: dco_decode_bool(arr[3]),
+      supportedNuts: dco_decode_list_u_32(arr[4]),
+      mppMethods: dco_decode_list_payment_method_settings(arr[5]),
+      websocket: dco_decode_list_web_socket_settings(arr[6]),
+      cacheTtl: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      cachedEndpoints: dco_decode_list_cached_endpoint(arr[8]),
+      auth: dco_decode_opt_box_autoadd_mint_auth_settings(arr[9]),
+    );
+  }
+
+  @protected
+  Nip60SyncReport dco_decode_nip_60_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return Nip60SyncReport(
+      importedProofs: dco_decode_u_64(arr[0]),
+      importedAmount: dco_decode_u_64(arr[1]),
+      removedProofs: dco_decode_u_64(arr[2]),
+      mintsAdded: dco_decode_list_String(arr[3]),
+      publishedEvents: dco_decode_u_64(arr[4]),
+      deletedEvents: dco_decode_u_64(arr[5]),
+      errors: dco_decode_list_String(arr[6]),
+      syncedAt: dco_decode_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  Nip60SyncStatus dco_decode_nip_60_sync_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Nip60SyncStatus(
+      enabled: dco_decode_bool(arr[0]),
+      relays: dco_decode_list_String(arr[1]),
+      publicKey: dco_decode_opt_String(arr[2]),
+      lastReport: dco_decode_opt_box_autoadd_nip_60_sync_report(arr[3]),
+      lastError: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  NostrEvent dco_decode_nostr_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return NostrEvent(
+      id: dco_decode_String(arr[0]),
+      pubkey: dco_decode_String(arr[1]),
+      createdAt: dco_decode_u_64(arr[2]),
+      kind: dco_decode_u_64(arr[3]),
+      tags: dco_decode_list_list_String(arr[4]),
+      content: dco_decode_String(arr[5]),
+      sig: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  NostrKeys dco_decode_nostr_keys(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NostrKeys(
+      publicKey: dco_decode_String(arr[0]),
+      privateKey: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  NostrKeysWithBech32 dco_decode_nostr_keys_with_bech_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NostrKeysWithBech32(
+      privateKey: dco_decode_String(arr[0]),
+      publicKey: dco_decode_String(arr[1]),
+      nsec: dco_decode_String(arr[2]),
+      npub: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  CashuDleq? dco_decode_opt_box_autoadd_cashu_dleq(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cashu_dleq(raw);
+  }
+
+  @protected
+  CashuWitness? dco_decode_opt_box_autoadd_cashu_witness(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cashu_witness(raw);
+  }
+
+  @protected
+  MintAuthSettings? dco_decode_opt_box_autoadd_mint_auth_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_mint_auth_settings(raw);
+  }
+
+  @protected
+  Nip60SyncReport? dco_decode_opt_box_autoadd_nip_60_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_nip_60_sync_report(raw);
+  }
+
+  @protected
+  ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_proxy_config(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  UnsupportedByMint? dco_decode_opt_box_autoadd_unsupported_by_mint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_unsupported_by_mint(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<ContactInfo>? dco_decode_opt_list_contact_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_contact_info(raw);
+  }
+
+  @protected
+  PaymentMethodSettings dco_decode_payment_method_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PaymentMethodSettings(
+      method: dco_decode_String(arr[0]),
+      unit: dco_decode_String(arr[1]),
+      minAmount: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      maxAmount: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      description: dco_decode_bool(arr[4]),
+      amountless: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  PaymentRequestInfo dco_decode_payment_request_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return PaymentRequestInfo(
+      paymentId: dco_decode_opt_String(arr[0]),
+      amount: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      unit: dco_decode_opt_String(arr[2]),
+      singleUse: dco_decode_opt_box_autoadd_bool(arr[3]),
+      mints: dco_decode_list_String(arr[4]),
+      description: dco_decode_opt_String(arr[5]),
+      transports: dco_decode_list_payment_request_transport(arr[6]),
+      lockKind: dco_decode_opt_String(arr[7]),
+      lockData: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  PaymentRequestTransport dco_decode_payment_request_transport(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PaymentRequestTransport(
+      transportType: dco_decode_String(arr[0]),
+      target: dco_decode_String(arr[1]),
+      tags: dco_decode_list_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  ProofVerification dco_decode_proof_verification(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ProofVerification(
+      y: dco_decode_String(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      keysetId: dco_decode_String(arr[2]),
+      dleqValid: dco_decode_opt_box_autoadd_bool(arr[3]),
+      secretValid: dco_decode_bool(arr[4]),
+      error: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  ProxyConfig dco_decode_proxy_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProxyConfig(
+      kind: dco_decode_String(arr[0]),
+      host: dco_decode_String(arr[1]),
+      port: dco_decode_u_16(arr[2]),
+      username: dco_decode_opt_String(arr[3]),
+      password: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ProxyTestResult dco_decode_proxy_test_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProxyTestResult(
+      success: dco_decode_bool(arr[0]),
+      latencyMs: dco_decode_u_64(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  QrDecodeProgress dco_decode_qr_decode_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return QrDecodeProgress(
+      complete: dco_decode_bool(arr[0]),
+      progress: dco_decode_f_64(arr[1]),
+      receivedFrames: dco_decode_u_32(arr[2]),
+      expectedFrames: dco_decode_u_32(arr[3]),
+      payload: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  (String, BigInt) dco_decode_record_string_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_u_64(arr[1]));
+  }
+
+  @protected
+  RestoredMint dco_decode_restored_mint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RestoredMint(
+      mintUrl: dco_decode_String(arr[0]),
+      restoredAmount: dco_decode_u_64(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  RestoredProfile dco_decode_restored_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RestoredProfile(
+      handle: dco_decode_wallet_handle(arr[0]),
+      mnemonic: dco_decode_String(arr[1]),
+      mints: dco_decode_list_restored_mint(arr[2]),
+      restoredAmount: dco_decode_u_64(arr[3]),
+      keysetCounters: dco_decode_list_keyset_counter(arr[4]),
+    );
+  }
+
+  @protected
+  SelectedProofsSendOptions dco_decode_selected_proofs_send_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SelectedProofsSendOptions(
+      swap: dco_decode_bool(arr[0]),
+      amount: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      memo: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  SpendingAllowance dco_decode_spending_allowance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SpendingAllowance(
+      mintUrl: dco_decode_opt_String(arr[0]),
+      perTransaction: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      dailySpent: dco_decode_u_64(arr[2]),
+      dailyRemaining: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      weeklySpent: dco_decode_u_64(arr[4]),
+      weeklyRemaining: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      action: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  SpendingLimit dco_decode_spending_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SpendingLimit(
+      mintUrl: dco_decode_opt_String(arr[0]),
+      perTransaction: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      daily: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      weekly: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      action: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  TokenVerification dco_decode_token_verification(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TokenVerification(
+      mintUrl: dco_decode_String(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      keysetsValid: dco_decode_bool(arr[2]),
+      fetchedKeys: dco_decode_bool(arr[3]),
+      valid: dco_decode_bool(arr[4]),
+      proofs: dco_decode_list_proof_verification(arr[5]),
+    );
+  }
+
+  @protected
+  TorBootstrapStatus dco_decode_tor_bootstrap_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TorBootstrapStatus(
+      started: dco_decode_bool(arr[0]),
+      progress: dco_decode_f_64(arr[1]),
+      ready: dco_decode_bool(arr[2]),
+      message: dco_decode_String(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  TransactionInfo dco_decode_transaction_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return TransactionInfo(
+      id: dco_decode_String(arr[0]),
+      direction: dco_decode_String(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+      fee: dco_decode_u_64(arr[3]),
+      memo: dco_decode_opt_String(arr[4]),
+      timestamp: dco_decode_u_64(arr[5]),
+      mintUrl: dco_decode_String(arr[6]),
+      transactionType: dco_decode_opt_String(arr[7]),
+      lightningInvoice: dco_decode_opt_String(arr[8]),
+      ecashToken: dco_decode_opt_String(arr[9]),
+      metadata: dco_decode_Map_String_String(arr[10]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UnsupportedByMint dco_decode_unsupported_by_mint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UnsupportedByMint(
+      mintUrl: dco_decode_String(arr[0]),
+      nut: dco_decode_u_32(arr[1]),
+      capability: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WalletEvent dco_decode_wallet_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return WalletEvent(
+      kind: dco_decode_String(arr[0]),
+      profileId: dco_decode_opt_String(arr[1]),
+      mintUrl: dco_decode_opt_String(arr[2]),
+      quoteId: dco_decode_opt_String(arr[3]),
+      amount: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      message: dco_decode_opt_String(arr[5]),
+      timestamp: dco_decode_u_64(arr[6]),
+      data: dco_decode_Map_String_String(arr[7]),
+    );
+  }
+
+  @protected
+  WalletHandle dco_decode_wallet_handle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletHandle(profileId: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  WalletInfo dco_decode_wallet_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WalletInfo(
+      mintUrl: dco_decode_String(arr[0]),
+      unit: dco_decode_String(arr[1]),
+      balance: dco_decode_u_64(arr[2]),
+      activeKeysetId: dco_decode_String(arr[3]),
+      fetchedAt: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      isStale: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  WalletProfile dco_decode_wallet_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WalletProfile(
+      profileId: dco_decode_String(arr[0]),
+      profileDir: dco_decode_String(arr[1]),
+      isOpen: dco_decode_bool(arr[2]),
+      isEncrypted: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  WebSocketSettings dco_decode_web_socket_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WebSocketSettings(
+      method: dco_decode_String(arr[0]),
+      unit: dco_decode_String(arr[1]),
+      commands: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  AnimatedQrDecoder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(deserializer);
+    return inner;
+  }
+
+  @protected
+  AnimatedQrDecoder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(deserializer);
+    return inner;
+  }
+
+  @protected
+  AnimatedQrDecoder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(deserializer);
+    return inner;
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, BigInt> sse_decode_Map_String_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_u_64(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  AnimatedQrDecoder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AnimatedQrDecoderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<TorBootstrapStatus> sse_decode_StreamSink_tor_bootstrap_status_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WalletEvent> sse_decode_StreamSink_wallet_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AnimatedQrFrames sse_decode_animated_qr_frames(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frames = sse_decode_list_String(deserializer);
+    var var_fragmentCount = sse_decode_u_32(deserializer);
+    var var_isAnimated = sse_decode_bool(deserializer);
+    return AnimatedQrFrames(
+      frames: var_frames,
+      fragmentCount: var_fragmentCount,
+      isAnimated: var_isAnimated,
+    );
+  }
+
+  @protected
+  Bolt12InvoiceRequestInfo sse_decode_bolt_12_invoice_request_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payerPubkey = sse_decode_String(deserializer);
+    var var_amountMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_quantity = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_payerNote = sse_decode_opt_String(deserializer);
+    var var_chain = sse_decode_String(deserializer);
+    var var_offerDescription = sse_decode_opt_String(deserializer);
+    var var_offerIssuer = sse_decode_opt_String(deserializer);
+    return Bolt12InvoiceRequestInfo(
+      payerPubkey: var_payerPubkey,
+      amountMsat: var_amountMsat,
+      quantity: var_quantity,
+      payerNote: var_payerNote,
+      chain: var_chain,
+      offerDescription: var_offerDescription,
+      offerIssuer: var_offerIssuer,
+    );
+  }
+
+  @protected
+  Bolt12OfferInfo sse_decode_bolt_12_offer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offerId = sse_decode_String(deserializer);
+    var var_amountMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_currency = sse_decode_opt_String(deserializer);
+    var var_currencyAmount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_issuer = sse_decode_opt_String(deserializer);
+    var var_issuerPubkey = sse_decode_opt_String(deserializer);
+    var var_absoluteExpiry = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_chains = sse_decode_list_String(deserializer);
+    var var_isExpired = sse_decode_bool(deserializer);
+    return Bolt12OfferInfo(
+      offerId: var_offerId,
+      amountMsat: var_amountMsat,
+      currency: var_currency,
+      currencyAmount: var_currencyAmount,
+      description: var_description,
+      issuer: var_issuer,
+      issuerPubkey: var_issuerPubkey,
+      absoluteExpiry: var_absoluteExpiry,
+      chains: var_chains,
+      isExpired: var_isExpired,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CashuDleq sse_decode_box_autoadd_cashu_dleq(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cashu_dleq(deserializer));
+  }
+
+  @protected
+  CashuWitness sse_decode_box_autoadd_cashu_witness(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cashu_witness(deserializer));
+  }
+
+  @protected
+  MintAuthSettings sse_decode_box_autoadd_mint_auth_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mint_auth_settings(deserializer));
+  }
+
+  @protected
+  Nip60SyncReport sse_decode_box_autoadd_nip_60_sync_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_nip_60_sync_report(deserializer));
+  }
+
+  @protected
+  NostrEvent sse_decode_box_autoadd_nostr_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_nostr_event(deserializer));
+  }
+
+  @protected
+  ProxyConfig sse_decode_box_autoadd_proxy_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proxy_config(deserializer));
+  }
+
+  @protected
+  SelectedProofsSendOptions sse_decode_box_autoadd_selected_proofs_send_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_selected_proofs_send_options(deserializer));
+  }
+
+  @protected
+  SpendingLimit sse_decode_box_autoadd_spending_limit(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_spending_limit(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  UnsupportedByMint sse_decode_box_autoadd_unsupported_by_mint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_unsupported_by_mint(deserializer));
+  }
+
+  @protected
+  WalletHandle sse_decode_box_autoadd_wallet_handle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_handle(deserializer));
+  }
+
+  @protected
+  CachedEndpoint sse_decode_cached_endpoint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    return CachedEndpoint(method: var_method, path: var_path);
+  }
+
+  @protected
+  CashuDleq sse_decode_cashu_dleq(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_e = sse_decode_String(deserializer);
+    var var_s = sse_decode_String(deserializer);
+    var var_r = sse_decode_String(deserializer);
+    return CashuDleq(e: var_e, s: var_s, r: var_r);
+  }
+
+  @protected
+  CashuProof sse_decode_cashu_proof(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_secret = sse_decode_cashu_secret(deserializer);
+    var var_c = sse_decode_String(deserializer);
+    var var_witness = sse_decode_opt_box_autoadd_cashu_witness(deserializer);
+    var var_dleq = sse_decode_opt_box_autoadd_cashu_dleq(deserializer);
+    var var_y = sse_decode_String(deserializer);
+    var var_state = sse_decode_opt_String(deserializer);
+    var var_reserved = sse_decode_bool(deserializer);
+    return CashuProof(
+      id: var_id,
+      amount: var_amount,
+      secret: var_secret,
+      c: var_c,
+      witness: var_witness,
+      dleq: var_dleq,
+      y: var_y,
+      state: var_state,
+      reserved: var_reserved,
+    );
+  }
+
+  @protected
+  CashuSecret sse_decode_cashu_secret(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_raw = sse_decode_String(deserializer);
+    var var_kind = sse_decode_opt_String(deserializer);
+    var var_nonce = sse_decode_opt_String(deserializer);
+    var var_data = sse_decode_opt_String(deserializer);
+    var var_tags = sse_decode_list_list_String(deserializer);
+    return CashuSecret(
+      raw: var_raw,
+      kind: var_kind,
+      nonce: var_nonce,
+      data: var_data,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  CashuWitness sse_decode_cashu_witness(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_preimage = sse_decode_opt_String(deserializer);
+    var var_signatures = sse_decode_opt_list_String(deserializer);
+    return CashuWitness(preimage: var_preimage, signatures: var_signatures);
+  }
+
+  @protected
+  ContactInfo sse_decode_contact_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_info = sse_decode_String(deserializer);
+    return ContactInfo(method: var_method, info: var_info);
+  }
+
+  @protected
+  DaemonClient sse_decode_daemon_client(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_permissions = sse_decode_list_String(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    return DaemonClient(
+      name: var_name,
+      permissions: var_permissions,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  DiagnosticsBundle sse_decode_diagnostics_bundle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_createdAt = sse_decode_u_64(deserializer);
+    var var_crateVersion = sse_decode_String(deserializer);
+    var var_os = sse_decode_String(deserializer);
+    var var_arch = sse_decode_String(deserializer);
+    var var_logLevels = sse_decode_Map_String_String(deserializer);
+    var var_records = sse_decode_list_log_record(deserializer);
+    return DiagnosticsBundle(
+      createdAt: var_createdAt,
+      crateVersion: var_crateVersion,
+      os: var_os,
+      arch: var_arch,
+      logLevels: var_logLevels,
+      records: var_records,
+    );
+  }
+
+  @protected
+  EncodedToken sse_decode_encoded_token(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_token = sse_decode_String(deserializer);
+    var var_encoding = sse_decode_String(deserializer);
+    var var_byteSize = sse_decode_u_64(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_proofsCount = sse_decode_u_64(deserializer);
+    var var_mintUrls = sse_decode_list_String(deserializer);
+    return EncodedToken(
+      token: var_token,
+      encoding: var_encoding,
+      byteSize: var_byteSize,
+      amount: var_amount,
+      proofsCount: var_proofsCount,
+      mintUrls: var_mintUrls,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  ImportReport sse_decode_import_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_String(deserializer);
+    var var_mints = sse_decode_list_imported_mint(deserializer);
+    var var_importedAmount = sse_decode_u_64(deserializer);
+    var var_unassignedProofs = sse_decode_u_64(deserializer);
+    var var_hasMnemonic = sse_decode_bool(deserializer);
+    var var_keysetCounters = sse_decode_list_keyset_counter(deserializer);
+    return ImportReport(
+      format: var_format,
+      mints: var_mints,
+      importedAmount: var_importedAmount,
+      unassignedProofs: var_unassignedProofs,
+      hasMnemonic: var_hasMnemonic,
+      keysetCounters: var_keysetCounters,
+    );
+  }
+
+  @protected
+  ImportedMint sse_decode_imported_mint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintUrl = sse_decode_String(deserializer);
+    var var_proofsFound = sse_decode_u_64(deserializer);
+    var var_amountFound = sse_decode_u_64(deserializer);
+    var var_spentAmount = sse_decode_u_64(deserializer);
+    var var_importedAmount = sse_decode_u_64(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ImportedMint(
+      mintUrl: var_mintUrl,
+      proofsFound: var_proofsFound,
+      amountFound: var_amountFound,
+      spentAmount: var_spentAmount,
+      importedAmount: var_importedAmount,
+      error: var_error,
+    );
+  }
+
+  @protected
+  InterruptedOperation sse_decode_interrupted_operation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_mintUrl = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_startedAt = sse_decode_u_64(deserializer);
+    return InterruptedOperation(
+      id: var_id,
+      kind: var_kind,
+      mintUrl: var_mintUrl,
+      amount: var_amount,
+      startedAt: var_startedAt,
+    );
+  }
+
+  @protected
+  KeysetCounter sse_decode_keyset_counter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keysetId = sse_decode_String(deserializer);
+    var var_counter = sse_decode_u_32(deserializer);
+    return KeysetCounter(keysetId: var_keysetId, counter: var_counter);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CachedEndpoint> sse_decode_list_cached_endpoint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CachedEndpoint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cached_endpoint(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CashuProof> sse_decode_list_cashu_proof(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CashuProof>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cashu_proof(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ContactInfo> sse_decode_list_contact_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ContactInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_contact_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DaemonClient> sse_decode_list_daemon_client(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DaemonClient>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_daemon_client(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImportedMint> sse_decode_list_imported_mint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImportedMint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_imported_mint(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InterruptedOperation> sse_decode_list_interrupted_operation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InterruptedOperation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_interrupted_operation(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KeysetCounter> sse_decode_list_keyset_counter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KeysetCounter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_keyset_counter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <List<String>>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LogRecord> sse_decode_list_log_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LogRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentMethodSettings> sse_decode_list_payment_method_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentMethodSettings>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_method_settings(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentRequestTransport> sse_decode_list_payment_request_transport(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentRequestTransport>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_request_transport(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ProofVerification> sse_decode_list_proof_verification(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProofVerification>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_proof_verification(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, BigInt)> sse_decode_list_record_string_u_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, BigInt)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_u_64(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RestoredMint> sse_decode_list_restored_mint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RestoredMint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_restored_mint(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SpendingAllowance> sse_decode_list_spending_allowance(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SpendingAllowance>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_spending_allowance(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SpendingLimit> sse_decode_list_spending_limit(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SpendingLimit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_spending_limit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TransactionInfo> sse_decode_list_transaction_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TransactionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transaction_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <int>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_u_32(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WalletProfile> sse_decode_list_wallet_profile(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WalletProfile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_wallet_profile(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WebSocketSettings> sse_decode_list_web_socket_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WebSocketSettings>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_web_socket_settings(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LnurlPayInfo sse_decode_lnurl_pay_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_callback = sse_decode_String(deserializer);
+    var var_minSendableSats = sse_decode_u_64(deserializer);
+    var var_maxSendableSats = sse_decode_u_64(deserializer);
+    var var_commentAllowed = sse_decode_u_64(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_metadata = sse_decode_String(deserializer);
+    var var_domain = sse_decode_String(deserializer);
+    return LnurlPayInfo(
+      callback: var_callback,
+      minSendableSats: var_minSendableSats,
+      maxSendableSats: var_maxSendableSats,
+      commentAllowed: var_commentAllowed,
+      description: var_description,
+      metadata: var_metadata,
+      domain: var_domain,
+    );
+  }
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_String(deserializer);
+    var var_module = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_timestampMs = sse_decode_u_64(deserializer);
+    return LogRecord(
+      level: var_level,
+      module: var_module,
+      target: var_target,
+      message: var_message,
+      timestampMs: var_timestampMs,
+    );
+  }
+
+  @protected
+  MintAuthSettings sse_decode_mint_auth_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_openidDiscovery = sse_decode_opt_String(deserializer);
+    var var_clientId = sse_decode_opt_String(deserializer);
+    var var_clearAuthEndpoints = sse_decode_list_String(deserializer);
+    var var_blindAuthMaxMint = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_blindAuthEndpoints = sse_decode_list_String(deserializer);
+    return MintAuthSettings(
+      openidDiscovery: var_openidDiscovery,
+      clientId: var_clientId,
+      clearAuthEndpoints: var_clearAuthEndpoints,
+      blindAuthMaxMint: var_blindAuthMaxMint,
+      blindAuthEndpoints: var_blindAuthEndpoints,
+    );
+  }
+
+  @protected
+  MintInfo sse_decode_mint_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_version = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_descriptionLong = sse_decode_opt_String(deserializer);
+    var var_contact = sse_decode_opt_list_contact_info(deserializer);
+    var var_motd = sse_decode_opt_String(deserializer);
+    var var_iconUrl = sse_decode_opt_String(deserializer);
+    var var_urls = sse_decode_opt_list_String(deserializer);
+    var var_nuts = sse_decode_opt_list_String(deserializer);
+    var var_publicKey = sse_decode_opt_String(deserializer);
+    var var_additionalInfo = sse_decode_opt_String(deserializer);
+    var var_tosUrl = sse_decode_opt_String(deserializer);
+    var var_time = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_settings = sse_decode_mint_settings(deserializer);
+    var var_fetchedAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_isStale = sse_decode_bool(deserializer);
+    return MintInfo(
+      name: var_name,
+      version: var_version,
+      description: var_description,
+      descriptionLong: var_descriptionLong,
+      contact: var_contact,
+      motd: var_motd,
+      iconUrl: var_iconUrl,
+      urls: var_urls,
+      nuts: var_nuts,
+      publicKey: var_publicKey,
+      additionalInfo: var_additionalInfo,
+      tosUrl: var_tosUrl,
+      time: var_time,
+      settings: var_settings,
+      fetchedAt: var_fetchedAt,
+      isStale: var_isStale,
+    );
+  }
+
+  @protected
+  MintSettings sse_decode_mint_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintMethods = sse_decode_list_payment_method_settings(deserializer);
+    var var_mintingDisabled = sse_decode_bool(deserializer);
+    var var_meltMethods = sse_decode_list_payment_method_settings(deserializer);
+    var var_meltingDisabled = sse_decode_bool(deserializer);
+    var var_supportedNuts = sse_decode_list_u_32(deserializer);
+    var var_mppMethods = sse_decode_list_payment_method_settings(deserializer);
+    var var_websocket = sse_decode_list_web_socket_settings(deserializer);
+    var var_cacheTtl = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_cachedEndpoints = sse_decode_list_cached_endpoint(deserializer);
+    var var_auth = sse_decode_opt_box_autoadd_mint_auth_settings(deserializer);
+    return MintSettings(
+      mintMethods: var_mintMethods,
+      mintingDisabled: var_mintingDisabled,
+      meltMethods: var_meltMethods,
+      meltingDisabled: var_meltingDisabled,
+      supportedNuts: var_supportedNuts,
+      mppMethods: var_mppMethods,
+      websocket: var_websocket,
+      cacheTtl: var_cacheTtl,
+      cachedEndpoints: var_cachedEndpoints,
+      auth: var_auth,
+    );
+  }
+
+  @protected
+  Nip60SyncReport sse_decode_nip_60_sync_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_importedProofs = sse_decode_u_64(deserializer);
+    var var_importedAmount = sse_decode_u_64(deserializer);
+    var var_removedProofs = sse_decode_u_64(deserializer);
+    var var_mintsAdded = sse_decode_list_String(deserializer);
+    var var_publishedEvents = sse_decode_u_64(deserializer);
+    var var_deletedEvents = sse_decode_u_64(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    var var_syncedAt = sse_decode_u_64(deserializer);
+    return Nip60SyncReport(
+      importedProofs: var_importedProofs,
+      importedAmount: var_importedAmount,
+      removedProofs: var_removedProofs,
+      mintsAdded: var_mintsAdded,
+      publishedEvents: var_publishedEvents,
+      deletedEvents: var_deletedEvents,
+      errors: var_errors,
+      syncedAt: var_syncedAt,
+    );
+  }
+
+  @protected
+  Nip60SyncStatus sse_decode_nip_60_sync_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_relays = sse_decode_list_String(deserializer);
+    var var_publicKey = sse_decode_opt_String(deserializer);
+    var var_lastReport = sse_decode_opt_box_autoadd_nip_60_sync_report(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    return Nip60SyncStatus(
+      enabled: var_enabled,
+      relays: var_relays,
+      publicKey: var_publicKey,
+      lastReport: var_lastReport,
+      lastError: var_lastError,
+    );
+  }
+
+  @protected
+  NostrEvent sse_decode_nostr_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_pubkey = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    var var_kind = sse_decode_u_64(deserializer);
+    var var_tags = sse_decode_list_list_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_sig = sse_decode_String(deserializer);
+    return NostrEvent(
+      id: var_id,
+      pubkey: var_pubkey,
+      createdAt: var_createdAt,
+      kind: var_kind,
+      tags: var_tags,
+      content: var_content,
+      sig: var_sig,
+    );
+  }
+
+  @protected
+  NostrKeys sse_decode_nostr_keys(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_publicKey = sse_decode_String(deserializer);
+    var var_privateKey = sse_decode_String(deserializer);
+    return NostrKeys(publicKey: var_publicKey, privateKey: var_privateKey);
+  }
+
+  @protected
+  NostrKeysWithBech32 sse_decode_nostr_keys_with_bech_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_privateKey = sse_decode_String(deserializer);
+    var var_publicKey = sse_decode_String(deserializer);
+    var var_nsec = sse_decode_String(deserializer);
+    var var_npub = sse_decode_String(deserializer);
+    return NostrKeysWithBech32(
+      privateKey: var_privateKey,
+      publicKey: var_publicKey,
+      nsec: var_nsec,
+      npub: var_npub,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CashuDleq? sse_decode_opt_box_autoadd_cashu_dleq(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cashu_dleq(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CashuWitness? sse_decode_opt_box_autoadd_cashu_witness(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cashu_witness(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MintAuthSettings? sse_decode_opt_box_autoadd_mint_auth_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_mint_auth_settings(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Nip60SyncReport? sse_decode_opt_box_autoadd_nip_60_sync_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_nip_60_sync_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_proxy_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  UnsupportedByMint? sse_decode_opt_box_autoadd_unsupported_by_mint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_unsupported_by_mint(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<ContactInfo>? sse_decode_opt_list_contact_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_contact_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PaymentMethodSettings sse_decode_payment_method_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    var var_minAmount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxAmount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_description = sse_decode_bool(deserializer);
+    var var_amountless = sse_decode_bool(deserializer);
+    return PaymentMethodSettings(
+      method: var_method,
+      unit: var_unit,
+      minAmount: var_minAmount,
+      maxAmount: var_maxAmount,
+      description: var_description,
+      amountless: var_amountless,
+    );
+  }
+
+  @protected
+  PaymentRequestInfo sse_decode_payment_request_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentId = sse_decode_opt_String(deserializer);
+    var var_amount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_unit = sse_decode_opt_String(deserializer);
+    var var_singleUse = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_mints = sse_decode_list_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_transports = sse_decode_list_payment_request_transport(deserializer);
+    var var_lockKind = sse_decode_opt_String(deserializer);
+    var var_lockData = sse_decode_opt_String(deserializer);
+    return PaymentRequestInfo(
+      paymentId: var_paymentId,
+      amount: var_amount,
+      unit: var_unit,
+      singleUse: var_singleUse,
+      mints: var_mints,
+      description: var_description,
+      transports: var_transports,
+      lockKind: var_lockKind,
+      lockData: var_lockData,
+    );
+  }
+
+  @protected
+  PaymentRequestTransport sse_decode_payment_request_transport(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_transportType = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_list_String(deserializer);
+    return PaymentRequestTransport(
+      transportType: var_transportType,
+      target: var_target,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  ProofVerification sse_decode_proof_verification(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_y = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_keysetId = sse_decode_String(deserializer);
+    var var_dleqValid = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_secretValid = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ProofVerification(
+      y: var_y,
+      amount: var_amount,
+      keysetId: var_keysetId,
+      dleqValid: var_dleqValid,
+      secretValid: var_secretValid,
+      error: var_error,
+    );
+  }
+
+  @protected
+  ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_host = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_username = sse_decode_opt_String(deserializer);
+    var var_password = sse_decode_opt_String(deserializer);
+    return ProxyConfig(
+      kind: var_kind,
+      host: var_host,
+      port: var_port,
+      username: var_username,
+      password: var_password,
+    );
+  }
+
+  @protected
+  ProxyTestResult sse_decode_proxy_test_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_success = sse_decode_bool(deserializer);
+    var var_latencyMs = sse_decode_u_64(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ProxyTestResult(
+      success: var_success,
+      latencyMs: var_latencyMs,
+      error: var_error,
+    );
+  }
+
+  @protected
+  QrDecodeProgress sse_decode_qr_decode_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_complete = sse_decode_bool(deserializer);
+    var var_progress = sse_decode_f_64(deserializer);
+    var var_receivedFrames = sse_decode_u_32(deserializer);
+    var var_expectedFrames = sse_decode_u_32(deserializer);
+    var var_payload = sse_decode_opt_String(deserializer);
+    return QrDecodeProgress(
+      complete: var_complete,
+      progress: var_progress,
+      receivedFrames: var_receivedFrames,
+      expectedFrames: var_expectedFrames,
+      payload: var_payload,
+    );
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RestoredMint sse_decode_restored_mint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintUrl = sse_decode_String(deserializer);
+    var var_restoredAmount = sse_decode_u_64(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return RestoredMint(
+      mintUrl: var_mintUrl,
+      restoredAmount: var_restoredAmount,
+      error: var_error,
+    );
+  }
+
+  @protected
+  RestoredProfile sse_decode_restored_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_handle = sse_decode_wallet_handle(deserializer);
+    var var_mnemonic = sse_decode_String(deserializer);
+    var var_mints = sse_decode_list_restored_mint(deserializer);
+    var var_restoredAmount = sse_decode_u_64(deserializer);
+    var var_keysetCounters = sse_decode_list_keyset_counter(deserializer);
+    return RestoredProfile(
+      handle: var_handle,
+      mnemonic: var_mnemonic,
+      mints: var_mints,
+      restoredAmount: var_restoredAmount,
+      keysetCounters: var_keysetCounters,
+    );
+  }
+
+  @protected
+  SelectedProofsSendOptions sse_decode_selected_proofs_send_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swap = sse_decode_bool(deserializer);
+    var var_amount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_memo = sse_decode_opt_String(deserializer);
+    return SelectedProofsSendOptions(
+      swap: var_swap,
+      amount: var_amount,
+      memo: var_memo,
     );
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  SpendingAllowance sse_decode_spending_allowance(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintUrl = sse_decode_opt_String(deserializer);
+    var var_perTransaction = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_dailySpent = sse_decode_u_64(deserializer);
+    var var_dailyRemaining = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_weeklySpent = sse_decode_u_64(deserializer);
+    var var_weeklyRemaining = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_action = sse_decode_String(deserializer);
+    return SpendingAllowance(
+      mintUrl: var_mintUrl,
+      perTransaction: var_perTransaction,
+      dailySpent: var_dailySpent,
+      dailyRemaining: var_dailyRemaining,
+      weeklySpent: var_weeklySpent,
+      weeklyRemaining: var_weeklyRemaining,
+      action: var_action,
+    );
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  SpendingLimit sse_decode_spending_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintUrl = sse_decode_opt_String(deserializer);
+    var var_perTransaction = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_daily = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_weekly = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_action = sse_decode_String(deserializer);
+    return SpendingLimit(
+      mintUrl: var_mintUrl,
+      perTransaction: var_perTransaction,
+      daily: var_daily,
+      weekly: var_weekly,
+      action: var_action,
+    );
   }
 
   @protected
-  NostrEvent dco_decode_box_autoadd_nostr_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_nostr_event(raw);
+  TokenVerification sse_decode_token_verification(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintUrl = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_keysetsValid = sse_decode_bool(deserializer);
+    var var_fetchedKeys = sse_decode_bool(deserializer);
+    var var_valid = sse_decode_bool(deserializer);
+    var var_proofs = sse_decode_list_proof_verification(deserializer);
+    return TokenVerification(
+      mintUrl: var_mintUrl,
+      amount: var_amount,
+      keysetsValid: var_keysetsValid,
+      fetchedKeys: var_fetchedKeys,
+      valid: var_valid,
+      proofs: var_proofs,
+    );
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  TorBootstrapStatus sse_decode_tor_bootstrap_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_started = sse_decode_bool(deserializer);
+    var var_progress = sse_decode_f_64(deserializer);
+    var var_ready = sse_decode_bool(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return TorBootstrapStatus(
+      started: var_started,
+      progress: var_progress,
+      ready: var_ready,
+      message: var_message,
+      error: var_error,
+    );
   }
 
   @protected
-  CashuProof dco_decode_cashu_proof(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return CashuProof(
-      id: dco_decode_String(arr[0]),
-      amount: dco_decode_u_64(arr[1]),
-      secret: dco_decode_String(arr[2]),
-      c: dco_decode_String(arr[3]),
+  TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_direction = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_fee = sse_decode_u_64(deserializer);
+    var var_memo = sse_decode_opt_String(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_mintUrl = sse_decode_String(deserializer);
+    var var_transactionType = sse_decode_opt_String(deserializer);
+    var var_lightningInvoice = sse_decode_opt_String(deserializer);
+    var var_ecashToken = sse_decode_opt_String(deserializer);
+    var var_metadata = sse_decode_Map_String_String(deserializer);
+    return TransactionInfo(
+      id: var_id,
+      direction: var_direction,
+      amount: var_amount,
+      fee: var_fee,
+      memo: var_memo,
+      timestamp: var_timestamp,
+      mintUrl: var_mintUrl,
+      transactionType: var_transactionType,
+      lightningInvoice: var_lightningInvoice,
+      ecashToken: var_ecashToken,
+      metadata: var_metadata,
     );
   }
 
   @protected
-  ContactInfo dco_decode_contact_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ContactInfo(
-      method: dco_decode_String(arr[0]),
-      info: dco_decode_String(arr[1]),
-    );
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  List<CashuProof> dco_decode_list_cashu_proof(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_cashu_proof).toList();
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  List<ContactInfo> dco_decode_list_contact_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_contact_info).toList();
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  List<List<String>> dco_decode_list_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_String).toList();
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  UnsupportedByMint sse_decode_unsupported_by_mint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintUrl = sse_decode_String(deserializer);
+    var var_nut = sse_decode_u_32(deserializer);
+    var var_capability = sse_decode_String(deserializer);
+    return UnsupportedByMint(
+      mintUrl: var_mintUrl,
+      nut: var_nut,
+      capability: var_capability,
+    );
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_u_64).toList();
+  WalletEvent sse_decode_wallet_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_profileId = sse_decode_opt_String(deserializer);
+    var var_mintUrl = sse_decode_opt_String(deserializer);
+    var var_quoteId = sse_decode_opt_String(deserializer);
+    var var_amount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_data = sse_decode_Map_String_String(deserializer);
+    return WalletEvent(
+      kind: var_kind,
+      profileId: var_profileId,
+      mintUrl: var_mintUrl,
+      quoteId: var_quoteId,
+      amount: var_amount,
+      message: var_message,
+      timestamp: var_timestamp,
+      data: var_data,
+    );
   }
 
   @protected
-  List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_transaction_info).toList();
+  WalletHandle sse_decode_wallet_handle(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_profileId = sse_decode_String(deserializer);
+    return WalletHandle(profileId: var_profileId);
   }
 
   @protected
-  MintInfo dco_decode_mint_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return MintInfo(
-      name: dco_decode_opt_String(arr[0]),
-      version: dco_decode_opt_String(arr[1]),
-      description: dco_decode_opt_String(arr[2]),
-      descriptionLong: dco_decode_opt_String(arr[3]),
-      contact: dco_decode_opt_list_contact_info(arr[4]),
-      motd: dco_decode_opt_String(arr[5]),
-      iconUrl: dco_decode_opt_String(arr[6]),
-      urls: dco_decode_opt_list_String(arr[7]),
-      nuts: dco_decode_opt_list_String(arr[8]),
-      publicKey: dco_decode_opt_String(arr[9]),
-      additionalInfo: dco_decode_opt_String(arr[10]),
+  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintUrl = sse_decode_String(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    var var_balance = sse_decode_u_64(deserializer);
+    var var_activeKeysetId = sse_decode_String(deserializer);
+    var var_fetchedAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_isStale = sse_decode_bool(deserializer);
+    return WalletInfo(
+      mintUrl: var_mintUrl,
+      unit: var_unit,
+      balance: var_balance,
+      activeKeysetId: var_activeKeysetId,
+      fetchedAt: var_fetchedAt,
+      isStale: var_isStale,
     );
   }
 
   @protected
-  NostrEvent dco_decode_nostr_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return NostrEvent(
-      id: dco_decode_String(arr[0]),
-      pubkey: dco_decode_String(arr[1]),
-      createdAt: dco_decode_u_64(arr[2]),
-      kind: dco_decode_u_64(arr[3]),
-      tags: dco_decode_list_list_String(arr[4]),
-      content: dco_decode_String(arr[5]),
-      sig: dco_decode_String(arr[6]),
+  WalletProfile sse_decode_wallet_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_profileId = sse_decode_String(deserializer);
+    var var_profileDir = sse_decode_String(deserializer);
+    var var_isOpen = sse_decode_bool(deserializer);
+    var var_isEncrypted = sse_decode_bool(deserializer);
+    return WalletProfile(
+      profileId: var_profileId,
+      profileDir: var_profileDir,
+      isOpen: var_isOpen,
+      isEncrypted: var_isEncrypted,
     );
   }
 
   @protected
-  NostrKeys dco_decode_nostr_keys(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return NostrKeys(
-      publicKey: dco_decode_String(arr[0]),
-      privateKey: dco_decode_String(arr[1]),
+  WebSocketSettings sse_decode_web_socket_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    var var_commands = sse_decode_list_String(deserializer);
+    return WebSocketSettings(
+      method: var_method,
+      unit: var_unit,
+      commands: var_commands,
     );
   }
 
   @protected
-  NostrKeysWithBech32 dco_decode_nostr_keys_with_bech_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return NostrKeysWithBech32(
-      privateKey: dco_decode_String(arr[0]),
-      publicKey: dco_decode_String(arr[1]),
-      nsec: dco_decode_String(arr[2]),
-      npub: dco_decode_String(arr[3]),
-    );
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AnimatedQrDecoderImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_String(raw);
+  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AnimatedQrDecoderImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  List<ContactInfo>? dco_decode_opt_list_contact_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_contact_info(raw);
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AnimatedQrDecoderImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  void sse_encode_Map_String_String(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Map_String_u_64(
+    Map<String, BigInt> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_u_64(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
   }
 
   @protected
-  (String, BigInt) dco_decode_record_string_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_u_64(arr[1]));
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AnimatedQrDecoderImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  TransactionInfo dco_decode_transaction_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return TransactionInfo(
-      id: dco_decode_String(arr[0]),
-      direction: dco_decode_String(arr[1]),
-      amount: dco_decode_u_64(arr[2]),
-      fee: dco_decode_u_64(arr[3]),
-      memo: dco_decode_opt_String(arr[4]),
-      timestamp: dco_decode_u_64(arr[5]),
-      mintUrl: dco_decode_String(arr[6]),
-      transactionType: dco_decode_opt_String(arr[7]),
-      lightningInvoice: dco_decode_opt_String(arr[8]),
-      ecashToken: dco_decode_opt_String(arr[9]),
-      metadata: dco_decode_Map_String_String(arr[10]),
+  void sse_encode_StreamSink_log_record_Sse(
+    RustStreamSink<LogRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_tor_bootstrap_status_Sse(
+    RustStreamSink<TorBootstrapStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tor_bootstrap_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_StreamSink_wallet_event_Sse(
+    RustStreamSink<WalletEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_animated_qr_frames(
+    AnimatedQrFrames self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.frames, serializer);
+    sse_encode_u_32(self.fragmentCount, serializer);
+    sse_encode_bool(self.isAnimated, serializer);
   }
 
   @protected
-  WalletInfo dco_decode_wallet_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return WalletInfo(
-      mintUrl: dco_decode_String(arr[0]),
-      unit: dco_decode_String(arr[1]),
-      balance: dco_decode_u_64(arr[2]),
-      activeKeysetId: dco_decode_String(arr[3]),
-    );
+  void sse_encode_bolt_12_invoice_request_info(
+    Bolt12InvoiceRequestInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.payerPubkey, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountMsat, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.quantity, serializer);
+    sse_encode_opt_String(self.payerNote, serializer);
+    sse_encode_String(self.chain, serializer);
+    sse_encode_opt_String(self.offerDescription, serializer);
+    sse_encode_opt_String(self.offerIssuer, serializer);
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String(
-    SseDeserializer deserializer,
+  void sse_encode_bolt_12_offer_info(
+    Bolt12OfferInfo self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_String(self.offerId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountMsat, serializer);
+    sse_encode_opt_String(self.currency, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.currencyAmount, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.issuer, serializer);
+    sse_encode_opt_String(self.issuerPubkey, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.absoluteExpiry, serializer);
+    sse_encode_list_String(self.chains, serializer);
+    sse_encode_bool(self.isExpired, serializer);
   }
 
   @protected
-  Map<String, BigInt> sse_decode_Map_String_u_64(SseDeserializer deserializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_u_64(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_cashu_dleq(
+    CashuDleq self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_cashu_dleq(self, serializer);
   }
 
   @protected
-  NostrEvent sse_decode_box_autoadd_nostr_event(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_cashu_witness(
+    CashuWitness self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_nostr_event(deserializer));
+    sse_encode_cashu_witness(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_mint_auth_settings(
+    MintAuthSettings self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_mint_auth_settings(self, serializer);
   }
 
   @protected
-  CashuProof sse_decode_cashu_proof(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_nip_60_sync_report(
+    Nip60SyncReport self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_amount = sse_decode_u_64(deserializer);
-    var var_secret = sse_decode_String(deserializer);
-    var var_c = sse_decode_String(deserializer);
-    return CashuProof(
-      id: var_id,
-      amount: var_amount,
-      secret: var_secret,
-      c: var_c,
-    );
+    sse_encode_nip_60_sync_report(self, serializer);
   }
 
   @protected
-  ContactInfo sse_decode_contact_info(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_nostr_event(
+    NostrEvent self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_method = sse_decode_String(deserializer);
-    var var_info = sse_decode_String(deserializer);
-    return ContactInfo(method: var_method, info: var_info);
+    sse_encode_nostr_event(self, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_proxy_config(
+    ProxyConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_proxy_config(self, serializer);
   }
 
   @protected
-  List<CashuProof> sse_decode_list_cashu_proof(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_selected_proofs_send_options(
+    SelectedProofsSendOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <CashuProof>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_cashu_proof(deserializer));
-    }
-    return ans_;
+    sse_encode_selected_proofs_send_options(self, serializer);
   }
 
   @protected
-  List<ContactInfo> sse_decode_list_contact_info(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_spending_limit(
+    SpendingLimit self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ContactInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_contact_info(deserializer));
-    }
-    return ans_;
+    sse_encode_spending_limit(self, serializer);
   }
 
   @protected
-  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <List<String>>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_String(deserializer));
-    }
-    return ans_;
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_unsupported_by_mint(
+    UnsupportedByMint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
+    sse_encode_unsupported_by_mint(self, serializer);
   }
 
   @protected
-  List<(String, BigInt)> sse_decode_list_record_string_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_wallet_handle(
+    WalletHandle self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_u_64(deserializer));
-    }
-    return ans_;
+    sse_encode_wallet_handle(self, serializer);
   }
 
   @protected
-  List<TransactionInfo> sse_decode_list_transaction_info(
-    SseDeserializer deserializer,
+  void sse_encode_cached_endpoint(
+    CachedEndpoint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TransactionInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_transaction_info(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.method, serializer);
+    sse_encode_String(self.path, serializer);
   }
 
   @protected
-  MintInfo sse_decode_mint_info(SseDeserializer deserializer) {
+  void sse_encode_cashu_dleq(CashuDleq self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_version = sse_decode_opt_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_descriptionLong = sse_decode_opt_String(deserializer);
-    var var_contact = sse_decode_opt_list_contact_info(deserializer);
-    var var_motd = sse_decode_opt_String(deserializer);
-    var var_iconUrl = sse_decode_opt_String(deserializer);
-    var var_urls = sse_decode_opt_list_String(deserializer);
-    var var_nuts = sse_decode_opt_list_String(deserializer);
-    var var_publicKey = sse_decode_opt_String(deserializer);
-    var var_additionalInfo = sse_decode_opt_String(deserializer);
-    return MintInfo(
-      name: var_name,
-      version: var_version,
-      description: var_description,
-      descriptionLong: var_descriptionLong,
-      contact: var_contact,
-      motd: var_motd,
-      iconUrl: var_iconUrl,
-      urls: var_urls,
-      nuts: var_nuts,
-      publicKey: var_publicKey,
-      additionalInfo: var_additionalInfo,
-    );
+    sse_encode_String(self.e, serializer);
+    sse_encode_String(self.s, serializer);
+    sse_encode_String(self.r, serializer);
   }
 
   @protected
-  NostrEvent sse_decode_nostr_event(SseDeserializer deserializer) {
+  void sse_encode_cashu_proof(CashuProof self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_pubkey = sse_decode_String(deserializer);
-    var var_createdAt = sse_decode_u_64(deserializer);
-    var var_kind = sse_decode_u_64(deserializer);
-    var var_tags = sse_decode_list_list_String(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_sig = sse_decode_String(deserializer);
-    return NostrEvent(
-      id: var_id,
-      pubkey: var_pubkey,
-      createdAt: var_createdAt,
-      kind: var_kind,
-      tags: var_tags,
-      content: var_content,
-      sig: var_sig,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_cashu_secret(self.secret, serializer);
+    sse_encode_String(self.c, serializer);
+    sse_encode_opt_box_autoadd_cashu_witness(self.witness, serializer);
+    sse_encode_opt_box_autoadd_cashu_dleq(self.dleq, serializer);
+    sse_encode_String(self.y, serializer);
+    sse_encode_opt_String(self.state, serializer);
+    sse_encode_bool(self.reserved, serializer);
   }
 
   @protected
-  NostrKeys sse_decode_nostr_keys(SseDeserializer deserializer) {
+  void sse_encode_cashu_secret(CashuSecret self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_publicKey = sse_decode_String(deserializer);
-    var var_privateKey = sse_decode_String(deserializer);
-    return NostrKeys(publicKey: var_publicKey, privateKey: var_privateKey);
+    sse_encode_String(self.raw, serializer);
+    sse_encode_opt_String(self.kind, serializer);
+    sse_encode_opt_String(self.nonce, serializer);
+    sse_encode_opt_String(self.data, serializer);
+    sse_encode_list_list_String(self.tags, serializer);
   }
 
   @protected
-  NostrKeysWithBech32 sse_decode_nostr_keys_with_bech_32(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_cashu_witness(CashuWitness self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_privateKey = sse_decode_String(deserializer);
-    var var_publicKey = sse_decode_String(deserializer);
-    var var_nsec = sse_decode_String(deserializer);
-    var var_npub = sse_decode_String(deserializer);
-    return NostrKeysWithBech32(
-      privateKey: var_privateKey,
-      publicKey: var_publicKey,
-      nsec: var_nsec,
-      npub: var_npub,
-    );
+    sse_encode_opt_String(self.preimage, serializer);
+    sse_encode_opt_list_String(self.signatures, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_contact_info(ContactInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_String(self.method, serializer);
+    sse_encode_String(self.info, serializer);
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_daemon_client(DaemonClient self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_String(self.permissions, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_diagnostics_bundle(
+    DiagnosticsBundle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_String(self.crateVersion, serializer);
+    sse_encode_String(self.os, serializer);
+    sse_encode_String(self.arch, serializer);
+    sse_encode_Map_String_String(self.logLevels, serializer);
+    sse_encode_list_log_record(self.records, serializer);
   }
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+  void sse_encode_encoded_token(EncodedToken self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.token, serializer);
+    sse_encode_String(self.encoding, serializer);
+    sse_encode_u_64(self.byteSize, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.proofsCount, serializer);
+    sse_encode_list_String(self.mintUrls, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_String(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  List<ContactInfo>? sse_decode_opt_list_contact_info(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_import_report(ImportReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.format, serializer);
+    sse_encode_list_imported_mint(self.mints, serializer);
+    sse_encode_u_64(self.importedAmount, serializer);
+    sse_encode_u_64(self.unassignedProofs, serializer);
+    sse_encode_bool(self.hasMnemonic, serializer);
+    sse_encode_list_keyset_counter(self.keysetCounters, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_contact_info(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_imported_mint(ImportedMint self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mintUrl, serializer);
+    sse_encode_u_64(self.proofsFound, serializer);
+    sse_encode_u_64(self.amountFound, serializer);
+    sse_encode_u_64(self.spentAmount, serializer);
+    sse_encode_u_64(self.importedAmount, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
+  void sse_encode_interrupted_operation(
+    InterruptedOperation self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.mintUrl, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.startedAt, serializer);
   }
 
   @protected
-  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer) {
+  void sse_encode_keyset_counter(KeysetCounter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_u_64(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_String(self.keysetId, serializer);
+    sse_encode_u_32(self.counter, serializer);
   }
 
   @protected
-  TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_direction = sse_decode_String(deserializer);
-    var var_amount = sse_decode_u_64(deserializer);
-    var var_fee = sse_decode_u_64(deserializer);
-    var var_memo = sse_decode_opt_String(deserializer);
-    var var_timestamp = sse_decode_u_64(deserializer);
-    var var_mintUrl = sse_decode_String(deserializer);
-    var var_transactionType = sse_decode_opt_String(deserializer);
-    var var_lightningInvoice = sse_decode_opt_String(deserializer);
-    var var_ecashToken = sse_decode_opt_String(deserializer);
-    var var_metadata = sse_decode_Map_String_String(deserializer);
-    return TransactionInfo(
-      id: var_id,
-      direction: var_direction,
-      amount: var_amount,
-      fee: var_fee,
-      memo: var_memo,
-      timestamp: var_timestamp,
-      mintUrl: var_mintUrl,
-      transactionType: var_transactionType,
-      lightningInvoice: var_lightningInvoice,
-      ecashToken: var_ecashToken,
-      metadata: var_metadata,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_cached_endpoint(
+    List<CachedEndpoint> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cached_endpoint(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_cashu_proof(
+    List<CashuProof> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cashu_proof(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_contact_info(
+    List<ContactInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_contact_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_daemon_client(
+    List<DaemonClient> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_daemon_client(item, serializer);
+    }
   }
 
   @protected
-  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer) {
+  void sse_encode_list_imported_mint(
+    List<ImportedMint> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_mintUrl = sse_decode_String(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_balance = sse_decode_u_64(deserializer);
-    var var_activeKeysetId = sse_decode_String(deserializer);
-    return WalletInfo(
-      mintUrl: var_mintUrl,
-      unit: var_unit,
-      balance: var_balance,
-      activeKeysetId: var_activeKeysetId,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_imported_mint(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_interrupted_operation(
+    List<InterruptedOperation> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_interrupted_operation(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_String(
-    Map<String, String> self,
+  void sse_encode_list_keyset_counter(
+    List<KeysetCounter> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_keyset_counter(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_u_64(
-    Map<String, BigInt> self,
+  void sse_encode_list_list_String(
+    List<List<String>> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_u_64(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_String(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_log_record(
+    List<LogRecord> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_log_record(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_payment_method_settings(
+    List<PaymentMethodSettings> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_method_settings(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_nostr_event(
-    NostrEvent self,
+  void sse_encode_list_payment_request_transport(
+    List<PaymentRequestTransport> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_nostr_event(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_request_transport(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_cashu_proof(CashuProof self, SseSerializer serializer) {
+  void sse_encode_list_proof_verification(
+    List<ProofVerification> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_u_64(self.amount, serializer);
-    sse_encode_String(self.secret, serializer);
-    sse_encode_String(self.c, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_proof_verification(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_contact_info(ContactInfo self, SseSerializer serializer) {
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.method, serializer);
-    sse_encode_String(self.info, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_list_record_string_u_64(
+    List<(String, BigInt)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_String(item, serializer);
+      sse_encode_record_string_u_64(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_cashu_proof(
-    List<CashuProof> self,
+  void sse_encode_list_restored_mint(
+    List<RestoredMint> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_cashu_proof(item, serializer);
+      sse_encode_restored_mint(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_contact_info(
-    List<ContactInfo> self,
+  void sse_encode_list_spending_allowance(
+    List<SpendingAllowance> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_contact_info(item, serializer);
+      sse_encode_spending_allowance(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_list_String(
-    List<List<String>> self,
+  void sse_encode_list_spending_limit(
+    List<SpendingLimit> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_spending_limit(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_transaction_info(
+    List<TransactionInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_list_String(item, serializer);
+      sse_encode_transaction_info(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_list_u_32(List<int> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    for (final item in self) {
+      sse_encode_u_32(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
+  void sse_encode_list_wallet_profile(
+    List<WalletProfile> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
+      sse_encode_wallet_profile(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_record_string_u_64(
-    List<(String, BigInt)> self,
+  void sse_encode_list_web_socket_settings(
+    List<WebSocketSettings> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_record_string_u_64(item, serializer);
+      sse_encode_web_socket_settings(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_transaction_info(
-    List<TransactionInfo> self,
+  void sse_encode_lnurl_pay_info(LnurlPayInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.callback, serializer);
+    sse_encode_u_64(self.minSendableSats, serializer);
+    sse_encode_u_64(self.maxSendableSats, serializer);
+    sse_encode_u_64(self.commentAllowed, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_String(self.metadata, serializer);
+    sse_encode_String(self.domain, serializer);
+  }
+
+  @protected
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.level, serializer);
+    sse_encode_String(self.module, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_64(self.timestampMs, serializer);
+  }
+
+  @protected
+  void sse_encode_mint_auth_settings(
+    MintAuthSettings self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_transaction_info(item, serializer);
-    }
+    sse_encode_opt_String(self.openidDiscovery, serializer);
+    sse_encode_opt_String(self.clientId, serializer);
+    sse_encode_list_String(self.clearAuthEndpoints, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.blindAuthMaxMint, serializer);
+    sse_encode_list_String(self.blindAuthEndpoints, serializer);
   }
 
   @protected
@@ -2567,6 +8134,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_list_String(self.nuts, serializer);
     sse_encode_opt_String(self.publicKey, serializer);
     sse_encode_opt_String(self.additionalInfo, serializer);
+    sse_encode_opt_String(self.tosUrl, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.time, serializer);
+    sse_encode_mint_settings(self.settings, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.fetchedAt, serializer);
+    sse_encode_bool(self.isStale, serializer);
+  }
+
+  @protected
+  void sse_encode_mint_settings(MintSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_payment_method_settings(self.mintMethods, serializer);
+    sse_encode_bool(self.mintingDisabled, serializer);
+    sse_encode_list_payment_method_settings(self.meltMethods, serializer);
+    sse_encode_bool(self.meltingDisabled, serializer);
+    sse_encode_list_u_32(self.supportedNuts, serializer);
+    sse_encode_list_payment_method_settings(self.mppMethods, serializer);
+    sse_encode_list_web_socket_settings(self.websocket, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.cacheTtl, serializer);
+    sse_encode_list_cached_endpoint(self.cachedEndpoints, serializer);
+    sse_encode_opt_box_autoadd_mint_auth_settings(self.auth, serializer);
+  }
+
+  @protected
+  void sse_encode_nip_60_sync_report(
+    Nip60SyncReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.importedProofs, serializer);
+    sse_encode_u_64(self.importedAmount, serializer);
+    sse_encode_u_64(self.removedProofs, serializer);
+    sse_encode_list_String(self.mintsAdded, serializer);
+    sse_encode_u_64(self.publishedEvents, serializer);
+    sse_encode_u_64(self.deletedEvents, serializer);
+    sse_encode_list_String(self.errors, serializer);
+    sse_encode_u_64(self.syncedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_nip_60_sync_status(
+    Nip60SyncStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_list_String(self.relays, serializer);
+    sse_encode_opt_String(self.publicKey, serializer);
+    sse_encode_opt_box_autoadd_nip_60_sync_report(self.lastReport, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
   }
 
   @protected
@@ -2610,6 +8226,91 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_cashu_dleq(
+    CashuDleq? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cashu_dleq(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_cashu_witness(
+    CashuWitness? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cashu_witness(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_mint_auth_settings(
+    MintAuthSettings? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_mint_auth_settings(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_nip_60_sync_report(
+    Nip60SyncReport? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_nip_60_sync_report(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_proxy_config(
+    ProxyConfig? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_proxy_config(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2620,6 +8321,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_unsupported_by_mint(
+    UnsupportedByMint? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_unsupported_by_mint(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_String(
     List<String>? self,
@@ -2646,6 +8360,96 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_payment_method_settings(
+    PaymentMethodSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.method, serializer);
+    sse_encode_String(self.unit, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.minAmount, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxAmount, serializer);
+    sse_encode_bool(self.description, serializer);
+    sse_encode_bool(self.amountless, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_request_info(
+    PaymentRequestInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.paymentId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amount, serializer);
+    sse_encode_opt_String(self.unit, serializer);
+    sse_encode_opt_box_autoadd_bool(self.singleUse, serializer);
+    sse_encode_list_String(self.mints, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_list_payment_request_transport(self.transports, serializer);
+    sse_encode_opt_String(self.lockKind, serializer);
+    sse_encode_opt_String(self.lockData, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_request_transport(
+    PaymentRequestTransport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.transportType, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_list_list_String(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_proof_verification(
+    ProofVerification self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.y, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_String(self.keysetId, serializer);
+    sse_encode_opt_box_autoadd_bool(self.dleqValid, serializer);
+    sse_encode_bool(self.secretValid, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.host, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_opt_String(self.username, serializer);
+    sse_encode_opt_String(self.password, serializer);
+  }
+
+  @protected
+  void sse_encode_proxy_test_result(
+    ProxyTestResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.success, serializer);
+    sse_encode_u_64(self.latencyMs, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_qr_decode_progress(
+    QrDecodeProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.complete, serializer);
+    sse_encode_f_64(self.progress, serializer);
+    sse_encode_u_32(self.receivedFrames, serializer);
+    sse_encode_u_32(self.expectedFrames, serializer);
+    sse_encode_opt_String(self.payload, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -2666,6 +8470,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_restored_mint(RestoredMint self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mintUrl, serializer);
+    sse_encode_u_64(self.restoredAmount, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_restored_profile(
+    RestoredProfile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_handle(self.handle, serializer);
+    sse_encode_String(self.mnemonic, serializer);
+    sse_encode_list_restored_mint(self.mints, serializer);
+    sse_encode_u_64(self.restoredAmount, serializer);
+    sse_encode_list_keyset_counter(self.keysetCounters, serializer);
+  }
+
+  @protected
+  void sse_encode_selected_proofs_send_options(
+    SelectedProofsSendOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.swap, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amount, serializer);
+    sse_encode_opt_String(self.memo, serializer);
+  }
+
+  @protected
+  void sse_encode_spending_allowance(
+    SpendingAllowance self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.mintUrl, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.perTransaction, serializer);
+    sse_encode_u_64(self.dailySpent, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.dailyRemaining, serializer);
+    sse_encode_u_64(self.weeklySpent, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.weeklyRemaining, serializer);
+    sse_encode_String(self.action, serializer);
+  }
+
+  @protected
+  void sse_encode_spending_limit(SpendingLimit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.mintUrl, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.perTransaction, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.daily, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.weekly, serializer);
+    sse_encode_String(self.action, serializer);
+  }
+
+  @protected
+  void sse_encode_token_verification(
+    TokenVerification self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mintUrl, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_bool(self.keysetsValid, serializer);
+    sse_encode_bool(self.fetchedKeys, serializer);
+    sse_encode_bool(self.valid, serializer);
+    sse_encode_list_proof_verification(self.proofs, serializer);
+  }
+
+  @protected
+  void sse_encode_tor_bootstrap_status(
+    TorBootstrapStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.started, serializer);
+    sse_encode_f_64(self.progress, serializer);
+    sse_encode_bool(self.ready, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_transaction_info(
     TransactionInfo self,
@@ -2685,6 +8573,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Map_String_String(self.metadata, serializer);
   }
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2708,6 +8602,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_unsupported_by_mint(
+    UnsupportedByMint self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mintUrl, serializer);
+    sse_encode_u_32(self.nut, serializer);
+    sse_encode_String(self.capability, serializer);
+  }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_wallet_event(WalletEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_opt_String(self.profileId, serializer);
+    sse_encode_opt_String(self.mintUrl, serializer);
+    sse_encode_opt_String(self.quoteId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amount, serializer);
+    sse_encode_opt_String(self.message, serializer);
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_Map_String_String(self.data, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_handle(WalletHandle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.profileId, serializer);
+  }
+
   @protected
   void sse_encode_wallet_info(WalletInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2715,6 +8645,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.unit, serializer);
     sse_encode_u_64(self.balance, serializer);
     sse_encode_String(self.activeKeysetId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.fetchedAt, serializer);
+    sse_encode_bool(self.isStale, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_profile(WalletProfile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.profileId, serializer);
+    sse_encode_String(self.profileDir, serializer);
+    sse_encode_bool(self.isOpen, serializer);
+    sse_encode_bool(self.isEncrypted, serializer);
+  }
+
+  @protected
+  void sse_encode_web_socket_settings(
+    WebSocketSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.method, serializer);
+    sse_encode_String(self.unit, serializer);
+    sse_encode_list_String(self.commands, serializer);
   }
 
   @protected
@@ -2723,3 +8675,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putInt32(self);
   }
 }
+
+@sealed
+class AnimatedQrDecoderImpl extends RustOpaque implements AnimatedQrDecoder {
+  // Not to be used by end users
+  AnimatedQrDecoderImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  AnimatedQrDecoderImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_AnimatedQrDecoder,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_AnimatedQrDecoder,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_AnimatedQrDecoderPtr,
+  );
+
+  /// Current progress without feeding a frame
+  QrDecodeProgress progress() =>
+      RustLib.instance.api.crateApiQrAnimatedQrDecoderProgress(that: this);
+
+  /// Feed one scanned frame and get the updated progress
+  QrDecodeProgress receive({required String frame}) =>
+      RustLib.instance.api.crateApiQrAnimatedQrDecoderReceive(
+        that: this,
+        frame: frame,
+      );
+
+  /// Forget all received frames to scan a new code
+  void reset() =>
+      RustLib.instance.api.crateApiQrAnimatedQrDecoderReset(that: this);
+}
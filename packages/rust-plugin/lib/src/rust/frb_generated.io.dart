@@ -4,11 +4,18 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/cashu.dart';
+import 'api/daemon.dart';
+import 'api/events.dart';
+import 'api/limits.dart';
+import 'api/logging.dart';
+import 'api/nip60.dart';
 import 'api/nostr.dart';
+import 'api/qr.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
+import 'network.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
@@ -19,6 +26,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_AnimatedQrDecoderPtr =>
+      wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoderPtr;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  AnimatedQrDecoder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  );
+
+  @protected
+  AnimatedQrDecoder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  );
+
+  @protected
+  AnimatedQrDecoder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  );
+
   @protected
   Map<String, String> dco_decode_Map_String_String(dynamic raw);
 
@@ -26,325 +55,1319 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   Map<String, BigInt> dco_decode_Map_String_u_64(dynamic raw);
 
   @protected
-  String dco_decode_String(dynamic raw);
+  AnimatedQrDecoder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<TorBootstrapStatus> dco_decode_StreamSink_tor_bootstrap_status_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<WalletEvent> dco_decode_StreamSink_wallet_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  AnimatedQrFrames dco_decode_animated_qr_frames(dynamic raw);
+
+  @protected
+  Bolt12InvoiceRequestInfo dco_decode_bolt_12_invoice_request_info(dynamic raw);
+
+  @protected
+  Bolt12OfferInfo dco_decode_bolt_12_offer_info(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CashuDleq dco_decode_box_autoadd_cashu_dleq(dynamic raw);
+
+  @protected
+  CashuWitness dco_decode_box_autoadd_cashu_witness(dynamic raw);
+
+  @protected
+  MintAuthSettings dco_decode_box_autoadd_mint_auth_settings(dynamic raw);
+
+  @protected
+  Nip60SyncReport dco_decode_box_autoadd_nip_60_sync_report(dynamic raw);
+
+  @protected
+  NostrEvent dco_decode_box_autoadd_nostr_event(dynamic raw);
+
+  @protected
+  ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw);
+
+  @protected
+  SelectedProofsSendOptions dco_decode_box_autoadd_selected_proofs_send_options(
+    dynamic raw,
+  );
+
+  @protected
+  SpendingLimit dco_decode_box_autoadd_spending_limit(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  UnsupportedByMint dco_decode_box_autoadd_unsupported_by_mint(dynamic raw);
+
+  @protected
+  WalletHandle dco_decode_box_autoadd_wallet_handle(dynamic raw);
+
+  @protected
+  CachedEndpoint dco_decode_cached_endpoint(dynamic raw);
+
+  @protected
+  CashuDleq dco_decode_cashu_dleq(dynamic raw);
+
+  @protected
+  CashuProof dco_decode_cashu_proof(dynamic raw);
+
+  @protected
+  CashuSecret dco_decode_cashu_secret(dynamic raw);
+
+  @protected
+  CashuWitness dco_decode_cashu_witness(dynamic raw);
+
+  @protected
+  ContactInfo dco_decode_contact_info(dynamic raw);
+
+  @protected
+  DaemonClient dco_decode_daemon_client(dynamic raw);
+
+  @protected
+  DiagnosticsBundle dco_decode_diagnostics_bundle(dynamic raw);
+
+  @protected
+  EncodedToken dco_decode_encoded_token(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  ImportReport dco_decode_import_report(dynamic raw);
+
+  @protected
+  ImportedMint dco_decode_imported_mint(dynamic raw);
+
+  @protected
+  InterruptedOperation dco_decode_interrupted_operation(dynamic raw);
+
+  @protected
+  KeysetCounter dco_decode_keyset_counter(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<CachedEndpoint> dco_decode_list_cached_endpoint(dynamic raw);
+
+  @protected
+  List<CashuProof> dco_decode_list_cashu_proof(dynamic raw);
+
+  @protected
+  List<ContactInfo> dco_decode_list_contact_info(dynamic raw);
+
+  @protected
+  List<DaemonClient> dco_decode_list_daemon_client(dynamic raw);
+
+  @protected
+  List<ImportedMint> dco_decode_list_imported_mint(dynamic raw);
+
+  @protected
+  List<InterruptedOperation> dco_decode_list_interrupted_operation(dynamic raw);
+
+  @protected
+  List<KeysetCounter> dco_decode_list_keyset_counter(dynamic raw);
+
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw);
+
+  @protected
+  List<LogRecord> dco_decode_list_log_record(dynamic raw);
+
+  @protected
+  List<PaymentMethodSettings> dco_decode_list_payment_method_settings(
+    dynamic raw,
+  );
+
+  @protected
+  List<PaymentRequestTransport> dco_decode_list_payment_request_transport(
+    dynamic raw,
+  );
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ProofVerification> dco_decode_list_proof_verification(dynamic raw);
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw);
+
+  @protected
+  List<RestoredMint> dco_decode_list_restored_mint(dynamic raw);
+
+  @protected
+  List<SpendingAllowance> dco_decode_list_spending_allowance(dynamic raw);
+
+  @protected
+  List<SpendingLimit> dco_decode_list_spending_limit(dynamic raw);
+
+  @protected
+  List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_u_32(dynamic raw);
+
+  @protected
+  List<WalletProfile> dco_decode_list_wallet_profile(dynamic raw);
+
+  @protected
+  List<WebSocketSettings> dco_decode_list_web_socket_settings(dynamic raw);
+
+  @protected
+  LnurlPayInfo dco_decode_lnurl_pay_info(dynamic raw);
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw);
+
+  @protected
+  MintAuthSettings dco_decode_mint_auth_settings(dynamic raw);
+
+  @protected
+  MintInfo dco_decode_mint_info(dynamic raw);
+
+  @protected
+  MintSettings dco_decode_mint_settings(dynamic raw);
+
+  @protected
+  Nip60SyncReport dco_decode_nip_60_sync_report(dynamic raw);
+
+  @protected
+  Nip60SyncStatus dco_decode_nip_60_sync_status(dynamic raw);
+
+  @protected
+  NostrEvent dco_decode_nostr_event(dynamic raw);
+
+  @protected
+  NostrKeys dco_decode_nostr_keys(dynamic raw);
+
+  @protected
+  NostrKeysWithBech32 dco_decode_nostr_keys_with_bech_32(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CashuDleq? dco_decode_opt_box_autoadd_cashu_dleq(dynamic raw);
+
+  @protected
+  CashuWitness? dco_decode_opt_box_autoadd_cashu_witness(dynamic raw);
+
+  @protected
+  MintAuthSettings? dco_decode_opt_box_autoadd_mint_auth_settings(dynamic raw);
+
+  @protected
+  Nip60SyncReport? dco_decode_opt_box_autoadd_nip_60_sync_report(dynamic raw);
+
+  @protected
+  ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  UnsupportedByMint? dco_decode_opt_box_autoadd_unsupported_by_mint(
+    dynamic raw,
+  );
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<ContactInfo>? dco_decode_opt_list_contact_info(dynamic raw);
+
+  @protected
+  PaymentMethodSettings dco_decode_payment_method_settings(dynamic raw);
+
+  @protected
+  PaymentRequestInfo dco_decode_payment_request_info(dynamic raw);
+
+  @protected
+  PaymentRequestTransport dco_decode_payment_request_transport(dynamic raw);
+
+  @protected
+  ProofVerification dco_decode_proof_verification(dynamic raw);
+
+  @protected
+  ProxyConfig dco_decode_proxy_config(dynamic raw);
+
+  @protected
+  ProxyTestResult dco_decode_proxy_test_result(dynamic raw);
+
+  @protected
+  QrDecodeProgress dco_decode_qr_decode_progress(dynamic raw);
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw);
+
+  @protected
+  (String, BigInt) dco_decode_record_string_u_64(dynamic raw);
+
+  @protected
+  RestoredMint dco_decode_restored_mint(dynamic raw);
+
+  @protected
+  RestoredProfile dco_decode_restored_profile(dynamic raw);
+
+  @protected
+  SelectedProofsSendOptions dco_decode_selected_proofs_send_options(
+    dynamic raw,
+  );
+
+  @protected
+  SpendingAllowance dco_decode_spending_allowance(dynamic raw);
+
+  @protected
+  SpendingLimit dco_decode_spending_limit(dynamic raw);
+
+  @protected
+  TokenVerification dco_decode_token_verification(dynamic raw);
+
+  @protected
+  TorBootstrapStatus dco_decode_tor_bootstrap_status(dynamic raw);
+
+  @protected
+  TransactionInfo dco_decode_transaction_info(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  UnsupportedByMint dco_decode_unsupported_by_mint(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WalletEvent dco_decode_wallet_event(dynamic raw);
+
+  @protected
+  WalletHandle dco_decode_wallet_handle(dynamic raw);
+
+  @protected
+  WalletInfo dco_decode_wallet_info(dynamic raw);
+
+  @protected
+  WalletProfile dco_decode_wallet_profile(dynamic raw);
+
+  @protected
+  WebSocketSettings dco_decode_web_socket_settings(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  AnimatedQrDecoder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AnimatedQrDecoder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AnimatedQrDecoder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Map<String, BigInt> sse_decode_Map_String_u_64(SseDeserializer deserializer);
+
+  @protected
+  AnimatedQrDecoder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<TorBootstrapStatus> sse_decode_StreamSink_tor_bootstrap_status_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WalletEvent> sse_decode_StreamSink_wallet_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AnimatedQrFrames sse_decode_animated_qr_frames(SseDeserializer deserializer);
+
+  @protected
+  Bolt12InvoiceRequestInfo sse_decode_bolt_12_invoice_request_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Bolt12OfferInfo sse_decode_bolt_12_offer_info(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CashuDleq sse_decode_box_autoadd_cashu_dleq(SseDeserializer deserializer);
+
+  @protected
+  CashuWitness sse_decode_box_autoadd_cashu_witness(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MintAuthSettings sse_decode_box_autoadd_mint_auth_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Nip60SyncReport sse_decode_box_autoadd_nip_60_sync_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NostrEvent sse_decode_box_autoadd_nostr_event(SseDeserializer deserializer);
+
+  @protected
+  ProxyConfig sse_decode_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+  @protected
+  SelectedProofsSendOptions sse_decode_box_autoadd_selected_proofs_send_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SpendingLimit sse_decode_box_autoadd_spending_limit(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  UnsupportedByMint sse_decode_box_autoadd_unsupported_by_mint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletHandle sse_decode_box_autoadd_wallet_handle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CachedEndpoint sse_decode_cached_endpoint(SseDeserializer deserializer);
+
+  @protected
+  CashuDleq sse_decode_cashu_dleq(SseDeserializer deserializer);
+
+  @protected
+  CashuProof sse_decode_cashu_proof(SseDeserializer deserializer);
+
+  @protected
+  CashuSecret sse_decode_cashu_secret(SseDeserializer deserializer);
+
+  @protected
+  CashuWitness sse_decode_cashu_witness(SseDeserializer deserializer);
+
+  @protected
+  ContactInfo sse_decode_contact_info(SseDeserializer deserializer);
+
+  @protected
+  DaemonClient sse_decode_daemon_client(SseDeserializer deserializer);
+
+  @protected
+  DiagnosticsBundle sse_decode_diagnostics_bundle(SseDeserializer deserializer);
+
+  @protected
+  EncodedToken sse_decode_encoded_token(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  ImportReport sse_decode_import_report(SseDeserializer deserializer);
+
+  @protected
+  ImportedMint sse_decode_imported_mint(SseDeserializer deserializer);
+
+  @protected
+  InterruptedOperation sse_decode_interrupted_operation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KeysetCounter sse_decode_keyset_counter(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<CachedEndpoint> sse_decode_list_cached_endpoint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CashuProof> sse_decode_list_cashu_proof(SseDeserializer deserializer);
+
+  @protected
+  List<ContactInfo> sse_decode_list_contact_info(SseDeserializer deserializer);
+
+  @protected
+  List<DaemonClient> sse_decode_list_daemon_client(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ImportedMint> sse_decode_list_imported_mint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InterruptedOperation> sse_decode_list_interrupted_operation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<KeysetCounter> sse_decode_list_keyset_counter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<LogRecord> sse_decode_list_log_record(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentMethodSettings> sse_decode_list_payment_method_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PaymentRequestTransport> sse_decode_list_payment_request_transport(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ProofVerification> sse_decode_list_proof_verification(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, BigInt)> sse_decode_list_record_string_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RestoredMint> sse_decode_list_restored_mint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SpendingAllowance> sse_decode_list_spending_allowance(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SpendingLimit> sse_decode_list_spending_limit(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TransactionInfo> sse_decode_list_transaction_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<int> sse_decode_list_u_32(SseDeserializer deserializer);
+
+  @protected
+  List<WalletProfile> sse_decode_list_wallet_profile(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WebSocketSettings> sse_decode_list_web_socket_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LnurlPayInfo sse_decode_lnurl_pay_info(SseDeserializer deserializer);
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer);
+
+  @protected
+  MintAuthSettings sse_decode_mint_auth_settings(SseDeserializer deserializer);
+
+  @protected
+  MintInfo sse_decode_mint_info(SseDeserializer deserializer);
+
+  @protected
+  MintSettings sse_decode_mint_settings(SseDeserializer deserializer);
+
+  @protected
+  Nip60SyncReport sse_decode_nip_60_sync_report(SseDeserializer deserializer);
+
+  @protected
+  Nip60SyncStatus sse_decode_nip_60_sync_status(SseDeserializer deserializer);
+
+  @protected
+  NostrEvent sse_decode_nostr_event(SseDeserializer deserializer);
+
+  @protected
+  NostrKeys sse_decode_nostr_keys(SseDeserializer deserializer);
+
+  @protected
+  NostrKeysWithBech32 sse_decode_nostr_keys_with_bech_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CashuDleq? sse_decode_opt_box_autoadd_cashu_dleq(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CashuWitness? sse_decode_opt_box_autoadd_cashu_witness(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MintAuthSettings? sse_decode_opt_box_autoadd_mint_auth_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Nip60SyncReport? sse_decode_opt_box_autoadd_nip_60_sync_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  UnsupportedByMint? sse_decode_opt_box_autoadd_unsupported_by_mint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ContactInfo>? sse_decode_opt_list_contact_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PaymentMethodSettings sse_decode_payment_method_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PaymentRequestInfo sse_decode_payment_request_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PaymentRequestTransport sse_decode_payment_request_transport(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProofVerification sse_decode_proof_verification(SseDeserializer deserializer);
+
+  @protected
+  ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer);
+
+  @protected
+  ProxyTestResult sse_decode_proxy_test_result(SseDeserializer deserializer);
+
+  @protected
+  QrDecodeProgress sse_decode_qr_decode_progress(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer);
+
+  @protected
+  RestoredMint sse_decode_restored_mint(SseDeserializer deserializer);
+
+  @protected
+  RestoredProfile sse_decode_restored_profile(SseDeserializer deserializer);
+
+  @protected
+  SelectedProofsSendOptions sse_decode_selected_proofs_send_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SpendingAllowance sse_decode_spending_allowance(SseDeserializer deserializer);
+
+  @protected
+  SpendingLimit sse_decode_spending_limit(SseDeserializer deserializer);
+
+  @protected
+  TokenVerification sse_decode_token_verification(SseDeserializer deserializer);
+
+  @protected
+  TorBootstrapStatus sse_decode_tor_bootstrap_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UnsupportedByMint sse_decode_unsupported_by_mint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WalletEvent sse_decode_wallet_event(SseDeserializer deserializer);
+
+  @protected
+  WalletHandle sse_decode_wallet_handle(SseDeserializer deserializer);
+
+  @protected
+  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer);
+
+  @protected
+  WalletProfile sse_decode_wallet_profile(SseDeserializer deserializer);
+
+  @protected
+  WebSocketSettings sse_decode_web_socket_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_String(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_u_64(
+    Map<String, BigInt> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    AnimatedQrDecoder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_log_record_Sse(
+    RustStreamSink<LogRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_tor_bootstrap_status_Sse(
+    RustStreamSink<TorBootstrapStatus> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_wallet_event_Sse(
+    RustStreamSink<WalletEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_animated_qr_frames(
+    AnimatedQrFrames self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bolt_12_invoice_request_info(
+    Bolt12InvoiceRequestInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bolt_12_offer_info(
+    Bolt12OfferInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  bool dco_decode_bool(dynamic raw);
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
   @protected
-  NostrEvent dco_decode_box_autoadd_nostr_event(dynamic raw);
+  void sse_encode_box_autoadd_cashu_dleq(
+    CashuDleq self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+  void sse_encode_box_autoadd_cashu_witness(
+    CashuWitness self,
+    SseSerializer serializer,
+  );
 
   @protected
-  CashuProof dco_decode_cashu_proof(dynamic raw);
+  void sse_encode_box_autoadd_mint_auth_settings(
+    MintAuthSettings self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ContactInfo dco_decode_contact_info(dynamic raw);
+  void sse_encode_box_autoadd_nip_60_sync_report(
+    Nip60SyncReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  void sse_encode_box_autoadd_nostr_event(
+    NostrEvent self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<CashuProof> dco_decode_list_cashu_proof(dynamic raw);
+  void sse_encode_box_autoadd_proxy_config(
+    ProxyConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ContactInfo> dco_decode_list_contact_info(dynamic raw);
+  void sse_encode_box_autoadd_selected_proofs_send_options(
+    SelectedProofsSendOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<List<String>> dco_decode_list_list_String(dynamic raw);
+  void sse_encode_box_autoadd_spending_limit(
+    SpendingLimit self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw);
+  void sse_encode_box_autoadd_unsupported_by_mint(
+    UnsupportedByMint self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw);
+  void sse_encode_box_autoadd_wallet_handle(
+    WalletHandle self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MintInfo dco_decode_mint_info(dynamic raw);
+  void sse_encode_cached_endpoint(
+    CachedEndpoint self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NostrEvent dco_decode_nostr_event(dynamic raw);
+  void sse_encode_cashu_dleq(CashuDleq self, SseSerializer serializer);
 
   @protected
-  NostrKeys dco_decode_nostr_keys(dynamic raw);
+  void sse_encode_cashu_proof(CashuProof self, SseSerializer serializer);
 
   @protected
-  NostrKeysWithBech32 dco_decode_nostr_keys_with_bech_32(dynamic raw);
+  void sse_encode_cashu_secret(CashuSecret self, SseSerializer serializer);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_cashu_witness(CashuWitness self, SseSerializer serializer);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+  void sse_encode_contact_info(ContactInfo self, SseSerializer serializer);
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw);
+  void sse_encode_daemon_client(DaemonClient self, SseSerializer serializer);
 
   @protected
-  List<ContactInfo>? dco_decode_opt_list_contact_info(dynamic raw);
+  void sse_encode_diagnostics_bundle(
+    DiagnosticsBundle self,
+    SseSerializer serializer,
+  );
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
+  void sse_encode_encoded_token(EncodedToken self, SseSerializer serializer);
 
   @protected
-  (String, BigInt) dco_decode_record_string_u_64(dynamic raw);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  TransactionInfo dco_decode_transaction_info(dynamic raw);
+  void sse_encode_import_report(ImportReport self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_imported_mint(ImportedMint self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void sse_encode_interrupted_operation(
+    InterruptedOperation self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_keyset_counter(KeysetCounter self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  WalletInfo dco_decode_wallet_info(dynamic raw);
+  void sse_encode_list_cached_endpoint(
+    List<CachedEndpoint> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Map<String, String> sse_decode_Map_String_String(
-    SseDeserializer deserializer,
+  void sse_encode_list_cashu_proof(
+    List<CashuProof> self,
+    SseSerializer serializer,
   );
 
   @protected
-  Map<String, BigInt> sse_decode_Map_String_u_64(SseDeserializer deserializer);
+  void sse_encode_list_contact_info(
+    List<ContactInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_daemon_client(
+    List<DaemonClient> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_imported_mint(
+    List<ImportedMint> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NostrEvent sse_decode_box_autoadd_nostr_event(SseDeserializer deserializer);
+  void sse_encode_list_interrupted_operation(
+    List<InterruptedOperation> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  void sse_encode_list_keyset_counter(
+    List<KeysetCounter> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  CashuProof sse_decode_cashu_proof(SseDeserializer deserializer);
+  void sse_encode_list_list_String(
+    List<List<String>> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ContactInfo sse_decode_contact_info(SseDeserializer deserializer);
+  void sse_encode_list_log_record(
+    List<LogRecord> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_list_payment_method_settings(
+    List<PaymentMethodSettings> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<CashuProof> sse_decode_list_cashu_proof(SseDeserializer deserializer);
+  void sse_encode_list_payment_request_transport(
+    List<PaymentRequestTransport> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ContactInfo> sse_decode_list_contact_info(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+  void sse_encode_list_proof_verification(
+    List<ProofVerification> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
+  void sse_encode_list_record_string_u_64(
+    List<(String, BigInt)> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<(String, BigInt)> sse_decode_list_record_string_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_list_restored_mint(
+    List<RestoredMint> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<TransactionInfo> sse_decode_list_transaction_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_spending_allowance(
+    List<SpendingAllowance> self,
+    SseSerializer serializer,
   );
 
   @protected
-  MintInfo sse_decode_mint_info(SseDeserializer deserializer);
+  void sse_encode_list_spending_limit(
+    List<SpendingLimit> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NostrEvent sse_decode_nostr_event(SseDeserializer deserializer);
+  void sse_encode_list_transaction_info(
+    List<TransactionInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NostrKeys sse_decode_nostr_keys(SseDeserializer deserializer);
+  void sse_encode_list_u_32(List<int> self, SseSerializer serializer);
 
   @protected
-  NostrKeysWithBech32 sse_decode_nostr_keys_with_bech_32(
-    SseDeserializer deserializer,
+  void sse_encode_list_wallet_profile(
+    List<WalletProfile> self,
+    SseSerializer serializer,
   );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_list_web_socket_settings(
+    List<WebSocketSettings> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  void sse_encode_lnurl_pay_info(LnurlPayInfo self, SseSerializer serializer);
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer);
 
   @protected
-  List<ContactInfo>? sse_decode_opt_list_contact_info(
-    SseDeserializer deserializer,
+  void sse_encode_mint_auth_settings(
+    MintAuthSettings self,
+    SseSerializer serializer,
   );
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_mint_info(MintInfo self, SseSerializer serializer);
 
   @protected
-  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer);
+  void sse_encode_mint_settings(MintSettings self, SseSerializer serializer);
 
   @protected
-  TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer);
+  void sse_encode_nip_60_sync_report(
+    Nip60SyncReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_nip_60_sync_status(
+    Nip60SyncStatus self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_nostr_event(NostrEvent self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_nostr_keys(NostrKeys self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_nostr_keys_with_bech_32(
+    NostrKeysWithBech32 self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_Map_String_String(
-    Map<String, String> self,
+  void sse_encode_opt_box_autoadd_cashu_dleq(
+    CashuDleq? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_Map_String_u_64(
-    Map<String, BigInt> self,
+  void sse_encode_opt_box_autoadd_cashu_witness(
+    CashuWitness? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_mint_auth_settings(
+    MintAuthSettings? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_nip_60_sync_report(
+    Nip60SyncReport? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_nostr_event(
-    NostrEvent self,
+  void sse_encode_opt_box_autoadd_proxy_config(
+    ProxyConfig? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_cashu_proof(CashuProof self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_contact_info(ContactInfo self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_unsupported_by_mint(
+    UnsupportedByMint? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_cashu_proof(
-    List<CashuProof> self,
+  void sse_encode_opt_list_contact_info(
+    List<ContactInfo>? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_contact_info(
-    List<ContactInfo> self,
+  void sse_encode_payment_method_settings(
+    PaymentMethodSettings self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_list_String(
-    List<List<String>> self,
+  void sse_encode_payment_request_info(
+    PaymentRequestInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_payment_request_transport(
+    PaymentRequestTransport self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
+  void sse_encode_proof_verification(
+    ProofVerification self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_record_string_u_64(
-    List<(String, BigInt)> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_transaction_info(
-    List<TransactionInfo> self,
+  void sse_encode_proxy_test_result(
+    ProxyTestResult self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_mint_info(MintInfo self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_nostr_event(NostrEvent self, SseSerializer serializer);
+  void sse_encode_qr_decode_progress(
+    QrDecodeProgress self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_nostr_keys(NostrKeys self, SseSerializer serializer);
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_nostr_keys_with_bech_32(
-    NostrKeysWithBech32 self,
+  void sse_encode_record_string_u_64(
+    (String, BigInt) self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_restored_mint(RestoredMint self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+  void sse_encode_restored_profile(
+    RestoredProfile self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+  void sse_encode_selected_proofs_send_options(
+    SelectedProofsSendOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_list_contact_info(
-    List<ContactInfo>? self,
+  void sse_encode_spending_allowance(
+    SpendingAllowance self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
+  void sse_encode_spending_limit(SpendingLimit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_token_verification(
+    TokenVerification self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_record_string_u_64(
-    (String, BigInt) self,
+  void sse_encode_tor_bootstrap_status(
+    TorBootstrapStatus self,
     SseSerializer serializer,
   );
 
@@ -354,6 +1377,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -366,9 +1392,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_unsupported_by_mint(
+    UnsupportedByMint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wallet_event(WalletEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wallet_handle(WalletHandle self, SseSerializer serializer);
+
   @protected
   void sse_encode_wallet_info(WalletInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_wallet_profile(WalletProfile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_socket_settings(
+    WebSocketSettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 }
@@ -386,4 +1436,38 @@ class RustLibWire implements BaseWire {
   /// The symbols are looked up in [dynamicLibrary].
   RustLibWire(ffi.DynamicLibrary dynamicLibrary)
     : _lookup = dynamicLibrary.lookup;
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoderPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_rust_plugin_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder',
+      );
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoderPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoderPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_rust_plugin_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder',
+      );
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoderPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 }
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_onion`, `network_key`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Proxy for mint connections
+class ProxyConfig {
+  final String kind;
+  final String host;
+  final int port;
+  final String? username;
+  final String? password;
+
+  const ProxyConfig({
+    required this.kind,
+    required this.host,
+    required this.port,
+    this.username,
+    this.password,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      host.hashCode ^
+      port.hashCode ^
+      username.hashCode ^
+      password.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxyConfig &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          host == other.host &&
+          port == other.port &&
+          username == other.username &&
+          password == other.password;
+}
@@ -1,27 +1,332 @@
+use flutter_rust_bridge::frb;
 use serde::{Deserialize, Serialize};
 use serde_json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use cdk::amount::Amount;
+use cdk::amount::SplitTarget;
+use cdk::nuts::nut00::token::TokenV3Token;
+use cdk::nuts::nut10::{Kind, Secret as Nut10Secret};
+use cdk::nuts::nut18::{
+    Nut10SecretRequest, PaymentRequest, PaymentRequestPayload, Transport, TransportType,
+};
 use cdk::nuts::{
-    Conditions, CurrencyUnit, Id, Proof, PublicKey, SecretKey, SigFlag, SpendingConditions, Token,
+    Conditions, CurrencyUnit, HTLCWitness, Id, KeySet, KeySetInfo, MeltOptions, MintQuoteState,
+    P2PKWitness, PaymentMethod, Proof, ProofDleq, PublicKey, SecretKey, SigFlag,
+    SpendingConditions, State, Token, TokenV3, TokenV4, Witness,
 };
 use cdk::secret::Secret;
-use cdk::wallet::{MultiMintSendOptions, MultiMintWallet, ReceiveOptions, SendOptions, Wallet};
+use cdk::wallet::{
+    HttpClient, MintConnector, MultiMintSendOptions, MultiMintWallet, ReceiveOptions, SendOptions,
+    Wallet, WalletConfig,
+};
 // Note: Old Tor configuration API (TorPolicy, TorConfig, set_tor_config, get_tor_config)
 // has been removed. New implementation uses WalletBuilder::use_tor() instead.
 use bip39::{Language, Mnemonic};
 use cdk::mint_url::MintUrl;
-use cdk::wallet::types::TransactionDirection;
+use cdk::types::ProofInfo;
+use cdk::wallet::types::{Transaction, TransactionDirection};
 use cdk_sqlite::WalletSqliteDatabase;
 use rand::random;
 use std::path::PathBuf;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use url::Url;
+
+use crate::api::events::{emit_wallet_event, WalletEvent};
+use crate::frb_generated::StreamSink;
+use crate::mint_cache::MintCache;
+pub use crate::network::ProxyConfig;
+use crate::network::{network_key, NetworkSettings, StoredProxy, TorPolicy};
+use crate::operations::{OperationGuard, WalletOperations};
+
+/// Profile used by the deprecated single-wallet APIs, stored directly in the wallet directory
+const DEFAULT_PROFILE_ID: &str = "default";
+
+/// Open wallet profiles, keyed by profile id
+static OPEN_WALLETS: RwLock<BTreeMap<String, OpenWallet>> = RwLock::const_new(BTreeMap::new());
+
+/// Per-profile locks held while a profile is opened, shut down or its files change
+/// OPEN_WALLETS itself is only locked briefly, so other profiles keep working meanwhile.
+static PROFILE_LOCKS: std::sync::Mutex<BTreeMap<String, Arc<tokio::sync::Mutex<()>>>> =
+    std::sync::Mutex::new(BTreeMap::new());
+
+async fn lock_profile(profile_id: &str) -> tokio::sync::OwnedMutexGuard<()> {
+    let lock = PROFILE_LOCKS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(profile_id.to_string())
+        .or_default()
+        .clone();
+    lock.lock_owned().await
+}
+
+struct OpenWallet {
+    wallet: Arc<MultiMintWallet>,
+    profile_dir: PathBuf,
+    operations: Arc<WalletOperations>,
+    mint_cache: Arc<MintCache>,
+    // Prefer local data and refuse calls that need the network
+    offline: Arc<AtomicBool>,
+    // Policy the wallet was opened with, changes apply on the next open
+    tor_policy: TorPolicy,
+    // Seals secrets such as proxy passwords in the network settings file
+    network_key: [u8; 32],
+    // Held while coin control reads and reserves proofs
+    proof_selection: Arc<tokio::sync::Mutex<()>>,
+}
+
+/// How long shutdown waits for running sends and melts by default
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Handle to an open wallet profile, passed to every wallet API
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletHandle {
+    pub profile_id: String,
+}
+
+/// Wallet profile found on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletProfile {
+    pub profile_id: String,
+    pub profile_dir: String,
+    pub is_open: bool,
+    pub is_encrypted: bool,
+}
+
+/// Send or melt that had not finished when the wallet was shut down or the app died
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterruptedOperation {
+    pub id: String,
+    // "send", "p2pk_send", "melt" or "bolt12_melt"
+    pub kind: String,
+    pub mint_url: String,
+    pub amount: u64,
+    pub started_at: u64,
+}
+
+/// Get the wallet behind a handle
+pub(crate) async fn get_multi_mint_wallet(
+    handle: &WalletHandle,
+) -> Result<Arc<MultiMintWallet>, String> {
+    with_open_wallet(handle, |open| open.wallet.clone()).await
+}
+
+/// Get the directory of the profile behind a handle
+pub(crate) async fn get_profile_dir_for_handle(handle: &WalletHandle) -> Result<PathBuf, String> {
+    with_open_wallet(handle, |open| open.profile_dir.clone()).await
+}
+
+/// Get the wallet behind a handle for an operation that shutdown has to wait for
+/// The operation ends when the returned guard is dropped.
+pub(crate) async fn begin_wallet_operation(
+    handle: &WalletHandle,
+) -> Result<(Arc<MultiMintWallet>, OperationGuard), String> {
+    let (wallet, operations) = with_open_wallet(handle, |open| {
+        (open.wallet.clone(), open.operations.clone())
+    })
+    .await?;
+    let operation = operations.begin().await?;
+    Ok((wallet, operation))
+}
+
+/// Read from an open profile's state
+async fn with_open_wallet<T>(
+    handle: &WalletHandle,
+    f: impl FnOnce(&OpenWallet) -> T,
+) -> Result<T, String> {
+    OPEN_WALLETS
+        .read()
+        .await
+        .get(&handle.profile_id)
+        .map(f)
+        .ok_or_else(|| format!("Wallet profile '{}' is not open", handle.profile_id))
+}
+
+/// Fail early for calls that cannot work without the network
+async fn ensure_online(handle: &WalletHandle) -> Result<(), String> {
+    if with_open_wallet(handle, |open| open.offline.load(Ordering::Relaxed)).await? {
+        return Err("Wallet is in offline mode".to_string());
+    }
+    Ok(())
+}
+
+/// Refuse mints the Tor policy cannot reach
+/// Returns the connector for the mint's transport, None for a direct connection.
+async fn mint_connection(
+    handle: &WalletHandle,
+    mint_url: &MintUrl,
+) -> Result<Option<Arc<dyn MintConnector + Send + Sync>>, String> {
+    let (tor_policy, profile_dir, network_key) = with_open_wallet(handle, |open| {
+        (open.tor_policy, open.profile_dir.clone(), open.network_key)
+    })
+    .await?;
+    let settings = NetworkSettings::load(&profile_dir)?;
+    if settings.proxy_for(mint_url).is_none() {
+        tor_policy.check_mint(mint_url)?;
+    }
+    mint_connector(
+        &handle.profile_id,
+        &profile_dir,
+        &settings,
+        &network_key,
+        mint_url,
+        tor_policy.uses_tor(mint_url),
+    )
+}
+
+/// Like mint_connection, as the config for adding the mint to the wallet
+/// None keeps the wallet's default transport.
+async fn prepare_mint_connection(
+    handle: &WalletHandle,
+    mint_url: &MintUrl,
+) -> Result<Option<WalletConfig>, String> {
+    Ok(mint_connection(handle, mint_url)
+        .await?
+        .map(|connector| WalletConfig::new().with_mint_connector(connector)))
+}
+
+/// Transport for a mint: its proxy, the profile's Tor client, or None for a direct
+/// connection
+/// A proxied mint never uses the embedded Tor. Over Tor, a mint gets circuits of its
+/// own when isolation is on and shares them with the other mints otherwise.
+fn mint_connector(
+    profile_id: &str,
+    profile_dir: &std::path::Path,
+    settings: &NetworkSettings,
+    network_key: &[u8; 32],
+    mint_url: &MintUrl,
+    uses_tor: bool,
+) -> Result<Option<Arc<dyn MintConnector + Send + Sync>>, String> {
+    if let Some(proxy) = settings.proxy_for(mint_url) {
+        return proxied_connector(mint_url, proxy.to_url(network_key)?).map(Some);
+    }
+    #[cfg(feature = "tor")]
+    if uses_tor {
+        let isolated_mint = settings.tor_isolation.then_some(mint_url);
+        let proxy = crate::tor::mint_proxy_url(profile_id, profile_dir, isolated_mint)?;
+        return proxied_connector(mint_url, proxy).map(Some);
+    }
+    #[cfg(not(feature = "tor"))]
+    let _ = (profile_id, profile_dir, uses_tor);
+    Ok(None)
+}
+
+/// mint_connector as the config of a mint in the wallet
+fn mint_wallet_config(
+    profile_id: &str,
+    profile_dir: &std::path::Path,
+    settings: &NetworkSettings,
+    network_key: &[u8; 32],
+    mint_url: &MintUrl,
+    uses_tor: bool,
+) -> Result<Option<WalletConfig>, String> {
+    Ok(mint_connector(
+        profile_id,
+        profile_dir,
+        settings,
+        network_key,
+        mint_url,
+        uses_tor,
+    )?
+    .map(|connector| WalletConfig::new().with_mint_connector(connector)))
+}
+
+/// Client that sends every request for the mint through the proxy
+fn proxied_connector(
+    mint_url: &MintUrl,
+    proxy: Url,
+) -> Result<Arc<dyn MintConnector + Send + Sync>, String> {
+    let client = HttpClient::with_proxy(mint_url.clone(), proxy, None, false)
+        .map_err(|e| format!("Failed to create proxied mint client: {}", e))?;
+    Ok(Arc::new(client))
+}
+
+/// HTTP client for a service that is not a mint, such as an LNURL server
+/// Follows the mint transport rules: the wallet proxy if one is set, otherwise Tor when
+/// the policy sends the service's host through it, with circuits of its own when
+/// isolation is on.
+pub(crate) async fn service_http_client(
+    handle: &WalletHandle,
+    url: &Url,
+) -> Result<reqwest::Client, String> {
+    let (tor_policy, profile_dir, network_key) = with_open_wallet(handle, |open| {
+        (open.tor_policy, open.profile_dir.clone(), open.network_key)
+    })
+    .await?;
+    let settings = NetworkSettings::load(&profile_dir)?;
+    // The policy rules are written for mints, apply them to the service's origin
+    let service = MintUrl::from_str(&url.origin().ascii_serialization())
+        .map_err(|e| format!("Invalid service URL {}: {}", url, e))?;
+
+    let mut proxy = None;
+    if let Some(stored) = settings.proxy.as_ref() {
+        proxy = Some(stored.to_url(&network_key)?);
+    } else {
+        tor_policy.check_mint(&service)?;
+        #[cfg(feature = "tor")]
+        if tor_policy.uses_tor(&service) {
+            let isolated_service = settings.tor_isolation.then_some(&service);
+            proxy = Some(crate::tor::mint_proxy_url(
+                &handle.profile_id,
+                &profile_dir,
+                isolated_service,
+            )?);
+        }
+    }
+
+    let mut builder = reqwest::Client::builder();
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(
+            reqwest::Proxy::all(proxy.as_str()).map_err(|e| format!("Invalid proxy: {}", e))?,
+        );
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
+/// Add a mint to the wallet with the transport from prepare_mint_connection
+async fn register_mint(
+    multi_mint_wallet: &MultiMintWallet,
+    mint_url: &MintUrl,
+    config: Option<WalletConfig>,
+) -> Result<(), String> {
+    match config {
+        Some(config) => {
+            multi_mint_wallet
+                .add_mint_with_config(mint_url.clone(), config)
+                .await
+        }
+        None => multi_mint_wallet.add_mint(mint_url.clone()).await,
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// Wallet for a mint found outside the wallet (a synced wallet event), added if missing
+pub(crate) async fn ensure_mint(
+    handle: &WalletHandle,
+    mint_url: &MintUrl,
+) -> Result<Wallet, String> {
+    let config = prepare_mint_connection(handle, mint_url).await?;
+    let multi_mint_wallet = get_multi_mint_wallet(handle).await?;
+    if !multi_mint_wallet.has_mint(mint_url).await {
+        register_mint(&multi_mint_wallet, mint_url, config)
+            .await
+            .map_err(|e| format!("Failed to add mint {}: {}", mint_url, e))?;
+    }
+    multi_mint_wallet
+        .get_wallet(mint_url)
+        .await
+        .ok_or_else(|| "Failed to get wallet".to_string())
+}
 
-/// Global MultiMintWallet instance
-static MULTI_MINT_WALLET: RwLock<Option<Arc<MultiMintWallet>>> = RwLock::const_new(None);
+/// How long to wait for an LNURL-withdraw service to pay our invoice
+const LNURL_WITHDRAW_TIMEOUT: Duration = Duration::from_secs(120);
+const LNURL_WITHDRAW_POLL_INTERVAL: Duration = Duration::from_secs(2);
 
 // Tor is automatically used for .onion addresses when tor feature is enabled.
 
@@ -43,13 +348,50 @@ fn get_database_path(base_dir: &str, mint_url: &str) -> PathBuf {
     PathBuf::from(base_dir).join("wallet_data").join(db_name)
 }
 
-/// Cashu proof structure for FFI
+/// Cashu proof structure for FFI, converts back to the exact CDK proof
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CashuProof {
     pub id: String,
     pub amount: u64,
-    pub secret: String,
+    pub secret: CashuSecret,
     pub c: String,
+    pub witness: Option<CashuWitness>,
+    pub dleq: Option<CashuDleq>,
+    // Y = hash_to_curve(secret), identifies the proof towards the mint
+    pub y: String,
+    // unspent, pending, reserved, spent or pending_spent, None when unknown
+    pub state: Option<String>,
+    // Set aside for a send that has not finished yet
+    pub reserved: bool,
+}
+
+/// Proof secret, either a plain random string or a NUT-10 well-known secret
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashuSecret {
+    // The secret exactly as signed by the mint, used when converting back
+    pub raw: String,
+    // "P2PK" or "HTLC" for NUT-10 secrets, None for plain secrets
+    pub kind: Option<String>,
+    pub nonce: Option<String>,
+    // Public key for P2PK, payment hash for HTLC
+    pub data: Option<String>,
+    pub tags: Vec<Vec<String>>,
+}
+
+/// Witness unlocking a NUT-10 secret
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashuWitness {
+    // Set for HTLC witnesses only
+    pub preimage: Option<String>,
+    pub signatures: Option<Vec<String>>,
+}
+
+/// NUT-12 DLEQ proof, hex encoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashuDleq {
+    pub e: String,
+    pub s: String,
+    pub r: String,
 }
 
 /// Wallet information structure
@@ -59,6 +401,9 @@ pub struct WalletInfo {
     pub unit: String,
     pub balance: u64,
     pub active_keyset_id: String,
+    // When the mint's keysets were last fetched ("stale since" in the UI), None if unknown
+    pub fetched_at: Option<u64>,
+    pub is_stale: bool,
 }
 
 /// Transaction information structure
@@ -97,6 +442,243 @@ pub struct MintInfo {
     pub nuts: Option<Vec<String>>,
     pub public_key: Option<String>,
     pub additional_info: Option<String>,
+    pub tos_url: Option<String>,
+    // Mint's clock when the info was served, unix seconds
+    pub time: Option<u64>,
+    pub settings: MintSettings,
+    // When this info was fetched from the mint ("stale since" in the UI), None if unknown
+    pub fetched_at: Option<u64>,
+    // Older than the mint's NUT-19 TTL, a background refresh has been started
+    pub is_stale: bool,
+}
+
+/// Decoded BOLT12 offer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bolt12OfferInfo {
+    pub offer_id: String,
+    pub amount_msat: Option<u64>,
+    // Set when the offer is denominated in a fiat currency instead of bitcoin
+    pub currency: Option<String>,
+    pub currency_amount: Option<u64>,
+    pub description: Option<String>,
+    pub issuer: Option<String>,
+    pub issuer_pubkey: Option<String>,
+    pub absolute_expiry: Option<u64>,
+    pub chains: Vec<String>,
+    pub is_expired: bool,
+}
+
+/// Decoded BOLT12 invoice request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bolt12InvoiceRequestInfo {
+    pub payer_pubkey: String,
+    pub amount_msat: Option<u64>,
+    pub quantity: Option<u64>,
+    pub payer_note: Option<String>,
+    pub chain: String,
+    pub offer_description: Option<String>,
+    pub offer_issuer: Option<String>,
+}
+
+/// Resolved LNURL-pay request (LUD-06 / LUD-16)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LnurlPayInfo {
+    pub callback: String,
+    pub min_sendable_sats: u64,
+    pub max_sendable_sats: u64,
+    pub comment_allowed: u64,
+    pub description: Option<String>,
+    pub metadata: String,
+    pub domain: String,
+}
+
+/// NUT-18 payment request transport
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRequestTransport {
+    // "nostr" or "post"
+    pub transport_type: String,
+    // nprofile for nostr, URL for post
+    pub target: String,
+    pub tags: Vec<Vec<String>>,
+}
+
+/// Decoded NUT-18 payment request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRequestInfo {
+    pub payment_id: Option<String>,
+    pub amount: Option<u64>,
+    pub unit: Option<String>,
+    pub single_use: Option<bool>,
+    pub mints: Vec<String>,
+    pub description: Option<String>,
+    pub transports: Vec<PaymentRequestTransport>,
+    // NUT-10 lock requested by the receiver, e.g. kind "P2PK" with the pubkey as data
+    pub lock_kind: Option<String>,
+    pub lock_data: Option<String>,
+}
+
+/// Encoded token along with what callers need to pick a QR strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncodedToken {
+    pub token: String,
+    // "v3" (cashuA) or "v4" (cashuB)
+    pub encoding: String,
+    pub byte_size: u64,
+    pub amount: u64,
+    pub proofs_count: u64,
+    pub mint_urls: Vec<String>,
+}
+
+/// How send_selected_proofs turns the chosen proofs into a token
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SelectedProofsSendOptions {
+    // Swap the chosen proofs at the mint first, otherwise they go out exactly as they are
+    pub swap: bool,
+    // Amount to send when swapping, the rest comes back as change
+    // Defaults to the proofs' total minus the mint's input fee.
+    pub amount: Option<u64>,
+    pub memo: Option<String>,
+}
+
+/// Offline verification result for one proof
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofVerification {
+    pub y: String,
+    pub amount: u64,
+    pub keyset_id: String,
+    // None when the proof carries no DLEQ proof
+    pub dleq_valid: Option<bool>,
+    pub secret_valid: bool,
+    pub error: Option<String>,
+}
+
+/// Offline verification of a token or proofs against the mint's keysets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenVerification {
+    pub mint_url: String,
+    pub amount: u64,
+    // Every keyset id matches the id derived from its keys
+    pub keysets_valid: bool,
+    // Keys were not cached and had to be fetched from the mint
+    pub fetched_keys: bool,
+    // Keysets match, every proof has a valid DLEQ proof and a well-formed secret
+    pub valid: bool,
+    pub proofs: Vec<ProofVerification>,
+}
+
+/// What happened to one mint's proofs during a backup import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedMint {
+    pub mint_url: String,
+    pub proofs_found: u64,
+    pub amount_found: u64,
+    // Already spent or pending at the mint, skipped
+    pub spent_amount: u64,
+    // Received into this wallet after swapping
+    pub imported_amount: u64,
+    pub error: Option<String>,
+}
+
+/// Deterministic secret counter of a keyset in the imported wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeysetCounter {
+    pub keyset_id: String,
+    pub counter: u32,
+}
+
+/// Report of a backup import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportReport {
+    // "tokens", "proofs", "nutshell" or "cashu_me"
+    pub format: String,
+    pub mints: Vec<ImportedMint>,
+    pub imported_amount: u64,
+    // Proofs the backup did not say the mint of, not imported
+    pub unassigned_proofs: u64,
+    // The backup carries the other wallet's mnemonic, restore_backup_profile recovers
+    // what the proofs in the backup do not cover
+    pub has_mnemonic: bool,
+    pub keyset_counters: Vec<KeysetCounter>,
+}
+
+/// What NUT-09 restore recovered from one mint of a backup's seed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoredMint {
+    pub mint_url: String,
+    pub restored_amount: u64,
+    pub error: Option<String>,
+}
+
+/// Profile opened with the seed of another wallet's backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoredProfile {
+    pub handle: WalletHandle,
+    // The app stores it like any other seed, the profile cannot be opened without it
+    pub mnemonic: String,
+    pub mints: Vec<RestoredMint>,
+    pub restored_amount: u64,
+    pub keyset_counters: Vec<KeysetCounter>,
+}
+
+/// NUT-04 / NUT-05 settings for one payment method and unit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMethodSettings {
+    // e.g. "bolt11", "bolt12"
+    pub method: String,
+    pub unit: String,
+    pub min_amount: Option<u64>,
+    pub max_amount: Option<u64>,
+    // NUT-04: the mint accepts a description for the invoice
+    pub description: bool,
+    // NUT-05: the mint can pay invoices without an amount
+    pub amountless: bool,
+}
+
+/// NUT-17 WebSocket subscriptions supported for a method and unit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebSocketSettings {
+    pub method: String,
+    pub unit: String,
+    // e.g. "bolt11_mint_quote", "proof_state"
+    pub commands: Vec<String>,
+}
+
+/// NUT-19 endpoint whose responses the mint caches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedEndpoint {
+    pub method: String,
+    pub path: String,
+}
+
+/// NUT-21 / NUT-22 authentication the mint requires
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MintAuthSettings {
+    // NUT-21 clear auth
+    pub openid_discovery: Option<String>,
+    pub client_id: Option<String>,
+    // "METHOD /path" of endpoints that need a clear auth token
+    pub clear_auth_endpoints: Vec<String>,
+    // NUT-22 blind auth
+    pub blind_auth_max_mint: Option<u64>,
+    pub blind_auth_endpoints: Vec<String>,
+}
+
+/// Typed NUT-06 settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MintSettings {
+    pub mint_methods: Vec<PaymentMethodSettings>,
+    pub minting_disabled: bool,
+    pub melt_methods: Vec<PaymentMethodSettings>,
+    pub melting_disabled: bool,
+    // NUT numbers the mint supports, e.g. [4, 5, 7, 12]
+    pub supported_nuts: Vec<u32>,
+    // NUT-15 multi-path payments, as PaymentMethodSettings without limits
+    pub mpp_methods: Vec<PaymentMethodSettings>,
+    pub websocket: Vec<WebSocketSettings>,
+    pub cache_ttl: Option<u64>,
+    pub cached_endpoints: Vec<CachedEndpoint>,
+    // None when the mint needs no authentication
+    pub auth: Option<MintAuthSettings>,
 }
 
 /// Contact information structure
@@ -106,14 +688,94 @@ pub struct ContactInfo {
     pub info: String,
 }
 
-/// Convert CDK Proof to CashuProof
+impl From<&Secret> for CashuSecret {
+    fn from(secret: &Secret) -> Self {
+        match Nut10Secret::try_from(secret) {
+            Ok(nut10) => {
+                let secret_data = nut10.secret_data();
+                Self {
+                    raw: secret.to_string(),
+                    kind: Some(
+                        match nut10.kind() {
+                            Kind::P2PK => "P2PK",
+                            Kind::HTLC => "HTLC",
+                        }
+                        .to_string(),
+                    ),
+                    nonce: Some(secret_data.nonce().to_string()),
+                    data: Some(secret_data.data().to_string()),
+                    tags: secret_data.tags().cloned().unwrap_or_default(),
+                }
+            }
+            Err(_) => Self {
+                raw: secret.to_string(),
+                kind: None,
+                nonce: None,
+                data: None,
+                tags: Vec::new(),
+            },
+        }
+    }
+}
+
+impl From<Witness> for CashuWitness {
+    fn from(witness: Witness) -> Self {
+        match witness {
+            Witness::P2PKWitness(witness) => Self {
+                preimage: None,
+                signatures: Some(witness.signatures),
+            },
+            Witness::HTLCWitness(witness) => Self {
+                preimage: Some(witness.preimage),
+                signatures: witness.signatures,
+            },
+        }
+    }
+}
+
+impl From<CashuWitness> for Witness {
+    fn from(witness: CashuWitness) -> Self {
+        match witness.preimage {
+            Some(preimage) => Witness::HTLCWitness(HTLCWitness {
+                preimage,
+                signatures: witness.signatures,
+            }),
+            None => Witness::P2PKWitness(P2PKWitness {
+                signatures: witness.signatures.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+/// Convert CDK Proof to CashuProof, state is unknown without the wallet database
 impl From<Proof> for CashuProof {
     fn from(proof: Proof) -> Self {
         Self {
             id: proof.keyset_id.to_string(),
             amount: proof.amount.into(),
-            secret: proof.secret.to_string(),
+            secret: CashuSecret::from(&proof.secret),
             c: proof.c.to_string(),
+            y: proof.y().map(|y| y.to_hex()).unwrap_or_default(),
+            witness: proof.witness.map(CashuWitness::from),
+            dleq: proof.dleq.map(|dleq| CashuDleq {
+                e: dleq.e.to_secret_hex(),
+                s: dleq.s.to_secret_hex(),
+                r: dleq.r.to_secret_hex(),
+            }),
+            state: None,
+            reserved: false,
+        }
+    }
+}
+
+/// Convert a stored proof to CashuProof, including its state
+impl From<ProofInfo> for CashuProof {
+    fn from(info: ProofInfo) -> Self {
+        let state = info.state;
+        Self {
+            state: Some(state.to_string().to_lowercase()),
+            reserved: state == State::Reserved,
+            ..info.proof.into()
         }
     }
 }
@@ -125,128 +787,564 @@ impl TryFrom<CashuProof> for Proof {
     fn try_from(cashu_proof: CashuProof) -> Result<Self, Self::Error> {
         let id = Id::from_str(&cashu_proof.id).map_err(|e| format!("Invalid proof ID: {}", e))?;
         let amount = Amount::from(cashu_proof.amount);
-        let secret_key = SecretKey::from_str(&cashu_proof.secret)
-            .map_err(|e| format!("Invalid secret: {}", e))?;
-        let secret = Secret::new(secret_key.to_secret_hex());
+        // The raw secret is what the mint signed, the structured fields are derived from it
+        if cashu_proof.secret.raw.is_empty() {
+            return Err("Invalid secret: empty".to_string());
+        }
+        let secret = Secret::new(cashu_proof.secret.raw);
         let c = PublicKey::from_str(&cashu_proof.c).map_err(|e| format!("Invalid C: {}", e))?;
+        let dleq = match cashu_proof.dleq {
+            Some(dleq) => {
+                let key = |hex: &str| {
+                    SecretKey::from_str(hex).map_err(|e| format!("Invalid DLEQ: {}", e))
+                };
+                Some(ProofDleq::new(key(&dleq.e)?, key(&dleq.s)?, key(&dleq.r)?))
+            }
+            None => None,
+        };
 
         Ok(Proof {
             keyset_id: id,
             amount,
             secret,
             c,
-            witness: None,
-            dleq: None,
+            witness: cashu_proof.witness.map(Witness::from),
+            dleq,
         })
     }
 }
 
-/// Initialize MultiMintWallet
-pub async fn init_multi_mint_wallet(
+/// Profile ids become directory names, so keep them to a safe character set
+fn validate_profile_id(profile_id: &str) -> Result<(), String> {
+    if profile_id.is_empty() || profile_id.len() > 64 {
+        return Err("Profile id must be 1 to 64 characters".to_string());
+    }
+    if !profile_id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Profile id may only contain letters, digits, '-' and '_'".to_string());
+    }
+    Ok(())
+}
+
+/// Directory holding a profile's database, spending policy and Tor state
+/// The default profile keeps the layout of the single-wallet releases.
+fn get_profile_dir(database_dir: &str, profile_id: &str) -> PathBuf {
+    if profile_id == DEFAULT_PROFILE_ID {
+        PathBuf::from(database_dir)
+    } else {
+        PathBuf::from(database_dir)
+            .join("profiles")
+            .join(profile_id)
+    }
+}
+
+/// Open a wallet profile and return its handle
+/// Every profile has its own database, spending policy and Tor state under database_dir.
+/// Opening a profile that is already open returns the existing handle.
+pub async fn open_wallet(
+    profile_id: String,
     database_dir: String,
     seed_hex: String,
-) -> Result<String, String> {
-    let mut wallet_guard = MULTI_MINT_WALLET.write().await;
+) -> Result<WalletHandle, String> {
+    open_wallet_with_database_key(profile_id, database_dir, seed_hex, None).await
+}
 
-    if wallet_guard.is_some() {
-        return Ok("MultiMintWallet already initialized".to_string());
-    }
+/// Open a wallet profile with an encrypted database
+/// key_material comes from the user's PIN or the OS keystore. An existing plaintext
+/// database is encrypted in place on first use.
+#[cfg(feature = "sqlcipher")]
+pub async fn open_encrypted_wallet(
+    profile_id: String,
+    database_dir: String,
+    seed_hex: String,
+    key_material: String,
+) -> Result<WalletHandle, String> {
+    open_wallet_with_database_key(profile_id, database_dir, seed_hex, Some(key_material)).await
+}
 
-    // Set Tor data directories to use the same base directory as the wallet database
-    // This ensures Tor data is stored in the app's data directory, not system temp
-    let tor_cache_dir = PathBuf::from(&database_dir).join("tor_cache");
-    let tor_data_dir = PathBuf::from(&database_dir).join("tor_data");
+/// Close a wallet profile, the handle stops working until the profile is opened again
+pub async fn close_wallet(handle: WalletHandle) -> Result<(), String> {
+    shutdown_wallet(handle, None).await.map(|_| ())
+}
 
-    // Create Tor directories if they don't exist
-    std::fs::create_dir_all(&tor_cache_dir)
-        .map_err(|e| format!("Failed to create Tor cache directory: {}", e))?;
-    std::fs::create_dir_all(&tor_data_dir)
-        .map_err(|e| format!("Failed to create Tor data directory: {}", e))?;
+/// Shut a wallet profile down cleanly
+/// New operations are refused, background tasks such as LNURL-withdraw polling are
+/// cancelled and running sends and melts get up to timeout_secs (default 30) to finish.
+/// Sends and melts still running after that are returned; they stay in the operation
+/// journal and show up in get_interrupted_operations the next time the profile is opened.
+/// The SQLite store and any Tor client are released once the last task lets go.
+pub async fn shutdown_wallet(
+    handle: WalletHandle,
+    timeout_secs: Option<u64>,
+) -> Result<Vec<InterruptedOperation>, String> {
+    let timeout = timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT);
+    let _profile_lock = lock_profile(&handle.profile_id).await;
+    shutdown_profile(&handle.profile_id, timeout)
+        .await?
+        .ok_or_else(|| format!("Wallet profile '{}' is not open", handle.profile_id))
+}
 
-    // Set environment variables for Tor configuration
-    // These will be used by TorAsync when initializing TorClientConfig
-    let cache_path = tor_cache_dir.to_string_lossy().to_string();
-    let data_path = tor_data_dir.to_string_lossy().to_string();
-    std::env::set_var("ARTI_CACHE", &cache_path);
-    std::env::set_var("ARTI_LOCAL_DATA", &data_path);
+/// Remove a profile from the registry and wait for its operations
+/// Returns None if the profile was not open. Callers hold the profile lock.
+async fn shutdown_profile(
+    profile_id: &str,
+    timeout: Duration,
+) -> Result<Option<Vec<InterruptedOperation>>, String> {
+    // Removing the profile first makes every new call fail with "not open"
+    let Some(open) = OPEN_WALLETS.write().await.remove(profile_id) else {
+        return Ok(None);
+    };
 
-    // Parse seed from hex string
-    let seed = parse_seed_from_hex(&seed_hex)?;
-    let db_path = PathBuf::from(&database_dir).join("multi_mint_wallet.db");
+    let still_running = open.operations.shutdown(timeout).await;
+    crate::api::limits::unload_spending_policy(profile_id).await;
+    #[cfg(feature = "tor")]
+    crate::tor::stop(profile_id);
+    #[cfg(feature = "daemon")]
+    crate::daemon::stop(profile_id);
+    crate::nip60::stop(profile_id);
 
-    std::fs::create_dir_all(db_path.parent().unwrap())
-        .map_err(|e| format!("Failed to create database directory: {}", e))?;
+    // Last reference closes the SQLite pool and drops the wallet's Tor transport
+    drop(open);
 
-    let localstore = WalletSqliteDatabase::new(db_path.to_str().unwrap())
-        .await
-        .map_err(|e| format!("Failed to create SQLite store: {}", e))?;
+    Ok(Some(still_running))
+}
 
-    // Convert seed to [u8; 64] by extending if needed
-    let seed_64: [u8; 64] = if seed.len() == 32 {
-        // Extend 32-byte seed to 64 bytes by repeating
-        let mut extended = [0u8; 64];
-        extended[..32].copy_from_slice(&seed);
-        extended[32..].copy_from_slice(&seed);
-        extended
-    } else if seed.len() == 64 {
-        let mut seed_array = [0u8; 64];
-        seed_array.copy_from_slice(&seed);
-        seed_array
-    } else {
-        return Err(format!("Seed must be 32 or 64 bytes, got {}", seed.len()));
-    };
+/// Sends and melts from earlier sessions that never finished
+/// The proofs involved may be reserved or spent, check_melt_quote_status and
+/// restore_mint recover them.
+pub async fn get_interrupted_operations(
+    handle: WalletHandle,
+) -> Result<Vec<InterruptedOperation>, String> {
+    with_open_wallet(&handle, |open| open.operations.interrupted()).await
+}
 
-    // Create MultiMintWallet without shared Tor transport
-    // Tor will be automatically used only for .onion addresses when adding mints
-    // This allows regular HTTPS URLs to use direct connections (faster and more reliable)
-    // Note: MultiMintWallet now supports only one currency unit per instance
-    let multi_mint_wallet = MultiMintWallet::new(
-        Arc::new(localstore),
-        seed_64,
-        CurrencyUnit::Sat, // Default to Sat unit
-    )
+/// Forget interrupted operations once the app has dealt with them
+pub async fn clear_interrupted_operations(handle: WalletHandle) -> Result<(), String> {
+    with_open_wallet(&handle, |open| open.operations.clear_interrupted()).await?
+}
+
+/// Switch offline mode for a profile
+/// While offline, mint info and keysets are served from the cache only and calls
+/// that need the mint (receiving, minting, melting, quote checks) fail right away.
+/// Sends that do not need a swap keep working.
+pub async fn set_offline_mode(handle: WalletHandle, offline: bool) -> Result<(), String> {
+    with_open_wallet(&handle, |open| {
+        open.offline.store(offline, Ordering::Relaxed)
+    })
     .await
-    .map_err(|e| format!("Failed to create MultiMintWallet: {}", e))?;
+}
 
-    *wallet_guard = Some(Arc::new(multi_mint_wallet));
+pub async fn is_offline_mode(handle: WalletHandle) -> Result<bool, String> {
+    with_open_wallet(&handle, |open| open.offline.load(Ordering::Relaxed)).await
+}
 
-    Ok("MultiMintWallet initialized successfully".to_string())
+/// Refuse to go on while sends or melts outlived a shutdown, unless forced
+/// Their proofs would be lost with the database, so the error lists them.
+pub(crate) fn ensure_no_running_operations(
+    still_running: Option<Vec<InterruptedOperation>>,
+    force: bool,
+) -> Result<(), String> {
+    let still_running = still_running.unwrap_or_default();
+    if force || still_running.is_empty() {
+        return Ok(());
+    }
+    let operations: Vec<String> = still_running
+        .iter()
+        .map(|op| {
+            format!(
+                "{} of {} sat at {} ({})",
+                op.kind, op.amount, op.mint_url, op.id
+            )
+        })
+        .collect();
+    Err(format!(
+        "Wallet operations are still running: {}",
+        operations.join(", ")
+    ))
 }
 
-/// Parse seed from hex string (returns Vec<u8> to support both 32 and 64 byte seeds)
-fn parse_seed_from_hex(seed_hex: &str) -> Result<Vec<u8>, String> {
-    // Accept both 64-byte (128 hex chars) and 32-byte (64 hex chars) seeds
-    let expected_lengths = [64, 128]; // 32 bytes or 64 bytes
-    if !expected_lengths.contains(&seed_hex.len()) {
-        return Err(format!(
-            "Seed must be 64 or 128 hex characters (32 or 64 bytes), got {}",
-            seed_hex.len()
-        ));
+/// Delete a wallet profile from disk, shutting it down first if it is open
+/// This destroys the ecash in it, the app must confirm with the user first.
+/// Sends and melts still running after the shutdown make this fail unless force is set.
+/// For the default profile only the wallet files are removed, as its directory
+/// also holds the other profiles.
+pub async fn delete_wallet(
+    profile_id: String,
+    database_dir: String,
+    force: bool,
+) -> Result<(), String> {
+    validate_profile_id(&profile_id)?;
+    let _profile_lock = lock_profile(&profile_id).await;
+    let still_running = shutdown_profile(&profile_id, DEFAULT_SHUTDOWN_TIMEOUT).await?;
+    ensure_no_running_operations(still_running, force)?;
+
+    let profile_dir = get_profile_dir(&database_dir, &profile_id);
+    if profile_id != DEFAULT_PROFILE_ID {
+        if profile_dir.exists() {
+            std::fs::remove_dir_all(&profile_dir)
+                .map_err(|e| format!("Failed to delete wallet profile: {}", e))?;
+        }
+        return Ok(());
     }
 
-    // Parse hex string to bytes
-    let seed_bytes = hex::decode(seed_hex).map_err(|e| format!("Invalid hex string: {}", e))?;
+    for file in [
+        "multi_mint_wallet.db",
+        "multi_mint_wallet.db-wal",
+        "multi_mint_wallet.db-shm",
+        "multi_mint_wallet.db-journal",
+        "multi_mint_wallet.salt",
+        "multi_mint_wallet.salt.new",
+        "spending_policy.json",
+        "operation_journal.json",
+        "mint_cache.json",
+        "network_settings.json",
+        "daemon_clients.json",
+        "nip60_sync.json",
+    ] {
+        let path = profile_dir.join(file);
+        if path.exists() {
+            std::fs::remove_file(&path).map_err(|e| format!("Failed to delete {}: {}", file, e))?;
+        }
+    }
+    for dir in ["tor_cache", "tor_data"] {
+        let path = profile_dir.join(dir);
+        if path.exists() {
+            std::fs::remove_dir_all(&path)
+                .map_err(|e| format!("Failed to delete {}: {}", dir, e))?;
+        }
+    }
 
-    Ok(seed_bytes)
+    Ok(())
 }
 
-/// Load existing wallets from database
-/// Note: This function is no longer needed as MultiMintWallet automatically loads wallets
-async fn load_wallets_from_database(
-    _localstore: &WalletSqliteDatabase,
-    _seed: &[u8],
-) -> Result<Vec<Wallet>, String> {
-    // MultiMintWallet now automatically loads wallets from database in its constructor
-    // This function is kept for compatibility but returns empty vector
-    Ok(Vec::new())
-}
+/// List the wallet profiles stored under database_dir
+pub async fn list_profiles(database_dir: String) -> Result<Vec<WalletProfile>, String> {
+    let mut profile_ids = Vec::new();
+    if get_multi_mint_database_path(&get_profile_dir(&database_dir, DEFAULT_PROFILE_ID)).exists() {
+        profile_ids.push(DEFAULT_PROFILE_ID.to_string());
+    }
 
-/// Add a mint to MultiMintWallet - defaults to sat unit
-pub async fn add_mint(mint_url: String) -> Result<String, String> {
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let profiles_dir = PathBuf::from(&database_dir).join("profiles");
+    if profiles_dir.exists() {
+        let entries = std::fs::read_dir(&profiles_dir)
+            .map_err(|e| format!("Failed to read profiles directory: {}", e))?;
+        for entry in entries.flatten() {
+            let profile_id = entry.file_name().to_string_lossy().to_string();
+            if validate_profile_id(&profile_id).is_ok()
+                && get_multi_mint_database_path(&entry.path()).exists()
+            {
+                profile_ids.push(profile_id);
+            }
+        }
+    }
+    profile_ids.sort();
+
+    let open_wallets = OPEN_WALLETS.read().await;
+    let mut profiles = Vec::new();
+    for profile_id in profile_ids {
+        let profile_dir = get_profile_dir(&database_dir, &profile_id);
+        let is_open = open_wallets
+            .get(&profile_id)
+            .is_some_and(|open| open.profile_dir == profile_dir);
+        profiles.push(WalletProfile {
+            is_encrypted: !is_plaintext_database(&get_multi_mint_database_path(&profile_dir))?,
+            profile_dir: profile_dir.to_string_lossy().to_string(),
+            profile_id,
+            is_open,
+        });
+    }
+
+    Ok(profiles)
+}
+
+/// Initialize MultiMintWallet (deprecated - use open_wallet)
+/// Opens the default profile, later calls return "already initialized".
+pub async fn init_multi_mint_wallet(
+    database_dir: String,
+    seed_hex: String,
+) -> Result<String, String> {
+    if OPEN_WALLETS.read().await.contains_key(DEFAULT_PROFILE_ID) {
+        return Ok("MultiMintWallet already initialized".to_string());
+    }
+    open_wallet(DEFAULT_PROFILE_ID.to_string(), database_dir, seed_hex).await?;
+    Ok("MultiMintWallet initialized successfully".to_string())
+}
+
+/// Change the key of a profile's encrypted wallet database
+/// The profile is closed first and has to be opened again with the new key.
+/// Sends and melts still running after the shutdown make this fail unless force is set.
+#[cfg(feature = "sqlcipher")]
+pub async fn rotate_wallet_database_key(
+    profile_id: String,
+    database_dir: String,
+    old_key_material: String,
+    new_key_material: String,
+    force: bool,
+) -> Result<String, String> {
+    validate_profile_id(&profile_id)?;
+
+    // Release the database before rekeying
+    let _profile_lock = lock_profile(&profile_id).await;
+    let still_running = shutdown_profile(&profile_id, DEFAULT_SHUTDOWN_TIMEOUT).await?;
+    ensure_no_running_operations(still_running, force)?;
+
+    let db_path = get_multi_mint_database_path(&get_profile_dir(&database_dir, &profile_id));
+    if !db_path.exists() {
+        return Err("Wallet database not found".to_string());
+    }
+
+    tokio::task::spawn_blocking(move || {
+        crate::db_encryption::rotate_key(&db_path, &old_key_material, &new_key_material)
+    })
+    .await
+    .map_err(|e| format!("Key rotation failed: {}", e))??;
+
+    Ok("Wallet database key changed successfully".to_string())
+}
+
+/// Check whether a profile's wallet database is encrypted
+pub async fn is_wallet_database_encrypted(
+    profile_id: String,
+    database_dir: String,
+) -> Result<bool, String> {
+    validate_profile_id(&profile_id)?;
+    let db_path = get_multi_mint_database_path(&get_profile_dir(&database_dir, &profile_id));
+    if !db_path.exists() {
+        return Ok(false);
+    }
+    Ok(!is_plaintext_database(&db_path)?)
+}
+
+/// Non-SQLCipher fallback implementations to keep FFI stable
+#[cfg(not(feature = "sqlcipher"))]
+pub async fn open_encrypted_wallet(
+    _profile_id: String,
+    _database_dir: String,
+    _seed_hex: String,
+    _key_material: String,
+) -> Result<WalletHandle, String> {
+    Err("Database encryption feature not enabled".to_string())
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+pub async fn rotate_wallet_database_key(
+    _profile_id: String,
+    _database_dir: String,
+    _old_key_material: String,
+    _new_key_material: String,
+    _force: bool,
+) -> Result<String, String> {
+    Err("Database encryption feature not enabled".to_string())
+}
+
+/// Path of the MultiMintWallet database inside a profile directory
+fn get_multi_mint_database_path(profile_dir: &std::path::Path) -> PathBuf {
+    profile_dir.join("multi_mint_wallet.db")
+}
+
+#[cfg(feature = "sqlcipher")]
+use crate::db_encryption::is_plaintext_database;
+
+#[cfg(not(feature = "sqlcipher"))]
+fn is_plaintext_database(db_path: &std::path::Path) -> Result<bool, String> {
+    use std::io::Read;
+
+    let mut file =
+        std::fs::File::open(db_path).map_err(|e| format!("Failed to read database: {}", e))?;
+    let mut header = [0u8; 16];
+    match file.read_exact(&mut header) {
+        Ok(()) => Ok(&header == b"SQLite format 3\0"),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(format!("Failed to read database: {}", e)),
+    }
+}
+
+/// Open the wallet's SQLite store, encrypted when a key is given
+#[cfg(feature = "sqlcipher")]
+async fn open_wallet_store(
+    db_path: &std::path::Path,
+    key_material: Option<String>,
+) -> Result<WalletSqliteDatabase, String> {
+    // An empty SQLCipher key leaves the database in plaintext
+    let key = match key_material {
+        Some(key_material) => {
+            let path = db_path.to_path_buf();
+            tokio::task::spawn_blocking(move || {
+                crate::db_encryption::prepare_encrypted_database(&path, &key_material)
+            })
+            .await
+            .map_err(|e| format!("Failed to prepare encrypted database: {}", e))??
+        }
+        None => String::new(),
+    };
+
+    WalletSqliteDatabase::new((db_path.to_path_buf(), key))
+        .await
+        .map_err(|e| format!("Failed to create SQLite store: {}", e))
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+async fn open_wallet_store(
+    db_path: &std::path::Path,
+    key_material: Option<String>,
+) -> Result<WalletSqliteDatabase, String> {
+    if key_material.is_some() {
+        return Err("Database encryption feature not enabled".to_string());
+    }
+
+    WalletSqliteDatabase::new(db_path.to_str().unwrap())
+        .await
+        .map_err(|e| format!("Failed to create SQLite store: {}", e))
+}
+
+async fn open_wallet_with_database_key(
+    profile_id: String,
+    database_dir: String,
+    seed_hex: String,
+    key_material: Option<String>,
+) -> Result<WalletHandle, String> {
+    validate_profile_id(&profile_id)?;
+    let handle = WalletHandle {
+        profile_id: profile_id.clone(),
+    };
+    let profile_dir = get_profile_dir(&database_dir, &profile_id);
+
+    // Held until the wallet is registered so the same profile cannot be opened twice
+    let _profile_lock = lock_profile(&profile_id).await;
+    if let Some(open) = OPEN_WALLETS.read().await.get(&profile_id) {
+        if open.profile_dir != profile_dir {
+            return Err(format!(
+                "Wallet profile '{}' is already open from another directory",
+                profile_id
+            ));
+        }
+        return Ok(handle);
+    }
+
+    // Parse seed from hex string
+    let seed = parse_seed_from_hex(&seed_hex)?;
+    let db_path = get_multi_mint_database_path(&profile_dir);
+
+    std::fs::create_dir_all(&profile_dir)
+        .map_err(|e| format!("Failed to create database directory: {}", e))?;
+
+    if key_material.is_none() && db_path.exists() && !is_plaintext_database(&db_path)? {
+        return Err("Wallet database is encrypted, a key is required".to_string());
+    }
+
+    let localstore = open_wallet_store(&db_path, key_material).await?;
+
+    // Convert seed to [u8; 64] by extending if needed
+    let seed_64: [u8; 64] = if seed.len() == 32 {
+        // Extend 32-byte seed to 64 bytes by repeating
+        let mut extended = [0u8; 64];
+        extended[..32].copy_from_slice(&seed);
+        extended[32..].copy_from_slice(&seed);
+        extended
+    } else if seed.len() == 64 {
+        let mut seed_array = [0u8; 64];
+        seed_array.copy_from_slice(&seed);
+        seed_array
+    } else {
+        return Err(format!("Seed must be 32 or 64 bytes, got {}", seed.len()));
+    };
+
+    let network_key = network_key(&seed_64);
+    let mut network_settings = NetworkSettings::load(&profile_dir)?;
+    if network_settings.seal_legacy_passwords(&network_key)? {
+        network_settings.save(&profile_dir)?;
+    }
+
+    // Note: MultiMintWallet now supports only one currency unit per instance
+    let multi_mint_wallet = MultiMintWallet::new(Arc::new(localstore), seed_64, CurrencyUnit::Sat)
+        .await
+        .map_err(|e| format!("Failed to create MultiMintWallet: {}", e))?;
+
+    // Mints loaded from the database start with a direct connection, swap in their
+    // proxy or the profile's Tor client. With the "onion_only" policy regular HTTPS
+    // mints stay direct (faster and more reliable)
+    for wallet in multi_mint_wallet.get_wallets().await {
+        let uses_tor = network_settings.tor_policy.uses_tor(&wallet.mint_url);
+        if let Some(config) = mint_wallet_config(
+            &profile_id,
+            &profile_dir,
+            &network_settings,
+            &network_key,
+            &wallet.mint_url,
+            uses_tor,
+        )? {
+            multi_mint_wallet
+                .set_mint_config(wallet.mint_url.clone(), config)
+                .await
+                .map_err(|e| format!("Failed to apply transport for {}: {}", wallet.mint_url, e))?;
+        }
+    }
+    // Bootstrap early when every mint will need Tor
+    #[cfg(feature = "tor")]
+    if network_settings.tor_policy == TorPolicy::Always {
+        crate::tor::start(&profile_id, &profile_dir)?;
+    }
+
+    crate::api::limits::load_spending_policy(&profile_id, &profile_dir).await?;
+    let operations = WalletOperations::open(&profile_dir)?;
+    let mint_cache = MintCache::open(&profile_dir)?;
+
+    OPEN_WALLETS.write().await.insert(
+        profile_id,
+        OpenWallet {
+            wallet: Arc::new(multi_mint_wallet),
+            profile_dir,
+            operations: Arc::new(operations),
+            mint_cache: Arc::new(mint_cache),
+            offline: Arc::new(AtomicBool::new(false)),
+            tor_policy: network_settings.tor_policy,
+            network_key,
+            proof_selection: Arc::new(tokio::sync::Mutex::new(())),
+        },
+    );
+
+    Ok(handle)
+}
+
+/// Parse seed from hex string (returns Vec<u8> to support both 32 and 64 byte seeds)
+fn parse_seed_from_hex(seed_hex: &str) -> Result<Vec<u8>, String> {
+    // Accept both 64-byte (128 hex chars) and 32-byte (64 hex chars) seeds
+    let expected_lengths = [64, 128]; // 32 bytes or 64 bytes
+    if !expected_lengths.contains(&seed_hex.len()) {
+        return Err(format!(
+            "Seed must be 64 or 128 hex characters (32 or 64 bytes), got {}",
+            seed_hex.len()
+        ));
+    }
+
+    // Parse hex string to bytes
+    let seed_bytes = hex::decode(seed_hex).map_err(|e| format!("Invalid hex string: {}", e))?;
+
+    Ok(seed_bytes)
+}
+
+/// Load existing wallets from database
+/// Note: This function is no longer needed as MultiMintWallet automatically loads wallets
+async fn load_wallets_from_database(
+    _localstore: &WalletSqliteDatabase,
+    _seed: &[u8],
+) -> Result<Vec<Wallet>, String> {
+    // MultiMintWallet now automatically loads wallets from database in its constructor
+    // This function is kept for compatibility but returns empty vector
+    Ok(Vec::new())
+}
+
+/// Add a mint to MultiMintWallet - defaults to sat unit
+pub async fn add_mint(handle: WalletHandle, mint_url: String) -> Result<String, String> {
+    ensure_online(&handle).await?;
+    let (multi_mint_wallet, mint_cache) = with_open_wallet(&handle, |open| {
+        (open.wallet.clone(), open.mint_cache.clone())
+    })
+    .await?;
 
     // Normalize URL: convert https:// to http:// for .onion addresses
     // .onion addresses must use HTTP, not HTTPS
@@ -264,9 +1362,11 @@ pub async fn add_mint(mint_url: String) -> Result<String, String> {
         return Ok("Mint already exists".to_string());
     }
 
-    // Add mint - Tor is automatically used for .onion addresses
-    multi_mint_wallet
-        .add_mint(mint_url_parsed.clone())
+    let config = prepare_mint_connection(&handle, &mint_url_parsed).await?;
+
+    // Add mint - Tor is used for .onion addresses, or for every mint with the "always" policy,
+    // unless the mint goes through a proxy
+    register_mint(&multi_mint_wallet, &mint_url_parsed, config)
         .await
         .map_err(|e| format!("Failed to add mint: {}", e))?;
 
@@ -285,21 +1385,13 @@ pub async fn add_mint(mint_url: String) -> Result<String, String> {
         Ok(None) => {
             // fetch_mint_info returned None, meaning connection failed
             // Rollback by removing the wallet from memory
-            drop(wallet_guard);
-            let mut wallet_guard_write = MULTI_MINT_WALLET.write().await;
-            if let Some(multi_mint_wallet) = wallet_guard_write.as_ref() {
-                multi_mint_wallet.remove_mint(&mint_url_parsed).await;
-            }
+            multi_mint_wallet.remove_mint(&mint_url_parsed).await;
             return Err("Failed to fetch mint info: Tor connection failed or mint is unreachable. Please check your network connection and try again.".to_string());
         }
         Err(e) => {
             // fetch_mint_info returned an error
             // Rollback by removing the wallet from memory
-            drop(wallet_guard);
-            let mut wallet_guard_write = MULTI_MINT_WALLET.write().await;
-            if let Some(multi_mint_wallet) = wallet_guard_write.as_ref() {
-                multi_mint_wallet.remove_mint(&mint_url_parsed).await;
-            }
+            multi_mint_wallet.remove_mint(&mint_url_parsed).await;
             return Err(format!("Failed to fetch mint info: {}", e));
         }
     };
@@ -313,11 +1405,7 @@ pub async fn add_mint(mint_url: String) -> Result<String, String> {
 
     if saved_mint_info.is_none() {
         // Mint info was not saved, rollback
-        drop(wallet_guard);
-        let mut wallet_guard_write = MULTI_MINT_WALLET.write().await;
-        if let Some(multi_mint_wallet) = wallet_guard_write.as_ref() {
-            multi_mint_wallet.remove_mint(&mint_url_parsed).await;
-        }
+        multi_mint_wallet.remove_mint(&mint_url_parsed).await;
         return Err("Failed to save mint info to database".to_string());
     }
 
@@ -339,15 +1427,18 @@ pub async fn add_mint(mint_url: String) -> Result<String, String> {
     // 3. Active keyset will be fetched automatically when needed for operations
     // 4. Forcing fetch_active_keyset() here can cause unnecessary delays
 
+    // Start the NUT-19 TTL for the info and keysets just stored
+    mint_cache.record(&mint_url_parsed, mint_info.nuts.nut19.ttl)?;
+
     Ok("Mint added successfully".to_string())
 }
 
 /// Remove a mint from MultiMintWallet - defaults to sat unit
-pub async fn remove_mint(mint_url: String) -> Result<String, String> {
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+pub async fn remove_mint(handle: WalletHandle, mint_url: String) -> Result<String, String> {
+    let (multi_mint_wallet, mint_cache) = with_open_wallet(&handle, |open| {
+        (open.wallet.clone(), open.mint_cache.clone())
+    })
+    .await?;
 
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
@@ -361,17 +1452,15 @@ pub async fn remove_mint(mint_url: String) -> Result<String, String> {
         .remove_mint(&mint_url_parsed)
         .await
         .map_err(|e| format!("Failed to remove mint: {}", e))?;
+    mint_cache.forget(&mint_url_parsed)?;
 
     Ok("Mint removed successfully".to_string())
 }
 
 /// List all mints in MultiMintWallet
 
-pub async fn list_mints() -> Result<Vec<String>, String> {
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+pub async fn list_mints(handle: WalletHandle) -> Result<Vec<String>, String> {
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     let wallets = multi_mint_wallet.get_wallets().await;
     let mint_list: Vec<String> = wallets
@@ -388,16 +1477,22 @@ pub async fn wallet_exists(mint_url: String, database_dir: String) -> bool {
     wallet_database_exists(&database_dir, &mint_url)
 }
 
-/// Get wallet information (may make network requests for keyset info) - defaults to sat unit
-pub async fn get_wallet_info(mint_url: String) -> Result<WalletInfo, String> {
+/// Get wallet information - defaults to sat unit
+/// Keysets come from the local cache when there is one, stale keysets are refreshed
+/// in the background.
+pub async fn get_wallet_info(handle: WalletHandle, mint_url: String) -> Result<WalletInfo, String> {
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
     // Use MultiMintWallet to get wallet info
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let (multi_mint_wallet, mint_cache, offline) = with_open_wallet(&handle, |open| {
+        (
+            open.wallet.clone(),
+            open.mint_cache.clone(),
+            open.offline.load(Ordering::Relaxed),
+        )
+    })
+    .await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
@@ -413,28 +1508,94 @@ pub async fn get_wallet_info(mint_url: String) -> Result<WalletInfo, String> {
         .await
         .map_err(|e| format!("Failed to get balance: {}", e))?;
 
-    // Get active keyset ID from mint info
-    let active_keyset_id = wallet
-        .get_active_keyset()
+    // Prefer the cheapest active keyset already in the database
+    let cached_keyset = wallet
+        .localstore
+        .get_mint_keysets(mint_url_parsed.clone())
         .await
-        .map_err(|e| format!("Failed to get active keyset: {}", e))?
-        .id
-        .to_string();
+        .map_err(|e| format!("Failed to read cached keysets: {}", e))?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|k| k.active && k.unit == wallet.unit)
+        .min_by_key(|k| k.input_fee_ppk);
+
+    let freshness = mint_cache.freshness(&mint_url_parsed);
+    let active_keyset_id = match cached_keyset {
+        Some(keyset) => {
+            if !offline && freshness.is_none_or(|f| f.is_stale) {
+                spawn_mint_cache_refresh(&handle, wallet.clone(), mint_cache.clone());
+            }
+            keyset.id.to_string()
+        }
+        None if offline => return Err("Keysets not cached and the wallet is offline".to_string()),
+        None => {
+            let keyset = wallet
+                .get_active_keyset()
+                .await
+                .map_err(|e| format!("Failed to get active keyset: {}", e))?;
+            keyset.id.to_string()
+        }
+    };
 
     Ok(WalletInfo {
         mint_url: wallet.mint_url.to_string(),
         unit: wallet.unit.to_string(),
         balance: balance.into(),
         active_keyset_id,
+        fetched_at: freshness.map(|f| f.fetched_at),
+        is_stale: freshness.is_none_or(|f| f.is_stale),
     })
 }
 
+/// Fetch the mint's info and keysets and record when that happened
+/// The wallet stores both in its database, so later reads can be served offline.
+async fn refresh_mint_cache(
+    wallet: &Wallet,
+    mint_cache: &MintCache,
+) -> Result<Option<cdk::nuts::MintInfo>, String> {
+    let info = wallet
+        .fetch_mint_info()
+        .await
+        .map_err(|e| format!("Failed to get mint info: {}", e))?;
+    wallet
+        .fetch_active_keyset()
+        .await
+        .map_err(|e| format!("Failed to refresh keysets: {}", e))?;
+
+    if let Some(info) = &info {
+        mint_cache.record(&wallet.mint_url, info.nuts.nut19.ttl)?;
+    }
+    Ok(info)
+}
+
+/// Refresh stale cached mint data without blocking the caller
+/// Reports the outcome as a "mint_info_refreshed" or "mint_info_refresh_failed" event.
+fn spawn_mint_cache_refresh(handle: &WalletHandle, wallet: Wallet, mint_cache: Arc<MintCache>) {
+    if !mint_cache.start_refresh(&wallet.mint_url) {
+        return;
+    }
+
+    let handle = handle.clone();
+    tokio::spawn(async move {
+        let mint_url = wallet.mint_url.clone();
+        if let Ok((_, operation)) = begin_wallet_operation(&handle).await {
+            let result = tokio::select! {
+                result = refresh_mint_cache(&wallet, &mint_cache) => result,
+                _ = operation.cancelled() => Err("Wallet is shutting down".to_string()),
+            };
+            let event = match result {
+                Ok(_) => WalletEvent::new("mint_info_refreshed"),
+                Err(e) => WalletEvent::new("mint_info_refresh_failed").message(e),
+            };
+            emit_wallet_event(event.profile_id(&handle.profile_id).mint_url(&mint_url));
+        }
+        mint_cache.finish_refresh(&mint_url);
+    });
+}
+
 /// Get all transactions from all mints (fast, no network requests)
-pub async fn get_all_transactions() -> Result<Vec<TransactionInfo>, String> {
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+pub async fn get_all_transactions(handle: WalletHandle) -> Result<Vec<TransactionInfo>, String> {
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     let transactions = multi_mint_wallet
         .list_transactions(None)
@@ -467,11 +1628,8 @@ pub async fn get_all_transactions() -> Result<Vec<TransactionInfo>, String> {
 }
 
 /// Get all wallet balances from all mints (fast, no network requests)
-pub async fn get_all_balances() -> Result<HashMap<String, u64>, String> {
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+pub async fn get_all_balances(handle: WalletHandle) -> Result<HashMap<String, u64>, String> {
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     let mut balances = HashMap::new();
 
@@ -536,30 +1694,167 @@ fn extract_supported_nuts(nuts: &cdk::nuts::Nuts) -> Vec<String> {
     }
 
     // Add auth NUTs if available
-    // Note: auth feature is not enabled in purrwallet, so NUT-21 and NUT-22 are skipped
-    // #[cfg(feature = "auth")]
-    // {
-    //     if nuts.nut21.is_some() {
-    //         supported_nuts.push("NUT-21".to_string());
-    //     }
-    //     if nuts.nut22.is_some() {
-    //         supported_nuts.push("NUT-22".to_string());
-    //     }
-    // }
+    #[cfg(feature = "auth")]
+    {
+        if nuts.nut21.is_some() {
+            supported_nuts.push("NUT-21".to_string());
+        }
+        if nuts.nut22.is_some() {
+            supported_nuts.push("NUT-22".to_string());
+        }
+    }
 
     supported_nuts
 }
 
+/// Render a serde enum (HTTP method, route, WebSocket command) as its wire string
+fn wire_string<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(other) => other.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// Typed NUT-04/05/15/17/19/21/22 settings from the Nuts struct
+pub(crate) fn mint_settings(nuts: &cdk::nuts::Nuts) -> MintSettings {
+    use cdk::nuts::nut04::MintMethodOptions;
+    use cdk::nuts::nut05::MeltMethodOptions;
+
+    let mint_methods = nuts
+        .nut04
+        .methods
+        .iter()
+        .map(|m| PaymentMethodSettings {
+            method: m.method.to_string(),
+            unit: m.unit.to_string(),
+            min_amount: m.min_amount.map(u64::from),
+            max_amount: m.max_amount.map(u64::from),
+            description: matches!(
+                m.options,
+                Some(MintMethodOptions::Bolt11 { description: true })
+            ),
+            amountless: false,
+        })
+        .collect();
+
+    let melt_methods = nuts
+        .nut05
+        .methods
+        .iter()
+        .map(|m| PaymentMethodSettings {
+            method: m.method.to_string(),
+            unit: m.unit.to_string(),
+            min_amount: m.min_amount.map(u64::from),
+            max_amount: m.max_amount.map(u64::from),
+            description: false,
+            amountless: matches!(
+                m.options,
+                Some(MeltMethodOptions::Bolt11 { amountless: true })
+            ),
+        })
+        .collect();
+
+    let mpp_methods = nuts
+        .nut15
+        .methods
+        .iter()
+        .map(|m| PaymentMethodSettings {
+            method: m.method.to_string(),
+            unit: m.unit.to_string(),
+            min_amount: None,
+            max_amount: None,
+            description: false,
+            amountless: false,
+        })
+        .collect();
+
+    let websocket = nuts
+        .nut17
+        .supported
+        .iter()
+        .map(|s| WebSocketSettings {
+            method: s.method.to_string(),
+            unit: s.unit.to_string(),
+            commands: s.commands.iter().map(wire_string).collect(),
+        })
+        .collect();
+
+    let cached_endpoints = nuts
+        .nut19
+        .cached_endpoints
+        .iter()
+        .map(|e| CachedEndpoint {
+            method: wire_string(&e.method),
+            path: wire_string(&e.path),
+        })
+        .collect();
+
+    let supported_nuts = extract_supported_nuts(nuts)
+        .iter()
+        .filter_map(|nut| nut.trim_start_matches("NUT-").parse().ok())
+        .collect();
+
+    MintSettings {
+        mint_methods,
+        minting_disabled: nuts.nut04.disabled,
+        melt_methods,
+        melting_disabled: nuts.nut05.disabled,
+        supported_nuts,
+        mpp_methods,
+        websocket,
+        cache_ttl: nuts.nut19.ttl,
+        cached_endpoints,
+        auth: mint_auth_settings(nuts),
+    }
+}
+
+#[cfg(feature = "auth")]
+fn mint_auth_settings(nuts: &cdk::nuts::Nuts) -> Option<MintAuthSettings> {
+    if nuts.nut21.is_none() && nuts.nut22.is_none() {
+        return None;
+    }
+
+    let endpoint = |e: &cdk::nuts::ProtectedEndpoint| {
+        format!("{} {}", wire_string(&e.method), wire_string(&e.path))
+    };
+    Some(MintAuthSettings {
+        openid_discovery: nuts.nut21.as_ref().map(|s| s.openid_discovery.clone()),
+        client_id: nuts.nut21.as_ref().map(|s| s.client_id.clone()),
+        clear_auth_endpoints: nuts
+            .nut21
+            .as_ref()
+            .map(|s| s.protected_endpoints.iter().map(endpoint).collect())
+            .unwrap_or_default(),
+        blind_auth_max_mint: nuts.nut22.as_ref().map(|s| s.bat_max_mint),
+        blind_auth_endpoints: nuts
+            .nut22
+            .as_ref()
+            .map(|s| s.protected_endpoints.iter().map(endpoint).collect())
+            .unwrap_or_default(),
+    })
+}
+
+/// Without the auth feature the NUT-21/22 settings are not parsed
+#[cfg(not(feature = "auth"))]
+fn mint_auth_settings(_nuts: &cdk::nuts::Nuts) -> Option<MintAuthSettings> {
+    None
+}
+
 /// Get mint information from NUT-06 endpoint - defaults to sat unit
-pub async fn get_mint_info(mint_url: String) -> Result<MintInfo, String> {
+pub async fn get_mint_info(handle: WalletHandle, mint_url: String) -> Result<MintInfo, String> {
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
     // Use MultiMintWallet to get mint info
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let (multi_mint_wallet, mint_cache, offline) = with_open_wallet(&handle, |open| {
+        (
+            open.wallet.clone(),
+            open.mint_cache.clone(),
+            open.offline.load(Ordering::Relaxed),
+        )
+    })
+    .await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
@@ -570,11 +1865,28 @@ pub async fn get_mint_info(mint_url: String) -> Result<MintInfo, String> {
         .await
         .ok_or("Failed to get wallet")?;
 
-    // Get mint info using the wallet's fetch_mint_info method
-    let mint_info_result = wallet
-        .fetch_mint_info()
+    // Serve the cached NUT-06 info right away, refreshing it in the background once
+    // it is older than the mint's NUT-19 TTL
+    let cached_info = wallet
+        .localstore
+        .get_mint(mint_url_parsed.clone())
         .await
-        .map_err(|e| format!("Failed to get mint info: {}", e))?;
+        .map_err(|e| format!("Failed to read cached mint info: {}", e))?;
+    let mint_info_result = match cached_info {
+        Some(info) => {
+            if !offline
+                && mint_cache
+                    .freshness(&mint_url_parsed)
+                    .is_none_or(|f| f.is_stale)
+            {
+                spawn_mint_cache_refresh(&handle, wallet.clone(), mint_cache.clone());
+            }
+            Some(info)
+        }
+        None if offline => return Err("Mint info not cached and the wallet is offline".to_string()),
+        None => refresh_mint_cache(&wallet, &mint_cache).await?,
+    };
+    let freshness = mint_cache.freshness(&mint_url_parsed);
 
     match mint_info_result {
         Some(info) => {
@@ -599,8 +1911,13 @@ pub async fn get_mint_info(mint_url: String) -> Result<MintInfo, String> {
                 icon_url: info.icon_url,
                 urls: info.urls,
                 nuts: Some(extract_supported_nuts(&info.nuts)),
-                public_key: None, // This would need to be extracted from keysets
+                public_key: info.pubkey.map(|pubkey| pubkey.to_hex()),
                 additional_info: None,
+                tos_url: info.tos_url,
+                time: info.time,
+                settings: mint_settings(&info.nuts),
+                fetched_at: freshness.map(|f| f.fetched_at),
+                is_stale: freshness.is_none_or(|f| f.is_stale),
             })
         }
         None => Err("Mint info not available".to_string()),
@@ -610,23 +1927,37 @@ pub async fn get_mint_info(mint_url: String) -> Result<MintInfo, String> {
 /// Send tokens using CDK MultiMintWallet API directly - defaults to sat unit
 
 pub async fn send_tokens(
+    handle: WalletHandle,
     mint_url: String,
     amount: u64,
     memo: Option<String>,
 ) -> Result<String, String> {
+    send_token(&handle, &mint_url, amount, memo)
+        .await
+        .map(|token| token.to_string())
+}
+
+/// Spend amount into a token, the proofs are pending from here on
+async fn send_token(
+    handle: &WalletHandle,
+    mint_url: &str,
+    amount: u64,
+    memo: Option<String>,
+) -> Result<Token, String> {
     let mint_url_parsed =
-        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+        MintUrl::from_str(mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let (multi_mint_wallet, mut operation) = begin_wallet_operation(handle).await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
     }
 
+    let _spend =
+        crate::api::limits::check_spend(handle, &multi_mint_wallet, &mint_url_parsed, amount)
+            .await?;
+    operation.journal("send", mint_url, amount)?;
+
     // Use CDK MultiMintWallet API directly
     let send_amount = Amount::from(amount);
 
@@ -655,54 +1986,277 @@ pub async fn send_tokens(
         .await
         .map_err(|e| format!("Failed to send: {}", e))?;
 
-    let token_str = token.to_string();
-
     // Note: Token is generated after transaction is created, so we cannot
     // store it in the transaction metadata at this time.
     // The token can still be retrieved from the token string returned to the user.
 
-    Ok(token_str)
+    Ok(token)
 }
 
-fn parse_public_key(input: &str) -> Result<PublicKey, String> {
-    let trimmed = input.trim();
-    if trimmed.is_empty() {
-        return Err("Public key cannot be empty".to_string());
+/// Token encodings accepted by the send and re-encode APIs
+fn parse_token_encoding(encoding: &str) -> Result<&'static str, String> {
+    match encoding.trim().to_lowercase().as_str() {
+        "v3" | "cashua" => Ok("v3"),
+        "v4" | "cashub" | "" => Ok("v4"),
+        other => Err(format!("Unknown token encoding '{}', use v3 or v4", other)),
     }
-
-    PublicKey::from_str(trimmed).map_err(|e| format!("Invalid public key '{}': {}", trimmed, e))
 }
 
-fn parse_pubkey_list(inputs: Option<Vec<String>>) -> Result<Option<Vec<PublicKey>>, String> {
-    match inputs {
-        Some(values) => {
-            let mut parsed: Vec<PublicKey> = Vec::new();
+/// Read the proofs of a token, using the wallet's keysets to expand short keyset ids
+async fn token_proofs(
+    multi_mint_wallet: &MultiMintWallet,
+    token: &Token,
+) -> Result<Vec<Proof>, String> {
+    let mint_url = token
+        .mint_url()
+        .map_err(|e| format!("Failed to get mint URL from token: {}", e))?;
 
-            for value in values {
-                let trimmed = value.trim();
-                if trimmed.is_empty() {
-                    continue;
-                }
+    let keysets = match multi_mint_wallet.get_wallet(&mint_url).await {
+        Some(wallet) => wallet
+            .load_mint_keysets()
+            .await
+            .map_err(|e| format!("Failed to load keysets: {}", e))?,
+        None => Vec::new(),
+    };
 
-                let pubkey = parse_public_key(trimmed)?;
-                if !parsed.contains(&pubkey) {
-                    parsed.push(pubkey);
-                }
-            }
+    token
+        .proofs(&keysets)
+        .map_err(|e| format!("Failed to read token proofs: {}", e))
+}
 
-            if parsed.is_empty() {
-                Ok(None)
-            } else {
-                Ok(Some(parsed))
-            }
-        }
-        None => Ok(None),
+fn encoded_token(
+    token: String,
+    encoding: &str,
+    amount: Amount,
+    proofs_count: usize,
+    mint_urls: Vec<String>,
+) -> EncodedToken {
+    EncodedToken {
+        byte_size: token.len() as u64,
+        token,
+        encoding: encoding.to_string(),
+        amount: amount.into(),
+        proofs_count: proofs_count as u64,
+        mint_urls,
     }
 }
 
-fn parse_sigflag(sigflag: Option<String>) -> Result<Option<SigFlag>, String> {
-    match sigflag {
-        Some(flag) => {
+/// Send tokens with an explicit encoding ("v3" for cashuA, "v4" for cashuB) - defaults to sat unit
+pub async fn send_tokens_with_encoding(
+    handle: WalletHandle,
+    mint_url: String,
+    amount: u64,
+    memo: Option<String>,
+    encoding: String,
+) -> Result<EncodedToken, String> {
+    // Validate before spending anything
+    let encoding = parse_token_encoding(&encoding)?;
+
+    let token = send_token(&handle, &mint_url, amount, memo).await?;
+    // The proofs are spent into this token now, never fail without handing it back
+    let raw_token = token.to_string();
+    reencode_token(token, encoding).map_err(|e| format!("{}. Token: {}", e, raw_token))
+}
+
+/// Encode a token as V3 or V4, its amount and proof count come from the token itself
+/// A V3 token holding several mints, as combine_tokens makes them, can only stay V3.
+fn reencode_token(token: Token, encoding: &'static str) -> Result<EncodedToken, String> {
+    let (amount, proofs_count, mint_urls) = match &token {
+        Token::TokenV3(token_v3) => {
+            let mut mint_urls: Vec<String> = Vec::new();
+            for entry in &token_v3.token {
+                if !mint_urls.contains(&entry.mint.to_string()) {
+                    mint_urls.push(entry.mint.to_string());
+                }
+            }
+            let proofs = token_v3.token.iter().flat_map(|t| t.proofs.iter());
+            (
+                proofs
+                    .clone()
+                    .fold(Amount::ZERO, |total, proof| total + proof.amount),
+                proofs.count(),
+                mint_urls,
+            )
+        }
+        Token::TokenV4(token_v4) => {
+            let proofs = token_v4.token.iter().flat_map(|t| t.proofs.iter());
+            (
+                proofs
+                    .clone()
+                    .fold(Amount::ZERO, |total, proof| total + proof.amount),
+                proofs.count(),
+                vec![token_v4.mint_url.to_string()],
+            )
+        }
+    };
+
+    let encoded = match (encoding, token) {
+        ("v3", Token::TokenV3(token_v3)) => token_v3.to_string(),
+        ("v3", token) => token.to_v3_string(),
+        (_, Token::TokenV4(token_v4)) => token_v4.to_string(),
+        (_, Token::TokenV3(_)) if mint_urls.len() > 1 => {
+            return Err("V4 tokens can only hold one mint, keep this token as v3".to_string())
+        }
+        (_, Token::TokenV3(token_v3)) => TokenV4::try_from(token_v3)
+            .map_err(|e| format!("Failed to convert token to V4: {}", e))?
+            .to_string(),
+    };
+
+    Ok(encoded_token(
+        encoded,
+        encoding,
+        amount,
+        proofs_count,
+        mint_urls,
+    ))
+}
+
+/// Re-encode an existing token as V3 (cashuA) or V4 (cashuB)
+pub async fn encode_token(
+    handle: WalletHandle,
+    token: String,
+    encoding: String,
+) -> Result<EncodedToken, String> {
+    let encoding = parse_token_encoding(&encoding)?;
+    // Only checks that the profile is open, re-encoding needs nothing from the wallet
+    get_multi_mint_wallet(&handle).await?;
+    let cashu_token =
+        Token::from_str(token.trim()).map_err(|e| format!("Failed to parse token: {}", e))?;
+    reencode_token(cashu_token, encoding)
+}
+
+/// Bundle several tokens into one token
+/// Tokens from more than one mint can only be combined into a V3 (cashuA) token.
+pub async fn combine_tokens(
+    handle: WalletHandle,
+    tokens: Vec<String>,
+    memo: Option<String>,
+    encoding: String,
+) -> Result<EncodedToken, String> {
+    let encoding = parse_token_encoding(&encoding)?;
+    if tokens.is_empty() {
+        return Err("No tokens to combine".to_string());
+    }
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
+
+    // Group proofs by mint, keeping the order the mints first appear in
+    let mut unit: Option<CurrencyUnit> = None;
+    let mut proofs_by_mint: Vec<(MintUrl, Vec<Proof>)> = Vec::new();
+    for token in &tokens {
+        let cashu_token =
+            Token::from_str(token.trim()).map_err(|e| format!("Failed to parse token: {}", e))?;
+
+        let token_unit = cashu_token.unit().unwrap_or(CurrencyUnit::Sat);
+        match &unit {
+            Some(existing) if *existing != token_unit => {
+                return Err("Tokens with different units cannot be combined".to_string())
+            }
+            _ => unit = Some(token_unit),
+        }
+
+        // A V3 token may already bundle several mints
+        let token_mints = match cashu_token {
+            Token::TokenV3(token_v3) => token_v3
+                .token
+                .into_iter()
+                .map(|entry| (entry.mint, entry.proofs))
+                .collect(),
+            token => {
+                let mint_url = token
+                    .mint_url()
+                    .map_err(|e| format!("Failed to get mint URL from token: {}", e))?;
+                vec![(mint_url, token_proofs(&multi_mint_wallet, &token).await?)]
+            }
+        };
+
+        for (mint_url, proofs) in token_mints {
+            match proofs_by_mint
+                .iter_mut()
+                .find(|(mint, _)| *mint == mint_url)
+            {
+                Some((_, existing)) => existing.extend(proofs),
+                None => proofs_by_mint.push((mint_url, proofs)),
+            }
+        }
+    }
+
+    if proofs_by_mint.len() > 1 && encoding == "v4" {
+        return Err(
+            "V4 tokens can only hold one mint, use v3 to combine tokens from several mints"
+                .to_string(),
+        );
+    }
+
+    let mint_urls: Vec<String> = proofs_by_mint.iter().map(|(m, _)| m.to_string()).collect();
+    let proofs_count = proofs_by_mint.iter().map(|(_, p)| p.len()).sum();
+    let amount = proofs_by_mint
+        .iter()
+        .flat_map(|(_, proofs)| proofs.iter())
+        .fold(Amount::ZERO, |total, proof| total + proof.amount);
+
+    let encoded = if encoding == "v3" {
+        TokenV3 {
+            token: proofs_by_mint
+                .into_iter()
+                .map(|(mint_url, proofs)| TokenV3Token::new(mint_url, proofs))
+                .collect(),
+            memo,
+            unit,
+        }
+        .to_string()
+    } else {
+        let (mint_url, proofs) = proofs_by_mint.remove(0);
+        Token::new(mint_url, proofs, memo, unit.unwrap_or(CurrencyUnit::Sat)).to_string()
+    };
+
+    Ok(encoded_token(
+        encoded,
+        encoding,
+        amount,
+        proofs_count,
+        mint_urls,
+    ))
+}
+
+fn parse_public_key(input: &str) -> Result<PublicKey, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err("Public key cannot be empty".to_string());
+    }
+
+    PublicKey::from_str(trimmed).map_err(|e| format!("Invalid public key '{}': {}", trimmed, e))
+}
+
+fn parse_pubkey_list(inputs: Option<Vec<String>>) -> Result<Option<Vec<PublicKey>>, String> {
+    match inputs {
+        Some(values) => {
+            let mut parsed: Vec<PublicKey> = Vec::new();
+
+            for value in values {
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+
+                let pubkey = parse_public_key(trimmed)?;
+                if !parsed.contains(&pubkey) {
+                    parsed.push(pubkey);
+                }
+            }
+
+            if parsed.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(parsed))
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+fn parse_sigflag(sigflag: Option<String>) -> Result<Option<SigFlag>, String> {
+    match sigflag {
+        Some(flag) => {
             let trimmed = flag.trim();
             if trimmed.is_empty() {
                 return Ok(None);
@@ -759,7 +2313,30 @@ fn build_p2pk_spending_conditions(
     ))
 }
 
+/// P2PK lock a NUT-18 payment request asks for, with locktime, refund keys, n_sigs and
+/// sigflag taken from the request's NUT-10 tags
+pub(crate) fn payment_request_conditions(
+    nut10: &Nut10SecretRequest,
+) -> Result<SpendingConditions, String> {
+    if nut10.kind != Kind::P2PK {
+        return Err(format!(
+            "Unsupported payment request lock kind '{}'",
+            nut10.kind
+        ));
+    }
+    let recipient_key = parse_public_key(&nut10.data)?;
+    let conditions = nut10
+        .tags
+        .clone()
+        .filter(|tags| !tags.is_empty())
+        .map(Conditions::try_from)
+        .transpose()
+        .map_err(|e| format!("Invalid payment request lock: {}", e))?;
+    Ok(SpendingConditions::new_p2pk(recipient_key, conditions))
+}
+
 pub async fn send_p2pk_tokens(
+    handle: WalletHandle,
     mint_url: String,
     amount: u64,
     memo: Option<String>,
@@ -771,19 +2348,6 @@ pub async fn send_p2pk_tokens(
     sigflag: Option<String>,
     refund_required_sigs: Option<u64>,
 ) -> Result<String, String> {
-    let mint_url_parsed =
-        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
-
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
-
-    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
-        return Err("Mint not found in wallet".to_string());
-    }
-
     let spending_conditions = build_p2pk_spending_conditions(
         recipient_pubkey.clone(),
         additional_pubkeys,
@@ -794,6 +2358,47 @@ pub async fn send_p2pk_tokens(
         refund_required_sigs,
     )?;
 
+    send_locked_tokens(
+        &handle,
+        mint_url,
+        amount,
+        memo,
+        spending_conditions,
+        recipient_pubkey,
+    )
+    .await
+}
+
+/// Send a token locked to spending conditions, recorded as a P2PK send to recipient_pubkey
+async fn send_locked_tokens(
+    handle: &WalletHandle,
+    mint_url: String,
+    amount: u64,
+    memo: Option<String>,
+    spending_conditions: SpendingConditions,
+    recipient_pubkey: String,
+) -> Result<String, String> {
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let (multi_mint_wallet, mut operation) = begin_wallet_operation(handle).await?;
+
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        return Err("Mint not found in wallet".to_string());
+    }
+
+    // Ecash locked at a mint without NUT-11 could never be redeemed
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+    require_capabilities(&wallet, &[MintCapability::P2pk]).await?;
+
+    let _spend =
+        crate::api::limits::check_spend(handle, &multi_mint_wallet, &mint_url_parsed, amount)
+            .await?;
+    operation.journal("p2pk_send", &mint_url, amount)?;
+
     let send_amount = Amount::from(amount);
 
     let mut metadata = HashMap::new();
@@ -825,128 +2430,1653 @@ pub async fn send_p2pk_tokens(
     Ok(token.to_string())
 }
 
-/// Receive tokens using CDK MultiMintWallet API directly - auto-detects mint URL from token
-pub async fn receive_tokens(token: String) -> Result<u64, String> {
-    // Parse token to get mint URL
-    let cashu_token =
-        Token::from_str(&token).map_err(|e| format!("Failed to parse token: {}", e))?;
-
-    let token_mint_url = cashu_token
-        .mint_url()
-        .map_err(|e| format!("Failed to get mint URL from token: {}", e))?;
+/// Send exactly the chosen proofs as a token (coin control)
+/// proof_ys are the `y` values from get_wallet_proofs, every proof has to be unspent.
+/// Without swap the token holds those proofs and they are marked pending until
+/// the receiver claims them. With swap they are exchanged at the mint, the send part
+/// goes into the token and is marked pending, change stays in the wallet.
+pub async fn send_selected_proofs(
+    handle: WalletHandle,
+    mint_url: String,
+    proof_ys: Vec<String>,
+    options: SelectedProofsSendOptions,
+) -> Result<String, String> {
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+    let mut ys = Vec::new();
+    for y in &proof_ys {
+        let y = PublicKey::from_str(y).map_err(|e| format!("Invalid proof Y '{}': {}", y, e))?;
+        if !ys.contains(&y) {
+            ys.push(y);
+        }
+    }
+    if ys.is_empty() {
+        return Err("No proofs selected".to_string());
+    }
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let (multi_mint_wallet, mut operation) = begin_wallet_operation(&handle).await?;
 
-    // If mint doesn't exist, add it automatically
-    if !multi_mint_wallet.has_mint(&token_mint_url).await {
-        // Add the mint automatically
-        multi_mint_wallet
-            .add_mint(token_mint_url.clone())
-            .await
-            .map_err(|e| format!("Failed to add mint automatically: {}", e))?;
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        return Err("Mint not found in wallet".to_string());
     }
-
-    // Get wallet for receiving
     let wallet = multi_mint_wallet
-        .get_wallet(&token_mint_url)
+        .get_wallet(&mint_url_parsed)
         .await
         .ok_or("Failed to get wallet")?;
 
-    // Add metadata for transaction tracking
+    let selected = reserve_selected_proofs(&handle, &wallet, &mint_url_parsed, &ys).await?;
+    let result = send_reserved_proofs(
+        &handle,
+        &multi_mint_wallet,
+        &wallet,
+        &mut operation,
+        &mint_url_parsed,
+        selected,
+        options,
+    )
+    .await;
+    if let Err(e) = result {
+        return Err(
+            match release_reserved_proofs(&wallet, &mint_url_parsed, &ys).await {
+                Ok(()) => e,
+                Err(release_error) => format!("{}; {}", e, release_error),
+            },
+        );
+    }
+    result
+}
+
+/// Move the chosen proofs from unspent to reserved, so no other send can pick them
+/// Fails without reserving anything when one of them is not unspent.
+async fn reserve_selected_proofs(
+    handle: &WalletHandle,
+    wallet: &Wallet,
+    mint_url: &MintUrl,
+    ys: &[PublicKey],
+) -> Result<Vec<Proof>, String> {
+    let proof_selection = with_open_wallet(handle, |open| open.proof_selection.clone()).await?;
+    let _selection = proof_selection.lock().await;
+
+    let unspent = wallet
+        .localstore
+        .get_proofs(
+            Some(mint_url.clone()),
+            Some(wallet.unit.clone()),
+            Some(vec![State::Unspent]),
+            None,
+        )
+        .await
+        .map_err(|e| format!("Failed to get proofs: {}", e))?;
+    let mut selected = Vec::new();
+    for y in ys {
+        let info = unspent
+            .iter()
+            .find(|info| &info.y == y)
+            .ok_or_else(|| format!("Proof {} is not unspent in this wallet", y.to_hex()))?;
+        selected.push(info.proof.clone());
+    }
+    wallet
+        .localstore
+        .update_proofs_state(ys.to_vec(), State::Reserved)
+        .await
+        .map_err(|e| format!("Failed to reserve selected proofs: {}", e))?;
+    Ok(selected)
+}
+
+/// Put proofs reserved for a failed send back to unspent
+/// Proofs that moved on, such as swap inputs the mint may have seen, keep their state.
+async fn release_reserved_proofs(
+    wallet: &Wallet,
+    mint_url: &MintUrl,
+    ys: &[PublicKey],
+) -> Result<(), String> {
+    let reserved: Vec<PublicKey> = wallet
+        .localstore
+        .get_proofs(
+            Some(mint_url.clone()),
+            Some(wallet.unit.clone()),
+            Some(vec![State::Reserved]),
+            None,
+        )
+        .await
+        .map_err(|e| format!("Failed to release reserved proofs: {}", e))?
+        .into_iter()
+        .map(|info| info.y)
+        .filter(|y| ys.contains(y))
+        .collect();
+    if reserved.is_empty() {
+        return Ok(());
+    }
+    wallet
+        .localstore
+        .update_proofs_state(reserved, State::Unspent)
+        .await
+        .map_err(|e| format!("Failed to release reserved proofs: {}", e))
+}
+
+async fn send_reserved_proofs(
+    handle: &WalletHandle,
+    multi_mint_wallet: &MultiMintWallet,
+    wallet: &Wallet,
+    operation: &mut OperationGuard,
+    mint_url_parsed: &MintUrl,
+    selected: Vec<Proof>,
+    options: SelectedProofsSendOptions,
+) -> Result<String, String> {
+    let mint_url = mint_url_parsed.to_string();
+    let total: u64 = selected.iter().map(|proof| u64::from(proof.amount)).sum();
+
+    let (send_proofs, fee, _spend) = if options.swap {
+        ensure_online(handle).await?;
+        let fee = u64::from(
+            wallet
+                .get_proofs_fee(&selected)
+                .await
+                .map_err(|e| format!("Failed to calculate fee: {}", e))?,
+        );
+        let amount = match options.amount {
+            Some(amount) => amount,
+            None => total
+                .checked_sub(fee)
+                .filter(|amount| *amount > 0)
+                .ok_or("Selected proofs do not cover the mint's fee")?,
+        };
+        let cost = amount.checked_add(fee).ok_or("Amount is too large")?;
+        if cost > total {
+            return Err(format!(
+                "Selected proofs hold {} but sending {} costs {} in fees",
+                total, amount, fee
+            ));
+        }
+
+        let spend =
+            crate::api::limits::check_spend(handle, multi_mint_wallet, mint_url_parsed, amount)
+                .await?;
+        operation.journal("send", &mint_url, amount)?;
+
+        let send_proofs = wallet
+            .swap(
+                Some(Amount::from(amount)),
+                SplitTarget::default(),
+                selected,
+                None,
+                false,
+            )
+            .await
+            .map_err(|e| format!("Failed to swap selected proofs: {}", e))?
+            .ok_or("Swap returned no proofs to send")?;
+        (send_proofs, fee, spend)
+    } else {
+        if options.amount.is_some_and(|amount| amount != total) {
+            return Err(format!(
+                "Without a swap the token holds exactly the selected {}",
+                total
+            ));
+        }
+        let spend =
+            crate::api::limits::check_spend(handle, multi_mint_wallet, mint_url_parsed, total)
+                .await?;
+        operation.journal("send", &mint_url, total)?;
+        (selected, 0, spend)
+    };
+
+    // Pending until the receiver claims them, like proofs from send_tokens
+    let send_ys = send_proofs
+        .iter()
+        .map(|proof| proof.y())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to compute proof Y: {}", e))?;
+    let pending = send_proofs
+        .iter()
+        .map(|proof| {
+            ProofInfo::new(
+                proof.clone(),
+                mint_url_parsed.clone(),
+                State::PendingSpent,
+                wallet.unit.clone(),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to mark proofs pending: {}", e))?;
+    wallet
+        .localstore
+        .update_proofs(pending, vec![])
+        .await
+        .map_err(|e| format!("Failed to mark proofs pending: {}", e))?;
+
+    let amount: u64 = send_proofs
+        .iter()
+        .map(|proof| u64::from(proof.amount))
+        .sum();
+    let token = Token::new(
+        mint_url_parsed.clone(),
+        send_proofs,
+        options.memo.clone(),
+        wallet.unit.clone(),
+    );
+
     let mut metadata = HashMap::new();
-    metadata.insert("transaction_type".to_string(), "ecash_receive".to_string());
-    metadata.insert("ecash_token".to_string(), token.clone());
+    metadata.insert("transaction_type".to_string(), "ecash_send".to_string());
+    metadata.insert(
+        "coin_control".to_string(),
+        if options.swap { "swap" } else { "no_swap" }.to_string(),
+    );
+    wallet
+        .localstore
+        .add_transaction(Transaction {
+            mint_url: mint_url_parsed.clone(),
+            direction: TransactionDirection::Outgoing,
+            amount: Amount::from(amount),
+            fee: Amount::from(fee),
+            unit: wallet.unit.clone(),
+            ys: send_ys,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            memo: options.memo,
+            metadata,
+            quote_id: None,
+            payment_request: None,
+            payment_proof: None,
+        })
+        .await
+        .map_err(|e| format!("Failed to record transaction: {}", e))?;
 
-    let receive_options = ReceiveOptions {
-        metadata,
-        ..Default::default()
+    Ok(token.to_string())
+}
+
+fn parse_transport(transport: PaymentRequestTransport) -> Result<Transport, String> {
+    let transport_type = match transport.transport_type.trim().to_lowercase().as_str() {
+        "nostr" => TransportType::Nostr,
+        "post" | "http" | "http_post" => TransportType::HttpPost,
+        other => return Err(format!("Unsupported transport type '{}'", other)),
     };
-    let received_amount = wallet
-        .receive(&token, receive_options)
+
+    Ok(Transport {
+        _type: transport_type,
+        target: transport.target,
+        tags: if transport.tags.is_empty() {
+            None
+        } else {
+            Some(transport.tags)
+        },
+    })
+}
+
+/// Create a NUT-18 payment request (creqA...) - defaults to sat unit
+pub async fn create_payment_request(
+    amount: Option<u64>,
+    mints: Option<Vec<String>>,
+    description: Option<String>,
+    single_use: Option<bool>,
+    p2pk_pubkey: Option<String>,
+    transports: Vec<PaymentRequestTransport>,
+) -> Result<String, String> {
+    let mints = mints
+        .unwrap_or_default()
+        .iter()
+        .map(|m| MintUrl::from_str(m).map_err(|e| format!("Invalid mint URL '{}': {}", m, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let transports = transports
+        .into_iter()
+        .map(parse_transport)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Ask the payer to lock the ecash to our key
+    let nut10 = match p2pk_pubkey {
+        Some(pubkey) => {
+            let pubkey = parse_public_key(&pubkey)?;
+            Some(Nut10SecretRequest::new(Kind::P2PK, pubkey.to_hex(), None))
+        }
+        None => None,
+    };
+
+    let payment_request = PaymentRequest {
+        payment_id: Some(hex::encode(random::<[u8; 8]>())),
+        amount: amount.map(Amount::from),
+        unit: Some(CurrencyUnit::Sat),
+        single_use,
+        mints: if mints.is_empty() { None } else { Some(mints) },
+        description,
+        transports,
+        nut10,
+    };
+
+    Ok(payment_request.to_string())
+}
+
+/// Decode a NUT-18 payment request
+pub async fn decode_payment_request(request: String) -> Result<PaymentRequestInfo, String> {
+    let payment_request = PaymentRequest::from_str(request.trim())
+        .map_err(|e| format!("Failed to parse payment request: {}", e))?;
+
+    let transports = payment_request
+        .transports
+        .iter()
+        .map(|t| PaymentRequestTransport {
+            transport_type: match t._type {
+                TransportType::Nostr => "nostr",
+                TransportType::HttpPost => "post",
+            }
+            .to_string(),
+            target: t.target.clone(),
+            tags: t.tags.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(PaymentRequestInfo {
+        payment_id: payment_request.payment_id,
+        amount: payment_request.amount.map(u64::from),
+        unit: payment_request.unit.map(|u| u.to_string()),
+        single_use: payment_request.single_use,
+        mints: payment_request
+            .mints
+            .unwrap_or_default()
+            .iter()
+            .map(|m| m.to_string())
+            .collect(),
+        description: payment_request.description,
+        transports,
+        lock_kind: payment_request.nut10.as_ref().map(|n| n.kind.to_string()),
+        lock_data: payment_request.nut10.map(|n| n.data),
+    })
+}
+
+/// Pay a NUT-18 payment request - defaults to sat unit
+/// The token is delivered through the first of the request's HTTP POST or Nostr
+/// transports that accepts it. If the request has no transport the token is returned for
+/// the caller to hand over in-band.
+/// amount is only used when the request does not specify one.
+pub async fn pay_payment_request(
+    handle: WalletHandle,
+    request: String,
+    mint_url: Option<String>,
+    amount: Option<u64>,
+) -> Result<HashMap<String, String>, String> {
+    ensure_online(&handle).await?;
+    let payment_request = PaymentRequest::from_str(request.trim())
+        .map_err(|e| format!("Failed to parse payment request: {}", e))?;
+
+    if let Some(unit) = &payment_request.unit {
+        if *unit != CurrencyUnit::Sat {
+            return Err(format!("Unsupported payment request unit '{}'", unit));
+        }
+    }
+
+    let amount = payment_request
+        .amount
+        .map(u64::from)
+        .or(amount)
+        .ok_or("Payment request has no amount, an amount is required")?;
+
+    // Pick a mint the receiver accepts and we hold enough ecash at
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
+    let balances = multi_mint_wallet
+        .get_balances()
         .await
-        .map_err(|e| format!("Failed to receive: {}", e))?;
+        .map_err(|e| format!("Failed to get balances: {}", e))?;
+    let allowed_mints = payment_request.mints.clone().unwrap_or_default();
+    let mint_url = match mint_url {
+        Some(mint_url) => {
+            let parsed =
+                MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+            if !allowed_mints.is_empty() && !allowed_mints.contains(&parsed) {
+                return Err("Payment request does not accept this mint".to_string());
+            }
+            parsed
+        }
+        None => balances
+            .iter()
+            .filter(|(mint, _)| allowed_mints.is_empty() || allowed_mints.contains(mint))
+            .find(|(_, balance)| u64::from(**balance) >= amount)
+            .map(|(mint, _)| mint.clone())
+            .ok_or("No accepted mint has enough balance for this payment request")?,
+    };
+
+    // Lock the ecash exactly the way the receiver asked for
+    let token = match &payment_request.nut10 {
+        Some(nut10) => {
+            let spending_conditions = payment_request_conditions(nut10)?;
+            send_locked_tokens(
+                &handle,
+                mint_url.to_string(),
+                amount,
+                payment_request.description.clone(),
+                spending_conditions,
+                nut10.data.clone(),
+            )
+            .await?
+        }
+        None => {
+            send_tokens(
+                handle.clone(),
+                mint_url.to_string(),
+                amount,
+                payment_request.description.clone(),
+            )
+            .await?
+        }
+    };
+
+    let mut result = HashMap::new();
+    result.insert("token".to_string(), token.clone());
+    result.insert("mint_url".to_string(), mint_url.to_string());
+    result.insert("amount".to_string(), amount.to_string());
+
+    if payment_request.transports.is_empty() {
+        result.insert("delivered_via".to_string(), "none".to_string());
+        return Ok(result);
+    }
+
+    let cashu_token =
+        Token::from_str(&token).map_err(|e| format!("Failed to parse token: {}", e))?;
+    let proofs = token_proofs(&multi_mint_wallet, &cashu_token).await?;
+
+    let payload = PaymentRequestPayload {
+        id: payment_request.payment_id.clone(),
+        memo: payment_request.description.clone(),
+        mint: mint_url,
+        unit: CurrencyUnit::Sat,
+        proofs,
+    };
+    let payload_json = serde_json::to_string(&payload)
+        .map_err(|e| format!("Failed to serialize payment: {}", e))?;
+
+    // Try the transports in the receiver's order until one takes the payment. The token
+    // has been created at this point, so if none does it is returned through the error
+    // message to avoid losing funds.
+    let mut errors = Vec::new();
+    for transport in &payment_request.transports {
+        let delivered = match transport._type {
+            TransportType::HttpPost => {
+                deliver_payment_over_http(&handle, &transport.target, &payload_json)
+                    .await
+                    .map(|_| "post")
+            }
+            TransportType::Nostr => {
+                deliver_payment_over_nostr(&transport.target, payload_json.clone())
+                    .await
+                    .map(|_| "nostr")
+            }
+        };
+        match delivered {
+            Ok(delivered_via) => {
+                result.insert("delivered_via".to_string(), delivered_via.to_string());
+                return Ok(result);
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    Err(format!(
+        "Failed to deliver payment: {}. Token: {}",
+        errors.join("; "),
+        token
+    ))
+}
+
+/// POST a NUT-18 payload to the transport target, through the profile's proxy or Tor
+async fn deliver_payment_over_http(
+    handle: &WalletHandle,
+    target: &str,
+    payload_json: &str,
+) -> Result<(), String> {
+    let url = Url::parse(target).map_err(|e| format!("Invalid HTTP transport target: {}", e))?;
+    let response = service_http_client(handle, &url)
+        .await?
+        .post(url)
+        .header("Content-Type", "application/json")
+        .body(payload_json.to_string())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to post payment to {}: {}", target, e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Payment request endpoint {} returned {}",
+            target,
+            response.status()
+        ));
+    }
+    Ok(())
+}
+
+/// Send a NUT-18 payload as a NIP-17 direct message to the nprofile in the transport target
+async fn deliver_payment_over_nostr(target: &str, payload_json: String) -> Result<(), String> {
+    use nostr::event::EventBuilder;
+    use nostr::key::Keys;
+    use nostr::nips::nip19::{FromBech32, Nip19Profile};
+
+    let profile = Nip19Profile::from_bech32(target)
+        .map_err(|e| format!("Invalid nostr transport target: {}", e))?;
+    if profile.relays.is_empty() {
+        return Err("Nostr transport target has no relays".to_string());
+    }
+
+    // NUT-18 payments are sent from a throwaway key
+    let sender = Keys::generate();
+    let rumor = EventBuilder::private_msg_rumor(profile.public_key, payload_json)
+        .build(sender.public_key());
+    let gift_wrap = EventBuilder::gift_wrap(&sender, &profile.public_key, rumor, [])
+        .await
+        .map_err(|e| format!("Failed to wrap payment message: {}", e))?;
+
+    let relays: Vec<String> = profile.relays.iter().map(|r| r.to_string()).collect();
+    crate::relay::publish_event(&relays, &gift_wrap).await?;
+
+    Ok(())
+}
+
+/// Receive tokens using CDK MultiMintWallet API directly - auto-detects mint URL from token
+pub async fn receive_tokens(handle: WalletHandle, token: String) -> Result<u64, String> {
+    ensure_online(&handle).await?;
+    // Parse token to get mint URL
+    let cashu_token =
+        Token::from_str(&token).map_err(|e| format!("Failed to parse token: {}", e))?;
+
+    let token_mint_url = cashu_token
+        .mint_url()
+        .map_err(|e| format!("Failed to get mint URL from token: {}", e))?;
+    let config = prepare_mint_connection(&handle, &token_mint_url).await?;
+
+    let (multi_mint_wallet, _operation) = begin_wallet_operation(&handle).await?;
+
+    // If mint doesn't exist, add it automatically
+    if !multi_mint_wallet.has_mint(&token_mint_url).await {
+        // Add the mint automatically
+        register_mint(&multi_mint_wallet, &token_mint_url, config)
+            .await
+            .map_err(|e| format!("Failed to add mint automatically: {}", e))?;
+    }
+
+    // Get wallet for receiving
+    let wallet = multi_mint_wallet
+        .get_wallet(&token_mint_url)
+        .await
+        .ok_or("Failed to get wallet")?;
+
+    // Locked proofs can only be redeemed if the mint understands the lock
+    let mut required = Vec::new();
+    for proof in token_proofs(&multi_mint_wallet, &cashu_token).await? {
+        let capability = match Nut10Secret::try_from(&proof.secret).map(|s| s.kind()) {
+            Ok(Kind::P2PK) => MintCapability::P2pk,
+            Ok(Kind::HTLC) => MintCapability::Htlc,
+            _ => continue,
+        };
+        if !required.contains(&capability) {
+            required.push(capability);
+        }
+    }
+    require_capabilities(&wallet, &required).await?;
+
+    // Add metadata for transaction tracking
+    let mut metadata = HashMap::new();
+    metadata.insert("transaction_type".to_string(), "ecash_receive".to_string());
+    metadata.insert("ecash_token".to_string(), token.clone());
+
+    let receive_options = ReceiveOptions {
+        metadata,
+        ..Default::default()
+    };
+    let received_amount = wallet
+        .receive(&token, receive_options)
+        .await
+        .map_err(|e| format!("Failed to receive: {}", e))?;
+
+    Ok(received_amount.into())
+}
+
+/// Restore wallet balance for a specific mint
+pub async fn restore_mint(handle: WalletHandle, mint_url: String) -> Result<u64, String> {
+    ensure_online(&handle).await?;
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let (multi_mint_wallet, _operation) = begin_wallet_operation(&handle).await?;
+
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        return Err("Mint not found in wallet".to_string());
+    }
+
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+    require_capabilities(
+        &wallet,
+        &[MintCapability::Restore, MintCapability::TokenState],
+    )
+    .await?;
+
+    let restored_amount = multi_mint_wallet
+        .restore(&mint_url_parsed)
+        .await
+        .map_err(|e| format!("Failed to restore mint: {}", e))?;
+
+    Ok(restored_amount.into())
+}
+
+/// Create mint quote using CDK MultiMintWallet API directly - defaults to sat unit
+
+pub async fn create_mint_quote(
+    handle: WalletHandle,
+    mint_url: String,
+    amount: u64,
+    description: Option<String>,
+) -> Result<HashMap<String, String>, String> {
+    ensure_online(&handle).await?;
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
+
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        return Err("Mint not found in wallet".to_string());
+    }
+
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+    let info = require_capabilities(&wallet, &[MintCapability::Bolt11Mint]).await?;
+    check_method_limits(&info, &wallet.unit, PaymentMethod::Bolt11, true, amount)?;
+
+    // Use CDK MultiMintWallet API directly
+    let mint_amount = Amount::from(amount);
+    let quote = multi_mint_wallet
+        .mint_quote(&mint_url_parsed, mint_amount, description)
+        .await
+        .map_err(|e| format!("Failed to create mint quote: {}", e))?;
+
+    let mut result = HashMap::new();
+    result.insert("quote_id".to_string(), quote.id);
+    result.insert("request".to_string(), quote.request);
+    result.insert(
+        "amount".to_string(),
+        u64::from(quote.amount.unwrap_or(Amount::ZERO)).to_string(),
+    );
+    result.insert("unit".to_string(), quote.unit.to_string());
+
+    Ok(result)
+}
+
+/// Redeem an LNURL-withdraw code into a mint quote - defaults to sat unit
+/// Progress is reported through the wallet event stream. If amount is None the
+/// maximum withdrawable amount is requested.
+pub async fn redeem_lnurl_withdraw(
+    handle: WalletHandle,
+    lnurl: String,
+    mint_url: String,
+    amount: Option<u64>,
+) -> Result<u64, String> {
+    ensure_online(&handle).await?;
+    let result = redeem_lnurl_withdraw_inner(&handle, &lnurl, &mint_url, amount).await;
+
+    if let Err(e) = &result {
+        emit_wallet_event(
+            WalletEvent::new("lnurl_withdraw_failed")
+                .profile_id(&handle.profile_id)
+                .mint_url(&mint_url)
+                .message(e),
+        );
+    }
+
+    result
+}
+
+async fn redeem_lnurl_withdraw_inner(
+    handle: &WalletHandle,
+    lnurl: &str,
+    mint_url: &str,
+    amount: Option<u64>,
+) -> Result<u64, String> {
+    let client = crate::lnurl::http_client(handle);
+    let withdraw_request = crate::lnurl::fetch_withdraw_request(client.as_ref(), lnurl).await?;
+
+    let min_sats = withdraw_request.min_withdrawable.div_ceil(1000);
+    let max_sats = withdraw_request.max_withdrawable / 1000;
+    let amount = amount.unwrap_or(max_sats);
+    if amount == 0 || amount < min_sats || amount > max_sats {
+        return Err(format!(
+            "Amount must be between {} and {} sats",
+            min_sats, max_sats
+        ));
+    }
+
+    emit_wallet_event(
+        WalletEvent::new("lnurl_withdraw_resolved")
+            .profile_id(&handle.profile_id)
+            .mint_url(mint_url)
+            .amount(amount)
+            .message(&withdraw_request.default_description),
+    );
+
+    // Create the mint quote through the regular path so it shows up like any other
+    let description = if withdraw_request.default_description.is_empty() {
+        None
+    } else {
+        Some(withdraw_request.default_description.clone())
+    };
+    let quote =
+        create_mint_quote(handle.clone(), mint_url.to_string(), amount, description).await?;
+    let quote_id = quote
+        .get("quote_id")
+        .cloned()
+        .ok_or("Mint quote has no id")?;
+    let invoice = quote
+        .get("request")
+        .cloned()
+        .ok_or("Mint quote has no invoice")?;
+
+    emit_wallet_event(
+        WalletEvent::new("lnurl_withdraw_quote_created")
+            .profile_id(&handle.profile_id)
+            .mint_url(mint_url)
+            .quote_id(&quote_id)
+            .amount(amount),
+    );
+
+    crate::lnurl::submit_withdraw_invoice(client.as_ref(), &withdraw_request, &invoice).await?;
+
+    emit_wallet_event(
+        WalletEvent::new("lnurl_withdraw_invoice_submitted")
+            .profile_id(&handle.profile_id)
+            .mint_url(mint_url)
+            .quote_id(&quote_id)
+            .amount(amount),
+    );
+
+    let mint_url_parsed =
+        MintUrl::from_str(mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let (multi_mint_wallet, operation) = begin_wallet_operation(handle).await?;
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+
+    // The service pays asynchronously, poll the quote until the mint sees the payment
+    let deadline = std::time::Instant::now() + LNURL_WITHDRAW_TIMEOUT;
+    loop {
+        let state = wallet
+            .mint_quote_state(&quote_id)
+            .await
+            .map_err(|e| format!("Failed to check mint quote: {}", e))?;
+
+        match state.state {
+            MintQuoteState::Paid => break,
+            MintQuoteState::Issued => {
+                return Err("Mint quote was already issued".to_string());
+            }
+            MintQuoteState::Unpaid => {
+                if std::time::Instant::now() >= deadline {
+                    return Err(
+                        "Timed out waiting for the LNURL-withdraw service to pay".to_string()
+                    );
+                }
+                tokio::select! {
+                    _ = tokio::time::sleep(LNURL_WITHDRAW_POLL_INTERVAL) => {}
+                    _ = operation.cancelled() => {
+                        return Err("Wallet is shutting down".to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    emit_wallet_event(
+        WalletEvent::new("lnurl_withdraw_paid")
+            .profile_id(&handle.profile_id)
+            .mint_url(mint_url)
+            .quote_id(&quote_id)
+            .amount(amount),
+    );
+
+    let proofs = wallet
+        .mint(&quote_id, SplitTarget::default(), None)
+        .await
+        .map_err(|e| format!("Failed to mint proofs: {}", e))?;
+    let minted: u64 = proofs
+        .iter()
+        .fold(Amount::ZERO, |total, proof| total + proof.amount)
+        .into();
+
+    emit_wallet_event(
+        WalletEvent::new("lnurl_withdraw_minted")
+            .profile_id(&handle.profile_id)
+            .mint_url(mint_url)
+            .quote_id(&quote_id)
+            .amount(minted),
+    );
+
+    Ok(minted)
+}
+
+/// Mint feature an operation depends on, checked against the mint's NUT-06 info
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MintCapability {
+    Bolt11Mint,
+    Bolt11Melt,
+    Bolt12Mint,
+    Bolt12Melt,
+    TokenState,
+    Restore,
+    SpendingConditions,
+    P2pk,
+    Dleq,
+    Htlc,
+    Mpp,
+    WebSocket,
+}
+
+impl MintCapability {
+    const ALL: [MintCapability; 12] = [
+        MintCapability::Bolt11Mint,
+        MintCapability::Bolt11Melt,
+        MintCapability::Bolt12Mint,
+        MintCapability::Bolt12Melt,
+        MintCapability::TokenState,
+        MintCapability::Restore,
+        MintCapability::SpendingConditions,
+        MintCapability::P2pk,
+        MintCapability::Dleq,
+        MintCapability::Htlc,
+        MintCapability::Mpp,
+        MintCapability::WebSocket,
+    ];
+
+    /// NUT that defines the capability
+    fn nut(self) -> u32 {
+        match self {
+            MintCapability::Bolt11Mint | MintCapability::Bolt12Mint => 4,
+            MintCapability::Bolt11Melt | MintCapability::Bolt12Melt => 5,
+            MintCapability::TokenState => 7,
+            MintCapability::Restore => 9,
+            MintCapability::SpendingConditions => 10,
+            MintCapability::P2pk => 11,
+            MintCapability::Dleq => 12,
+            MintCapability::Htlc => 14,
+            MintCapability::Mpp => 15,
+            MintCapability::WebSocket => 17,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            MintCapability::Bolt11Mint => "bolt11_mint",
+            MintCapability::Bolt11Melt => "bolt11_melt",
+            MintCapability::Bolt12Mint => "bolt12_mint",
+            MintCapability::Bolt12Melt => "bolt12_melt",
+            MintCapability::TokenState => "token_state",
+            MintCapability::Restore => "restore",
+            MintCapability::SpendingConditions => "spending_conditions",
+            MintCapability::P2pk => "p2pk",
+            MintCapability::Dleq => "dleq",
+            MintCapability::Htlc => "htlc",
+            MintCapability::Mpp => "mpp",
+            MintCapability::WebSocket => "websocket",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.name() == name.trim().to_lowercase())
+            .ok_or_else(|| format!("Unknown mint capability '{}'", name))
+    }
+
+    pub(crate) fn is_supported(self, info: &cdk::nuts::MintInfo, unit: &CurrencyUnit) -> bool {
+        let nuts = &info.nuts;
+        let mint_method = |method: PaymentMethod| {
+            !nuts.nut04.disabled && nuts.nut04.get_settings(unit, &method).is_some()
+        };
+        let melt_method = |method: PaymentMethod| {
+            !nuts.nut05.disabled && nuts.nut05.get_settings(unit, &method).is_some()
+        };
+
+        match self {
+            MintCapability::Bolt11Mint => mint_method(PaymentMethod::Bolt11),
+            MintCapability::Bolt11Melt => melt_method(PaymentMethod::Bolt11),
+            MintCapability::Bolt12Mint => mint_method(PaymentMethod::Bolt12),
+            MintCapability::Bolt12Melt => melt_method(PaymentMethod::Bolt12),
+            MintCapability::TokenState => nuts.nut07.supported,
+            MintCapability::Restore => nuts.nut09.supported,
+            MintCapability::SpendingConditions => nuts.nut10.supported,
+            // P2PK and HTLC locks are NUT-10 secrets, the mint needs both NUTs
+            MintCapability::P2pk => nuts.nut10.supported && nuts.nut11.supported,
+            MintCapability::Dleq => nuts.nut12.supported,
+            MintCapability::Htlc => nuts.nut10.supported && nuts.nut14.supported,
+            MintCapability::Mpp => nuts.nut15.methods.iter().any(|m| m.unit == *unit),
+            MintCapability::WebSocket => nuts.nut17.supported.iter().any(|s| s.unit == *unit),
+        }
+    }
+}
+
+/// Error for an operation the mint does not support
+/// Wallet APIs return it as a string, parse_unsupported_by_mint turns it back into
+/// this struct.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnsupportedByMint {
+    pub mint_url: String,
+    pub nut: u32,
+    // e.g. "p2pk", "dleq", "restore"
+    pub capability: String,
+}
+
+const UNSUPPORTED_BY_MINT_PREFIX: &str = "Unsupported by mint: ";
+
+impl std::fmt::Display for UnsupportedByMint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}NUT-{:02} ({}) is not supported by {}",
+            UNSUPPORTED_BY_MINT_PREFIX, self.nut, self.capability, self.mint_url
+        )
+    }
+}
+
+/// Recover the typed error from a wallet API error message
+/// Returns None for any other error.
+#[frb(sync)]
+pub fn parse_unsupported_by_mint(error: String) -> Option<UnsupportedByMint> {
+    let (_, rest) = error.split_once(UNSUPPORTED_BY_MINT_PREFIX)?;
+    let rest = rest.strip_prefix("NUT-")?;
+    let (nut, rest) = rest.split_once(" (")?;
+    let (capability, mint_url) = rest.split_once(") is not supported by ")?;
+    Some(UnsupportedByMint {
+        mint_url: mint_url.to_string(),
+        nut: nut.parse().ok()?,
+        capability: capability.to_string(),
+    })
+}
+
+/// Mint info stored when the mint was added, falling back to the network
+async fn cached_mint_info(wallet: &Wallet) -> Result<cdk::nuts::MintInfo, String> {
+    let cached_info = wallet
+        .localstore
+        .get_mint(wallet.mint_url.clone())
+        .await
+        .map_err(|e| format!("Failed to read mint info: {}", e))?;
+
+    match cached_info {
+        Some(info) => Ok(info),
+        None => wallet
+            .fetch_mint_info()
+            .await
+            .map_err(|e| format!("Failed to get mint info: {}", e))?
+            .ok_or_else(|| "Mint info not available".to_string()),
+    }
+}
+
+/// Central capability check, run before every operation that relies on an optional NUT
+pub(crate) async fn require_capabilities(
+    wallet: &Wallet,
+    capabilities: &[MintCapability],
+) -> Result<cdk::nuts::MintInfo, String> {
+    let info = cached_mint_info(wallet).await?;
+    if let Some(missing) = capabilities
+        .iter()
+        .find(|c| !c.is_supported(&info, &wallet.unit))
+    {
+        return Err(UnsupportedByMint {
+            mint_url: wallet.mint_url.to_string(),
+            nut: missing.nut(),
+            capability: missing.name().to_string(),
+        }
+        .to_string());
+    }
+    Ok(info)
+}
+
+/// Check an amount against the NUT-04 / NUT-05 limits the mint advertises
+fn check_method_limits(
+    info: &cdk::nuts::MintInfo,
+    unit: &CurrencyUnit,
+    method: PaymentMethod,
+    is_mint: bool,
+    amount: u64,
+) -> Result<(), String> {
+    let (min_amount, max_amount) = if is_mint {
+        match info.nuts.nut04.get_settings(unit, &method) {
+            Some(settings) => (settings.min_amount, settings.max_amount),
+            None => return Ok(()),
+        }
+    } else {
+        match info.nuts.nut05.get_settings(unit, &method) {
+            Some(settings) => (settings.min_amount, settings.max_amount),
+            None => return Ok(()),
+        }
+    };
+
+    if let Some(min) = min_amount.map(u64::from) {
+        if amount < min {
+            return Err(format!(
+                "Amount {} is below the mint's minimum of {} {}",
+                amount, min, unit
+            ));
+        }
+    }
+    if let Some(max) = max_amount.map(u64::from) {
+        if amount > max {
+            return Err(format!(
+                "Amount {} is above the mint's maximum of {} {}",
+                amount, max, unit
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check a capability by name before starting a flow that needs it
+/// Names: bolt11_mint, bolt11_melt, bolt12_mint, bolt12_melt, token_state, restore,
+/// spending_conditions, p2pk, dleq, htlc, mpp, websocket.
+pub async fn check_mint_capability(
+    handle: WalletHandle,
+    mint_url: String,
+    capability: String,
+) -> Result<(), String> {
+    let capability = MintCapability::from_name(&capability)?;
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let wallet = get_multi_mint_wallet(&handle)
+        .await?
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Mint not found in wallet")?;
+
+    require_capabilities(&wallet, &[capability]).await?;
+    Ok(())
+}
+
+/// Create a reusable BOLT12 mint quote (offer) - defaults to sat unit
+/// If amount is None the offer is amountless and can be paid any number of times.
+pub async fn create_bolt12_mint_quote(
+    handle: WalletHandle,
+    mint_url: String,
+    amount: Option<u64>,
+    description: Option<String>,
+) -> Result<HashMap<String, String>, String> {
+    ensure_online(&handle).await?;
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
+
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        return Err("Mint not found in wallet".to_string());
+    }
+
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+
+    let info = require_capabilities(&wallet, &[MintCapability::Bolt12Mint]).await?;
+    if let Some(amount) = amount {
+        check_method_limits(&info, &wallet.unit, PaymentMethod::Bolt12, true, amount)?;
+    }
+
+    let quote = wallet
+        .mint_bolt12_quote(amount.map(Amount::from), description)
+        .await
+        .map_err(|e| format!("Failed to create BOLT12 mint quote: {}", e))?;
+
+    let mut result = HashMap::new();
+    result.insert("quote_id".to_string(), quote.id);
+    result.insert("request".to_string(), quote.request);
+    result.insert(
+        "amount".to_string(),
+        u64::from(quote.amount.unwrap_or(Amount::ZERO)).to_string(),
+    );
+    result.insert("unit".to_string(), quote.unit.to_string());
+    result.insert("payment_method".to_string(), "bolt12".to_string());
+    result.insert("expiry".to_string(), quote.expiry.to_string());
+
+    Ok(result)
+}
+
+/// Mint whatever has been paid to a BOLT12 quote but not yet issued
+/// Offers can be paid repeatedly, so this can be called again after each payment.
+pub async fn mint_bolt12_quote(
+    handle: WalletHandle,
+    mint_url: String,
+    quote_id: String,
+) -> Result<HashMap<String, String>, String> {
+    ensure_online(&handle).await?;
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let (multi_mint_wallet, _operation) = begin_wallet_operation(&handle).await?;
+
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        return Err("Mint not found in wallet".to_string());
+    }
+
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+
+    let state = wallet
+        .mint_bolt12_quote_state(&quote_id)
+        .await
+        .map_err(|e| format!("Failed to check BOLT12 quote state: {}", e))?;
+
+    let mut minted = Amount::ZERO;
+    if state.amount_paid > state.amount_issued {
+        let proofs = wallet
+            .mint_bolt12(&quote_id, None, SplitTarget::default(), None)
+            .await
+            .map_err(|e| format!("Failed to mint BOLT12 quote: {}", e))?;
+
+        minted = proofs
+            .iter()
+            .fold(Amount::ZERO, |total, proof| total + proof.amount);
+    }
+
+    let mut result = HashMap::new();
+    result.insert("quote_id".to_string(), quote_id);
+    result.insert("minted".to_string(), u64::from(minted).to_string());
+    result.insert(
+        "amount_paid".to_string(),
+        u64::from(state.amount_paid).to_string(),
+    );
+    result.insert(
+        "amount_issued".to_string(),
+        (u64::from(state.amount_issued) + u64::from(minted)).to_string(),
+    );
+    result.insert("unit".to_string(), wallet.unit.to_string());
+
+    Ok(result)
+}
+
+/// Import proofs and tokens from another wallet's backup
+/// Accepts nutshell exports, cashu.me backups, JSON proof dumps and lists of tokens.
+/// Proofs are checked with their mints, unspent ones are swapped into this wallet.
+/// Mints in the backup are added to the wallet. A failing mint does not stop the others.
+pub async fn import_wallet_backup(
+    handle: WalletHandle,
+    backup: String,
+) -> Result<ImportReport, String> {
+    ensure_online(&handle).await?;
+    let parsed = crate::import::parse_backup(&backup)?;
+
+    // The same mint may be spelled differently by proofs and tokens
+    let mut by_mint: BTreeMap<String, (Vec<Proof>, Vec<Token>)> = BTreeMap::new();
+    let mut invalid_mints = Vec::new();
+    for (mint_url, proofs) in parsed.proofs {
+        match MintUrl::from_str(&mint_url) {
+            Ok(url) => by_mint.entry(url.to_string()).or_default().0.extend(proofs),
+            Err(e) => invalid_mints.push(ImportedMint {
+                mint_url,
+                proofs_found: proofs.len() as u64,
+                amount_found: proofs.iter().map(|p| u64::from(p.amount)).sum(),
+                spent_amount: 0,
+                imported_amount: 0,
+                error: Some(format!("Invalid mint URL: {}", e)),
+            }),
+        }
+    }
+    for (mint_url, tokens) in parsed.tokens {
+        by_mint.entry(mint_url).or_default().1.extend(tokens);
+    }
+
+    let mut mints = invalid_mints;
+    for (mint_url, (proofs, tokens)) in by_mint {
+        mints.push(import_mint_proofs(&handle, mint_url, proofs, tokens).await);
+    }
+
+    Ok(ImportReport {
+        format: parsed.format.to_string(),
+        imported_amount: mints.iter().map(|mint| mint.imported_amount).sum(),
+        mints,
+        unassigned_proofs: parsed.unassigned,
+        has_mnemonic: parsed.mnemonic.is_some(),
+        keyset_counters: keyset_counters(&parsed.counters),
+    })
+}
+
+/// Open the seed in another wallet's backup as a new profile and restore its mints
+/// Every mint the backup mentions is added to the profile and restored with NUT-09,
+/// which also moves the keyset counters past the ones the other wallet used.
+/// The mnemonic is only returned here, import_wallet_backup never reports it.
+pub async fn restore_backup_profile(
+    profile_id: String,
+    database_dir: String,
+    backup: String,
+) -> Result<RestoredProfile, String> {
+    validate_profile_id(&profile_id)?;
+    let parsed = crate::import::parse_backup(&backup)?;
+    let mnemonic = parsed
+        .mnemonic
+        .clone()
+        .ok_or("Backup does not contain a mnemonic")?;
+    if get_multi_mint_database_path(&get_profile_dir(&database_dir, &profile_id)).exists() {
+        return Err(format!("Wallet profile '{}' already exists", profile_id));
+    }
+
+    let seed_hex = mnemonic_to_seed_hex(mnemonic.clone()).await?;
+    let handle = open_wallet(profile_id, database_dir, seed_hex).await?;
+
+    // The same mint may be spelled differently in different parts of the backup
+    let mint_urls: BTreeSet<String> = parsed
+        .all_mints()
+        .into_iter()
+        .map(|url| MintUrl::from_str(&url).map_or(url, |url| url.to_string()))
+        .collect();
+
+    let mut mints = Vec::new();
+    for mint_url in mint_urls {
+        let mut restored = RestoredMint {
+            mint_url: mint_url.clone(),
+            restored_amount: 0,
+            error: None,
+        };
+        let result = match add_mint(handle.clone(), mint_url.clone()).await {
+            Ok(_) => restore_mint(handle.clone(), mint_url).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(amount) => restored.restored_amount = amount,
+            Err(e) => restored.error = Some(e),
+        }
+        mints.push(restored);
+    }
+
+    Ok(RestoredProfile {
+        handle,
+        mnemonic,
+        restored_amount: mints.iter().map(|mint| mint.restored_amount).sum(),
+        mints,
+        keyset_counters: keyset_counters(&parsed.counters),
+    })
+}
+
+fn keyset_counters(counters: &BTreeMap<String, u32>) -> Vec<KeysetCounter> {
+    counters
+        .iter()
+        .map(|(keyset_id, counter)| KeysetCounter {
+            keyset_id: keyset_id.clone(),
+            counter: *counter,
+        })
+        .collect()
+}
+
+async fn import_mint_proofs(
+    handle: &WalletHandle,
+    mint_url: String,
+    proofs: Vec<Proof>,
+    tokens: Vec<Token>,
+) -> ImportedMint {
+    let mut report = ImportedMint {
+        mint_url: mint_url.clone(),
+        proofs_found: 0,
+        amount_found: 0,
+        spent_amount: 0,
+        imported_amount: 0,
+        error: None,
+    };
+    if let Err(e) = import_mint_proofs_inner(handle, &mint_url, proofs, tokens, &mut report).await {
+        report.error = Some(e);
+    }
+    report
+}
+
+async fn import_mint_proofs_inner(
+    handle: &WalletHandle,
+    mint_url: &str,
+    mut proofs: Vec<Proof>,
+    tokens: Vec<Token>,
+    report: &mut ImportedMint,
+) -> Result<(), String> {
+    let mint_url_parsed =
+        MintUrl::from_str(mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+    let config = prepare_mint_connection(handle, &mint_url_parsed).await?;
+    let (multi_mint_wallet, _operation) = begin_wallet_operation(handle).await?;
+
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        register_mint(&multi_mint_wallet, &mint_url_parsed, config)
+            .await
+            .map_err(|e| format!("Failed to add mint: {}", e))?;
+    }
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+
+    for token in &tokens {
+        proofs.extend(token_proofs(&multi_mint_wallet, token).await?);
+    }
+    // Backups often hold the same proof more than once
+    let mut seen = std::collections::HashSet::new();
+    proofs.retain(|proof| seen.insert(proof.secret.clone()));
+    report.proofs_found = proofs.len() as u64;
+    report.amount_found = proofs.iter().map(|p| u64::from(p.amount)).sum();
+    if proofs.is_empty() {
+        return Ok(());
+    }
+
+    require_capabilities(&wallet, &[MintCapability::TokenState]).await?;
+    let states = wallet
+        .check_proofs_spent(proofs.clone())
+        .await
+        .map_err(|e| format!("Failed to check proof states: {}", e))?;
+    let unspent_ys: std::collections::HashSet<PublicKey> = states
+        .into_iter()
+        .filter(|state| state.state == State::Unspent)
+        .map(|state| state.y)
+        .collect();
+    let (unspent, spent): (Vec<Proof>, Vec<Proof>) = proofs
+        .into_iter()
+        .partition(|proof| proof.y().is_ok_and(|y| unspent_ys.contains(&y)));
+    report.spent_amount = spent.iter().map(|p| u64::from(p.amount)).sum();
+    if unspent.is_empty() {
+        return Ok(());
+    }
+
+    let mut metadata = HashMap::new();
+    metadata.insert("transaction_type".to_string(), "import".to_string());
+    let receive_options = ReceiveOptions {
+        metadata,
+        ..Default::default()
+    };
+    let received = wallet
+        .receive_proofs(unspent, receive_options, None)
+        .await
+        .map_err(|e| format!("Failed to swap imported proofs: {}", e))?;
+    report.imported_amount = received.into();
+    Ok(())
+}
+
+/// Client for one-off requests to a mint that may not be in the wallet
+/// Goes through mint_connection, so the same proxy and Tor rules apply as for mints
+/// added to the wallet.
+async fn standalone_mint_client(
+    handle: &WalletHandle,
+    mint_url: &MintUrl,
+) -> Result<Arc<dyn MintConnector + Send + Sync>, String> {
+    if let Some(connector) = mint_connection(handle, mint_url).await? {
+        return Ok(connector);
+    }
 
-    Ok(received_amount.into())
+    #[cfg(feature = "auth")]
+    let client = HttpClient::new(mint_url.clone(), None);
+    #[cfg(not(feature = "auth"))]
+    let client = HttpClient::new(mint_url.clone());
+    Ok(Arc::new(client))
 }
 
-/// Restore wallet balance for a specific mint
-pub async fn restore_mint(mint_url: String) -> Result<u64, String> {
-    let mint_url_parsed =
-        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+/// Whether every proof's keyset is among the keysets
+fn has_keysets(keyset_infos: &[KeySetInfo], proofs: &[Proof]) -> bool {
+    proofs
+        .iter()
+        .all(|proof| keyset_infos.iter().any(|info| info.id == proof.keyset_id))
+}
 
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?
-        .clone();
-    drop(wallet_guard);
+/// Keysets for offline verification, from the wallet database or, if allowed, the mint
+struct VerificationKeys {
+    // Set when the mint is in the wallet, its keysets may be cached
+    wallet: Option<Wallet>,
+    // Set when fetching from the mint is allowed
+    client: Option<Arc<dyn MintConnector + Send + Sync>>,
+    fetched: bool,
+}
 
-    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
-        return Err("Mint not found in wallet".to_string());
+impl VerificationKeys {
+    async fn open(
+        handle: &WalletHandle,
+        mint_url: &MintUrl,
+        allow_fetch: bool,
+    ) -> Result<Self, String> {
+        let multi_mint_wallet = get_multi_mint_wallet(handle).await?;
+        let offline = with_open_wallet(handle, |open| open.offline.load(Ordering::Relaxed)).await?;
+        let client = if allow_fetch && !offline {
+            Some(standalone_mint_client(handle, mint_url).await?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            wallet: multi_mint_wallet.get_wallet(mint_url).await,
+            client,
+            fetched: false,
+        })
     }
 
-    let restored_amount = multi_mint_wallet
-        .restore(&mint_url_parsed)
-        .await
-        .map_err(|e| format!("Failed to restore mint: {}", e))?;
+    fn client(&mut self) -> Result<Arc<dyn MintConnector + Send + Sync>, String> {
+        let client = self
+            .client
+            .clone()
+            .ok_or("Keys for this mint are not cached, allow fetching to verify")?;
+        self.fetched = true;
+        Ok(client)
+    }
 
-    Ok(restored_amount.into())
+    /// Keysets of the mint, from the cache while it has every keyset `covers` asks for
+    /// A keyset missing from the cache may be one the mint added since, so the
+    /// keysets are fetched again if allowed.
+    async fn keyset_infos(
+        &mut self,
+        covers: impl Fn(&[KeySetInfo]) -> bool,
+    ) -> Result<Vec<KeySetInfo>, String> {
+        if let Some(wallet) = &self.wallet {
+            let cached = wallet
+                .localstore
+                .get_mint_keysets(wallet.mint_url.clone())
+                .await
+                .map_err(|e| format!("Failed to read cached keysets: {}", e))?
+                .unwrap_or_default();
+            // Without fetching, unknown keysets are reported by the verification
+            if !cached.is_empty() && (covers(&cached) || self.client.is_none()) {
+                return Ok(cached);
+            }
+        }
+
+        self.client()?
+            .get_mint_keysets()
+            .await
+            .map(|response| response.keysets)
+            .map_err(|e| format!("Failed to fetch keysets: {}", e))
+    }
+
+    /// Keys of a keyset, checked against the keyset id
+    async fn keyset(&mut self, info: &KeySetInfo) -> Result<KeySet, String> {
+        let cached = match &self.wallet {
+            Some(wallet) => wallet
+                .localstore
+                .get_keys(&info.id)
+                .await
+                .map_err(|e| format!("Failed to read cached keys: {}", e))?,
+            None => None,
+        };
+        let keys = match cached {
+            Some(keys) => keys,
+            None => {
+                self.client()?
+                    .get_mint_keyset(info.id)
+                    .await
+                    .map_err(|e| format!("Failed to fetch keyset {}: {}", info.id, e))?
+                    .keys
+            }
+        };
+
+        let keyset = KeySet {
+            id: info.id,
+            unit: info.unit.clone(),
+            keys,
+            final_expiry: info.final_expiry,
+        };
+        keyset
+            .verify_id()
+            .map_err(|e| format!("Keyset {} does not match its keys: {}", info.id, e))?;
+        Ok(keyset)
+    }
 }
 
-/// Create mint quote using CDK MultiMintWallet API directly - defaults to sat unit
+/// Check that a NUT-10 secret is well formed, plain secrets only need to be non-empty
+fn validate_proof_secret(secret: &Secret) -> Result<(), String> {
+    let raw = secret.to_string();
+    if raw.is_empty() {
+        return Err("Secret is empty".to_string());
+    }
+    // Plain secrets are random strings, anything shaped like a JSON array claims to be NUT-10
+    if !raw.starts_with('[') {
+        return Ok(());
+    }
 
-pub async fn create_mint_quote(
-    mint_url: String,
-    amount: u64,
-    description: Option<String>,
-) -> Result<HashMap<String, String>, String> {
-    let mint_url_parsed =
-        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+    let nut10 =
+        Nut10Secret::try_from(secret).map_err(|e| format!("Invalid NUT-10 secret: {}", e))?;
+    let secret_data = nut10.secret_data();
+    match nut10.kind() {
+        Kind::P2PK => {
+            PublicKey::from_str(secret_data.data())
+                .map_err(|e| format!("Invalid P2PK public key: {}", e))?;
+        }
+        Kind::HTLC => {
+            let hash =
+                hex::decode(secret_data.data()).map_err(|e| format!("Invalid HTLC hash: {}", e))?;
+            if hash.len() != 32 {
+                return Err("HTLC hash must be 32 bytes".to_string());
+            }
+        }
+    }
+    if let Some(tags) = secret_data.tags() {
+        Conditions::try_from(tags.clone()).map_err(|e| format!("Invalid secret tags: {}", e))?;
+    }
+    Ok(())
+}
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+/// Verify proofs without asking the mint whether they are spent
+async fn verify_proofs_with_keys(
+    keys: &mut VerificationKeys,
+    mint_url: &MintUrl,
+    keyset_infos: &[KeySetInfo],
+    proofs: Vec<Proof>,
+) -> TokenVerification {
+    let mut keysets: HashMap<Id, Result<KeySet, String>> = HashMap::new();
+    for proof in &proofs {
+        if keysets.contains_key(&proof.keyset_id) {
+            continue;
+        }
+        let keyset = match keyset_infos.iter().find(|info| info.id == proof.keyset_id) {
+            Some(info) => keys.keyset(info).await,
+            None => Err(format!("Unknown keyset {}", proof.keyset_id)),
+        };
+        keysets.insert(proof.keyset_id, keyset);
+    }
+    let keysets_valid = keysets.values().all(|keyset| keyset.is_ok());
 
-    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
-        return Err("Mint not found in wallet".to_string());
+    let mut amount = 0u64;
+    let proofs: Vec<ProofVerification> = proofs
+        .into_iter()
+        .map(|proof| {
+            amount += u64::from(proof.amount);
+            let secret = validate_proof_secret(&proof.secret);
+            let dleq = match (&proof.dleq, &keysets[&proof.keyset_id]) {
+                (None, _) => Ok(None),
+                (Some(_), Err(e)) => Err(e.clone()),
+                (Some(_), Ok(keyset)) => match keyset.keys.amount_key(proof.amount) {
+                    Some(mint_pubkey) => Ok(Some(proof.verify_dleq(mint_pubkey).is_ok())),
+                    None => Err(format!(
+                        "Keyset {} has no key for amount {}",
+                        proof.keyset_id, proof.amount
+                    )),
+                },
+            };
+
+            let error = match (&secret, &dleq) {
+                (Err(e), _) | (_, Err(e)) => Some(e.clone()),
+                (_, Ok(None)) => Some("Proof has no DLEQ proof".to_string()),
+                (_, Ok(Some(false))) => {
+                    Some("DLEQ proof does not match the mint's key".to_string())
+                }
+                _ => None,
+            };
+            ProofVerification {
+                y: proof.y().map(|y| y.to_hex()).unwrap_or_default(),
+                amount: proof.amount.into(),
+                keyset_id: proof.keyset_id.to_string(),
+                dleq_valid: dleq.ok().flatten(),
+                secret_valid: secret.is_ok(),
+                error,
+            }
+        })
+        .collect();
+
+    TokenVerification {
+        mint_url: mint_url.to_string(),
+        amount: amount.into(),
+        keysets_valid,
+        fetched_keys: keys.fetched,
+        valid: keysets_valid && proofs.iter().all(|proof| proof.error.is_none()),
+        proofs,
     }
+}
 
-    // Use CDK MultiMintWallet API directly
-    let mint_amount = Amount::from(amount);
-    let quote = multi_mint_wallet
-        .mint_quote(&mint_url_parsed, mint_amount, description)
-        .await
-        .map_err(|e| format!("Failed to create mint quote: {}", e))?;
+/// Verify a token without the mint: DLEQ proofs against the keyset keys, keyset ids
+/// against their keys, and NUT-10 secret structure
+/// Works for mints that are not in the wallet. Keys that are not cached are fetched from
+/// the mint only when allow_fetch is set and the wallet is not in offline mode.
+/// Says nothing about whether the proofs are already spent.
+pub async fn verify_token_offline(
+    handle: WalletHandle,
+    token: String,
+    allow_fetch: bool,
+) -> Result<TokenVerification, String> {
+    let cashu_token = Token::from_str(&token).map_err(|e| format!("Invalid token: {}", e))?;
+    let mint_url = cashu_token
+        .mint_url()
+        .map_err(|e| format!("Failed to get mint URL from token: {}", e))?;
 
-    let mut result = HashMap::new();
-    result.insert("quote_id".to_string(), quote.id);
-    result.insert("request".to_string(), quote.request);
-    result.insert(
-        "amount".to_string(),
-        u64::from(quote.amount.unwrap_or(Amount::ZERO)).to_string(),
-    );
-    result.insert("unit".to_string(), quote.unit.to_string());
+    let mut keys = VerificationKeys::open(&handle, &mint_url, allow_fetch).await?;
+    // V4 tokens carry short keyset ids, the full ids come from the keysets
+    let keyset_infos = keys
+        .keyset_infos(|infos| {
+            cashu_token
+                .proofs(infos)
+                .is_ok_and(|proofs| has_keysets(infos, &proofs))
+        })
+        .await?;
+    let proofs = cashu_token
+        .proofs(&keyset_infos)
+        .map_err(|e| format!("Failed to read token proofs: {}", e))?;
 
-    Ok(result)
+    Ok(verify_proofs_with_keys(&mut keys, &mint_url, &keyset_infos, proofs).await)
+}
+
+/// Verify proofs of a mint without the mint, same checks as verify_token_offline
+pub async fn verify_proofs_offline(
+    handle: WalletHandle,
+    mint_url: String,
+    proofs: Vec<CashuProof>,
+    allow_fetch: bool,
+) -> Result<TokenVerification, String> {
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+    let proofs = proofs
+        .into_iter()
+        .map(Proof::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut keys = VerificationKeys::open(&handle, &mint_url_parsed, allow_fetch).await?;
+    let keyset_infos = keys
+        .keyset_infos(|infos| has_keysets(infos, &proofs))
+        .await?;
+    Ok(verify_proofs_with_keys(&mut keys, &mint_url_parsed, &keyset_infos, proofs).await)
 }
 
-/// Get wallet proofs - defaults to sat unit
+/// Get wallet proofs that are not spent yet, with their state - defaults to sat unit
 
-pub async fn get_wallet_proofs(mint_url: String) -> Result<Vec<CashuProof>, String> {
+pub async fn get_wallet_proofs(
+    handle: WalletHandle,
+    mint_url: String,
+) -> Result<Vec<CashuProof>, String> {
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
@@ -958,7 +4088,18 @@ pub async fn get_wallet_proofs(mint_url: String) -> Result<Vec<CashuProof>, Stri
         .ok_or("Failed to get wallet")?;
 
     let proofs = wallet
-        .get_unspent_proofs()
+        .localstore
+        .get_proofs(
+            Some(mint_url_parsed),
+            Some(wallet.unit.clone()),
+            Some(vec![
+                State::Unspent,
+                State::Pending,
+                State::Reserved,
+                State::PendingSpent,
+            ]),
+            None,
+        )
         .await
         .map_err(|e| format!("Failed to get proofs: {}", e))?;
 
@@ -1077,18 +4218,16 @@ pub async fn seed_hex_to_mnemonic(seed_hex: String) -> Result<String, String> {
 /// Pay lightning invoice using wallet tokens - defaults to sat unit
 
 pub async fn pay_invoice_for_wallet(
+    handle: WalletHandle,
     mint_url: String,
     bolt11_invoice: String,
     max_fee_sats: Option<u64>,
 ) -> Result<String, String> {
+    ensure_online(&handle).await?;
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let (multi_mint_wallet, mut operation) = begin_wallet_operation(&handle).await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
@@ -1104,11 +4243,34 @@ pub async fn pay_invoice_for_wallet(
     // Note: max_fee is not currently used in melt_quote, but kept for future use
     let _max_fee = max_fee_sats.map(Amount::from);
 
+    let info = require_capabilities(&wallet, &[MintCapability::Bolt11Melt]).await?;
+
     // First, get a melt quote for the invoice
     let quote = wallet
         .melt_quote(bolt11_invoice.clone(), None)
         .await
         .map_err(|e| format!("Failed to get melt quote: {}", e))?;
+    check_method_limits(
+        &info,
+        &wallet.unit,
+        PaymentMethod::Bolt11,
+        false,
+        u64::from(quote.amount),
+    )?;
+
+    // The worst case spend is the invoice amount plus the full fee reserve
+    let _spend = crate::api::limits::check_spend(
+        &handle,
+        &multi_mint_wallet,
+        &mint_url_parsed,
+        u64::from(quote.amount + quote.fee_reserve),
+    )
+    .await?;
+    operation.journal(
+        "melt",
+        &mint_url,
+        u64::from(quote.amount + quote.fee_reserve),
+    )?;
 
     // Then, execute the melt using the quote ID
     let melted = wallet
@@ -1120,9 +4282,128 @@ pub async fn pay_invoice_for_wallet(
     Ok(melted.state.to_string())
 }
 
+/// Pay a BOLT12 offer using wallet tokens - defaults to sat unit
+/// amount_sats is required when the offer does not specify an amount.
+pub async fn pay_bolt12_offer(
+    handle: WalletHandle,
+    mint_url: String,
+    offer: String,
+    amount_sats: Option<u64>,
+) -> Result<String, String> {
+    ensure_online(&handle).await?;
+    let mint_url_parsed =
+        MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let (multi_mint_wallet, mut operation) = begin_wallet_operation(&handle).await?;
+
+    if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
+        return Err("Mint not found in wallet".to_string());
+    }
+
+    // Get wallet for melting
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+
+    let info = require_capabilities(&wallet, &[MintCapability::Bolt12Melt]).await?;
+
+    // Amountless offers need the amount passed to the mint in millisatoshis
+    let offer_info = decode_bolt12_offer(offer.clone()).await?;
+    let melt_options = match (offer_info.amount_msat, amount_sats) {
+        (Some(_), _) => None,
+        (None, Some(sats)) => Some(MeltOptions::new_amountless(
+            sats.checked_mul(1000).ok_or("Amount is too large")?,
+        )),
+        (None, None) => return Err("Offer has no amount, an amount is required".to_string()),
+    };
+
+    // First, get a melt quote for the offer
+    let quote = wallet
+        .melt_bolt12_quote(offer, melt_options)
+        .await
+        .map_err(|e| format!("Failed to get BOLT12 melt quote: {}", e))?;
+    check_method_limits(
+        &info,
+        &wallet.unit,
+        PaymentMethod::Bolt12,
+        false,
+        u64::from(quote.amount),
+    )?;
+
+    let _spend = crate::api::limits::check_spend(
+        &handle,
+        &multi_mint_wallet,
+        &mint_url_parsed,
+        u64::from(quote.amount + quote.fee_reserve),
+    )
+    .await?;
+    operation.journal(
+        "bolt12_melt",
+        &mint_url,
+        u64::from(quote.amount + quote.fee_reserve),
+    )?;
+
+    // Then, execute the melt using the quote ID
+    let melted = wallet
+        .melt(&quote.id)
+        .await
+        .map_err(|e| format!("Failed to pay offer: {}", e))?;
+
+    // Return payment status
+    Ok(melted.state.to_string())
+}
+
+/// Resolve a lightning address (name@domain) or LNURL into its pay request
+/// The request goes through the wallet's proxy or Tor like mint connections do.
+pub async fn resolve_lnurl_pay(
+    handle: WalletHandle,
+    lnurl: String,
+) -> Result<LnurlPayInfo, String> {
+    ensure_online(&handle).await?;
+    let client = crate::lnurl::http_client(&handle);
+    let pay_request = crate::lnurl::fetch_pay_request(client.as_ref(), &lnurl).await?;
+
+    let domain = Url::parse(&pay_request.callback)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host.to_string()))
+        .unwrap_or_default();
+
+    Ok(LnurlPayInfo {
+        description: crate::lnurl::metadata_description(&pay_request.metadata),
+        min_sendable_sats: pay_request.min_sendable.div_ceil(1000),
+        max_sendable_sats: pay_request.max_sendable / 1000,
+        comment_allowed: pay_request.comment_allowed,
+        callback: pay_request.callback,
+        metadata: pay_request.metadata,
+        domain,
+    })
+}
+
+/// Pay a lightning address or LNURL-pay code using wallet tokens - defaults to sat unit
+pub async fn pay_lnurl(
+    handle: WalletHandle,
+    mint_url: String,
+    lnurl: String,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<String, String> {
+    ensure_online(&handle).await?;
+    let amount_msat = amount_sats.checked_mul(1000).ok_or("Amount is too large")?;
+    let client = crate::lnurl::http_client(&handle);
+    let pay_request = crate::lnurl::fetch_pay_request(client.as_ref(), &lnurl).await?;
+
+    // Fetch an invoice for the chosen amount, checked against the pay request metadata
+    let invoice =
+        crate::lnurl::request_invoice(client.as_ref(), &pay_request, amount_msat, comment).await?;
+
+    pay_invoice_for_wallet(handle, mint_url, invoice.to_string(), None).await
+}
+
 /// Verify token matches p2pk conditions - defaults to sat unit
 
 pub async fn verify_token_p2pk(
+    handle: WalletHandle,
     mint_url: String,
     token: String,
     conditions: String,
@@ -1138,11 +4419,7 @@ pub async fn verify_token_p2pk(
         serde_json::from_str(&conditions)
             .map_err(|e| format!("Invalid spending conditions: {}", e))?;
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
@@ -1160,23 +4437,29 @@ pub async fn verify_token_p2pk(
 
 /// Verify all proofs in token have valid dleq proof - defaults to sat unit
 
-pub async fn verify_token_dleq(mint_url: String, token: String) -> Result<bool, String> {
+pub async fn verify_token_dleq(
+    handle: WalletHandle,
+    mint_url: String,
+    token: String,
+) -> Result<bool, String> {
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
     // Parse token
     let cashu_token = Token::from_str(&token).map_err(|e| format!("Invalid token: {}", e))?;
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
     }
 
+    let wallet = multi_mint_wallet
+        .get_wallet(&mint_url_parsed)
+        .await
+        .ok_or("Failed to get wallet")?;
+    require_capabilities(&wallet, &[MintCapability::Dleq]).await?;
+
     // Use CDK MultiMintWallet API directly (no longer needs WalletKey)
     match multi_mint_wallet.verify_token_dleq(&cashu_token).await {
         Ok(_) => Ok(true),
@@ -1186,15 +4469,15 @@ pub async fn verify_token_dleq(mint_url: String, token: String) -> Result<bool,
 
 /// Check all mint quotes and automatically mint if paid - defaults to sat unit
 
-pub async fn check_mint_quote_status(mint_url: String) -> Result<String, String> {
+pub async fn check_mint_quote_status(
+    handle: WalletHandle,
+    mint_url: String,
+) -> Result<String, String> {
+    ensure_online(&handle).await?;
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let (multi_mint_wallet, _operation) = begin_wallet_operation(&handle).await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
@@ -1210,11 +4493,11 @@ pub async fn check_mint_quote_status(mint_url: String) -> Result<String, String>
 }
 
 /// Check all mint quotes across all wallets and automatically mint if paid
-pub async fn check_all_mint_quotes() -> Result<HashMap<String, String>, String> {
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+pub async fn check_all_mint_quotes(
+    handle: WalletHandle,
+) -> Result<HashMap<String, String>, String> {
+    ensure_online(&handle).await?;
+    let (multi_mint_wallet, _operation) = begin_wallet_operation(&handle).await?;
 
     let total_minted = multi_mint_wallet
         .check_all_mint_quotes(None)
@@ -1231,15 +4514,15 @@ pub async fn check_all_mint_quotes() -> Result<HashMap<String, String>, String>
 }
 
 /// Check melt quote status for a specific mint URL - automatically checks all melt quotes
-pub async fn check_melt_quote_status(mint_url: String) -> Result<String, String> {
+pub async fn check_melt_quote_status(
+    handle: WalletHandle,
+    mint_url: String,
+) -> Result<String, String> {
+    ensure_online(&handle).await?;
     let mint_url_parsed =
         MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
 
-    // Use the global MULTI_MINT_WALLET
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     if !multi_mint_wallet.has_mint(&mint_url_parsed).await {
         return Err("Mint not found in wallet".to_string());
@@ -1257,11 +4540,9 @@ pub async fn check_melt_quote_status(mint_url: String) -> Result<String, String>
 }
 
 /// Check all melt quotes across all wallets and return completed count
-pub async fn check_all_melt_quotes() -> Result<String, String> {
-    let wallet_guard = MULTI_MINT_WALLET.read().await;
-    let multi_mint_wallet = wallet_guard
-        .as_ref()
-        .ok_or("MultiMintWallet not initialized")?;
+pub async fn check_all_melt_quotes(handle: WalletHandle) -> Result<String, String> {
+    ensure_online(&handle).await?;
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
 
     let wallets = multi_mint_wallet.get_wallets().await;
     let mut total_completed_count = 0u64;
@@ -1269,7 +4550,7 @@ pub async fn check_all_melt_quotes() -> Result<String, String> {
     // Iterate through all wallets and call check_melt_quote_status for each
     for wallet in wallets.iter() {
         let mint_url = wallet.mint_url.to_string();
-        let result = check_melt_quote_status(mint_url)
+        let result = check_melt_quote_status(handle.clone(), mint_url)
             .await
             .map_err(|e| format!("Failed to check melt quotes for {}: {}", wallet.mint_url, e))?;
 
@@ -1291,93 +4572,247 @@ pub async fn validate_mnemonic_phrase(mnemonic_phrase: String) -> Result<bool, S
     }
 }
 
-// Old Tor configuration API has been removed.
-// New implementation automatically uses Tor for .onion addresses.
-// To explicitly control Tor usage, use WalletBuilder::use_tor() when creating wallets.
+/// Progress of the Tor bootstrap for a wallet profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorBootstrapStatus {
+    // False until a mint connection needs Tor
+    pub started: bool,
+    // Estimate between 0.0 and 1.0
+    pub progress: f64,
+    pub ready: bool,
+    pub message: String,
+    pub error: Option<String>,
+}
 
-/// Set Tor configuration (deprecated - Tor is now auto-detected for .onion addresses)
-/// This function is kept for FFI compatibility but does nothing.
-/// The policy parameter is ignored.
+/// Set the Tor policy: "always", "onion_only" or "never"
+/// The policy is saved with the profile and applies when the wallet is next opened.
+/// Returns true when the wallet has to be reopened for the change to take effect.
 #[cfg(feature = "tor")]
-pub async fn set_tor_config(_policy: bool) -> Result<(), String> {
-    // Tor is now automatically used for .onion addresses
-    Ok(())
+pub async fn set_tor_config(handle: WalletHandle, policy: String) -> Result<bool, String> {
+    let tor_policy = TorPolicy::parse(&policy)?;
+    let (profile_dir, current_policy) =
+        with_open_wallet(&handle, |open| (open.profile_dir.clone(), open.tor_policy)).await?;
+
+    let mut settings = NetworkSettings::load(&profile_dir)?;
+    settings.tor_policy = tor_policy;
+    settings.save(&profile_dir)?;
+
+    Ok(tor_policy != current_policy)
+}
+
+/// Get the saved Tor policy
+#[cfg(feature = "tor")]
+pub async fn get_tor_config(handle: WalletHandle) -> Result<String, String> {
+    let profile_dir = with_open_wallet(&handle, |open| open.profile_dir.clone()).await?;
+    Ok(NetworkSettings::load(&profile_dir)?
+        .tor_policy
+        .as_str()
+        .to_string())
+}
+
+/// Check if the open wallet may use Tor
+#[cfg(feature = "tor")]
+pub async fn is_tor_enabled(handle: WalletHandle) -> Result<bool, String> {
+    with_open_wallet(&handle, |open| open.tor_policy != TorPolicy::Never).await
+}
+
+/// Check if Tor has finished bootstrapping for the wallet
+#[cfg(feature = "tor")]
+pub async fn is_tor_ready(handle: WalletHandle) -> Result<bool, String> {
+    with_open_wallet(&handle, |_| ()).await?;
+    Ok(crate::tor::status(&handle.profile_id).ready)
+}
+
+/// Current Tor bootstrap progress for the wallet
+#[cfg(feature = "tor")]
+pub async fn get_tor_bootstrap_status(handle: WalletHandle) -> Result<TorBootstrapStatus, String> {
+    with_open_wallet(&handle, |_| ()).await?;
+    Ok(crate::tor::status(&handle.profile_id))
 }
 
-/// Get current Tor configuration (deprecated)
+/// Stream Tor bootstrap progress until Tor is ready or fails
+/// Starts the bootstrap unless the policy is "never".
 #[cfg(feature = "tor")]
-pub async fn get_tor_config() -> Result<(), String> {
-    // Tor is now automatically used for .onion addresses
+pub async fn subscribe_tor_bootstrap(
+    handle: WalletHandle,
+    sink: StreamSink<TorBootstrapStatus>,
+) -> Result<(), String> {
+    let (tor_policy, profile_dir) =
+        with_open_wallet(&handle, |open| (open.tor_policy, open.profile_dir.clone())).await?;
+    if tor_policy != TorPolicy::Never {
+        crate::tor::start(&handle.profile_id, &profile_dir)?;
+    }
+
+    let Some(mut receiver) = crate::tor::subscribe(&handle.profile_id) else {
+        let _ = sink.add(crate::tor::status(&handle.profile_id));
+        return Ok(());
+    };
+
+    tokio::spawn(async move {
+        loop {
+            let status = receiver.borrow_and_update().clone();
+            let finished = status.ready || status.error.is_some();
+            if sink.add(status).is_err() || finished {
+                break;
+            }
+            // Sender dropped, the profile was closed
+            if receiver.changed().await.is_err() {
+                break;
+            }
+        }
+    });
+
     Ok(())
 }
 
-/// Check if Tor is currently enabled (deprecated - always returns true if tor feature enabled)
+/// Give every mint reached over Tor its own circuits, so mints cannot be correlated
+/// Applies right away to the wallet's mints.
+#[cfg(feature = "tor")]
+pub async fn set_tor_isolation(handle: WalletHandle, enabled: bool) -> Result<(), String> {
+    let profile_dir = with_open_wallet(&handle, |open| open.profile_dir.clone()).await?;
+    update_network_settings(&profile_dir, |settings| settings.tor_isolation = enabled)?;
+    apply_mint_transports(&handle, None).await
+}
+
 #[cfg(feature = "tor")]
-pub async fn is_tor_enabled() -> Result<bool, String> {
-    // Tor is automatically used for .onion addresses when tor feature is enabled
-    Ok(true)
+pub async fn get_tor_isolation(handle: WalletHandle) -> Result<bool, String> {
+    let profile_dir = with_open_wallet(&handle, |open| open.profile_dir.clone()).await?;
+    Ok(NetworkSettings::load(&profile_dir)?.tor_isolation)
 }
 
-/// Check if Tor is ready (deprecated - not available in new implementation)
+/// Move every mint reached over Tor onto fresh circuits
+/// Returns the number of mints that got a new transport.
 #[cfg(feature = "tor")]
-pub async fn is_tor_ready() -> Result<bool, String> {
-    // Tor readiness checking is not available in the new implementation
-    // Tor connections are made on-demand
-    Ok(true)
+pub async fn new_tor_identity(handle: WalletHandle) -> Result<u32, String> {
+    let (multi_mint_wallet, tor_policy, profile_dir, network_key) =
+        with_open_wallet(&handle, |open| {
+            (
+                open.wallet.clone(),
+                open.tor_policy,
+                open.profile_dir.clone(),
+                open.network_key,
+            )
+        })
+        .await?;
+    let settings = NetworkSettings::load(&profile_dir)?;
+
+    // New isolation tokens give later streams new circuits, new transports drop the
+    // pooled connections still on the old ones
+    crate::tor::new_identity(&handle.profile_id);
+    let mut rotated = 0;
+    for wallet in multi_mint_wallet.get_wallets().await {
+        if !tor_policy.uses_tor(&wallet.mint_url) || settings.proxy_for(&wallet.mint_url).is_some()
+        {
+            continue;
+        }
+        let Some(config) = mint_wallet_config(
+            &handle.profile_id,
+            &profile_dir,
+            &settings,
+            &network_key,
+            &wallet.mint_url,
+            true,
+        )?
+        else {
+            continue;
+        };
+        multi_mint_wallet
+            .set_mint_config(wallet.mint_url.clone(), config)
+            .await
+            .map_err(|e| {
+                format!(
+                    "Failed to rotate Tor circuits for {}: {}",
+                    wallet.mint_url, e
+                )
+            })?;
+        rotated += 1;
+    }
+
+    Ok(rotated)
 }
 
-/// Reinitialize MultiMintWallet with current Tor configuration (deprecated)
-/// Tor is now automatically used for .onion addresses, no reinitialization needed.
+/// Reinitialize MultiMintWallet with the saved Tor policy (deprecated, use close_wallet and open_wallet)
 #[cfg(feature = "tor")]
 pub async fn reinitialize_with_tor_config(
     database_dir: String,
     seed_hex: String,
 ) -> Result<String, String> {
-    // Clear existing wallet
+    // Shut the existing wallet down
     {
-        let mut wallet_guard = MULTI_MINT_WALLET.write().await;
-        *wallet_guard = None;
+        let _profile_lock = lock_profile(DEFAULT_PROFILE_ID).await;
+        shutdown_profile(DEFAULT_PROFILE_ID, DEFAULT_SHUTDOWN_TIMEOUT).await?;
     }
 
-    // Reinitialize - Tor will be automatically used for .onion addresses
+    // Reopening applies the saved Tor policy
     init_multi_mint_wallet(database_dir, seed_hex).await
 }
 
-/// Initialize MultiMintWallet with Tor configuration (deprecated)
-/// Tor is now automatically used for .onion addresses, no special initialization needed.
-/// The tor_config parameter is ignored.
+/// Initialize MultiMintWallet with a Tor policy (deprecated, use set_tor_config and open_wallet)
+/// A tor_config of "always", "onion_only" or "never" is saved for the default profile first.
 #[cfg(feature = "tor")]
 pub async fn init_multi_mint_wallet_with_tor(
     database_dir: String,
     seed_hex: String,
-    _tor_config: Option<String>,
+    tor_config: Option<String>,
 ) -> Result<String, String> {
-    // Tor is automatically used for .onion addresses
-    // Use the regular init function
+    if let Some(tor_config) = tor_config {
+        let profile_dir = get_profile_dir(&database_dir, DEFAULT_PROFILE_ID);
+        let mut settings = NetworkSettings::load(&profile_dir)?;
+        settings.tor_policy = TorPolicy::parse(&tor_config)?;
+        settings.save(&profile_dir)?;
+    }
     init_multi_mint_wallet(database_dir, seed_hex).await
 }
 
 /// Non-Tor fallback implementations to keep FFI stable
 #[cfg(not(feature = "tor"))]
-pub async fn set_tor_config(_policy: bool) -> Result<(), String> {
+pub async fn set_tor_config(_handle: WalletHandle, _policy: String) -> Result<bool, String> {
+    Err("Tor feature not enabled".to_string())
+}
+
+#[cfg(not(feature = "tor"))]
+pub async fn is_tor_ready(_handle: WalletHandle) -> Result<bool, String> {
+    Ok(false)
+}
+
+#[cfg(not(feature = "tor"))]
+pub async fn get_tor_config(_handle: WalletHandle) -> Result<String, String> {
     Err("Tor feature not enabled".to_string())
 }
 
 #[cfg(not(feature = "tor"))]
-pub async fn is_tor_ready() -> Result<bool, String> {
+pub async fn is_tor_enabled(_handle: WalletHandle) -> Result<bool, String> {
     Ok(false)
 }
 
 #[cfg(not(feature = "tor"))]
-pub async fn get_tor_config() -> Result<(), String> {
+pub async fn get_tor_bootstrap_status(_handle: WalletHandle) -> Result<TorBootstrapStatus, String> {
+    Err("Tor feature not enabled".to_string())
+}
+
+#[cfg(not(feature = "tor"))]
+pub async fn subscribe_tor_bootstrap(
+    _handle: WalletHandle,
+    _sink: StreamSink<TorBootstrapStatus>,
+) -> Result<(), String> {
+    Err("Tor feature not enabled".to_string())
+}
+
+#[cfg(not(feature = "tor"))]
+pub async fn set_tor_isolation(_handle: WalletHandle, _enabled: bool) -> Result<(), String> {
     Err("Tor feature not enabled".to_string())
 }
 
 #[cfg(not(feature = "tor"))]
-pub async fn is_tor_enabled() -> Result<bool, String> {
+pub async fn get_tor_isolation(_handle: WalletHandle) -> Result<bool, String> {
     Ok(false)
 }
 
+#[cfg(not(feature = "tor"))]
+pub async fn new_tor_identity(_handle: WalletHandle) -> Result<u32, String> {
+    Err("Tor feature not enabled".to_string())
+}
+
 #[cfg(not(feature = "tor"))]
 pub async fn reinitialize_with_tor_config(
     _database_dir: String,
@@ -1396,6 +4831,162 @@ pub async fn init_multi_mint_wallet_with_tor(
     init_multi_mint_wallet(database_dir, seed_hex).await
 }
 
+/// Result of a proxy connectivity test
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyTestResult {
+    pub success: bool,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// How long the proxy test waits for the mint
+const PROXY_TEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn update_network_settings(
+    profile_dir: &std::path::Path,
+    update: impl FnOnce(&mut NetworkSettings),
+) -> Result<(), String> {
+    let mut settings = NetworkSettings::load(profile_dir)?;
+    update(&mut settings);
+    settings.save(profile_dir)
+}
+
+/// Give the wallet's mints the transport their current network settings ask for
+/// Only mint_url is updated when given. A mint left without proxy or Tor connects directly.
+async fn apply_mint_transports(
+    handle: &WalletHandle,
+    mint_url: Option<&MintUrl>,
+) -> Result<(), String> {
+    let (multi_mint_wallet, tor_policy, profile_dir, network_key) =
+        with_open_wallet(handle, |open| {
+            (
+                open.wallet.clone(),
+                open.tor_policy,
+                open.profile_dir.clone(),
+                open.network_key,
+            )
+        })
+        .await?;
+    let settings = NetworkSettings::load(&profile_dir)?;
+
+    for wallet in multi_mint_wallet.get_wallets().await {
+        if mint_url.is_some_and(|mint_url| *mint_url != wallet.mint_url) {
+            continue;
+        }
+        let config = mint_wallet_config(
+            &handle.profile_id,
+            &profile_dir,
+            &settings,
+            &network_key,
+            &wallet.mint_url,
+            tor_policy.uses_tor(&wallet.mint_url),
+        )?
+        .unwrap_or_else(WalletConfig::new);
+        multi_mint_wallet
+            .set_mint_config(wallet.mint_url.clone(), config)
+            .await
+            .map_err(|e| format!("Failed to apply transport for {}: {}", wallet.mint_url, e))?;
+    }
+    Ok(())
+}
+
+/// Set the proxy for every mint of the wallet, None connects directly
+/// Applies right away to the mints without a proxy of their own.
+/// The password is stored encrypted with a key derived from the wallet seed.
+pub async fn set_wallet_proxy(
+    handle: WalletHandle,
+    proxy: Option<ProxyConfig>,
+) -> Result<(), String> {
+    if let Some(proxy) = &proxy {
+        proxy.to_url()?;
+    }
+    let (profile_dir, network_key) =
+        with_open_wallet(&handle, |open| (open.profile_dir.clone(), open.network_key)).await?;
+    let proxy = proxy
+        .map(|proxy| StoredProxy::seal(proxy, &network_key))
+        .transpose()?;
+    update_network_settings(&profile_dir, |settings| settings.proxy = proxy)?;
+    apply_mint_transports(&handle, None).await
+}
+
+pub async fn get_wallet_proxy(handle: WalletHandle) -> Result<Option<ProxyConfig>, String> {
+    let (profile_dir, network_key) =
+        with_open_wallet(&handle, |open| (open.profile_dir.clone(), open.network_key)).await?;
+    NetworkSettings::load(&profile_dir)?
+        .proxy
+        .map(|proxy| proxy.open(&network_key))
+        .transpose()
+}
+
+/// Set the proxy for one mint, overriding the wallet proxy. None falls back to the wallet proxy.
+/// Applies right away if the mint is in the wallet, otherwise when it is added.
+pub async fn set_mint_proxy(
+    handle: WalletHandle,
+    mint_url: String,
+    proxy: Option<ProxyConfig>,
+) -> Result<(), String> {
+    if let Some(proxy) = &proxy {
+        proxy.to_url()?;
+    }
+    let mint_url = MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+    let (profile_dir, network_key) =
+        with_open_wallet(&handle, |open| (open.profile_dir.clone(), open.network_key)).await?;
+    let proxy = proxy
+        .map(|proxy| StoredProxy::seal(proxy, &network_key))
+        .transpose()?;
+    update_network_settings(&profile_dir, |settings| match proxy {
+        Some(proxy) => {
+            settings.mint_proxies.insert(mint_url.to_string(), proxy);
+        }
+        None => {
+            settings.mint_proxies.remove(&mint_url.to_string());
+        }
+    })?;
+    apply_mint_transports(&handle, Some(&mint_url)).await
+}
+
+/// The proxy a mint's requests go through, its own or the wallet's
+pub async fn get_mint_proxy(
+    handle: WalletHandle,
+    mint_url: String,
+) -> Result<Option<ProxyConfig>, String> {
+    let mint_url = MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+    let (profile_dir, network_key) =
+        with_open_wallet(&handle, |open| (open.profile_dir.clone(), open.network_key)).await?;
+    NetworkSettings::load(&profile_dir)?
+        .proxy_for(&mint_url)
+        .map(|proxy| proxy.open(&network_key))
+        .transpose()
+}
+
+/// Check that a mint's info endpoint can be reached through the proxy
+pub async fn test_proxy(proxy: ProxyConfig, mint_url: String) -> Result<ProxyTestResult, String> {
+    let mint_url = MintUrl::from_str(&mint_url).map_err(|e| format!("Invalid mint URL: {}", e))?;
+    let proxy = reqwest::Proxy::all(proxy.to_url()?.as_str())
+        .map_err(|e| format!("Invalid proxy: {}", e))?;
+    let client = reqwest::Client::builder()
+        .proxy(proxy)
+        .timeout(PROXY_TEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let info_url = mint_url
+        .join_paths(&["v1", "info"])
+        .map_err(|e| format!("Invalid mint URL: {}", e))?;
+
+    let started = std::time::Instant::now();
+    let error = match client.get(info_url).send().await {
+        Ok(response) if response.status().is_success() => None,
+        Ok(response) => Some(format!("Mint answered with HTTP {}", response.status())),
+        Err(e) => Some(format!("Failed to connect through proxy: {}", e)),
+    };
+
+    Ok(ProxyTestResult {
+        success: error.is_none(),
+        latency_ms: started.elapsed().as_millis() as u64,
+        error,
+    })
+}
+
 /// Decode a bolt11 lightning invoice to extract amount and other info
 pub async fn decode_bolt11_invoice(invoice: String) -> Result<String, String> {
     use cdk::lightning_invoice::Bolt11Invoice;
@@ -1432,3 +5023,59 @@ pub async fn decode_bolt11_invoice(invoice: String) -> Result<String, String> {
 
     Ok(result.to_string())
 }
+
+/// Decode a BOLT12 offer (lno...) into its fields
+pub async fn decode_bolt12_offer(offer: String) -> Result<Bolt12OfferInfo, String> {
+    use lightning::offers::offer::{Amount as OfferAmount, Offer};
+
+    let offer =
+        Offer::from_str(offer.trim()).map_err(|e| format!("Failed to parse offer: {:?}", e))?;
+
+    let (amount_msat, currency, currency_amount) = match offer.amount() {
+        Some(OfferAmount::Bitcoin { amount_msats }) => (Some(amount_msats), None, None),
+        Some(OfferAmount::Currency {
+            iso4217_code,
+            amount,
+        }) => (
+            None,
+            Some(String::from_utf8_lossy(&iso4217_code).to_string()),
+            Some(amount),
+        ),
+        None => (None, None, None),
+    };
+
+    Ok(Bolt12OfferInfo {
+        offer_id: hex::encode(offer.id().0),
+        amount_msat,
+        currency,
+        currency_amount,
+        description: offer.description().map(|d| d.to_string()),
+        issuer: offer.issuer().map(|i| i.to_string()),
+        issuer_pubkey: offer.issuer_signing_pubkey().map(|k| k.to_string()),
+        absolute_expiry: offer.absolute_expiry().map(|d| d.as_secs()),
+        chains: offer.chains().iter().map(|c| c.to_string()).collect(),
+        is_expired: offer.is_expired(),
+    })
+}
+
+/// Decode a hex encoded BOLT12 invoice request into its fields
+pub async fn decode_bolt12_invoice_request(
+    invoice_request_hex: String,
+) -> Result<Bolt12InvoiceRequestInfo, String> {
+    use lightning::offers::invoice_request::InvoiceRequest;
+
+    let bytes = hex::decode(invoice_request_hex.trim())
+        .map_err(|e| format!("Invalid hex string: {}", e))?;
+    let invoice_request = InvoiceRequest::try_from(bytes)
+        .map_err(|e| format!("Failed to parse invoice request: {:?}", e))?;
+
+    Ok(Bolt12InvoiceRequestInfo {
+        payer_pubkey: invoice_request.payer_signing_pubkey().to_string(),
+        amount_msat: invoice_request.amount_msats(),
+        quantity: invoice_request.quantity(),
+        payer_note: invoice_request.payer_note().map(|n| n.to_string()),
+        chain: invoice_request.chain().to_string(),
+        offer_description: invoice_request.description().map(|d| d.to_string()),
+        offer_issuer: invoice_request.issuer().map(|i| i.to_string()),
+    })
+}
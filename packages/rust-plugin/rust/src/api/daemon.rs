@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::cashu::{get_profile_dir_for_handle, WalletHandle};
+
+/// File the daemon clients are stored in, inside the profile directory
+const CLIENTS_FILE_NAME: &str = "daemon_clients.json";
+
+/// What a daemon client may do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Permission {
+    // Balances, mints, history
+    Read,
+    // Receive tokens and create invoices
+    Receive,
+    // Create tokens
+    Send,
+    // Pay Lightning invoices
+    Pay,
+    // Subscribe to wallet events over WebSocket
+    Events,
+}
+
+impl Permission {
+    pub(crate) fn parse(permission: &str) -> Result<Self, String> {
+        match permission {
+            "read" => Ok(Self::Read),
+            "receive" => Ok(Self::Receive),
+            "send" => Ok(Self::Send),
+            "pay" => Ok(Self::Pay),
+            "events" => Ok(Self::Events),
+            _ => Err(format!(
+                "Unknown permission '{}', expected read, receive, send, pay or events",
+                permission
+            )),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Receive => "receive",
+            Self::Send => "send",
+            Self::Pay => "pay",
+            Self::Events => "events",
+        }
+    }
+}
+
+/// Client allowed to call the daemon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonClient {
+    pub name: String,
+    pub permissions: Vec<String>,
+    pub created_at: u64,
+}
+
+/// Persisted client, only a hash of the token is kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredClient {
+    token_hash: String,
+    permissions: Vec<String>,
+    created_at: u64,
+}
+
+fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn load_clients(profile_dir: &Path) -> Result<BTreeMap<String, StoredClient>, String> {
+    let path = profile_dir.join(CLIENTS_FILE_NAME);
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let json = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read daemon clients: {}", e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Invalid daemon clients: {}", e))
+}
+
+fn save_clients(
+    profile_dir: &Path,
+    clients: &BTreeMap<String, StoredClient>,
+) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(clients)
+        .map_err(|e| format!("Failed to serialize daemon clients: {}", e))?;
+    std::fs::write(profile_dir.join(CLIENTS_FILE_NAME), json)
+        .map_err(|e| format!("Failed to save daemon clients: {}", e))
+}
+
+/// Register a client and return its token, the token is not stored and cannot be shown again
+pub(crate) fn create_client(
+    profile_dir: &Path,
+    name: &str,
+    permissions: &[String],
+) -> Result<String, String> {
+    if name.is_empty() {
+        return Err("Client name cannot be empty".to_string());
+    }
+    for permission in permissions {
+        Permission::parse(permission)?;
+    }
+
+    let mut clients = load_clients(profile_dir)?;
+    if clients.contains_key(name) {
+        return Err(format!("Daemon client '{}' already exists", name));
+    }
+
+    let token = hex::encode(rand::random::<[u8; 32]>());
+    clients.insert(
+        name.to_string(),
+        StoredClient {
+            token_hash: hash_token(&token),
+            permissions: permissions.to_vec(),
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        },
+    );
+    save_clients(profile_dir, &clients)?;
+    Ok(token)
+}
+
+/// Name of the client the token belongs to, if it holds the permission
+#[cfg_attr(not(feature = "daemon"), allow(dead_code))]
+pub(crate) fn authorize(
+    profile_dir: &Path,
+    token: &str,
+    permission: Permission,
+) -> Result<String, String> {
+    let token_hash = hash_token(token);
+    let clients = load_clients(profile_dir)?;
+    let (name, client) = clients
+        .iter()
+        .find(|(_, client)| client.token_hash == token_hash)
+        .ok_or("Invalid daemon token")?;
+
+    if !client.permissions.iter().any(|p| p == permission.as_str()) {
+        return Err(format!(
+            "Daemon client '{}' lacks the '{}' permission",
+            name,
+            permission.as_str()
+        ));
+    }
+    Ok(name.clone())
+}
+
+/// Create a client for the daemon with permissions from read, receive, send, pay and events
+/// Returns the bearer token, it is only shown once.
+pub async fn add_daemon_client(
+    handle: WalletHandle,
+    name: String,
+    permissions: Vec<String>,
+) -> Result<String, String> {
+    let profile_dir = get_profile_dir_for_handle(&handle).await?;
+    create_client(&profile_dir, &name, &permissions)
+}
+
+/// Revoke a client, its token stops working immediately
+pub async fn remove_daemon_client(handle: WalletHandle, name: String) -> Result<(), String> {
+    let profile_dir = get_profile_dir_for_handle(&handle).await?;
+    let mut clients = load_clients(&profile_dir)?;
+    if clients.remove(&name).is_none() {
+        return Err(format!("Daemon client '{}' not found", name));
+    }
+    save_clients(&profile_dir, &clients)
+}
+
+pub async fn list_daemon_clients(handle: WalletHandle) -> Result<Vec<DaemonClient>, String> {
+    let profile_dir = get_profile_dir_for_handle(&handle).await?;
+    Ok(load_clients(&profile_dir)?
+        .into_iter()
+        .map(|(name, client)| DaemonClient {
+            name,
+            permissions: client.permissions,
+            created_at: client.created_at,
+        })
+        .collect())
+}
+
+/// Serve the wallet over JSON-RPC on `listen`, either "127.0.0.1:<port>" or "unix:<path>"
+/// Requests go to POST /rpc, events stream from the /events WebSocket. Both need
+/// "Authorization: Bearer <token>", /events also accepts ?token= for browsers.
+/// Returns the address the daemon listens on.
+#[cfg(feature = "daemon")]
+pub async fn start_daemon(handle: WalletHandle, listen: String) -> Result<String, String> {
+    let profile_dir = get_profile_dir_for_handle(&handle).await?;
+    crate::daemon::start(handle, profile_dir, &listen).await
+}
+
+#[cfg(feature = "daemon")]
+pub async fn stop_daemon(handle: WalletHandle) -> Result<(), String> {
+    crate::daemon::stop(&handle.profile_id);
+    Ok(())
+}
+
+/// Address the daemon listens on, None when it is not running
+#[cfg(feature = "daemon")]
+pub async fn get_daemon_address(handle: WalletHandle) -> Result<Option<String>, String> {
+    Ok(crate::daemon::address(&handle.profile_id))
+}
+
+/// Non-daemon fallback implementations to keep FFI stable
+#[cfg(not(feature = "daemon"))]
+pub async fn start_daemon(_handle: WalletHandle, _listen: String) -> Result<String, String> {
+    Err("Daemon feature not enabled".to_string())
+}
+
+#[cfg(not(feature = "daemon"))]
+pub async fn stop_daemon(_handle: WalletHandle) -> Result<(), String> {
+    Err("Daemon feature not enabled".to_string())
+}
+
+#[cfg(not(feature = "daemon"))]
+pub async fn get_daemon_address(_handle: WalletHandle) -> Result<Option<String>, String> {
+    Ok(None)
+}
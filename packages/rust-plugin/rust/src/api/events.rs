@@ -0,0 +1,111 @@
+use crate::frb_generated::StreamSink;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+
+/// Number of events buffered per subscriber before old ones are dropped
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Wallet event delivered to Dart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletEvent {
+    // e.g. "lnurl_withdraw_invoice_submitted", "lnurl_withdraw_minted"
+    pub kind: String,
+    #[serde(rename = "profileId")]
+    pub profile_id: Option<String>,
+    #[serde(rename = "mintUrl")]
+    pub mint_url: Option<String>,
+    #[serde(rename = "quoteId")]
+    pub quote_id: Option<String>,
+    pub amount: Option<u64>,
+    pub message: Option<String>,
+    pub timestamp: u64,
+    pub data: HashMap<String, String>,
+}
+
+impl WalletEvent {
+    pub(crate) fn new(kind: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            profile_id: None,
+            mint_url: None,
+            quote_id: None,
+            amount: None,
+            message: None,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            data: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn profile_id(mut self, profile_id: impl ToString) -> Self {
+        self.profile_id = Some(profile_id.to_string());
+        self
+    }
+
+    pub(crate) fn mint_url(mut self, mint_url: impl ToString) -> Self {
+        self.mint_url = Some(mint_url.to_string());
+        self
+    }
+
+    pub(crate) fn quote_id(mut self, quote_id: impl ToString) -> Self {
+        self.quote_id = Some(quote_id.to_string());
+        self
+    }
+
+    pub(crate) fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub(crate) fn message(mut self, message: impl ToString) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    pub(crate) fn data(mut self, key: &str, value: impl ToString) -> Self {
+        self.data.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+fn event_sender() -> &'static broadcast::Sender<WalletEvent> {
+    static SENDER: OnceLock<broadcast::Sender<WalletEvent>> = OnceLock::new();
+    SENDER.get_or_init(|| broadcast::channel(EVENT_CHANNEL_CAPACITY).0)
+}
+
+/// Publish an event to every subscriber (no-op if nobody is listening)
+pub(crate) fn emit_wallet_event(event: WalletEvent) {
+    let _ = event_sender().send(event);
+}
+
+/// Subscribe to events from inside the crate
+pub(crate) fn subscribe() -> broadcast::Receiver<WalletEvent> {
+    event_sender().subscribe()
+}
+
+/// Stream wallet events to Dart until the stream is closed on the Dart side
+pub async fn subscribe_wallet_events(sink: StreamSink<WalletEvent>) -> Result<(), String> {
+    let mut receiver = subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if sink.add(event).is_err() {
+                        // Dart side stopped listening
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,482 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use cdk::mint_url::MintUrl;
+use cdk::wallet::types::TransactionDirection;
+use cdk::wallet::MultiMintWallet;
+use rand::random;
+use sha2::Sha256;
+use tokio::sync::RwLock;
+
+use crate::api::cashu::{get_multi_mint_wallet, WalletHandle};
+
+/// File the policy is stored in, inside the wallet database directory
+const POLICY_FILE_NAME: &str = "spending_policy.json";
+
+/// PBKDF2 rounds for the unlock PIN
+const PIN_HASH_ROUNDS: u32 = 100_000;
+
+/// How long an unlock stays valid
+const UNLOCK_TTL: Duration = Duration::from_secs(120);
+
+/// Wrong PINs allowed before PIN checks are locked out
+const MAX_PIN_ATTEMPTS: u32 = 5;
+
+/// First lockout after too many wrong PINs, doubled for every further wrong PIN
+const PIN_LOCKOUT_SECS: u64 = 60;
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+const WEEK_SECS: u64 = 7 * DAY_SECS;
+
+/// Spending limit for one mint, or for all mints together when mint_url is None
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpendingLimit {
+    pub mint_url: Option<String>,
+    pub per_transaction: Option<u64>,
+    // Rolling 24 hour window
+    pub daily: Option<u64>,
+    // Rolling 7 day window
+    pub weekly: Option<u64>,
+    // "block" rejects the spend, "unlock" allows it after unlock_spending
+    pub action: String,
+}
+
+/// What is left of a spending limit right now
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpendingAllowance {
+    pub mint_url: Option<String>,
+    pub per_transaction: Option<u64>,
+    pub daily_spent: u64,
+    pub daily_remaining: Option<u64>,
+    pub weekly_spent: u64,
+    pub weekly_remaining: Option<u64>,
+    pub action: String,
+}
+
+/// Persisted policy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SpendingPolicy {
+    limits: Vec<SpendingLimit>,
+    pin_salt: Option<String>,
+    pin_hash: Option<String>,
+    // Wrong PINs since the last right one, kept on disk so reopening does not reset it
+    #[serde(default)]
+    pin_failures: u32,
+    #[serde(default)]
+    pin_locked_until: Option<u64>,
+}
+
+/// Single-use permission to exceed an "unlock" limit
+#[derive(Debug, Clone)]
+struct SpendingUnlock {
+    mint_url: Option<MintUrl>,
+    amount: u64,
+    expires_at: SystemTime,
+}
+
+/// Spend that passed check_spend and is not in the transaction history yet
+#[derive(Debug, Clone)]
+struct ReservedSpend {
+    id: u64,
+    mint_url: String,
+    amount: u64,
+}
+
+/// Keeps a checked spend counted against the limits until the spend is done
+/// Dropping it releases the amount, by then a finished spend is in the transaction
+/// history and a failed one spent nothing.
+pub(crate) struct SpendReservation {
+    reserved: Arc<Mutex<Vec<ReservedSpend>>>,
+    id: u64,
+}
+
+impl Drop for SpendReservation {
+    fn drop(&mut self) {
+        let mut reserved = self.reserved.lock().unwrap_or_else(|e| e.into_inner());
+        reserved.retain(|spend| spend.id != self.id);
+    }
+}
+
+struct PolicyState {
+    path: PathBuf,
+    policy: SpendingPolicy,
+    unlock: Option<SpendingUnlock>,
+    // Spends running right now, shared with their SpendReservation
+    reserved: Arc<Mutex<Vec<ReservedSpend>>>,
+}
+
+/// Policies of the open wallet profiles, keyed by profile id
+static SPENDING_POLICIES: RwLock<BTreeMap<String, PolicyState>> =
+    RwLock::const_new(BTreeMap::new());
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn hash_pin(pin: &str, salt: &[u8]) -> String {
+    let mut out = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(pin.as_bytes(), salt, PIN_HASH_ROUNDS, &mut out);
+    hex::encode(out)
+}
+
+/// Check the spending PIN, locking PIN checks out after too many wrong ones
+fn verify_pin(state: &mut PolicyState, pin: Option<&str>) -> Result<(), String> {
+    let (Some(salt), Some(hash)) = (state.policy.pin_salt.clone(), state.policy.pin_hash.clone())
+    else {
+        // No PIN configured
+        return Ok(());
+    };
+    let now = now_secs();
+    if let Some(locked_until) = state.policy.pin_locked_until.filter(|t| *t > now) {
+        return Err(format!(
+            "Too many wrong spending PINs, try again in {} seconds",
+            locked_until - now
+        ));
+    }
+    let pin = pin.ok_or("Spending PIN required")?;
+    let salt = hex::decode(salt).map_err(|e| format!("Corrupt spending policy: {}", e))?;
+    if hash_pin(pin, &salt) != hash {
+        state.policy.pin_failures = state.policy.pin_failures.saturating_add(1);
+        if state.policy.pin_failures >= MAX_PIN_ATTEMPTS {
+            let doublings = (state.policy.pin_failures - MAX_PIN_ATTEMPTS).min(16);
+            let lockout = (PIN_LOCKOUT_SECS << doublings).min(DAY_SECS);
+            state.policy.pin_locked_until = Some(now + lockout);
+        }
+        save_policy(state)?;
+        return Err("Wrong spending PIN".to_string());
+    }
+    if state.policy.pin_failures > 0 || state.policy.pin_locked_until.is_some() {
+        state.policy.pin_failures = 0;
+        state.policy.pin_locked_until = None;
+        save_policy(state)?;
+    }
+    Ok(())
+}
+
+fn normalize_mint_url(mint_url: Option<String>) -> Result<Option<String>, String> {
+    mint_url
+        .map(|m| {
+            MintUrl::from_str(&m)
+                .map(|m| m.to_string())
+                .map_err(|e| format!("Invalid mint URL: {}", e))
+        })
+        .transpose()
+}
+
+fn save_policy(state: &PolicyState) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&state.policy)
+        .map_err(|e| format!("Failed to serialize spending policy: {}", e))?;
+    std::fs::write(&state.path, json).map_err(|e| format!("Failed to save spending policy: {}", e))
+}
+
+fn policy_state<'a>(
+    policies: &'a mut BTreeMap<String, PolicyState>,
+    handle: &WalletHandle,
+) -> Result<&'a mut PolicyState, String> {
+    policies
+        .get_mut(&handle.profile_id)
+        .ok_or_else(|| format!("Wallet profile '{}' is not open", handle.profile_id))
+}
+
+/// Load the policy stored next to the wallet database, called when a profile is opened
+pub(crate) async fn load_spending_policy(
+    profile_id: &str,
+    profile_dir: &Path,
+) -> Result<(), String> {
+    let path = profile_dir.join(POLICY_FILE_NAME);
+    let policy = if path.exists() {
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read spending policy: {}", e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Invalid spending policy: {}", e))?
+    } else {
+        SpendingPolicy::default()
+    };
+
+    SPENDING_POLICIES.write().await.insert(
+        profile_id.to_string(),
+        PolicyState {
+            path,
+            policy,
+            unlock: None,
+            reserved: Arc::new(Mutex::new(Vec::new())),
+        },
+    );
+    Ok(())
+}
+
+/// Forget a profile's policy and pending unlock, called when the profile is closed
+pub(crate) async fn unload_spending_policy(profile_id: &str) {
+    SPENDING_POLICIES.write().await.remove(profile_id);
+}
+
+/// Amount reserved by spends still running, for one mint or all mints
+fn reserved_amount(reserved: &Mutex<Vec<ReservedSpend>>, mint_url: Option<&str>) -> u64 {
+    reserved
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .filter(|spend| mint_url.is_none_or(|m| spend.mint_url == m))
+        .map(|spend| spend.amount)
+        .sum()
+}
+
+/// Amount spent (amount + fee) in the last window_secs, for one mint or all mints
+/// Spends still running count with their reserved amount.
+async fn spent_since(
+    multi_mint_wallet: &MultiMintWallet,
+    reserved: &Mutex<Vec<ReservedSpend>>,
+    mint_url: Option<&str>,
+    window_secs: u64,
+) -> Result<u64, String> {
+    let since = now_secs().saturating_sub(window_secs);
+    let transactions = multi_mint_wallet
+        .list_transactions(Some(TransactionDirection::Outgoing))
+        .await
+        .map_err(|e| format!("Failed to get transactions: {}", e))?;
+
+    Ok(transactions
+        .iter()
+        .filter(|tx| tx.timestamp >= since)
+        .filter(|tx| mint_url.is_none_or(|m| tx.mint_url.to_string() == m))
+        .map(|tx| u64::from(tx.amount) + u64::from(tx.fee))
+        .sum::<u64>()
+        + reserved_amount(reserved, mint_url))
+}
+
+/// Check a spend against every limit that applies to the mint
+/// Called by every spending API before any proofs are touched. Keep the returned
+/// reservation until the spend is done, so concurrent spends see this one.
+pub(crate) async fn check_spend(
+    handle: &WalletHandle,
+    multi_mint_wallet: &MultiMintWallet,
+    mint_url: &MintUrl,
+    amount: u64,
+) -> Result<SpendReservation, String> {
+    // Held until the reservation is in place, checks run one at a time
+    let mut policies = SPENDING_POLICIES.write().await;
+    let state = policy_state(&mut policies, handle)?;
+    let mint = mint_url.to_string();
+
+    let mut unlock_needed: Option<String> = None;
+    for limit in &state.policy.limits {
+        if limit.mint_url.as_deref().is_some_and(|m| m != mint) {
+            continue;
+        }
+
+        let scope = limit.mint_url.as_deref().unwrap_or("all mints");
+        let mut violation = None;
+        if limit.per_transaction.is_some_and(|max| amount > max) {
+            violation = Some(format!("per-transaction limit for {}", scope));
+        }
+        if violation.is_none() {
+            if let Some(max) = limit.daily {
+                let spent = spent_since(
+                    multi_mint_wallet,
+                    &state.reserved,
+                    limit.mint_url.as_deref(),
+                    DAY_SECS,
+                )
+                .await?;
+                if spent + amount > max {
+                    violation = Some(format!("daily limit for {}", scope));
+                }
+            }
+        }
+        if violation.is_none() {
+            if let Some(max) = limit.weekly {
+                let spent = spent_since(
+                    multi_mint_wallet,
+                    &state.reserved,
+                    limit.mint_url.as_deref(),
+                    WEEK_SECS,
+                )
+                .await?;
+                if spent + amount > max {
+                    violation = Some(format!("weekly limit for {}", scope));
+                }
+            }
+        }
+
+        if let Some(violation) = violation {
+            if limit.action == "unlock" {
+                unlock_needed.get_or_insert(violation);
+            } else {
+                return Err(format!("Spending blocked: exceeds {}", violation));
+            }
+        }
+    }
+
+    if let Some(violation) = unlock_needed {
+        // Only a matching unlock is consumed, it covers a single spend
+        let unlocked = state.unlock.as_ref().is_some_and(|unlock| {
+            unlock.expires_at > SystemTime::now()
+                && unlock.amount >= amount
+                && unlock.mint_url.as_ref().is_none_or(|m| m == mint_url)
+        });
+        if !unlocked {
+            return Err(format!("Spending unlock required: exceeds {}", violation));
+        }
+        state.unlock = None;
+    }
+
+    let id = random();
+    state
+        .reserved
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(ReservedSpend {
+            id,
+            mint_url: mint,
+            amount,
+        });
+    Ok(SpendReservation {
+        reserved: state.reserved.clone(),
+        id,
+    })
+}
+
+/// Add or replace the spending limit for a mint (or globally when mint_url is None)
+/// Requires the spending PIN once one is set.
+pub async fn set_spending_limit(
+    handle: WalletHandle,
+    limit: SpendingLimit,
+    pin: Option<String>,
+) -> Result<(), String> {
+    if limit.action != "block" && limit.action != "unlock" {
+        return Err("Limit action must be 'block' or 'unlock'".to_string());
+    }
+    let limit = SpendingLimit {
+        mint_url: normalize_mint_url(limit.mint_url)?,
+        ..limit
+    };
+
+    let mut policies = SPENDING_POLICIES.write().await;
+    let state = policy_state(&mut policies, &handle)?;
+    verify_pin(state, pin.as_deref())?;
+
+    state.policy.limits.retain(|l| l.mint_url != limit.mint_url);
+    state.policy.limits.push(limit);
+    save_policy(state)
+}
+
+/// Remove the spending limit for a mint (or the global one when mint_url is None)
+pub async fn remove_spending_limit(
+    handle: WalletHandle,
+    mint_url: Option<String>,
+    pin: Option<String>,
+) -> Result<(), String> {
+    let mint_url = normalize_mint_url(mint_url)?;
+
+    let mut policies = SPENDING_POLICIES.write().await;
+    let state = policy_state(&mut policies, &handle)?;
+    verify_pin(state, pin.as_deref())?;
+
+    state.policy.limits.retain(|l| l.mint_url != mint_url);
+    save_policy(state)
+}
+
+/// List configured spending limits
+pub async fn get_spending_limits(handle: WalletHandle) -> Result<Vec<SpendingLimit>, String> {
+    let mut policies = SPENDING_POLICIES.write().await;
+    Ok(policy_state(&mut policies, &handle)?.policy.limits.clone())
+}
+
+/// Remaining allowance for every configured limit
+pub async fn get_spending_allowances(
+    handle: WalletHandle,
+) -> Result<Vec<SpendingAllowance>, String> {
+    let multi_mint_wallet = get_multi_mint_wallet(&handle).await?;
+
+    let (limits, reserved) = {
+        let mut policies = SPENDING_POLICIES.write().await;
+        let state = policy_state(&mut policies, &handle)?;
+        (state.policy.limits.clone(), state.reserved.clone())
+    };
+    let mut allowances = Vec::new();
+    for limit in limits {
+        let daily_spent = spent_since(
+            &multi_mint_wallet,
+            &reserved,
+            limit.mint_url.as_deref(),
+            DAY_SECS,
+        )
+        .await?;
+        let weekly_spent = spent_since(
+            &multi_mint_wallet,
+            &reserved,
+            limit.mint_url.as_deref(),
+            WEEK_SECS,
+        )
+        .await?;
+
+        allowances.push(SpendingAllowance {
+            per_transaction: limit.per_transaction,
+            daily_remaining: limit.daily.map(|max| max.saturating_sub(daily_spent)),
+            weekly_remaining: limit.weekly.map(|max| max.saturating_sub(weekly_spent)),
+            daily_spent,
+            weekly_spent,
+            mint_url: limit.mint_url,
+            action: limit.action,
+        });
+    }
+
+    Ok(allowances)
+}
+
+/// Set or change the spending PIN guarding limit changes and unlocks
+/// current_pin is required when a PIN is already set. An empty new_pin removes it.
+/// After five wrong PINs every PIN check is refused for a minute, doubling with each
+/// further wrong PIN.
+pub async fn set_spending_pin(
+    handle: WalletHandle,
+    current_pin: Option<String>,
+    new_pin: String,
+) -> Result<(), String> {
+    let mut policies = SPENDING_POLICIES.write().await;
+    let state = policy_state(&mut policies, &handle)?;
+    verify_pin(state, current_pin.as_deref())?;
+
+    if new_pin.is_empty() {
+        state.policy.pin_salt = None;
+        state.policy.pin_hash = None;
+    } else {
+        let salt = random::<[u8; 16]>();
+        state.policy.pin_hash = Some(hash_pin(&new_pin, &salt));
+        state.policy.pin_salt = Some(hex::encode(salt));
+    }
+    save_policy(state)
+}
+
+/// Allow the next spend of up to amount sats past an "unlock" limit
+/// The unlock is single use and expires after two minutes.
+pub async fn unlock_spending(
+    handle: WalletHandle,
+    pin: String,
+    mint_url: Option<String>,
+    amount: u64,
+) -> Result<(), String> {
+    let mint_url = mint_url
+        .map(|m| MintUrl::from_str(&m).map_err(|e| format!("Invalid mint URL: {}", e)))
+        .transpose()?;
+
+    let mut policies = SPENDING_POLICIES.write().await;
+    let state = policy_state(&mut policies, &handle)?;
+    if state.policy.pin_hash.is_none() {
+        return Err("Set a spending PIN before unlocking".to_string());
+    }
+    verify_pin(state, Some(&pin))?;
+
+    state.unlock = Some(SpendingUnlock {
+        mint_url,
+        amount,
+        expires_at: SystemTime::now() + UNLOCK_TTL,
+    });
+    Ok(())
+}
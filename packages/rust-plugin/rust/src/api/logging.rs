@@ -0,0 +1,331 @@
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Mutex, OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+
+/// Records kept for the diagnostics bundle
+const LOG_BUFFER_CAPACITY: usize = 2000;
+
+/// Records buffered per Dart subscriber before old ones are dropped
+const LOG_CHANNEL_CAPACITY: usize = 512;
+
+/// Words that put the next value in a log line under redaction
+const SENSITIVE_KEYS: &[&str] = &[
+    "secret",
+    "witness",
+    "password",
+    "mnemonic",
+    "seed",
+    "private_key",
+    "privkey",
+    "token",
+    "preimage",
+    "key_material",
+];
+
+/// Log record delivered to Dart, already redacted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogRecord {
+    pub level: String,
+    // cdk, arti, purrwallet or other
+    pub module: String,
+    pub target: String,
+    pub message: String,
+    pub timestamp_ms: u64,
+}
+
+/// Redacted logs and environment details for a bug report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsBundle {
+    pub created_at: u64,
+    pub crate_version: String,
+    pub os: String,
+    pub arch: String,
+    pub log_levels: BTreeMap<String, String>,
+    pub records: Vec<LogRecord>,
+}
+
+struct BridgeLogger {
+    // Level per module, modules without an entry log at Info
+    levels: RwLock<BTreeMap<&'static str, LevelFilter>>,
+    buffer: Mutex<VecDeque<LogRecord>>,
+    sender: broadcast::Sender<LogRecord>,
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Module a log target belongs to, for level filtering
+fn module_of(target: &str) -> &'static str {
+    if target.starts_with("cdk") || target.starts_with("cashu") {
+        "cdk"
+    } else if target.starts_with("arti") || target.starts_with("tor_") {
+        "arti"
+    } else if target.starts_with(env!("CARGO_CRATE_NAME")) || target.starts_with("purrwallet") {
+        "purrwallet"
+    } else {
+        "other"
+    }
+}
+
+fn is_sensitive_word(word: &str) -> bool {
+    let lower = word.to_ascii_lowercase();
+    let long = word.len() >= 20;
+    (long && (lower.starts_with("cashua") || lower.starts_with("cashub") || lower.starts_with("creqa")))
+        || lower.starts_with("nsec1")
+        || (long
+            && (lower.starts_with("lnbc")
+                || lower.starts_with("lntb")
+                || lower.starts_with("lno1")
+                || lower.starts_with("lnurl")))
+        // Keys, proof secrets, signatures and seeds
+        || (word.len() >= 32 && word.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn is_sensitive_key(word: &str) -> bool {
+    SENSITIVE_KEYS
+        .iter()
+        .any(|key| word.eq_ignore_ascii_case(key))
+}
+
+/// Base64 and bech32 payloads contain these, a token must not be split at them
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+' | '/' | '=')
+}
+
+/// Position relative to the value of a sensitive key
+enum RedactState {
+    Text,
+    // Between the key and its value, e.g. `": "`
+    AfterKey,
+    // Dropped up to the next quote, comma or end of line
+    InValue,
+}
+
+/// Scrub tokens, keys, proof secrets, invoices and values of sensitive fields
+pub(crate) fn redact(message: &str) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut word = String::new();
+    let mut state = RedactState::Text;
+
+    for c in message.chars() {
+        match state {
+            RedactState::Text if c == '=' && is_sensitive_key(&word) => {
+                // key=value, the value is not part of the key's word
+                flush_word(&mut word, &mut out);
+                out.push(c);
+                state = RedactState::AfterKey;
+            }
+            RedactState::Text if is_word_char(c) => word.push(c),
+            RedactState::Text => {
+                state = if flush_word(&mut word, &mut out) {
+                    after_key(c, &mut out)
+                } else {
+                    out.push(c);
+                    RedactState::Text
+                };
+            }
+            RedactState::AfterKey => state = after_key(c, &mut out),
+            RedactState::InValue => {
+                if matches!(c, '"' | ',' | '\n') {
+                    out.push(c);
+                    state = RedactState::Text;
+                }
+            }
+        }
+    }
+    flush_word(&mut word, &mut out);
+    out
+}
+
+/// Pass the separator between a sensitive key and its value, then drop the value
+fn after_key(c: char, out: &mut String) -> RedactState {
+    match c {
+        '"' | '\'' | ':' | '=' | ' ' | '\t' => {
+            out.push(c);
+            RedactState::AfterKey
+        }
+        // The key had no value
+        ',' | '\n' => {
+            out.push(c);
+            RedactState::Text
+        }
+        _ => {
+            out.push_str("[redacted]");
+            RedactState::InValue
+        }
+    }
+}
+
+/// Write a finished word, scrubbed if it looks sensitive
+/// Returns whether it is a sensitive key, whose value has to go.
+fn flush_word(word: &mut String, out: &mut String) -> bool {
+    if word.is_empty() {
+        return false;
+    }
+    let is_key = is_sensitive_key(word);
+    if !is_key && is_sensitive_word(word) {
+        out.push_str("[redacted]");
+    } else {
+        out.push_str(word);
+    }
+    word.clear();
+    is_key
+}
+
+impl Log for BridgeLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let module = module_of(metadata.target());
+        let level = self
+            .levels
+            .read()
+            .ok()
+            .and_then(|levels| levels.get(module).copied())
+            .unwrap_or(LevelFilter::Info);
+        metadata.level() <= level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let record = LogRecord {
+            level: record.level().to_string(),
+            module: module_of(record.target()).to_string(),
+            target: record.target().to_string(),
+            message: redact(&record.args().to_string()),
+            timestamp_ms: now_ms(),
+        };
+        if let Ok(mut buffer) = self.buffer.lock() {
+            if buffer.len() == LOG_BUFFER_CAPACITY {
+                buffer.pop_front();
+            }
+            buffer.push_back(record.clone());
+        }
+        let _ = self.sender.send(record);
+    }
+
+    fn flush(&self) {}
+}
+
+fn logger() -> &'static BridgeLogger {
+    static LOGGER: OnceLock<BridgeLogger> = OnceLock::new();
+    LOGGER.get_or_init(|| BridgeLogger {
+        levels: RwLock::new(BTreeMap::new()),
+        buffer: Mutex::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY)),
+        sender: broadcast::channel(LOG_CHANNEL_CAPACITY).0,
+    })
+}
+
+/// Install the bridge as the `log` logger, later calls are no-ops
+pub(crate) fn install_logger() {
+    if log::set_logger(logger()).is_ok() {
+        // Filtering per module happens in the logger
+        log::set_max_level(LevelFilter::Trace);
+    }
+}
+
+/// Set the level for a module: cdk, arti, purrwallet or other
+/// Levels are off, error, warn, info, debug and trace.
+#[frb(sync)]
+pub fn set_log_level(module: String, level: String) -> Result<(), String> {
+    let module = match module.as_str() {
+        "cdk" => "cdk",
+        "arti" => "arti",
+        "purrwallet" => "purrwallet",
+        "other" => "other",
+        _ => {
+            return Err(format!(
+                "Unknown log module '{}', expected cdk, arti, purrwallet or other",
+                module
+            ))
+        }
+    };
+    let level = level
+        .parse::<LevelFilter>()
+        .map_err(|e| format!("Invalid log level: {}", e))?;
+
+    logger()
+        .levels
+        .write()
+        .map_err(|e| e.to_string())?
+        .insert(module, level);
+    Ok(())
+}
+
+/// Stream log records to Dart until the stream is closed on the Dart side
+pub async fn subscribe_logs(sink: StreamSink<LogRecord>) -> Result<(), String> {
+    install_logger();
+    let mut receiver = logger().sender.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(record) => {
+                    if sink.add(record).is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Redacted copy of the buffered logs, ready to attach to a bug report
+#[frb(sync)]
+pub fn export_diagnostics() -> Result<DiagnosticsBundle, String> {
+    let logger = logger();
+    let records = logger
+        .buffer
+        .lock()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .cloned()
+        .collect();
+    let log_levels = logger
+        .levels
+        .read()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .map(|(module, level)| (module.to_string(), level.to_string()))
+        .collect();
+
+    Ok(DiagnosticsBundle {
+        created_at: now_ms() / 1000,
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        log_levels,
+        records,
+    })
+}
+
+/// Forget the buffered logs, e.g. after a bundle was sent
+#[frb(sync)]
+pub fn clear_log_buffer() -> Result<(), String> {
+    logger().buffer.lock().map_err(|e| e.to_string())?.clear();
+    Ok(())
+}
+
+/// Write a record from Dart into the same buffer, so bundles show both sides
+#[frb(sync)]
+pub fn log_from_dart(level: String, message: String) -> Result<(), String> {
+    let level = level
+        .parse::<Level>()
+        .map_err(|e| format!("Invalid log level: {}", e))?;
+    log::log!(target: "purrwallet::dart", level, "{}", message);
+    Ok(())
+}
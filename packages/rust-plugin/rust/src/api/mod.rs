@@ -1,2 +1,8 @@
 pub mod cashu;
+pub mod daemon;
+pub mod events;
+pub mod limits;
+pub mod logging;
+pub mod nip60;
 pub mod nostr;
+pub mod qr;
@@ -0,0 +1,125 @@
+use nostr::key::{Keys, SecretKey};
+use nostr::nips::nip19::FromBech32;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::api::cashu::{get_profile_dir_for_handle, WalletHandle};
+
+/// File the NIP-60 sync settings are stored in, inside the profile directory
+const SYNC_FILE_NAME: &str = "nip60_sync.json";
+
+/// Persisted sync settings, the Nostr key is never written to disk
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct SyncSettings {
+    #[serde(default)]
+    pub relays: Vec<String>,
+}
+
+impl SyncSettings {
+    pub(crate) fn load(profile_dir: &Path) -> Result<Self, String> {
+        let path = profile_dir.join(SYNC_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read NIP-60 sync settings: {}", e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Invalid NIP-60 sync settings: {}", e))
+    }
+
+    pub(crate) fn save(&self, profile_dir: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize NIP-60 sync settings: {}", e))?;
+        std::fs::write(profile_dir.join(SYNC_FILE_NAME), json)
+            .map_err(|e| format!("Failed to save NIP-60 sync settings: {}", e))
+    }
+}
+
+/// Outcome of one NIP-60 sync
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Nip60SyncReport {
+    // Proofs from other devices added to this wallet
+    pub imported_proofs: u64,
+    pub imported_amount: u64,
+    // Local proofs the mint reported spent, removed from this wallet
+    pub removed_proofs: u64,
+    // Mints from the wallet event added to this wallet
+    pub mints_added: Vec<String>,
+    // Token and wallet events published
+    pub published_events: u64,
+    // Token events rolled over or deleted
+    pub deleted_events: u64,
+    // Per mint problems that did not stop the sync
+    pub errors: Vec<String>,
+    pub synced_at: u64,
+}
+
+/// State of the background sync for a wallet profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Nip60SyncStatus {
+    pub enabled: bool,
+    pub relays: Vec<String>,
+    // Hex public key the wallet is synced under
+    pub public_key: Option<String>,
+    pub last_report: Option<Nip60SyncReport>,
+    pub last_error: Option<String>,
+}
+
+/// Nostr keys from a hex secret key or an nsec
+fn parse_keys(private_key: &str) -> Result<Keys, String> {
+    let secret_key = SecretKey::from_str(private_key)
+        .or_else(|_| SecretKey::from_bech32(private_key))
+        .map_err(|e| format!("Invalid private key: {}", e))?;
+    Ok(Keys::new(secret_key))
+}
+
+/// Keep the wallet's proofs in sync with NIP-60 events on the relays
+/// Publishes the kind 17375 wallet event and kind 7375 token events (NIP-44 encrypted
+/// to the key), merges events from other devices and deletes spent token events.
+/// Runs in the background until disabled or the wallet is closed. The key stays in
+/// memory only, call again after opening the wallet. relays None reuses the saved ones.
+pub async fn enable_nip60_sync(
+    handle: WalletHandle,
+    private_key: String,
+    relays: Option<Vec<String>>,
+) -> Result<(), String> {
+    let keys = parse_keys(&private_key)?;
+    let profile_dir = get_profile_dir_for_handle(&handle).await?;
+    let mut settings = SyncSettings::load(&profile_dir)?;
+    if let Some(relays) = relays {
+        for relay in &relays {
+            url::Url::parse(relay).map_err(|e| format!("Invalid relay URL '{}': {}", relay, e))?;
+        }
+        settings.relays = relays;
+        settings.save(&profile_dir)?;
+    }
+    if settings.relays.is_empty() {
+        return Err("NIP-60 sync needs at least one relay".to_string());
+    }
+
+    crate::nip60::start(handle, keys, settings.relays)
+}
+
+pub async fn disable_nip60_sync(handle: WalletHandle) -> Result<(), String> {
+    crate::nip60::stop(&handle.profile_id);
+    Ok(())
+}
+
+/// Sync right away instead of waiting for the next background round
+pub async fn sync_nip60_wallet(handle: WalletHandle) -> Result<Nip60SyncReport, String> {
+    crate::nip60::sync_now(&handle).await
+}
+
+pub async fn get_nip60_sync_status(handle: WalletHandle) -> Result<Nip60SyncStatus, String> {
+    let profile_dir = get_profile_dir_for_handle(&handle).await?;
+    if let Some(status) = crate::nip60::status(&handle.profile_id) {
+        return Ok(status);
+    }
+    Ok(Nip60SyncStatus {
+        enabled: false,
+        relays: SyncSettings::load(&profile_dir)?.relays,
+        public_key: None,
+        last_report: None,
+        last_error: None,
+    })
+}
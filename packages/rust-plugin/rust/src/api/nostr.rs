@@ -1,4 +1,4 @@
-use nostr::event::{EventBuilder, EventId, Kind, Tag};
+use nostr::event::{Event, EventBuilder, EventId, Kind, Tag};
 use nostr::key::{Keys, PublicKey, SecretKey};
 use nostr::nips::nip04;
 use nostr::nips::nip19::{FromBech32, ToBech32};
@@ -199,12 +199,16 @@ pub fn verify_event(event: NostrEvent) -> Result<bool, String> {
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Invalid tags: {}", e))?;
 
-    // Create the event for verification using EventBuilder
-    let nostr_event = EventBuilder::new(Kind::from(event.kind as u16), event.content)
-        .tags(tags)
-        .custom_created_at(Timestamp::from(event.created_at))
-        .sign_with_keys(&Keys::new(SecretKey::from_str(&event.pubkey).unwrap()))
-        .unwrap();
+    // Rebuild the event as received, re-signing it would always verify
+    let nostr_event = Event::new(
+        event_id,
+        pubkey,
+        Timestamp::from(event.created_at),
+        Kind::from(event.kind as u16),
+        tags,
+        event.content,
+        sig,
+    );
 
     // Verify the signature
     Ok(nostr_event.verify().is_ok())
@@ -217,6 +221,8 @@ pub fn greet(name: String) -> String {
 
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
+    // Our logger first, so Rust logs reach Dart instead of the console
+    crate::api::logging::install_logger();
     // Default utilities - feel free to customize
     flutter_rust_bridge::setup_default_user_utils();
 }
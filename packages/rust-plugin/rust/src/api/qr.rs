@@ -0,0 +1,241 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Default fragment size, keeps each frame comfortably inside a medium density QR code
+const DEFAULT_MAX_FRAGMENT_LENGTH: usize = 200;
+
+/// Frames for an animated QR code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimatedQrFrames {
+    pub frames: Vec<String>,
+    // Number of pure fragments, the decoder needs at least this many frames
+    pub fragment_count: u32,
+    // False when the payload fits in a single frame
+    pub is_animated: bool,
+}
+
+/// Decoding progress after a frame was received
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QrDecodeProgress {
+    pub complete: bool,
+    // Estimate between 0.0 and 1.0
+    pub progress: f64,
+    pub received_frames: u32,
+    pub expected_frames: u32,
+    // Reassembled payload once complete
+    pub payload: Option<String>,
+}
+
+/// Wrap raw bytes in a CBOR byte string, as other UR implementations expect for "bytes"
+fn cbor_wrap_bytes(data: &[u8]) -> Vec<u8> {
+    let len = data.len();
+    let mut out = Vec::with_capacity(len + 9);
+    match len {
+        0..=23 => out.push(0x40 | len as u8),
+        24..=0xff => out.extend([0x58, len as u8]),
+        0x100..=0xffff => {
+            out.push(0x59);
+            out.extend((len as u16).to_be_bytes());
+        }
+        0x10000..=0xffff_ffff => {
+            out.push(0x5a);
+            out.extend((len as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(0x5b);
+            out.extend((len as u64).to_be_bytes());
+        }
+    }
+    out.extend_from_slice(data);
+    out
+}
+
+/// Unwrap a CBOR byte string, falling back to the raw bytes for senders that skip CBOR
+fn cbor_unwrap_bytes(data: &[u8]) -> Vec<u8> {
+    let Some(&first) = data.first() else {
+        return Vec::new();
+    };
+    if first & 0xe0 != 0x40 {
+        return data.to_vec();
+    }
+
+    let (header_len, len) = match first & 0x1f {
+        n @ 0..=23 => (1, n as usize),
+        24 if data.len() >= 2 => (2, data[1] as usize),
+        25 if data.len() >= 3 => (3, u16::from_be_bytes([data[1], data[2]]) as usize),
+        26 if data.len() >= 5 => (
+            5,
+            u32::from_be_bytes([data[1], data[2], data[3], data[4]]) as usize,
+        ),
+        _ => return data.to_vec(),
+    };
+
+    if data.len() != header_len + len {
+        return data.to_vec();
+    }
+    data[header_len..].to_vec()
+}
+
+/// Split a payload (e.g. a token from send_tokens) into animated QR frames
+/// Frames use the multi-part UR format with fountain codes, so the scanner can start
+/// at any frame and missed frames are recovered from later ones.
+#[frb(sync)]
+pub fn encode_animated_qr(
+    payload: String,
+    max_fragment_length: Option<u32>,
+    frame_count: Option<u32>,
+) -> Result<AnimatedQrFrames, String> {
+    let max_fragment_length = max_fragment_length
+        .map(|l| l as usize)
+        .unwrap_or(DEFAULT_MAX_FRAGMENT_LENGTH);
+    if max_fragment_length < 10 {
+        return Err("Fragment length must be at least 10 bytes".to_string());
+    }
+
+    // Payloads are sent as the "bytes" UR type, a CBOR byte string
+    let data = cbor_wrap_bytes(payload.as_bytes());
+
+    // Small payloads fit in a single static frame
+    if data.len() <= max_fragment_length {
+        return Ok(AnimatedQrFrames {
+            frames: vec![ur::ur::encode(&data, &ur::Type::Bytes)],
+            fragment_count: 1,
+            is_animated: false,
+        });
+    }
+
+    let mut encoder = ur::Encoder::bytes(&data, max_fragment_length)
+        .map_err(|e| format!("Failed to create UR encoder: {}", e))?;
+    let fragment_count = encoder.fragment_count();
+
+    // Pure fragments first, then fountain frames mixing several fragments
+    let frame_count = frame_count
+        .map(|c| c as usize)
+        .unwrap_or(fragment_count * 2)
+        .max(fragment_count);
+    let frames = (0..frame_count)
+        .map(|_| {
+            encoder
+                .next_part()
+                .map_err(|e| format!("Failed to encode frame: {}", e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(AnimatedQrFrames {
+        frames,
+        fragment_count: fragment_count as u32,
+        is_animated: true,
+    })
+}
+
+/// Stateful decoder for animated QR frames, accepts frames in any order
+#[frb(opaque)]
+pub struct AnimatedQrDecoder {
+    decoder: ur::Decoder,
+    seen_frames: HashSet<u32>,
+    expected_frames: u32,
+    payload: Option<String>,
+}
+
+impl Default for AnimatedQrDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimatedQrDecoder {
+    #[frb(sync)]
+    pub fn new() -> Self {
+        Self {
+            decoder: ur::Decoder::default(),
+            seen_frames: HashSet::new(),
+            expected_frames: 0,
+            payload: None,
+        }
+    }
+
+    /// Feed one scanned frame and get the updated progress
+    #[frb(sync)]
+    pub fn receive(&mut self, frame: String) -> Result<QrDecodeProgress, String> {
+        if self.payload.is_some() {
+            return Ok(self.progress());
+        }
+
+        let frame = frame.trim().to_lowercase();
+        if !frame.starts_with("ur:") {
+            return Err("Not a UR frame".to_string());
+        }
+
+        // ur:<type>/<seq>-<count>/<fragment> for multi-part, ur:<type>/<data> for single part
+        let sections: Vec<&str> = frame.split('/').collect();
+        if sections.len() == 2 {
+            let (_, data) =
+                ur::ur::decode(&frame).map_err(|e| format!("Invalid UR frame: {}", e))?;
+            self.expected_frames = 1;
+            self.seen_frames.insert(1);
+            self.set_payload(&data)?;
+            return Ok(self.progress());
+        }
+
+        if let Some((seq, count)) = sections.get(1).and_then(|s| s.split_once('-')) {
+            if let (Ok(seq), Ok(count)) = (seq.parse::<u32>(), count.parse::<u32>()) {
+                if self.expected_frames != 0 && self.expected_frames != count {
+                    return Err("Frame belongs to a different QR sequence".to_string());
+                }
+                self.expected_frames = count;
+                self.seen_frames.insert(seq);
+            }
+        }
+
+        self.decoder
+            .receive(&frame)
+            .map_err(|e| format!("Invalid UR frame: {}", e))?;
+
+        if self.decoder.complete() {
+            let data = self
+                .decoder
+                .message()
+                .map_err(|e| format!("Failed to reassemble payload: {}", e))?
+                .ok_or("Decoder finished without a payload")?;
+            self.set_payload(&data)?;
+        }
+
+        Ok(self.progress())
+    }
+
+    /// Current progress without feeding a frame
+    #[frb(sync)]
+    pub fn progress(&self) -> QrDecodeProgress {
+        let received_frames = self.seen_frames.len() as u32;
+        let progress = if self.payload.is_some() {
+            1.0
+        } else if self.expected_frames == 0 {
+            0.0
+        } else {
+            // Fountain frames may still be needed after every sequence number is seen
+            (received_frames as f64 / self.expected_frames as f64).min(0.99)
+        };
+
+        QrDecodeProgress {
+            complete: self.payload.is_some(),
+            progress,
+            received_frames,
+            expected_frames: self.expected_frames,
+            payload: self.payload.clone(),
+        }
+    }
+
+    /// Forget all received frames to scan a new code
+    #[frb(sync)]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn set_payload(&mut self, data: &[u8]) -> Result<(), String> {
+        let payload = String::from_utf8(cbor_unwrap_bytes(data))
+            .map_err(|e| format!("Payload is not valid UTF-8: {}", e))?;
+        self.payload = Some(payload);
+        Ok(())
+    }
+}
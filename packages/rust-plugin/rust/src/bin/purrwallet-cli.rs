@@ -0,0 +1,407 @@
+//! Headless purrwallet on top of the same Rust core as the app
+//! Every command prints one JSON value to stdout, errors print {"error": "..."} and exit 1.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rust_lib_nostr_rust::api::cashu::{self, WalletHandle};
+use rust_lib_nostr_rust::api::daemon;
+use rust_lib_nostr_rust::api::nostr::{self, NostrEvent};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long `invoice --wait` polls the mint before giving up
+const INVOICE_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+const INVOICE_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Parser)]
+#[command(name = "purrwallet-cli", version, about = "Headless purrwallet")]
+struct Cli {
+    #[command(flatten)]
+    wallet: WalletArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct WalletArgs {
+    /// Data directory, defaults to the one the desktop app uses
+    #[arg(long, env = "PURRWALLET_DATA_DIR", global = true)]
+    data_dir: Option<PathBuf>,
+
+    /// Wallet profile
+    #[arg(
+        long,
+        env = "PURRWALLET_PROFILE",
+        default_value = "default",
+        global = true
+    )]
+    profile: String,
+
+    /// Mnemonic of the wallet, the app keeps it in secure storage the CLI cannot read
+    #[arg(
+        long,
+        env = "PURRWALLET_MNEMONIC",
+        hide_env_values = true,
+        global = true
+    )]
+    mnemonic: Option<String>,
+
+    /// Key material for an encrypted wallet database
+    #[arg(long, env = "PURRWALLET_DB_KEY", hide_env_values = true, global = true)]
+    db_key: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a new wallet and print its mnemonic
+    Init {
+        #[arg(long, default_value_t = 12)]
+        words: u32,
+    },
+    /// Recover the wallet's proofs from the mints using the mnemonic
+    Restore {
+        #[arg(long = "mint", required = true)]
+        mints: Vec<String>,
+    },
+    /// Manage mints
+    Mints {
+        #[command(subcommand)]
+        command: MintsCommand,
+    },
+    /// Balance per mint
+    Balance,
+    /// Create a token
+    Send {
+        #[arg(long)]
+        mint: String,
+        amount: u64,
+        #[arg(long)]
+        memo: Option<String>,
+    },
+    /// Redeem a token
+    Receive { token: String },
+    /// Create a Lightning invoice to fund the wallet
+    Invoice {
+        #[arg(long)]
+        mint: String,
+        amount: u64,
+        /// Wait for the invoice to be paid and mint the proofs
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Pay a Lightning invoice
+    Pay {
+        #[arg(long)]
+        mint: String,
+        invoice: String,
+        #[arg(long)]
+        max_fee: Option<u64>,
+    },
+    /// Transaction history
+    History,
+    /// Generate a Nostr key pair
+    Keys,
+    /// Encrypt a message for a Nostr public key
+    Encrypt {
+        #[arg(long, value_enum, default_value_t = Nip::Nip44)]
+        nip: Nip,
+        #[arg(long)]
+        to: String,
+        #[arg(long, env = "PURRWALLET_NOSTR_KEY", hide_env_values = true)]
+        key: String,
+        message: String,
+    },
+    /// Decrypt a message from a Nostr public key
+    Decrypt {
+        #[arg(long, value_enum, default_value_t = Nip::Nip44)]
+        nip: Nip,
+        #[arg(long)]
+        from: String,
+        #[arg(long, env = "PURRWALLET_NOSTR_KEY", hide_env_values = true)]
+        key: String,
+        ciphertext: String,
+    },
+    /// Sign an unsigned Nostr event given as JSON
+    Sign {
+        #[arg(long, env = "PURRWALLET_NOSTR_KEY", hide_env_values = true)]
+        key: String,
+        event: String,
+    },
+    /// Verify a signed Nostr event given as JSON
+    Verify { event: String },
+    /// Serve the wallet over JSON-RPC until interrupted
+    Daemon {
+        /// "127.0.0.1:<port>" or "unix:<path>"
+        #[arg(long, default_value = "127.0.0.1:3338")]
+        listen: String,
+    },
+    /// Manage the clients allowed to call the daemon
+    Clients {
+        #[command(subcommand)]
+        command: ClientsCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum ClientsCommand {
+    /// Create a client and print its token
+    Add {
+        name: String,
+        /// read, receive, send, pay or events
+        #[arg(long = "permission", required = true)]
+        permissions: Vec<String>,
+    },
+    Remove {
+        name: String,
+    },
+    List,
+}
+
+#[derive(Subcommand)]
+enum MintsCommand {
+    Add { url: String },
+    List,
+    Info { url: String },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Nip {
+    Nip04,
+    Nip44,
+}
+
+/// Documents directory, where the app's path_provider puts the wallet on Linux
+fn default_data_dir() -> Result<PathBuf, String> {
+    if let Some(dir) = std::env::var_os("XDG_DOCUMENTS_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+    let home = std::env::var_os("HOME").ok_or("HOME is not set, pass --data-dir")?;
+    Ok(PathBuf::from(home).join("Documents"))
+}
+
+impl WalletArgs {
+    fn data_dir(&self) -> Result<String, String> {
+        let dir = match &self.data_dir {
+            Some(dir) => dir.clone(),
+            None => default_data_dir()?,
+        };
+        Ok(dir.to_string_lossy().to_string())
+    }
+
+    async fn seed_hex(&self) -> Result<String, String> {
+        let mnemonic = self
+            .mnemonic
+            .clone()
+            .ok_or("A mnemonic is required, pass --mnemonic or set PURRWALLET_MNEMONIC")?;
+        cashu::mnemonic_to_seed_hex(mnemonic).await
+    }
+
+    async fn open(&self, seed_hex: String) -> Result<WalletHandle, String> {
+        let data_dir = self.data_dir()?;
+        match &self.db_key {
+            #[cfg(feature = "sqlcipher")]
+            Some(key) => {
+                cashu::open_encrypted_wallet(self.profile.clone(), data_dir, seed_hex, key.clone())
+                    .await
+            }
+            #[cfg(not(feature = "sqlcipher"))]
+            Some(_) => Err("Database encryption is not enabled in this build".to_string()),
+            None => cashu::open_wallet(self.profile.clone(), data_dir, seed_hex).await,
+        }
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| format!("Failed to serialize output: {}", e))
+}
+
+/// Commands that only need the Nostr keys, or no wallet at all
+fn run_nostr(command: &Command) -> Option<Result<Value, String>> {
+    let result = match command {
+        Command::Keys => nostr::generate_keys_with_bech32().and_then(to_json),
+        Command::Encrypt {
+            nip,
+            to,
+            key,
+            message,
+        } => match nip {
+            Nip::Nip04 => nostr::nip04_encrypt(message.clone(), to.clone(), key.clone()),
+            Nip::Nip44 => nostr::nip44_encrypt(message.clone(), to.clone(), key.clone()),
+        }
+        .map(|ciphertext| json!({ "ciphertext": ciphertext })),
+        Command::Decrypt {
+            nip,
+            from,
+            key,
+            ciphertext,
+        } => match nip {
+            Nip::Nip04 => nostr::nip04_decrypt(ciphertext.clone(), from.clone(), key.clone()),
+            Nip::Nip44 => nostr::nip44_decrypt(ciphertext.clone(), from.clone(), key.clone()),
+        }
+        .map(|message| json!({ "message": message })),
+        Command::Sign { key, event } => {
+            nostr::sign_event(event.clone(), key.clone()).and_then(|signed| {
+                serde_json::from_str::<Value>(&signed)
+                    .map_err(|e| format!("Failed to parse signed event: {}", e))
+            })
+        }
+        Command::Verify { event } => serde_json::from_str::<NostrEvent>(event)
+            .map_err(|e| format!("Invalid event JSON: {}", e))
+            .and_then(nostr::verify_event)
+            .map(|valid| json!({ "valid": valid })),
+        _ => return None,
+    };
+    Some(result)
+}
+
+async fn run_wallet(handle: &WalletHandle, command: Command) -> Result<Value, String> {
+    match command {
+        Command::Init { .. } => Ok(json!({ "profile": handle.profile_id })),
+        Command::Restore { mints } => {
+            let mut restored = BTreeMap::new();
+            for mint in mints {
+                cashu::add_mint(handle.clone(), mint.clone()).await?;
+                let amount = cashu::restore_mint(handle.clone(), mint.clone()).await?;
+                restored.insert(mint, amount);
+            }
+            Ok(json!({ "restored": restored }))
+        }
+        Command::Mints { command } => match command {
+            MintsCommand::Add { url } => cashu::add_mint(handle.clone(), url)
+                .await
+                .map(|message| json!({ "message": message })),
+            MintsCommand::List => cashu::list_mints(handle.clone()).await.and_then(to_json),
+            MintsCommand::Info { url } => cashu::get_mint_info(handle.clone(), url)
+                .await
+                .and_then(to_json),
+        },
+        Command::Balance => cashu::get_all_balances(handle.clone()).await.map(
+            |balances| json!({ "balances": balances.into_iter().collect::<BTreeMap<_, _>>() }),
+        ),
+        Command::Send { mint, amount, memo } => {
+            cashu::send_tokens(handle.clone(), mint, amount, memo)
+                .await
+                .map(|token| json!({ "token": token }))
+        }
+        Command::Receive { token } => cashu::receive_tokens(handle.clone(), token)
+            .await
+            .map(|amount| json!({ "amount": amount })),
+        Command::Invoice { mint, amount, wait } => {
+            let quote =
+                cashu::create_mint_quote(handle.clone(), mint.clone(), amount, None).await?;
+            let mut output = to_json(quote.into_iter().collect::<BTreeMap<_, _>>())?;
+            if wait {
+                output["minted"] = json!(wait_for_mint(handle, &mint).await?);
+            }
+            Ok(output)
+        }
+        Command::Pay {
+            mint,
+            invoice,
+            max_fee,
+        } => cashu::pay_invoice_for_wallet(handle.clone(), mint, invoice, max_fee)
+            .await
+            .map(|result| json!({ "result": result })),
+        Command::History => cashu::get_all_transactions(handle.clone())
+            .await
+            .and_then(to_json),
+        Command::Daemon { listen } => {
+            let address = daemon::start_daemon(handle.clone(), listen).await?;
+            // Printed right away so scripts can connect, the daemon runs until interrupted
+            println!("{}", json!({ "address": address }));
+            tokio::signal::ctrl_c()
+                .await
+                .map_err(|e| format!("Failed to wait for interrupt: {}", e))?;
+            daemon::stop_daemon(handle.clone()).await?;
+            Ok(Value::Null)
+        }
+        Command::Clients { command } => match command {
+            ClientsCommand::Add { name, permissions } => {
+                daemon::add_daemon_client(handle.clone(), name, permissions)
+                    .await
+                    .map(|token| json!({ "token": token }))
+            }
+            ClientsCommand::Remove { name } => daemon::remove_daemon_client(handle.clone(), name)
+                .await
+                .map(|_| json!({ "removed": true })),
+            ClientsCommand::List => daemon::list_daemon_clients(handle.clone())
+                .await
+                .and_then(to_json),
+        },
+        _ => Err("Not a wallet command".to_string()),
+    }
+}
+
+/// Poll the mint until the quote is paid and its proofs are minted
+async fn wait_for_mint(handle: &WalletHandle, mint: &str) -> Result<u64, String> {
+    let deadline = tokio::time::Instant::now() + INVOICE_WAIT_TIMEOUT;
+    loop {
+        let minted: u64 = cashu::check_mint_quote_status(handle.clone(), mint.to_string())
+            .await?
+            .parse()
+            .map_err(|e| format!("Invalid minted amount: {}", e))?;
+        if minted > 0 {
+            return Ok(minted);
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Err("Timed out waiting for the invoice to be paid".to_string());
+        }
+        tokio::time::sleep(INVOICE_POLL_INTERVAL).await;
+    }
+}
+
+async fn run(cli: Cli) -> Result<Value, String> {
+    if let Some(result) = run_nostr(&cli.command) {
+        return result;
+    }
+
+    let mut output = None;
+    let seed_hex = match &cli.command {
+        Command::Init { words } => {
+            if cli.wallet.mnemonic.is_some() {
+                return Err(
+                    "init creates a new mnemonic, use restore for an existing one".to_string(),
+                );
+            }
+            // A new mnemonic would open the existing database with the wrong seed
+            let profiles = cashu::list_profiles(cli.wallet.data_dir()?).await?;
+            if profiles.iter().any(|p| p.profile_id == cli.wallet.profile) {
+                return Err(format!(
+                    "Wallet profile '{}' already exists, use --profile for a new one",
+                    cli.wallet.profile
+                ));
+            }
+            let mnemonic = cashu::generate_mnemonic_phrase(*words).await?;
+            output = Some(json!({ "mnemonic": mnemonic }));
+            cashu::mnemonic_to_seed_hex(mnemonic).await?
+        }
+        _ => cli.wallet.seed_hex().await?,
+    };
+
+    let handle = cli.wallet.open(seed_hex).await?;
+    let result = run_wallet(&handle, cli.command).await;
+    // Closing waits for running operations and clears them from the journal
+    cashu::close_wallet(handle).await?;
+
+    let mut result = result?;
+    if let (Some(Value::Object(extra)), Value::Object(fields)) = (output, &mut result) {
+        fields.extend(extra);
+    }
+    Ok(result)
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    match run(cli).await {
+        // The daemon already printed its address
+        Ok(Value::Null) => {}
+        Ok(value) => println!("{}", value),
+        Err(e) => {
+            println!("{}", json!({ "error": e }));
+            std::process::exit(1);
+        }
+    }
+}
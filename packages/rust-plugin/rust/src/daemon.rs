@@ -0,0 +1,344 @@
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, oneshot};
+
+use crate::api::cashu::{self, WalletHandle};
+use crate::api::daemon::{authorize, Permission};
+use crate::api::events::subscribe;
+
+// JSON-RPC 2.0 error codes
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const WALLET_ERROR: i64 = -32000;
+const UNAUTHORIZED: i64 = -32001;
+
+/// Daemon serving one wallet profile
+struct RunningDaemon {
+    address: String,
+    shutdown: oneshot::Sender<()>,
+}
+
+/// Running daemons by profile id
+static DAEMONS: Mutex<BTreeMap<String, RunningDaemon>> = Mutex::new(BTreeMap::new());
+
+struct DaemonState {
+    handle: WalletHandle,
+    profile_dir: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct RpcRequest {
+    jsonrpc: Option<String>,
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize)]
+struct TokenQuery {
+    token: Option<String>,
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(|token| token.trim().to_string())
+}
+
+/// Permission a method needs
+fn required_permission(method: &str) -> Option<Permission> {
+    match method {
+        "get_balances" | "list_mints" | "get_mint_info" | "get_transactions" => {
+            Some(Permission::Read)
+        }
+        "receive_tokens" | "create_invoice" | "check_invoice" => Some(Permission::Receive),
+        "send_tokens" => Some(Permission::Send),
+        "pay_invoice" => Some(Permission::Pay),
+        _ => None,
+    }
+}
+
+fn param<T: serde::de::DeserializeOwned>(params: &Value, name: &str) -> Result<T, RpcError> {
+    serde_json::from_value(params.get(name).cloned().unwrap_or(Value::Null))
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid param '{}': {}", name, e)))
+}
+
+fn to_value<T: serde::Serialize>(result: Result<T, String>) -> Result<Value, RpcError> {
+    let value = result.map_err(|e| RpcError::new(WALLET_ERROR, e))?;
+    serde_json::to_value(value).map_err(|e| RpcError::new(WALLET_ERROR, e))
+}
+
+/// Run a method against the wallet the daemon was started for
+async fn dispatch(handle: &WalletHandle, method: &str, params: &Value) -> Result<Value, RpcError> {
+    let handle = handle.clone();
+    match method {
+        "get_balances" => to_value(cashu::get_all_balances(handle).await),
+        "list_mints" => to_value(cashu::list_mints(handle).await),
+        "get_mint_info" => to_value(cashu::get_mint_info(handle, param(params, "mint_url")?).await),
+        "get_transactions" => to_value(cashu::get_all_transactions(handle).await),
+        "receive_tokens" => to_value(cashu::receive_tokens(handle, param(params, "token")?).await),
+        "create_invoice" => to_value(
+            cashu::create_mint_quote(
+                handle,
+                param(params, "mint_url")?,
+                param(params, "amount")?,
+                param(params, "description")?,
+            )
+            .await,
+        ),
+        "check_invoice" => {
+            to_value(cashu::check_mint_quote_status(handle, param(params, "mint_url")?).await)
+        }
+        "send_tokens" => to_value(
+            cashu::send_tokens(
+                handle,
+                param(params, "mint_url")?,
+                param(params, "amount")?,
+                param(params, "memo")?,
+            )
+            .await,
+        ),
+        "pay_invoice" => to_value(
+            cashu::pay_invoice_for_wallet(
+                handle,
+                param(params, "mint_url")?,
+                param(params, "invoice")?,
+                param(params, "max_fee")?,
+            )
+            .await,
+        ),
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Unknown method '{}'", method),
+        )),
+    }
+}
+
+async fn rpc(
+    State(state): State<Arc<DaemonState>>,
+    headers: HeaderMap,
+    Json(request): Json<RpcRequest>,
+) -> Response {
+    let id = request.id.clone().unwrap_or(Value::Null);
+    let respond = |status: StatusCode, result: Result<Value, RpcError>| {
+        let body = match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": e.code, "message": e.message },
+            }),
+        };
+        (status, Json(body)).into_response()
+    };
+
+    if request.jsonrpc.as_deref() != Some("2.0") {
+        return respond(
+            StatusCode::BAD_REQUEST,
+            Err(RpcError::new(INVALID_REQUEST, "Expected jsonrpc 2.0")),
+        );
+    }
+    let Some(permission) = required_permission(&request.method) else {
+        return respond(
+            StatusCode::OK,
+            Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method '{}'", request.method),
+            )),
+        );
+    };
+    let token = bearer_token(&headers).unwrap_or_default();
+    if let Err(e) = authorize(&state.profile_dir, &token, permission) {
+        return respond(
+            StatusCode::UNAUTHORIZED,
+            Err(RpcError::new(UNAUTHORIZED, e)),
+        );
+    }
+
+    respond(
+        StatusCode::OK,
+        dispatch(&state.handle, &request.method, &request.params).await,
+    )
+}
+
+async fn events(
+    State(state): State<Arc<DaemonState>>,
+    headers: HeaderMap,
+    Query(query): Query<TokenQuery>,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let token = bearer_token(&headers).or(query.token).unwrap_or_default();
+    if let Err(e) = authorize(&state.profile_dir, &token, Permission::Events) {
+        return (StatusCode::UNAUTHORIZED, e).into_response();
+    }
+    let profile_id = state.handle.profile_id.clone();
+    upgrade.on_upgrade(move |socket| stream_events(socket, profile_id))
+}
+
+/// Forward the profile's wallet events until the client goes away
+async fn stream_events(mut socket: WebSocket, profile_id: String) {
+    let mut receiver = subscribe();
+    loop {
+        let event = match receiver.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        if event.profile_id.as_deref() != Some(profile_id.as_str()) {
+            continue;
+        }
+        let Ok(json) = serde_json::to_string(&event) else {
+            continue;
+        };
+        if socket.send(Message::Text(json.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Start serving a profile, fails if it already has a daemon
+pub(crate) async fn start(
+    handle: WalletHandle,
+    profile_dir: PathBuf,
+    listen: &str,
+) -> Result<String, String> {
+    if address(&handle.profile_id).is_some() {
+        return Err(format!(
+            "A daemon is already running for wallet profile '{}'",
+            handle.profile_id
+        ));
+    }
+
+    let profile_id = handle.profile_id.clone();
+    let router = Router::new()
+        .route("/rpc", post(rpc))
+        .route("/events", get(events))
+        .with_state(Arc::new(DaemonState {
+            handle,
+            profile_dir,
+        }));
+    let (shutdown, shutdown_received) = oneshot::channel::<()>();
+    let stopped = async move {
+        let _ = shutdown_received.await;
+    };
+
+    let address = match listen.strip_prefix("unix:") {
+        #[cfg(unix)]
+        Some(path) => {
+            // A socket left behind by a crashed daemon blocks the bind
+            remove_socket(path)?;
+
+            // Create the socket owner-only, a chmod after bind would leave it open
+            // to other users for a moment.
+            // SAFETY: umask only swaps the process file mode mask
+            let previous_umask = unsafe { libc::umask(0o177) };
+            let listener = tokio::net::UnixListener::bind(path);
+            // SAFETY: as above, restores the mask the process had
+            unsafe { libc::umask(previous_umask) };
+            let listener = listener.map_err(|e| format!("Failed to bind daemon socket: {}", e))?;
+            tokio::spawn(async move {
+                let _ = axum::serve(listener, router)
+                    .with_graceful_shutdown(stopped)
+                    .await;
+            });
+            format!("unix:{}", path)
+        }
+        #[cfg(not(unix))]
+        Some(_) => return Err("Unix sockets are not supported on this platform".to_string()),
+        None => {
+            let addr: SocketAddr = listen
+                .parse()
+                .map_err(|e| format!("Invalid daemon address: {}", e))?;
+            // The token is the only protection, keep the daemon off the network
+            if !addr.ip().is_loopback() {
+                return Err("The daemon only listens on localhost".to_string());
+            }
+            let listener = tokio::net::TcpListener::bind(addr)
+                .await
+                .map_err(|e| format!("Failed to bind daemon address: {}", e))?;
+            let address = listener
+                .local_addr()
+                .map_err(|e| format!("Failed to read daemon address: {}", e))?
+                .to_string();
+            tokio::spawn(async move {
+                let _ = axum::serve(listener, router)
+                    .with_graceful_shutdown(stopped)
+                    .await;
+            });
+            address
+        }
+    };
+
+    DAEMONS.lock().map_err(|e| e.to_string())?.insert(
+        profile_id,
+        RunningDaemon {
+            address: address.clone(),
+            shutdown,
+        },
+    );
+    Ok(address)
+}
+
+pub(crate) fn stop(profile_id: &str) {
+    let Ok(mut daemons) = DAEMONS.lock() else {
+        return;
+    };
+    if let Some(daemon) = daemons.remove(profile_id) {
+        let _ = daemon.shutdown.send(());
+        #[cfg(unix)]
+        if let Some(path) = daemon.address.strip_prefix("unix:") {
+            let _ = remove_socket(path);
+        }
+    }
+}
+
+/// Remove a Unix socket, refusing to touch anything at the path that is not a socket
+#[cfg(unix)]
+fn remove_socket(path: &str) -> Result<(), String> {
+    use std::os::unix::fs::FileTypeExt;
+
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path)
+            .map_err(|e| format!("Failed to remove stale daemon socket: {}", e)),
+        Ok(_) => Err(format!("{} exists and is not a socket", path)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to inspect daemon socket path: {}", e)),
+    }
+}
+
+pub(crate) fn address(profile_id: &str) -> Option<String> {
+    DAEMONS
+        .lock()
+        .ok()?
+        .get(profile_id)
+        .map(|daemon| daemon.address.clone())
+}
@@ -0,0 +1,174 @@
+use rand::random;
+use rusqlite::{Connection, ErrorCode};
+use sha2::Sha256;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// PBKDF2 rounds used to turn the PIN / keystore material into the database key
+const KEY_DERIVATION_ROUNDS: u32 = 210_000;
+
+/// Every plaintext SQLite file starts with this header, SQLCipher files look random
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+fn salt_path(db_path: &Path) -> PathBuf {
+    db_path.with_extension("salt")
+}
+
+/// Salt written during key rotation, promoted once the rekey is known to have succeeded
+fn pending_salt_path(db_path: &Path) -> PathBuf {
+    db_path.with_extension("salt.new")
+}
+
+/// Whether the file is a plaintext SQLite database, only its header is read
+pub(crate) fn is_plaintext_database(db_path: &Path) -> Result<bool, String> {
+    let mut file =
+        std::fs::File::open(db_path).map_err(|e| format!("Failed to read database: {}", e))?;
+    let mut header = [0u8; SQLITE_HEADER.len()];
+    match file.read_exact(&mut header) {
+        Ok(()) => Ok(header == *SQLITE_HEADER),
+        // Shorter than a header, so not a SQLite file either way
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(format!("Failed to read database: {}", e)),
+    }
+}
+
+fn derive_key(key_material: &str, salt: &[u8]) -> String {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(
+        key_material.as_bytes(),
+        salt,
+        KEY_DERIVATION_ROUNDS,
+        &mut key,
+    );
+    hex::encode(key)
+}
+
+fn read_salt(path: &Path) -> Result<Vec<u8>, String> {
+    let salt_hex = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read database salt: {}", e))?;
+    hex::decode(salt_hex.trim()).map_err(|e| format!("Corrupt database salt: {}", e))
+}
+
+fn write_salt(path: &Path, salt: &[u8]) -> Result<(), String> {
+    std::fs::write(path, hex::encode(salt))
+        .map_err(|e| format!("Failed to write database salt: {}", e))
+}
+
+/// Open a connection and apply the key
+fn open_with_key(db_path: &Path, key: &str) -> Result<Connection, String> {
+    let conn = Connection::open(db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+    conn.pragma_update(None, "key", key)
+        .map_err(|e| format!("Failed to set database key: {}", e))?;
+    Ok(conn)
+}
+
+/// Check the key by reading the schema, SQLCipher only notices a wrong key on first read
+fn check_key(db_path: &Path, key: &str) -> Result<bool, String> {
+    let conn = open_with_key(db_path, key)?;
+    match conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| {
+        row.get::<_, i64>(0)
+    }) {
+        Ok(_) => Ok(true),
+        Err(rusqlite::Error::SqliteFailure(e, _)) if e.code == ErrorCode::NotADatabase => Ok(false),
+        Err(e) => Err(format!("Failed to read database: {}", e)),
+    }
+}
+
+/// Copy a plaintext database into a new encrypted one and replace the original
+fn migrate_plaintext(db_path: &Path, key: &str) -> Result<(), String> {
+    let encrypted_path = db_path.with_extension("db.encrypting");
+    let _ = std::fs::remove_file(&encrypted_path);
+
+    {
+        let conn = Connection::open(db_path)
+            .map_err(|e| format!("Failed to open plaintext database: {}", e))?;
+        conn.execute(
+            "ATTACH DATABASE ?1 AS encrypted KEY ?2",
+            [encrypted_path.to_string_lossy().as_ref(), key],
+        )
+        .map_err(|e| format!("Failed to create encrypted database: {}", e))?;
+        conn.query_row("SELECT sqlcipher_export('encrypted')", [], |_| Ok(()))
+            .map_err(|e| format!("Failed to encrypt database: {}", e))?;
+        conn.execute("DETACH DATABASE encrypted", [])
+            .map_err(|e| format!("Failed to finish encrypting database: {}", e))?;
+    }
+
+    // Stale WAL / journal files belong to the plaintext copy
+    for suffix in ["db-wal", "db-shm", "db-journal"] {
+        let _ = std::fs::remove_file(db_path.with_extension(suffix));
+    }
+    std::fs::rename(&encrypted_path, db_path)
+        .map_err(|e| format!("Failed to replace plaintext database: {}", e))
+}
+
+/// Resolve the key for the wallet database, creating the salt and encrypting an
+/// existing plaintext database on first use
+pub(crate) fn prepare_encrypted_database(
+    db_path: &Path,
+    key_material: &str,
+) -> Result<String, String> {
+    if key_material.is_empty() {
+        return Err("Database key cannot be empty".to_string());
+    }
+
+    let salt_path = salt_path(db_path);
+    if !salt_path.exists() {
+        write_salt(&salt_path, &random::<[u8; 16]>())?;
+    }
+    let key = derive_key(key_material, &read_salt(&salt_path)?);
+
+    if !db_path.exists() {
+        // SQLCipher creates the database encrypted on first open
+        return Ok(key);
+    }
+
+    if is_plaintext_database(db_path)? {
+        migrate_plaintext(db_path, &key)?;
+        return Ok(key);
+    }
+
+    if check_key(db_path, &key)? {
+        let _ = std::fs::remove_file(pending_salt_path(db_path));
+        return Ok(key);
+    }
+
+    // A rotation may have been interrupted after the rekey but before the salt was promoted
+    let pending_salt_path = pending_salt_path(db_path);
+    if pending_salt_path.exists() {
+        let key = derive_key(key_material, &read_salt(&pending_salt_path)?);
+        if check_key(db_path, &key)? {
+            std::fs::rename(&pending_salt_path, &salt_path)
+                .map_err(|e| format!("Failed to update database salt: {}", e))?;
+            return Ok(key);
+        }
+    }
+
+    Err("Wrong wallet database key".to_string())
+}
+
+/// Re-encrypt the database under a key derived from new key material
+/// The wallet must not have the database open.
+pub(crate) fn rotate_key(
+    db_path: &Path,
+    old_key_material: &str,
+    new_key_material: &str,
+) -> Result<(), String> {
+    if new_key_material.is_empty() {
+        return Err("Database key cannot be empty".to_string());
+    }
+
+    let old_key = prepare_encrypted_database(db_path, old_key_material)?;
+
+    let new_salt = random::<[u8; 16]>();
+    let new_key = derive_key(new_key_material, &new_salt);
+    let pending_salt_path = pending_salt_path(db_path);
+    write_salt(&pending_salt_path, &new_salt)?;
+
+    let conn = open_with_key(db_path, &old_key)?;
+    conn.pragma_update(None, "rekey", &new_key)
+        .map_err(|e| format!("Failed to change database key: {}", e))?;
+    drop(conn);
+
+    std::fs::rename(&pending_salt_path, salt_path(db_path))
+        .map_err(|e| format!("Failed to update database salt: {}", e))
+}
@@ -25,6 +25,7 @@
 
 // Section: imports
 
+use crate::api::qr::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -37,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.7.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1346198312;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1017403726;
 
 // Section: executor
 
@@ -45,7 +46,174 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__cashu__add_mint_impl(
+fn wire__crate__api__qr__AnimatedQrDecoder_new_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AnimatedQrDecoder_new",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::qr::AnimatedQrDecoder::new())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__qr__AnimatedQrDecoder_progress_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AnimatedQrDecoder_progress",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <AnimatedQrDecoder>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::qr::AnimatedQrDecoder::progress(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__qr__AnimatedQrDecoder_receive_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AnimatedQrDecoder_receive",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <AnimatedQrDecoder>::sse_decode(&mut deserializer);
+            let api_frame = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::api::qr::AnimatedQrDecoder::receive(&mut *api_that_guard, api_frame)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__qr__AnimatedQrDecoder_reset_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AnimatedQrDecoder_reset",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <AnimatedQrDecoder>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::qr::AnimatedQrDecoder::reset(&mut *api_that_guard);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__daemon__add_daemon_client_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +221,7 @@ fn wire__crate__api__cashu__add_mint_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_mint",
+            debug_name: "add_daemon_client",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,12 +235,19 @@ fn wire__crate__api__cashu__add_mint_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_permissions = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::add_mint(api_mint_url).await?;
+                        let output_ok = crate::api::daemon::add_daemon_client(
+                            api_handle,
+                            api_name,
+                            api_permissions,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -81,7 +256,7 @@ fn wire__crate__api__cashu__add_mint_impl(
         },
     )
 }
-fn wire__crate__api__cashu__check_all_melt_quotes_impl(
+fn wire__crate__api__cashu__add_mint_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -89,7 +264,7 @@ fn wire__crate__api__cashu__check_all_melt_quotes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_all_melt_quotes",
+            debug_name: "add_mint",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -103,11 +278,14 @@ fn wire__crate__api__cashu__check_all_melt_quotes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::check_all_melt_quotes().await?;
+                        let output_ok =
+                            crate::api::cashu::add_mint(api_handle, api_mint_url).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -116,7 +294,7 @@ fn wire__crate__api__cashu__check_all_melt_quotes_impl(
         },
     )
 }
-fn wire__crate__api__cashu__check_all_mint_quotes_impl(
+fn wire__crate__api__cashu__check_all_melt_quotes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -124,7 +302,7 @@ fn wire__crate__api__cashu__check_all_mint_quotes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_all_mint_quotes",
+            debug_name: "check_all_melt_quotes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -138,11 +316,13 @@ fn wire__crate__api__cashu__check_all_mint_quotes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::check_all_mint_quotes().await?;
+                        let output_ok =
+                            crate::api::cashu::check_all_melt_quotes(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -151,7 +331,7 @@ fn wire__crate__api__cashu__check_all_mint_quotes_impl(
         },
     )
 }
-fn wire__crate__api__cashu__check_melt_quote_status_impl(
+fn wire__crate__api__cashu__check_all_mint_quotes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -159,7 +339,7 @@ fn wire__crate__api__cashu__check_melt_quote_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_melt_quote_status",
+            debug_name: "check_all_mint_quotes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -173,13 +353,13 @@ fn wire__crate__api__cashu__check_melt_quote_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::cashu::check_melt_quote_status(api_mint_url).await?;
+                            crate::api::cashu::check_all_mint_quotes(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -188,7 +368,7 @@ fn wire__crate__api__cashu__check_melt_quote_status_impl(
         },
     )
 }
-fn wire__crate__api__cashu__check_mint_quote_status_impl(
+fn wire__crate__api__cashu__check_melt_quote_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -196,7 +376,7 @@ fn wire__crate__api__cashu__check_mint_quote_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_mint_quote_status",
+            debug_name: "check_melt_quote_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -210,13 +390,15 @@ fn wire__crate__api__cashu__check_mint_quote_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             let api_mint_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::cashu::check_mint_quote_status(api_mint_url).await?;
+                            crate::api::cashu::check_melt_quote_status(api_handle, api_mint_url)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -225,7 +407,7 @@ fn wire__crate__api__cashu__check_mint_quote_status_impl(
         },
     )
 }
-fn wire__crate__api__cashu__create_mint_quote_impl(
+fn wire__crate__api__cashu__check_mint_capability_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -233,7 +415,7 @@ fn wire__crate__api__cashu__create_mint_quote_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_mint_quote",
+            debug_name: "check_mint_capability",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -247,17 +429,17 @@ fn wire__crate__api__cashu__create_mint_quote_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             let api_mint_url = <String>::sse_decode(&mut deserializer);
-            let api_amount = <u64>::sse_decode(&mut deserializer);
-            let api_description = <Option<String>>::sse_decode(&mut deserializer);
+            let api_capability = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::create_mint_quote(
+                        let output_ok = crate::api::cashu::check_mint_capability(
+                            api_handle,
                             api_mint_url,
-                            api_amount,
-                            api_description,
+                            api_capability,
                         )
                         .await?;
                         Ok(output_ok)
@@ -268,7 +450,7 @@ fn wire__crate__api__cashu__create_mint_quote_impl(
         },
     )
 }
-fn wire__crate__api__cashu__decode_bolt11_invoice_impl(
+fn wire__crate__api__cashu__check_mint_quote_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -276,7 +458,7 @@ fn wire__crate__api__cashu__decode_bolt11_invoice_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decode_bolt11_invoice",
+            debug_name: "check_mint_quote_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -290,13 +472,15 @@ fn wire__crate__api__cashu__decode_bolt11_invoice_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_invoice = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::cashu::decode_bolt11_invoice(api_invoice).await?;
+                            crate::api::cashu::check_mint_quote_status(api_handle, api_mint_url)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -305,16 +489,17 @@ fn wire__crate__api__cashu__decode_bolt11_invoice_impl(
         },
     )
 }
-fn wire__crate__api__nostr__generate_keys_impl(
+fn wire__crate__api__cashu__clear_interrupted_operations_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_keys",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "clear_interrupted_operations",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -326,22 +511,29 @@ fn wire__crate__api__nostr__generate_keys_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::generate_keys()?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::clear_interrupted_operations(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__nostr__generate_keys_with_bech32_impl(
+fn wire__crate__api__logging__clear_log_buffer_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_keys_with_bech32",
+            debug_name: "clear_log_buffer",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -357,13 +549,13 @@ fn wire__crate__api__nostr__generate_keys_with_bech32_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::generate_keys_with_bech32()?;
+                let output_ok = crate::api::logging::clear_log_buffer()?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cashu__generate_mnemonic_phrase_impl(
+fn wire__crate__api__cashu__close_wallet_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -371,7 +563,7 @@ fn wire__crate__api__cashu__generate_mnemonic_phrase_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_mnemonic_phrase",
+            debug_name: "close_wallet",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -385,13 +577,12 @@ fn wire__crate__api__cashu__generate_mnemonic_phrase_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_word_count = <u32>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::cashu::generate_mnemonic_phrase(api_word_count).await?;
+                        let output_ok = crate::api::cashu::close_wallet(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -400,7 +591,7 @@ fn wire__crate__api__cashu__generate_mnemonic_phrase_impl(
         },
     )
 }
-fn wire__crate__api__cashu__get_all_balances_impl(
+fn wire__crate__api__cashu__combine_tokens_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -408,7 +599,7 @@ fn wire__crate__api__cashu__get_all_balances_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_balances",
+            debug_name: "combine_tokens",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -422,11 +613,21 @@ fn wire__crate__api__cashu__get_all_balances_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_tokens = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_memo = <Option<String>>::sse_decode(&mut deserializer);
+            let api_encoding = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::get_all_balances().await?;
+                        let output_ok = crate::api::cashu::combine_tokens(
+                            api_handle,
+                            api_tokens,
+                            api_memo,
+                            api_encoding,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -435,7 +636,7 @@ fn wire__crate__api__cashu__get_all_balances_impl(
         },
     )
 }
-fn wire__crate__api__cashu__get_all_transactions_impl(
+fn wire__crate__api__cashu__create_bolt12_mint_quote_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -443,7 +644,7 @@ fn wire__crate__api__cashu__get_all_transactions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_transactions",
+            debug_name: "create_bolt12_mint_quote",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -457,11 +658,21 @@ fn wire__crate__api__cashu__get_all_transactions_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_amount = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_description = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::get_all_transactions().await?;
+                        let output_ok = crate::api::cashu::create_bolt12_mint_quote(
+                            api_handle,
+                            api_mint_url,
+                            api_amount,
+                            api_description,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -470,7 +681,7 @@ fn wire__crate__api__cashu__get_all_transactions_impl(
         },
     )
 }
-fn wire__crate__api__cashu__get_mint_info_impl(
+fn wire__crate__api__cashu__create_mint_quote_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -478,7 +689,7 @@ fn wire__crate__api__cashu__get_mint_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_mint_info",
+            debug_name: "create_mint_quote",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -492,12 +703,21 @@ fn wire__crate__api__cashu__get_mint_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_amount = <u64>::sse_decode(&mut deserializer);
+            let api_description = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::get_mint_info(api_mint_url).await?;
+                        let output_ok = crate::api::cashu::create_mint_quote(
+                            api_handle,
+                            api_mint_url,
+                            api_amount,
+                            api_description,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -506,16 +726,17 @@ fn wire__crate__api__cashu__get_mint_info_impl(
         },
     )
 }
-fn wire__crate__api__nostr__get_public_key_from_private_impl(
+fn wire__crate__api__cashu__create_payment_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_public_key_from_private",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "create_payment_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -527,16 +748,35 @@ fn wire__crate__api__nostr__get_public_key_from_private_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_private_key = <String>::sse_decode(&mut deserializer);
+            let api_amount = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_mints = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_description = <Option<String>>::sse_decode(&mut deserializer);
+            let api_single_use = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_p2pk_pubkey = <Option<String>>::sse_decode(&mut deserializer);
+            let api_transports =
+                <Vec<crate::api::cashu::PaymentRequestTransport>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::get_public_key_from_private(api_private_key)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::create_payment_request(
+                            api_amount,
+                            api_mints,
+                            api_description,
+                            api_single_use,
+                            api_p2pk_pubkey,
+                            api_transports,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__cashu__get_tor_config_impl(
+fn wire__crate__api__cashu__decode_bolt11_invoice_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -544,7 +784,7 @@ fn wire__crate__api__cashu__get_tor_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_tor_config",
+            debug_name: "decode_bolt11_invoice",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -558,11 +798,13 @@ fn wire__crate__api__cashu__get_tor_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_invoice = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::get_tor_config().await?;
+                        let output_ok =
+                            crate::api::cashu::decode_bolt11_invoice(api_invoice).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -571,7 +813,7 @@ fn wire__crate__api__cashu__get_tor_config_impl(
         },
     )
 }
-fn wire__crate__api__cashu__get_wallet_info_impl(
+fn wire__crate__api__cashu__decode_bolt12_invoice_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -579,7 +821,7 @@ fn wire__crate__api__cashu__get_wallet_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_wallet_info",
+            debug_name: "decode_bolt12_invoice_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -593,12 +835,15 @@ fn wire__crate__api__cashu__get_wallet_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_invoice_request_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::get_wallet_info(api_mint_url).await?;
+                        let output_ok = crate::api::cashu::decode_bolt12_invoice_request(
+                            api_invoice_request_hex,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -607,7 +852,7 @@ fn wire__crate__api__cashu__get_wallet_info_impl(
         },
     )
 }
-fn wire__crate__api__cashu__get_wallet_proofs_impl(
+fn wire__crate__api__cashu__decode_bolt12_offer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -615,7 +860,7 @@ fn wire__crate__api__cashu__get_wallet_proofs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_wallet_proofs",
+            debug_name: "decode_bolt12_offer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -629,12 +874,12 @@ fn wire__crate__api__cashu__get_wallet_proofs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_offer = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::get_wallet_proofs(api_mint_url).await?;
+                        let output_ok = crate::api::cashu::decode_bolt12_offer(api_offer).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -643,79 +888,15 @@ fn wire__crate__api__cashu__get_wallet_proofs_impl(
         },
     )
 }
-fn wire__crate__api__nostr__greet_impl(
+fn wire__crate__api__cashu__decode_payment_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::nostr::greet(api_name))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__nostr__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::nostr::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__cashu__init_multi_mint_wallet_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_multi_mint_wallet",
+            debug_name: "decode_payment_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -729,17 +910,13 @@ fn wire__crate__api__cashu__init_multi_mint_wallet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_database_dir = <String>::sse_decode(&mut deserializer);
-            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            let api_request = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::init_multi_mint_wallet(
-                            api_database_dir,
-                            api_seed_hex,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::cashu::decode_payment_request(api_request).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -748,7 +925,7 @@ fn wire__crate__api__cashu__init_multi_mint_wallet_impl(
         },
     )
 }
-fn wire__crate__api__cashu__init_multi_mint_wallet_with_tor_impl(
+fn wire__crate__api__cashu__delete_wallet_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -756,7 +933,7 @@ fn wire__crate__api__cashu__init_multi_mint_wallet_with_tor_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_multi_mint_wallet_with_tor",
+            debug_name: "delete_wallet",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -770,17 +947,17 @@ fn wire__crate__api__cashu__init_multi_mint_wallet_with_tor_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
             let api_database_dir = <String>::sse_decode(&mut deserializer);
-            let api_seed_hex = <String>::sse_decode(&mut deserializer);
-            let api__tor_config = <Option<String>>::sse_decode(&mut deserializer);
+            let api_force = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::init_multi_mint_wallet_with_tor(
+                        let output_ok = crate::api::cashu::delete_wallet(
+                            api_profile_id,
                             api_database_dir,
-                            api_seed_hex,
-                            api__tor_config,
+                            api_force,
                         )
                         .await?;
                         Ok(output_ok)
@@ -791,7 +968,7 @@ fn wire__crate__api__cashu__init_multi_mint_wallet_with_tor_impl(
         },
     )
 }
-fn wire__crate__api__cashu__is_tor_enabled_impl(
+fn wire__crate__api__nip60__disable_nip60_sync_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -799,7 +976,7 @@ fn wire__crate__api__cashu__is_tor_enabled_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_tor_enabled",
+            debug_name: "disable_nip60_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -813,11 +990,12 @@ fn wire__crate__api__cashu__is_tor_enabled_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::is_tor_enabled().await?;
+                        let output_ok = crate::api::nip60::disable_nip60_sync(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -826,7 +1004,7 @@ fn wire__crate__api__cashu__is_tor_enabled_impl(
         },
     )
 }
-fn wire__crate__api__cashu__is_tor_ready_impl(
+fn wire__crate__api__nip60__enable_nip60_sync_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -834,7 +1012,7 @@ fn wire__crate__api__cashu__is_tor_ready_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_tor_ready",
+            debug_name: "enable_nip60_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -848,11 +1026,19 @@ fn wire__crate__api__cashu__is_tor_ready_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_private_key = <String>::sse_decode(&mut deserializer);
+            let api_relays = <Option<Vec<String>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::is_tor_ready().await?;
+                        let output_ok = crate::api::nip60::enable_nip60_sync(
+                            api_handle,
+                            api_private_key,
+                            api_relays,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -861,17 +1047,16 @@ fn wire__crate__api__cashu__is_tor_ready_impl(
         },
     )
 }
-fn wire__crate__api__cashu__list_mints_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__qr__encode_animated_qr_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_mints",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "encode_animated_qr",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -883,20 +1068,22 @@ fn wire__crate__api__cashu__list_mints_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload = <String>::sse_decode(&mut deserializer);
+            let api_max_fragment_length = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_frame_count = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::cashu::list_mints().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::qr::encode_animated_qr(
+                    api_payload,
+                    api_max_fragment_length,
+                    api_frame_count,
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__cashu__mnemonic_to_seed_hex_impl(
+fn wire__crate__api__cashu__encode_token_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -904,7 +1091,7 @@ fn wire__crate__api__cashu__mnemonic_to_seed_hex_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mnemonic_to_seed_hex",
+            debug_name: "encode_token",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -918,13 +1105,16 @@ fn wire__crate__api__cashu__mnemonic_to_seed_hex_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mnemonic_phrase = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_encoding = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::cashu::mnemonic_to_seed_hex(api_mnemonic_phrase).await?;
+                            crate::api::cashu::encode_token(api_handle, api_token, api_encoding)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -933,14 +1123,14 @@ fn wire__crate__api__cashu__mnemonic_to_seed_hex_impl(
         },
     )
 }
-fn wire__crate__api__nostr__nip04_decrypt_impl(
+fn wire__crate__api__logging__export_diagnostics_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "nip04_decrypt",
+            debug_name: "export_diagnostics",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -954,29 +1144,22 @@ fn wire__crate__api__nostr__nip04_decrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ciphertext = <String>::sse_decode(&mut deserializer);
-            let api_public_key = <String>::sse_decode(&mut deserializer);
-            let api_private_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::nip04_decrypt(
-                    api_ciphertext,
-                    api_public_key,
-                    api_private_key,
-                )?;
+                let output_ok = crate::api::logging::export_diagnostics()?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__nostr__nip04_encrypt_impl(
+fn wire__crate__api__nostr__generate_keys_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "nip04_encrypt",
+            debug_name: "generate_keys",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -990,29 +1173,22 @@ fn wire__crate__api__nostr__nip04_encrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_plaintext = <String>::sse_decode(&mut deserializer);
-            let api_public_key = <String>::sse_decode(&mut deserializer);
-            let api_private_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::nip04_encrypt(
-                    api_plaintext,
-                    api_public_key,
-                    api_private_key,
-                )?;
+                let output_ok = crate::api::nostr::generate_keys()?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__nostr__nip44_decrypt_impl(
+fn wire__crate__api__nostr__generate_keys_with_bech32_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "nip44_decrypt",
+            debug_name: "generate_keys_with_bech32",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1026,31 +1202,25 @@ fn wire__crate__api__nostr__nip44_decrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ciphertext = <String>::sse_decode(&mut deserializer);
-            let api_public_key = <String>::sse_decode(&mut deserializer);
-            let api_private_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::nip44_decrypt(
-                    api_ciphertext,
-                    api_public_key,
-                    api_private_key,
-                )?;
+                let output_ok = crate::api::nostr::generate_keys_with_bech32()?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__nostr__nip44_encrypt_impl(
+fn wire__crate__api__cashu__generate_mnemonic_phrase_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "nip44_encrypt",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "generate_mnemonic_phrase",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1062,31 +1232,32 @@ fn wire__crate__api__nostr__nip44_encrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_plaintext = <String>::sse_decode(&mut deserializer);
-            let api_public_key = <String>::sse_decode(&mut deserializer);
-            let api_private_key = <String>::sse_decode(&mut deserializer);
+            let api_word_count = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::nip44_encrypt(
-                    api_plaintext,
-                    api_public_key,
-                    api_private_key,
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::generate_mnemonic_phrase(api_word_count).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__nostr__npub_to_public_key_impl(
+fn wire__crate__api__cashu__get_all_balances_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "npub_to_public_key",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_all_balances",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1098,25 +1269,31 @@ fn wire__crate__api__nostr__npub_to_public_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_npub = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::npub_to_public_key(api_npub)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::get_all_balances(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__nostr__nsec_to_secret_key_impl(
+fn wire__crate__api__cashu__get_all_transactions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "nsec_to_secret_key",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_all_transactions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1128,16 +1305,21 @@ fn wire__crate__api__nostr__nsec_to_secret_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_nsec = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::nsec_to_secret_key(api_nsec)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::get_all_transactions(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__cashu__parse_cashu_token_impl(
+fn wire__crate__api__daemon__get_daemon_address_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1145,7 +1327,7 @@ fn wire__crate__api__cashu__parse_cashu_token_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_cashu_token",
+            debug_name: "get_daemon_address",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1159,12 +1341,12 @@ fn wire__crate__api__cashu__parse_cashu_token_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::parse_cashu_token(api_token).await?;
+                        let output_ok = crate::api::daemon::get_daemon_address(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1173,7 +1355,7 @@ fn wire__crate__api__cashu__parse_cashu_token_impl(
         },
     )
 }
-fn wire__crate__api__cashu__pay_invoice_for_wallet_impl(
+fn wire__crate__api__cashu__get_interrupted_operations_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1181,7 +1363,7 @@ fn wire__crate__api__cashu__pay_invoice_for_wallet_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pay_invoice_for_wallet",
+            debug_name: "get_interrupted_operations",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1195,19 +1377,13 @@ fn wire__crate__api__cashu__pay_invoice_for_wallet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
-            let api_bolt11_invoice = <String>::sse_decode(&mut deserializer);
-            let api_max_fee_sats = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::pay_invoice_for_wallet(
-                            api_mint_url,
-                            api_bolt11_invoice,
-                            api_max_fee_sats,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::cashu::get_interrupted_operations(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1216,16 +1392,17 @@ fn wire__crate__api__cashu__pay_invoice_for_wallet_impl(
         },
     )
 }
-fn wire__crate__api__nostr__public_key_to_npub_impl(
+fn wire__crate__api__cashu__get_mint_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "public_key_to_npub",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_mint_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1237,16 +1414,23 @@ fn wire__crate__api__nostr__public_key_to_npub_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::public_key_to_npub(api_public_key)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::get_mint_info(api_handle, api_mint_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__cashu__receive_tokens_impl(
+fn wire__crate__api__cashu__get_mint_proxy_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1254,7 +1438,7 @@ fn wire__crate__api__cashu__receive_tokens_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receive_tokens",
+            debug_name: "get_mint_proxy",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1268,12 +1452,14 @@ fn wire__crate__api__cashu__receive_tokens_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::receive_tokens(api_token).await?;
+                        let output_ok =
+                            crate::api::cashu::get_mint_proxy(api_handle, api_mint_url).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1282,7 +1468,7 @@ fn wire__crate__api__cashu__receive_tokens_impl(
         },
     )
 }
-fn wire__crate__api__cashu__reinitialize_with_tor_config_impl(
+fn wire__crate__api__nip60__get_nip60_sync_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1290,7 +1476,7 @@ fn wire__crate__api__cashu__reinitialize_with_tor_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reinitialize_with_tor_config",
+            debug_name: "get_nip60_sync_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1304,17 +1490,13 @@ fn wire__crate__api__cashu__reinitialize_with_tor_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_database_dir = <String>::sse_decode(&mut deserializer);
-            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::reinitialize_with_tor_config(
-                            api_database_dir,
-                            api_seed_hex,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::nip60::get_nip60_sync_status(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1323,7 +1505,37 @@ fn wire__crate__api__cashu__reinitialize_with_tor_config_impl(
         },
     )
 }
-fn wire__crate__api__cashu__remove_mint_impl(
+fn wire__crate__api__nostr__get_public_key_from_private_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_public_key_from_private",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_private_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::get_public_key_from_private(api_private_key)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__limits__get_spending_allowances_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1331,7 +1543,7 @@ fn wire__crate__api__cashu__remove_mint_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_mint",
+            debug_name: "get_spending_allowances",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1345,12 +1557,13 @@ fn wire__crate__api__cashu__remove_mint_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::remove_mint(api_mint_url).await?;
+                        let output_ok =
+                            crate::api::limits::get_spending_allowances(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1359,7 +1572,7 @@ fn wire__crate__api__cashu__remove_mint_impl(
         },
     )
 }
-fn wire__crate__api__cashu__restore_mint_impl(
+fn wire__crate__api__limits__get_spending_limits_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1367,7 +1580,7 @@ fn wire__crate__api__cashu__restore_mint_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "restore_mint",
+            debug_name: "get_spending_limits",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1381,12 +1594,12 @@ fn wire__crate__api__cashu__restore_mint_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::restore_mint(api_mint_url).await?;
+                        let output_ok = crate::api::limits::get_spending_limits(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1395,16 +1608,17 @@ fn wire__crate__api__cashu__restore_mint_impl(
         },
     )
 }
-fn wire__crate__api__nostr__secret_key_to_nsec_impl(
+fn wire__crate__api__cashu__get_tor_bootstrap_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "secret_key_to_nsec",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_tor_bootstrap_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1416,16 +1630,22 @@ fn wire__crate__api__nostr__secret_key_to_nsec_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_secret_key = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::secret_key_to_nsec(api_secret_key)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::get_tor_bootstrap_status(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__cashu__seed_hex_to_mnemonic_impl(
+fn wire__crate__api__cashu__get_tor_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1433,7 +1653,7 @@ fn wire__crate__api__cashu__seed_hex_to_mnemonic_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "seed_hex_to_mnemonic",
+            debug_name: "get_tor_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1447,13 +1667,12 @@ fn wire__crate__api__cashu__seed_hex_to_mnemonic_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::cashu::seed_hex_to_mnemonic(api_seed_hex).await?;
+                        let output_ok = crate::api::cashu::get_tor_config(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1462,7 +1681,7 @@ fn wire__crate__api__cashu__seed_hex_to_mnemonic_impl(
         },
     )
 }
-fn wire__crate__api__cashu__send_p2pk_tokens_impl(
+fn wire__crate__api__cashu__get_tor_isolation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1470,7 +1689,43 @@ fn wire__crate__api__cashu__send_p2pk_tokens_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_p2pk_tokens",
+            debug_name: "get_tor_isolation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::get_tor_isolation(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__get_wallet_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_wallet_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1484,33 +1739,14 @@ fn wire__crate__api__cashu__send_p2pk_tokens_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             let api_mint_url = <String>::sse_decode(&mut deserializer);
-            let api_amount = <u64>::sse_decode(&mut deserializer);
-            let api_memo = <Option<String>>::sse_decode(&mut deserializer);
-            let api_recipient_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_additional_pubkeys = <Option<Vec<String>>>::sse_decode(&mut deserializer);
-            let api_refund_pubkeys = <Option<Vec<String>>>::sse_decode(&mut deserializer);
-            let api_required_sigs = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_locktime = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_sigflag = <Option<String>>::sse_decode(&mut deserializer);
-            let api_refund_required_sigs = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::send_p2pk_tokens(
-                            api_mint_url,
-                            api_amount,
-                            api_memo,
-                            api_recipient_pubkey,
-                            api_additional_pubkeys,
-                            api_refund_pubkeys,
-                            api_required_sigs,
-                            api_locktime,
-                            api_sigflag,
-                            api_refund_required_sigs,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::cashu::get_wallet_info(api_handle, api_mint_url).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1519,7 +1755,7 @@ fn wire__crate__api__cashu__send_p2pk_tokens_impl(
         },
     )
 }
-fn wire__crate__api__cashu__send_tokens_impl(
+fn wire__crate__api__cashu__get_wallet_proofs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1527,7 +1763,7 @@ fn wire__crate__api__cashu__send_tokens_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_tokens",
+            debug_name: "get_wallet_proofs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1541,16 +1777,14 @@ fn wire__crate__api__cashu__send_tokens_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             let api_mint_url = <String>::sse_decode(&mut deserializer);
-            let api_amount = <u64>::sse_decode(&mut deserializer);
-            let api_memo = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::cashu::send_tokens(api_mint_url, api_amount, api_memo)
-                                .await?;
+                            crate::api::cashu::get_wallet_proofs(api_handle, api_mint_url).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1559,7 +1793,7 @@ fn wire__crate__api__cashu__send_tokens_impl(
         },
     )
 }
-fn wire__crate__api__cashu__set_tor_config_impl(
+fn wire__crate__api__cashu__get_wallet_proxy_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1567,7 +1801,7 @@ fn wire__crate__api__cashu__set_tor_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tor_config",
+            debug_name: "get_wallet_proxy",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1581,12 +1815,12 @@ fn wire__crate__api__cashu__set_tor_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api__policy = <bool>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::set_tor_config(api__policy).await?;
+                        let output_ok = crate::api::cashu::get_wallet_proxy(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1595,14 +1829,14 @@ fn wire__crate__api__cashu__set_tor_config_impl(
         },
     )
 }
-fn wire__crate__api__nostr__sign_event_impl(
+fn wire__crate__api__nostr__greet_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sign_event",
+            debug_name: "greet",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1616,17 +1850,16 @@ fn wire__crate__api__nostr__sign_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event_json = <String>::sse_decode(&mut deserializer);
-            let api_private_key = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::sign_event(api_event_json, api_private_key)?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::nostr::greet(api_name))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cashu__validate_mnemonic_phrase_impl(
+fn wire__crate__api__cashu__import_wallet_backup_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1634,7 +1867,7 @@ fn wire__crate__api__cashu__validate_mnemonic_phrase_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "validate_mnemonic_phrase",
+            debug_name: "import_wallet_backup",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1648,14 +1881,14 @@ fn wire__crate__api__cashu__validate_mnemonic_phrase_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mnemonic_phrase = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_backup = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::cashu::validate_mnemonic_phrase(api_mnemonic_phrase)
-                                .await?;
+                            crate::api::cashu::import_wallet_backup(api_handle, api_backup).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1664,16 +1897,17 @@ fn wire__crate__api__cashu__validate_mnemonic_phrase_impl(
         },
     )
 }
-fn wire__crate__api__nostr__verify_event_impl(
+fn wire__crate__api__nostr__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "verify_event",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1685,16 +1919,19 @@ fn wire__crate__api__nostr__verify_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event = <crate::api::nostr::NostrEvent>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::nostr::verify_event(api_event)?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::nostr::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__cashu__verify_token_dleq_impl(
+fn wire__crate__api__cashu__init_multi_mint_wallet_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1702,7 +1939,7 @@ fn wire__crate__api__cashu__verify_token_dleq_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "verify_token_dleq",
+            debug_name: "init_multi_mint_wallet",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1716,14 +1953,17 @@ fn wire__crate__api__cashu__verify_token_dleq_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_seed_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::cashu::verify_token_dleq(api_mint_url, api_token).await?;
+                        let output_ok = crate::api::cashu::init_multi_mint_wallet(
+                            api_database_dir,
+                            api_seed_hex,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1732,7 +1972,7 @@ fn wire__crate__api__cashu__verify_token_dleq_impl(
         },
     )
 }
-fn wire__crate__api__cashu__verify_token_p2pk_impl(
+fn wire__crate__api__cashu__init_multi_mint_wallet_with_tor_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1740,7 +1980,7 @@ fn wire__crate__api__cashu__verify_token_p2pk_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "verify_token_p2pk",
+            debug_name: "init_multi_mint_wallet_with_tor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1754,17 +1994,17 @@ fn wire__crate__api__cashu__verify_token_p2pk_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
-            let api_conditions = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            let api_tor_config = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::cashu::verify_token_p2pk(
-                            api_mint_url,
-                            api_token,
-                            api_conditions,
+                        let output_ok = crate::api::cashu::init_multi_mint_wallet_with_tor(
+                            api_database_dir,
+                            api_seed_hex,
+                            api_tor_config,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1775,7 +2015,7 @@ fn wire__crate__api__cashu__verify_token_p2pk_impl(
         },
     )
 }
-fn wire__crate__api__cashu__wallet_exists_impl(
+fn wire__crate__api__cashu__is_offline_mode_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1783,7 +2023,7 @@ fn wire__crate__api__cashu__wallet_exists_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_exists",
+            debug_name: "is_offline_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1797,15 +2037,233 @@ fn wire__crate__api__cashu__wallet_exists_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mint_url = <String>::sse_decode(&mut deserializer);
-            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::cashu::wallet_exists(api_mint_url, api_database_dir).await,
-                        )?;
+                        let output_ok = crate::api::cashu::is_offline_mode(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__is_tor_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_tor_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::is_tor_enabled(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__is_tor_ready_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_tor_ready",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::is_tor_ready(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__is_wallet_database_encrypted_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_wallet_database_encrypted",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::is_wallet_database_encrypted(
+                            api_profile_id,
+                            api_database_dir,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__daemon__list_daemon_clients_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_daemon_clients",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::daemon::list_daemon_clients(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__list_mints_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_mints",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::list_mints(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__list_profiles_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_profiles",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::list_profiles(api_database_dir).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1814,806 +2272,5804 @@ fn wire__crate__api__cashu__wallet_exists_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for std::collections::HashMap<String, String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
+fn wire__crate__api__logging__log_from_dart_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_from_dart",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::logging::log_from_dart(api_level, api_message)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cashu__mint_bolt12_quote_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mint_bolt12_quote",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_quote_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::mint_bolt12_quote(
+                            api_handle,
+                            api_mint_url,
+                            api_quote_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__mnemonic_to_seed_hex_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mnemonic_to_seed_hex",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mnemonic_phrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::mnemonic_to_seed_hex(api_mnemonic_phrase).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__new_tor_identity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_tor_identity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::new_tor_identity(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nostr__nip04_decrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nip04_decrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ciphertext = <String>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_private_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::nip04_decrypt(
+                    api_ciphertext,
+                    api_public_key,
+                    api_private_key,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__nostr__nip04_encrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nip04_encrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plaintext = <String>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_private_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::nip04_encrypt(
+                    api_plaintext,
+                    api_public_key,
+                    api_private_key,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__nostr__nip44_decrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nip44_decrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ciphertext = <String>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_private_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::nip44_decrypt(
+                    api_ciphertext,
+                    api_public_key,
+                    api_private_key,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__nostr__nip44_encrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nip44_encrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plaintext = <String>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_private_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::nip44_encrypt(
+                    api_plaintext,
+                    api_public_key,
+                    api_private_key,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__nostr__npub_to_public_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "npub_to_public_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_npub = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::npub_to_public_key(api_npub)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__nostr__nsec_to_secret_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nsec_to_secret_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_nsec = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::nsec_to_secret_key(api_nsec)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cashu__open_encrypted_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_encrypted_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            let api_key_material = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::open_encrypted_wallet(
+                            api_profile_id,
+                            api_database_dir,
+                            api_seed_hex,
+                            api_key_material,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__open_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::open_wallet(
+                            api_profile_id,
+                            api_database_dir,
+                            api_seed_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__parse_cashu_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_cashu_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::parse_cashu_token(api_token).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__parse_unsupported_by_mint_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_unsupported_by_mint",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_error = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::cashu::parse_unsupported_by_mint(api_error))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cashu__pay_bolt12_offer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_bolt12_offer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_offer = <String>::sse_decode(&mut deserializer);
+            let api_amount_sats = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::pay_bolt12_offer(
+                            api_handle,
+                            api_mint_url,
+                            api_offer,
+                            api_amount_sats,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__pay_invoice_for_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_invoice_for_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_bolt11_invoice = <String>::sse_decode(&mut deserializer);
+            let api_max_fee_sats = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::pay_invoice_for_wallet(
+                            api_handle,
+                            api_mint_url,
+                            api_bolt11_invoice,
+                            api_max_fee_sats,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__pay_lnurl_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_lnurl",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_lnurl = <String>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::pay_lnurl(
+                            api_handle,
+                            api_mint_url,
+                            api_lnurl,
+                            api_amount_sats,
+                            api_comment,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__pay_payment_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_payment_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_request = <String>::sse_decode(&mut deserializer);
+            let api_mint_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_amount = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::pay_payment_request(
+                            api_handle,
+                            api_request,
+                            api_mint_url,
+                            api_amount,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nostr__public_key_to_npub_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "public_key_to_npub",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::public_key_to_npub(api_public_key)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cashu__receive_tokens_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "receive_tokens",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::receive_tokens(api_handle, api_token).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__redeem_lnurl_withdraw_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "redeem_lnurl_withdraw",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_lnurl = <String>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_amount = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::redeem_lnurl_withdraw(
+                            api_handle,
+                            api_lnurl,
+                            api_mint_url,
+                            api_amount,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__reinitialize_with_tor_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reinitialize_with_tor_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::reinitialize_with_tor_config(
+                            api_database_dir,
+                            api_seed_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__daemon__remove_daemon_client_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_daemon_client",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::daemon::remove_daemon_client(api_handle, api_name).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__remove_mint_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_mint",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::remove_mint(api_handle, api_mint_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__limits__remove_spending_limit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_spending_limit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_pin = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::limits::remove_spending_limit(
+                            api_handle,
+                            api_mint_url,
+                            api_pin,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__resolve_lnurl_pay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resolve_lnurl_pay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_lnurl = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::resolve_lnurl_pay(api_handle, api_lnurl).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__restore_backup_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_backup_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_backup = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::restore_backup_profile(
+                            api_profile_id,
+                            api_database_dir,
+                            api_backup,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__restore_mint_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_mint",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::restore_mint(api_handle, api_mint_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__rotate_wallet_database_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rotate_wallet_database_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            let api_old_key_material = <String>::sse_decode(&mut deserializer);
+            let api_new_key_material = <String>::sse_decode(&mut deserializer);
+            let api_force = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::rotate_wallet_database_key(
+                            api_profile_id,
+                            api_database_dir,
+                            api_old_key_material,
+                            api_new_key_material,
+                            api_force,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nostr__secret_key_to_nsec_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "secret_key_to_nsec",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::secret_key_to_nsec(api_secret_key)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cashu__seed_hex_to_mnemonic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "seed_hex_to_mnemonic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_seed_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::seed_hex_to_mnemonic(api_seed_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__send_p2pk_tokens_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_p2pk_tokens",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_amount = <u64>::sse_decode(&mut deserializer);
+            let api_memo = <Option<String>>::sse_decode(&mut deserializer);
+            let api_recipient_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_additional_pubkeys = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_refund_pubkeys = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_required_sigs = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_locktime = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_sigflag = <Option<String>>::sse_decode(&mut deserializer);
+            let api_refund_required_sigs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::send_p2pk_tokens(
+                            api_handle,
+                            api_mint_url,
+                            api_amount,
+                            api_memo,
+                            api_recipient_pubkey,
+                            api_additional_pubkeys,
+                            api_refund_pubkeys,
+                            api_required_sigs,
+                            api_locktime,
+                            api_sigflag,
+                            api_refund_required_sigs,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__send_selected_proofs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_selected_proofs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_proof_ys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::cashu::SelectedProofsSendOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::send_selected_proofs(
+                            api_handle,
+                            api_mint_url,
+                            api_proof_ys,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__send_tokens_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_tokens",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_amount = <u64>::sse_decode(&mut deserializer);
+            let api_memo = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::send_tokens(
+                            api_handle,
+                            api_mint_url,
+                            api_amount,
+                            api_memo,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__send_tokens_with_encoding_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_tokens_with_encoding",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_amount = <u64>::sse_decode(&mut deserializer);
+            let api_memo = <Option<String>>::sse_decode(&mut deserializer);
+            let api_encoding = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::send_tokens_with_encoding(
+                            api_handle,
+                            api_mint_url,
+                            api_amount,
+                            api_memo,
+                            api_encoding,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__set_log_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_log_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_module = <String>::sse_decode(&mut deserializer);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::logging::set_log_level(api_module, api_level)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cashu__set_mint_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_mint_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_proxy = <Option<crate::network::ProxyConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::set_mint_proxy(api_handle, api_mint_url, api_proxy)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__set_offline_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_offline_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::set_offline_mode(api_handle, api_offline).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__limits__set_spending_limit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_spending_limit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_limit = <crate::api::limits::SpendingLimit>::sse_decode(&mut deserializer);
+            let api_pin = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::limits::set_spending_limit(api_handle, api_limit, api_pin)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__limits__set_spending_pin_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_spending_pin",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_current_pin = <Option<String>>::sse_decode(&mut deserializer);
+            let api_new_pin = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::limits::set_spending_pin(
+                            api_handle,
+                            api_current_pin,
+                            api_new_pin,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__set_tor_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tor_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_policy = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::set_tor_config(api_handle, api_policy).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__set_tor_isolation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tor_isolation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::set_tor_isolation(api_handle, api_enabled).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__set_wallet_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_wallet_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_proxy = <Option<crate::network::ProxyConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::set_wallet_proxy(api_handle, api_proxy).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__shutdown_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "shutdown_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_timeout_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::shutdown_wallet(api_handle, api_timeout_secs)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nostr__sign_event_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sign_event",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event_json = <String>::sse_decode(&mut deserializer);
+            let api_private_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::sign_event(api_event_json, api_private_key)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__daemon__start_daemon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_daemon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_listen = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::daemon::start_daemon(api_handle, api_listen).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__daemon__stop_daemon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_daemon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::daemon::stop_daemon(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__subscribe_logs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_logs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::logging::LogRecord,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::logging::subscribe_logs(api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__subscribe_tor_bootstrap_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_tor_bootstrap",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::cashu::TorBootstrapStatus,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::subscribe_tor_bootstrap(api_handle, api_sink)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__events__subscribe_wallet_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_wallet_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::events::WalletEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::events::subscribe_wallet_events(api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nip60__sync_nip60_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_nip60_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::nip60::sync_nip60_wallet(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__test_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_proxy = <crate::network::ProxyConfig>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::test_proxy(api_proxy, api_mint_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__limits__unlock_spending_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_spending",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_pin = <String>::sse_decode(&mut deserializer);
+            let api_mint_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_amount = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::limits::unlock_spending(
+                            api_handle,
+                            api_pin,
+                            api_mint_url,
+                            api_amount,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__validate_mnemonic_phrase_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_mnemonic_phrase",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mnemonic_phrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::cashu::validate_mnemonic_phrase(api_mnemonic_phrase)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nostr__verify_event_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_event",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event = <crate::api::nostr::NostrEvent>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::nostr::verify_event(api_event)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cashu__verify_proofs_offline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_proofs_offline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_proofs = <Vec<crate::api::cashu::CashuProof>>::sse_decode(&mut deserializer);
+            let api_allow_fetch = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::verify_proofs_offline(
+                            api_handle,
+                            api_mint_url,
+                            api_proofs,
+                            api_allow_fetch,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__verify_token_dleq_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_token_dleq",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::verify_token_dleq(
+                            api_handle,
+                            api_mint_url,
+                            api_token,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__verify_token_offline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_token_offline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_allow_fetch = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::verify_token_offline(
+                            api_handle,
+                            api_token,
+                            api_allow_fetch,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__verify_token_p2pk_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_token_p2pk",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <crate::api::cashu::WalletHandle>::sse_decode(&mut deserializer);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_conditions = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::cashu::verify_token_p2pk(
+                            api_handle,
+                            api_mint_url,
+                            api_token,
+                            api_conditions,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cashu__wallet_exists_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_exists",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mint_url = <String>::sse_decode(&mut deserializer);
+            let api_database_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::cashu::wallet_exists(api_mint_url, api_database_dir).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for AnimatedQrDecoder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for std::collections::BTreeMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, u64)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { decode_rust_opaque_moi(inner) };
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::logging::LogRecord, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::cashu::TorBootstrapStatus,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::events::WalletEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::qr::AnimatedQrFrames {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frames = <Vec<String>>::sse_decode(deserializer);
+        let mut var_fragmentCount = <u32>::sse_decode(deserializer);
+        let mut var_isAnimated = <bool>::sse_decode(deserializer);
+        return crate::api::qr::AnimatedQrFrames {
+            frames: var_frames,
+            fragment_count: var_fragmentCount,
+            is_animated: var_isAnimated,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::Bolt12InvoiceRequestInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payerPubkey = <String>::sse_decode(deserializer);
+        let mut var_amountMsat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_quantity = <Option<u64>>::sse_decode(deserializer);
+        let mut var_payerNote = <Option<String>>::sse_decode(deserializer);
+        let mut var_chain = <String>::sse_decode(deserializer);
+        let mut var_offerDescription = <Option<String>>::sse_decode(deserializer);
+        let mut var_offerIssuer = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::Bolt12InvoiceRequestInfo {
+            payer_pubkey: var_payerPubkey,
+            amount_msat: var_amountMsat,
+            quantity: var_quantity,
+            payer_note: var_payerNote,
+            chain: var_chain,
+            offer_description: var_offerDescription,
+            offer_issuer: var_offerIssuer,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::Bolt12OfferInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offerId = <String>::sse_decode(deserializer);
+        let mut var_amountMsat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_currency = <Option<String>>::sse_decode(deserializer);
+        let mut var_currencyAmount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_issuer = <Option<String>>::sse_decode(deserializer);
+        let mut var_issuerPubkey = <Option<String>>::sse_decode(deserializer);
+        let mut var_absoluteExpiry = <Option<u64>>::sse_decode(deserializer);
+        let mut var_chains = <Vec<String>>::sse_decode(deserializer);
+        let mut var_isExpired = <bool>::sse_decode(deserializer);
+        return crate::api::cashu::Bolt12OfferInfo {
+            offer_id: var_offerId,
+            amount_msat: var_amountMsat,
+            currency: var_currency,
+            currency_amount: var_currencyAmount,
+            description: var_description,
+            issuer: var_issuer,
+            issuer_pubkey: var_issuerPubkey,
+            absolute_expiry: var_absoluteExpiry,
+            chains: var_chains,
+            is_expired: var_isExpired,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::cashu::CachedEndpoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_method = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        return crate::api::cashu::CachedEndpoint {
+            method: var_method,
+            path: var_path,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::CashuDleq {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_e = <String>::sse_decode(deserializer);
+        let mut var_s = <String>::sse_decode(deserializer);
+        let mut var_r = <String>::sse_decode(deserializer);
+        return crate::api::cashu::CashuDleq {
+            e: var_e,
+            s: var_s,
+            r: var_r,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::CashuProof {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_secret = <crate::api::cashu::CashuSecret>::sse_decode(deserializer);
+        let mut var_c = <String>::sse_decode(deserializer);
+        let mut var_witness = <Option<crate::api::cashu::CashuWitness>>::sse_decode(deserializer);
+        let mut var_dleq = <Option<crate::api::cashu::CashuDleq>>::sse_decode(deserializer);
+        let mut var_y = <String>::sse_decode(deserializer);
+        let mut var_state = <Option<String>>::sse_decode(deserializer);
+        let mut var_reserved = <bool>::sse_decode(deserializer);
+        return crate::api::cashu::CashuProof {
+            id: var_id,
+            amount: var_amount,
+            secret: var_secret,
+            c: var_c,
+            witness: var_witness,
+            dleq: var_dleq,
+            y: var_y,
+            state: var_state,
+            reserved: var_reserved,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::CashuSecret {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_raw = <String>::sse_decode(deserializer);
+        let mut var_kind = <Option<String>>::sse_decode(deserializer);
+        let mut var_nonce = <Option<String>>::sse_decode(deserializer);
+        let mut var_data = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
+        return crate::api::cashu::CashuSecret {
+            raw: var_raw,
+            kind: var_kind,
+            nonce: var_nonce,
+            data: var_data,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::CashuWitness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_preimage = <Option<String>>::sse_decode(deserializer);
+        let mut var_signatures = <Option<Vec<String>>>::sse_decode(deserializer);
+        return crate::api::cashu::CashuWitness {
+            preimage: var_preimage,
+            signatures: var_signatures,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::ContactInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_method = <String>::sse_decode(deserializer);
+        let mut var_info = <String>::sse_decode(deserializer);
+        return crate::api::cashu::ContactInfo {
+            method: var_method,
+            info: var_info,
+        };
+    }
+}
+
+impl SseDecode for crate::api::daemon::DaemonClient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_permissions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        return crate::api::daemon::DaemonClient {
+            name: var_name,
+            permissions: var_permissions,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::logging::DiagnosticsBundle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_crateVersion = <String>::sse_decode(deserializer);
+        let mut var_os = <String>::sse_decode(deserializer);
+        let mut var_arch = <String>::sse_decode(deserializer);
+        let mut var_logLevels =
+            <std::collections::BTreeMap<String, String>>::sse_decode(deserializer);
+        let mut var_records = <Vec<crate::api::logging::LogRecord>>::sse_decode(deserializer);
+        return crate::api::logging::DiagnosticsBundle {
+            created_at: var_createdAt,
+            crate_version: var_crateVersion,
+            os: var_os,
+            arch: var_arch,
+            log_levels: var_logLevels,
+            records: var_records,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::EncodedToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <String>::sse_decode(deserializer);
+        let mut var_encoding = <String>::sse_decode(deserializer);
+        let mut var_byteSize = <u64>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_proofsCount = <u64>::sse_decode(deserializer);
+        let mut var_mintUrls = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::cashu::EncodedToken {
+            token: var_token,
+            encoding: var_encoding,
+            byte_size: var_byteSize,
+            amount: var_amount,
+            proofs_count: var_proofsCount,
+            mint_urls: var_mintUrls,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::cashu::ImportReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_format = <String>::sse_decode(deserializer);
+        let mut var_mints = <Vec<crate::api::cashu::ImportedMint>>::sse_decode(deserializer);
+        let mut var_importedAmount = <u64>::sse_decode(deserializer);
+        let mut var_unassignedProofs = <u64>::sse_decode(deserializer);
+        let mut var_hasMnemonic = <bool>::sse_decode(deserializer);
+        let mut var_keysetCounters =
+            <Vec<crate::api::cashu::KeysetCounter>>::sse_decode(deserializer);
+        return crate::api::cashu::ImportReport {
+            format: var_format,
+            mints: var_mints,
+            imported_amount: var_importedAmount,
+            unassigned_proofs: var_unassignedProofs,
+            has_mnemonic: var_hasMnemonic,
+            keyset_counters: var_keysetCounters,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::ImportedMint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_proofsFound = <u64>::sse_decode(deserializer);
+        let mut var_amountFound = <u64>::sse_decode(deserializer);
+        let mut var_spentAmount = <u64>::sse_decode(deserializer);
+        let mut var_importedAmount = <u64>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::ImportedMint {
+            mint_url: var_mintUrl,
+            proofs_found: var_proofsFound,
+            amount_found: var_amountFound,
+            spent_amount: var_spentAmount,
+            imported_amount: var_importedAmount,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::InterruptedOperation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_startedAt = <u64>::sse_decode(deserializer);
+        return crate::api::cashu::InterruptedOperation {
+            id: var_id,
+            kind: var_kind,
+            mint_url: var_mintUrl,
+            amount: var_amount,
+            started_at: var_startedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::KeysetCounter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_keysetId = <String>::sse_decode(deserializer);
+        let mut var_counter = <u32>::sse_decode(deserializer);
+        return crate::api::cashu::KeysetCounter {
+            keyset_id: var_keysetId,
+            counter: var_counter,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::CachedEndpoint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::CachedEndpoint>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::CashuProof> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::CashuProof>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::ContactInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::ContactInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::daemon::DaemonClient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::daemon::DaemonClient>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::ImportedMint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::ImportedMint>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::InterruptedOperation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::InterruptedOperation>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::KeysetCounter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::KeysetCounter>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<String>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::logging::LogRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::logging::LogRecord>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::PaymentMethodSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::PaymentMethodSettings>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::PaymentRequestTransport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::PaymentRequestTransport>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::ProofVerification> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::ProofVerification>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, u64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, u64)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::RestoredMint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::RestoredMint>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::limits::SpendingAllowance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::limits::SpendingAllowance>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::limits::SpendingLimit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::limits::SpendingLimit>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::TransactionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::TransactionInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::WalletProfile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::WalletProfile>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cashu::WebSocketSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cashu::WebSocketSettings>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::cashu::LnurlPayInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_callback = <String>::sse_decode(deserializer);
+        let mut var_minSendableSats = <u64>::sse_decode(deserializer);
+        let mut var_maxSendableSats = <u64>::sse_decode(deserializer);
+        let mut var_commentAllowed = <u64>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata = <String>::sse_decode(deserializer);
+        let mut var_domain = <String>::sse_decode(deserializer);
+        return crate::api::cashu::LnurlPayInfo {
+            callback: var_callback,
+            min_sendable_sats: var_minSendableSats,
+            max_sendable_sats: var_maxSendableSats,
+            comment_allowed: var_commentAllowed,
+            description: var_description,
+            metadata: var_metadata,
+            domain: var_domain,
+        };
+    }
+}
+
+impl SseDecode for crate::api::logging::LogRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <String>::sse_decode(deserializer);
+        let mut var_module = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        return crate::api::logging::LogRecord {
+            level: var_level,
+            module: var_module,
+            target: var_target,
+            message: var_message,
+            timestamp_ms: var_timestampMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::MintAuthSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_openidDiscovery = <Option<String>>::sse_decode(deserializer);
+        let mut var_clientId = <Option<String>>::sse_decode(deserializer);
+        let mut var_clearAuthEndpoints = <Vec<String>>::sse_decode(deserializer);
+        let mut var_blindAuthMaxMint = <Option<u64>>::sse_decode(deserializer);
+        let mut var_blindAuthEndpoints = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::cashu::MintAuthSettings {
+            openid_discovery: var_openidDiscovery,
+            client_id: var_clientId,
+            clear_auth_endpoints: var_clearAuthEndpoints,
+            blind_auth_max_mint: var_blindAuthMaxMint,
+            blind_auth_endpoints: var_blindAuthEndpoints,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::MintInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_version = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_descriptionLong = <Option<String>>::sse_decode(deserializer);
+        let mut var_contact =
+            <Option<Vec<crate::api::cashu::ContactInfo>>>::sse_decode(deserializer);
+        let mut var_motd = <Option<String>>::sse_decode(deserializer);
+        let mut var_iconUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_urls = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_nuts = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_publicKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_additionalInfo = <Option<String>>::sse_decode(deserializer);
+        let mut var_tosUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_time = <Option<u64>>::sse_decode(deserializer);
+        let mut var_settings = <crate::api::cashu::MintSettings>::sse_decode(deserializer);
+        let mut var_fetchedAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isStale = <bool>::sse_decode(deserializer);
+        return crate::api::cashu::MintInfo {
+            name: var_name,
+            version: var_version,
+            description: var_description,
+            description_long: var_descriptionLong,
+            contact: var_contact,
+            motd: var_motd,
+            icon_url: var_iconUrl,
+            urls: var_urls,
+            nuts: var_nuts,
+            public_key: var_publicKey,
+            additional_info: var_additionalInfo,
+            tos_url: var_tosUrl,
+            time: var_time,
+            settings: var_settings,
+            fetched_at: var_fetchedAt,
+            is_stale: var_isStale,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::MintSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintMethods =
+            <Vec<crate::api::cashu::PaymentMethodSettings>>::sse_decode(deserializer);
+        let mut var_mintingDisabled = <bool>::sse_decode(deserializer);
+        let mut var_meltMethods =
+            <Vec<crate::api::cashu::PaymentMethodSettings>>::sse_decode(deserializer);
+        let mut var_meltingDisabled = <bool>::sse_decode(deserializer);
+        let mut var_supportedNuts = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_mppMethods =
+            <Vec<crate::api::cashu::PaymentMethodSettings>>::sse_decode(deserializer);
+        let mut var_websocket =
+            <Vec<crate::api::cashu::WebSocketSettings>>::sse_decode(deserializer);
+        let mut var_cacheTtl = <Option<u64>>::sse_decode(deserializer);
+        let mut var_cachedEndpoints =
+            <Vec<crate::api::cashu::CachedEndpoint>>::sse_decode(deserializer);
+        let mut var_auth = <Option<crate::api::cashu::MintAuthSettings>>::sse_decode(deserializer);
+        return crate::api::cashu::MintSettings {
+            mint_methods: var_mintMethods,
+            minting_disabled: var_mintingDisabled,
+            melt_methods: var_meltMethods,
+            melting_disabled: var_meltingDisabled,
+            supported_nuts: var_supportedNuts,
+            mpp_methods: var_mppMethods,
+            websocket: var_websocket,
+            cache_ttl: var_cacheTtl,
+            cached_endpoints: var_cachedEndpoints,
+            auth: var_auth,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nip60::Nip60SyncReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_importedProofs = <u64>::sse_decode(deserializer);
+        let mut var_importedAmount = <u64>::sse_decode(deserializer);
+        let mut var_removedProofs = <u64>::sse_decode(deserializer);
+        let mut var_mintsAdded = <Vec<String>>::sse_decode(deserializer);
+        let mut var_publishedEvents = <u64>::sse_decode(deserializer);
+        let mut var_deletedEvents = <u64>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        let mut var_syncedAt = <u64>::sse_decode(deserializer);
+        return crate::api::nip60::Nip60SyncReport {
+            imported_proofs: var_importedProofs,
+            imported_amount: var_importedAmount,
+            removed_proofs: var_removedProofs,
+            mints_added: var_mintsAdded,
+            published_events: var_publishedEvents,
+            deleted_events: var_deletedEvents,
+            errors: var_errors,
+            synced_at: var_syncedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nip60::Nip60SyncStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_relays = <Vec<String>>::sse_decode(deserializer);
+        let mut var_publicKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastReport =
+            <Option<crate::api::nip60::Nip60SyncReport>>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::nip60::Nip60SyncStatus {
+            enabled: var_enabled,
+            relays: var_relays,
+            public_key: var_publicKey,
+            last_report: var_lastReport,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nostr::NostrEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_kind = <u64>::sse_decode(deserializer);
+        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_sig = <String>::sse_decode(deserializer);
+        return crate::api::nostr::NostrEvent {
+            id: var_id,
+            pubkey: var_pubkey,
+            created_at: var_createdAt,
+            kind: var_kind,
+            tags: var_tags,
+            content: var_content,
+            sig: var_sig,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nostr::NostrKeys {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_publicKey = <String>::sse_decode(deserializer);
+        let mut var_privateKey = <String>::sse_decode(deserializer);
+        return crate::api::nostr::NostrKeys {
+            public_key: var_publicKey,
+            private_key: var_privateKey,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nostr::NostrKeysWithBech32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_privateKey = <String>::sse_decode(deserializer);
+        let mut var_publicKey = <String>::sse_decode(deserializer);
+        let mut var_nsec = <String>::sse_decode(deserializer);
+        let mut var_npub = <String>::sse_decode(deserializer);
+        return crate::api::nostr::NostrKeysWithBech32 {
+            private_key: var_privateKey,
+            public_key: var_publicKey,
+            nsec: var_nsec,
+            npub: var_npub,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cashu::CashuDleq> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cashu::CashuDleq>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cashu::CashuWitness> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cashu::CashuWitness>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cashu::MintAuthSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cashu::MintAuthSettings>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::nip60::Nip60SyncReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::nip60::Nip60SyncReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::network::ProxyConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::network::ProxyConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cashu::UnsupportedByMint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cashu::UnsupportedByMint>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::cashu::ContactInfo>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::cashu::ContactInfo>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::cashu::PaymentMethodSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_method = <String>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_minAmount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxAmount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_description = <bool>::sse_decode(deserializer);
+        let mut var_amountless = <bool>::sse_decode(deserializer);
+        return crate::api::cashu::PaymentMethodSettings {
+            method: var_method,
+            unit: var_unit,
+            min_amount: var_minAmount,
+            max_amount: var_maxAmount,
+            description: var_description,
+            amountless: var_amountless,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::PaymentRequestInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_unit = <Option<String>>::sse_decode(deserializer);
+        let mut var_singleUse = <Option<bool>>::sse_decode(deserializer);
+        let mut var_mints = <Vec<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_transports =
+            <Vec<crate::api::cashu::PaymentRequestTransport>>::sse_decode(deserializer);
+        let mut var_lockKind = <Option<String>>::sse_decode(deserializer);
+        let mut var_lockData = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::PaymentRequestInfo {
+            payment_id: var_paymentId,
+            amount: var_amount,
+            unit: var_unit,
+            single_use: var_singleUse,
+            mints: var_mints,
+            description: var_description,
+            transports: var_transports,
+            lock_kind: var_lockKind,
+            lock_data: var_lockData,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::PaymentRequestTransport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_transportType = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
+        return crate::api::cashu::PaymentRequestTransport {
+            transport_type: var_transportType,
+            target: var_target,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::ProofVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_y = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_keysetId = <String>::sse_decode(deserializer);
+        let mut var_dleqValid = <Option<bool>>::sse_decode(deserializer);
+        let mut var_secretValid = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::ProofVerification {
+            y: var_y,
+            amount: var_amount,
+            keyset_id: var_keysetId,
+            dleq_valid: var_dleqValid,
+            secret_valid: var_secretValid,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::network::ProxyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        return crate::network::ProxyConfig {
+            kind: var_kind,
+            host: var_host,
+            port: var_port,
+            username: var_username,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::ProxyTestResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_latencyMs = <u64>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::ProxyTestResult {
+            success: var_success,
+            latency_ms: var_latencyMs,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::qr::QrDecodeProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_complete = <bool>::sse_decode(deserializer);
+        let mut var_progress = <f64>::sse_decode(deserializer);
+        let mut var_receivedFrames = <u32>::sse_decode(deserializer);
+        let mut var_expectedFrames = <u32>::sse_decode(deserializer);
+        let mut var_payload = <Option<String>>::sse_decode(deserializer);
+        return crate::api::qr::QrDecodeProgress {
+            complete: var_complete,
+            progress: var_progress,
+            received_frames: var_receivedFrames,
+            expected_frames: var_expectedFrames,
+            payload: var_payload,
+        };
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (String, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <u64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::cashu::RestoredMint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_restoredAmount = <u64>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::RestoredMint {
+            mint_url: var_mintUrl,
+            restored_amount: var_restoredAmount,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::RestoredProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handle = <crate::api::cashu::WalletHandle>::sse_decode(deserializer);
+        let mut var_mnemonic = <String>::sse_decode(deserializer);
+        let mut var_mints = <Vec<crate::api::cashu::RestoredMint>>::sse_decode(deserializer);
+        let mut var_restoredAmount = <u64>::sse_decode(deserializer);
+        let mut var_keysetCounters =
+            <Vec<crate::api::cashu::KeysetCounter>>::sse_decode(deserializer);
+        return crate::api::cashu::RestoredProfile {
+            handle: var_handle,
+            mnemonic: var_mnemonic,
+            mints: var_mints,
+            restored_amount: var_restoredAmount,
+            keyset_counters: var_keysetCounters,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::SelectedProofsSendOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swap = <bool>::sse_decode(deserializer);
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_memo = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::SelectedProofsSendOptions {
+            swap: var_swap,
+            amount: var_amount,
+            memo: var_memo,
+        };
+    }
+}
+
+impl SseDecode for crate::api::limits::SpendingAllowance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_perTransaction = <Option<u64>>::sse_decode(deserializer);
+        let mut var_dailySpent = <u64>::sse_decode(deserializer);
+        let mut var_dailyRemaining = <Option<u64>>::sse_decode(deserializer);
+        let mut var_weeklySpent = <u64>::sse_decode(deserializer);
+        let mut var_weeklyRemaining = <Option<u64>>::sse_decode(deserializer);
+        let mut var_action = <String>::sse_decode(deserializer);
+        return crate::api::limits::SpendingAllowance {
+            mint_url: var_mintUrl,
+            per_transaction: var_perTransaction,
+            daily_spent: var_dailySpent,
+            daily_remaining: var_dailyRemaining,
+            weekly_spent: var_weeklySpent,
+            weekly_remaining: var_weeklyRemaining,
+            action: var_action,
+        };
+    }
+}
+
+impl SseDecode for crate::api::limits::SpendingLimit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_perTransaction = <Option<u64>>::sse_decode(deserializer);
+        let mut var_daily = <Option<u64>>::sse_decode(deserializer);
+        let mut var_weekly = <Option<u64>>::sse_decode(deserializer);
+        let mut var_action = <String>::sse_decode(deserializer);
+        return crate::api::limits::SpendingLimit {
+            mint_url: var_mintUrl,
+            per_transaction: var_perTransaction,
+            daily: var_daily,
+            weekly: var_weekly,
+            action: var_action,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::TokenVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_keysetsValid = <bool>::sse_decode(deserializer);
+        let mut var_fetchedKeys = <bool>::sse_decode(deserializer);
+        let mut var_valid = <bool>::sse_decode(deserializer);
+        let mut var_proofs = <Vec<crate::api::cashu::ProofVerification>>::sse_decode(deserializer);
+        return crate::api::cashu::TokenVerification {
+            mint_url: var_mintUrl,
+            amount: var_amount,
+            keysets_valid: var_keysetsValid,
+            fetched_keys: var_fetchedKeys,
+            valid: var_valid,
+            proofs: var_proofs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::TorBootstrapStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_started = <bool>::sse_decode(deserializer);
+        let mut var_progress = <f64>::sse_decode(deserializer);
+        let mut var_ready = <bool>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::cashu::TorBootstrapStatus {
+            started: var_started,
+            progress: var_progress,
+            ready: var_ready,
+            message: var_message,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::TransactionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_direction = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fee = <u64>::sse_decode(deserializer);
+        let mut var_memo = <Option<String>>::sse_decode(deserializer);
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_transactionType = <Option<String>>::sse_decode(deserializer);
+        let mut var_lightningInvoice = <Option<String>>::sse_decode(deserializer);
+        let mut var_ecashToken = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata =
+            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        return crate::api::cashu::TransactionInfo {
+            id: var_id,
+            direction: var_direction,
+            amount: var_amount,
+            fee: var_fee,
+            memo: var_memo,
+            timestamp: var_timestamp,
+            mint_url: var_mintUrl,
+            transaction_type: var_transactionType,
+            lightning_invoice: var_lightningInvoice,
+            ecash_token: var_ecashToken,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::cashu::UnsupportedByMint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_nut = <u32>::sse_decode(deserializer);
+        let mut var_capability = <String>::sse_decode(deserializer);
+        return crate::api::cashu::UnsupportedByMint {
+            mint_url: var_mintUrl,
+            nut: var_nut,
+            capability: var_capability,
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::events::WalletEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_profileId = <Option<String>>::sse_decode(deserializer);
+        let mut var_mintUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_quoteId = <Option<String>>::sse_decode(deserializer);
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        let mut var_data = <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        return crate::api::events::WalletEvent {
+            kind: var_kind,
+            profile_id: var_profileId,
+            mint_url: var_mintUrl,
+            quote_id: var_quoteId,
+            amount: var_amount,
+            message: var_message,
+            timestamp: var_timestamp,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::WalletHandle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_profileId = <String>::sse_decode(deserializer);
+        return crate::api::cashu::WalletHandle {
+            profile_id: var_profileId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::WalletInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_balance = <u64>::sse_decode(deserializer);
+        let mut var_activeKeysetId = <String>::sse_decode(deserializer);
+        let mut var_fetchedAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isStale = <bool>::sse_decode(deserializer);
+        return crate::api::cashu::WalletInfo {
+            mint_url: var_mintUrl,
+            unit: var_unit,
+            balance: var_balance,
+            active_keyset_id: var_activeKeysetId,
+            fetched_at: var_fetchedAt,
+            is_stale: var_isStale,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::WalletProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_profileId = <String>::sse_decode(deserializer);
+        let mut var_profileDir = <String>::sse_decode(deserializer);
+        let mut var_isOpen = <bool>::sse_decode(deserializer);
+        let mut var_isEncrypted = <bool>::sse_decode(deserializer);
+        return crate::api::cashu::WalletProfile {
+            profile_id: var_profileId,
+            profile_dir: var_profileDir,
+            is_open: var_isOpen,
+            is_encrypted: var_isEncrypted,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cashu::WebSocketSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_method = <String>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_commands = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::cashu::WebSocketSettings {
+            method: var_method,
+            unit: var_unit,
+            commands: var_commands,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        5 => wire__crate__api__daemon__add_daemon_client_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__cashu__add_mint_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__cashu__check_all_melt_quotes_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__cashu__check_all_mint_quotes_impl(port, ptr, rust_vec_len, data_len),
+        9 => {
+            wire__crate__api__cashu__check_melt_quote_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        10 => {
+            wire__crate__api__cashu__check_mint_capability_impl(port, ptr, rust_vec_len, data_len)
+        }
+        11 => {
+            wire__crate__api__cashu__check_mint_quote_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        12 => wire__crate__api__cashu__clear_interrupted_operations_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__cashu__close_wallet_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__cashu__combine_tokens_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__cashu__create_bolt12_mint_quote_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__cashu__create_mint_quote_impl(port, ptr, rust_vec_len, data_len),
+        18 => {
+            wire__crate__api__cashu__create_payment_request_impl(port, ptr, rust_vec_len, data_len)
+        }
+        19 => {
+            wire__crate__api__cashu__decode_bolt11_invoice_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__cashu__decode_bolt12_invoice_request_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__cashu__decode_bolt12_offer_impl(port, ptr, rust_vec_len, data_len),
+        22 => {
+            wire__crate__api__cashu__decode_payment_request_impl(port, ptr, rust_vec_len, data_len)
+        }
+        23 => wire__crate__api__cashu__delete_wallet_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__nip60__disable_nip60_sync_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__nip60__enable_nip60_sync_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__cashu__encode_token_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__cashu__generate_mnemonic_phrase_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__cashu__get_all_balances_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__cashu__get_all_transactions_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__daemon__get_daemon_address_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__cashu__get_interrupted_operations_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__cashu__get_mint_info_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__cashu__get_mint_proxy_impl(port, ptr, rust_vec_len, data_len),
+        38 => {
+            wire__crate__api__nip60__get_nip60_sync_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        40 => wire__crate__api__limits__get_spending_allowances_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__limits__get_spending_limits_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__cashu__get_tor_bootstrap_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__cashu__get_tor_config_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__cashu__get_tor_isolation_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__cashu__get_wallet_info_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__cashu__get_wallet_proofs_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__cashu__get_wallet_proxy_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__cashu__import_wallet_backup_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__nostr__init_app_impl(port, ptr, rust_vec_len, data_len),
+        51 => {
+            wire__crate__api__cashu__init_multi_mint_wallet_impl(port, ptr, rust_vec_len, data_len)
+        }
+        52 => wire__crate__api__cashu__init_multi_mint_wallet_with_tor_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__cashu__is_offline_mode_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__cashu__is_tor_enabled_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__cashu__is_tor_ready_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__cashu__is_wallet_database_encrypted_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__daemon__list_daemon_clients_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__cashu__list_mints_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__cashu__list_profiles_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__cashu__mint_bolt12_quote_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__cashu__mnemonic_to_seed_hex_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__cashu__new_tor_identity_impl(port, ptr, rust_vec_len, data_len),
+        70 => {
+            wire__crate__api__cashu__open_encrypted_wallet_impl(port, ptr, rust_vec_len, data_len)
+        }
+        71 => wire__crate__api__cashu__open_wallet_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__cashu__parse_cashu_token_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__cashu__pay_bolt12_offer_impl(port, ptr, rust_vec_len, data_len),
+        75 => {
+            wire__crate__api__cashu__pay_invoice_for_wallet_impl(port, ptr, rust_vec_len, data_len)
+        }
+        76 => wire__crate__api__cashu__pay_lnurl_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__cashu__pay_payment_request_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__cashu__receive_tokens_impl(port, ptr, rust_vec_len, data_len),
+        80 => {
+            wire__crate__api__cashu__redeem_lnurl_withdraw_impl(port, ptr, rust_vec_len, data_len)
+        }
+        81 => wire__crate__api__cashu__reinitialize_with_tor_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => {
+            wire__crate__api__daemon__remove_daemon_client_impl(port, ptr, rust_vec_len, data_len)
+        }
+        83 => wire__crate__api__cashu__remove_mint_impl(port, ptr, rust_vec_len, data_len),
+        84 => {
+            wire__crate__api__limits__remove_spending_limit_impl(port, ptr, rust_vec_len, data_len)
+        }
+        85 => wire__crate__api__cashu__resolve_lnurl_pay_impl(port, ptr, rust_vec_len, data_len),
+        86 => {
+            wire__crate__api__cashu__restore_backup_profile_impl(port, ptr, rust_vec_len, data_len)
+        }
+        87 => wire__crate__api__cashu__restore_mint_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__cashu__rotate_wallet_database_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => wire__crate__api__cashu__seed_hex_to_mnemonic_impl(port, ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__cashu__send_p2pk_tokens_impl(port, ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__cashu__send_selected_proofs_impl(port, ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__cashu__send_tokens_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__cashu__send_tokens_with_encoding_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        96 => wire__crate__api__cashu__set_mint_proxy_impl(port, ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__cashu__set_offline_mode_impl(port, ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__limits__set_spending_limit_impl(port, ptr, rust_vec_len, data_len),
+        99 => wire__crate__api__limits__set_spending_pin_impl(port, ptr, rust_vec_len, data_len),
+        100 => wire__crate__api__cashu__set_tor_config_impl(port, ptr, rust_vec_len, data_len),
+        101 => wire__crate__api__cashu__set_tor_isolation_impl(port, ptr, rust_vec_len, data_len),
+        102 => wire__crate__api__cashu__set_wallet_proxy_impl(port, ptr, rust_vec_len, data_len),
+        103 => wire__crate__api__cashu__shutdown_wallet_impl(port, ptr, rust_vec_len, data_len),
+        105 => wire__crate__api__daemon__start_daemon_impl(port, ptr, rust_vec_len, data_len),
+        106 => wire__crate__api__daemon__stop_daemon_impl(port, ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__logging__subscribe_logs_impl(port, ptr, rust_vec_len, data_len),
+        108 => {
+            wire__crate__api__cashu__subscribe_tor_bootstrap_impl(port, ptr, rust_vec_len, data_len)
+        }
+        109 => wire__crate__api__events__subscribe_wallet_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__nip60__sync_nip60_wallet_impl(port, ptr, rust_vec_len, data_len),
+        111 => wire__crate__api__cashu__test_proxy_impl(port, ptr, rust_vec_len, data_len),
+        112 => wire__crate__api__limits__unlock_spending_impl(port, ptr, rust_vec_len, data_len),
+        113 => wire__crate__api__cashu__validate_mnemonic_phrase_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => {
+            wire__crate__api__cashu__verify_proofs_offline_impl(port, ptr, rust_vec_len, data_len)
+        }
+        116 => wire__crate__api__cashu__verify_token_dleq_impl(port, ptr, rust_vec_len, data_len),
+        117 => {
+            wire__crate__api__cashu__verify_token_offline_impl(port, ptr, rust_vec_len, data_len)
+        }
+        118 => wire__crate__api__cashu__verify_token_p2pk_impl(port, ptr, rust_vec_len, data_len),
+        119 => wire__crate__api__cashu__wallet_exists_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__qr__AnimatedQrDecoder_new_impl(ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__qr__AnimatedQrDecoder_progress_impl(ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__qr__AnimatedQrDecoder_receive_impl(ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__qr__AnimatedQrDecoder_reset_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__logging__clear_log_buffer_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__qr__encode_animated_qr_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__logging__export_diagnostics_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__nostr__generate_keys_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__nostr__generate_keys_with_bech32_impl(ptr, rust_vec_len, data_len),
+        39 => {
+            wire__crate__api__nostr__get_public_key_from_private_impl(ptr, rust_vec_len, data_len)
+        }
+        48 => wire__crate__api__nostr__greet_impl(ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__logging__log_from_dart_impl(ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__nostr__nip04_decrypt_impl(ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__nostr__nip04_encrypt_impl(ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__nostr__nip44_decrypt_impl(ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__nostr__nip44_encrypt_impl(ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__nostr__npub_to_public_key_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__nostr__nsec_to_secret_key_impl(ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__cashu__parse_unsupported_by_mint_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__nostr__public_key_to_npub_impl(ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__nostr__secret_key_to_nsec_impl(ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__logging__set_log_level_impl(ptr, rust_vec_len, data_len),
+        104 => wire__crate__api__nostr__sign_event_impl(ptr, rust_vec_len, data_len),
+        114 => wire__crate__api__nostr__verify_event_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<AnimatedQrDecoder> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AnimatedQrDecoder> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AnimatedQrDecoder>> for AnimatedQrDecoder {
+    fn into_into_dart(self) -> FrbWrapper<AnimatedQrDecoder> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::qr::AnimatedQrFrames {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frames.into_into_dart().into_dart(),
+            self.fragment_count.into_into_dart().into_dart(),
+            self.is_animated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::qr::AnimatedQrFrames
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::qr::AnimatedQrFrames>
+    for crate::api::qr::AnimatedQrFrames
+{
+    fn into_into_dart(self) -> crate::api::qr::AnimatedQrFrames {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::Bolt12InvoiceRequestInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payer_pubkey.into_into_dart().into_dart(),
+            self.amount_msat.into_into_dart().into_dart(),
+            self.quantity.into_into_dart().into_dart(),
+            self.payer_note.into_into_dart().into_dart(),
+            self.chain.into_into_dart().into_dart(),
+            self.offer_description.into_into_dart().into_dart(),
+            self.offer_issuer.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::Bolt12InvoiceRequestInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::Bolt12InvoiceRequestInfo>
+    for crate::api::cashu::Bolt12InvoiceRequestInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::Bolt12InvoiceRequestInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::Bolt12OfferInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.offer_id.into_into_dart().into_dart(),
+            self.amount_msat.into_into_dart().into_dart(),
+            self.currency.into_into_dart().into_dart(),
+            self.currency_amount.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.issuer.into_into_dart().into_dart(),
+            self.issuer_pubkey.into_into_dart().into_dart(),
+            self.absolute_expiry.into_into_dart().into_dart(),
+            self.chains.into_into_dart().into_dart(),
+            self.is_expired.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::Bolt12OfferInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::Bolt12OfferInfo>
+    for crate::api::cashu::Bolt12OfferInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::Bolt12OfferInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::CachedEndpoint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.method.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::CachedEndpoint
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::CachedEndpoint>
+    for crate::api::cashu::CachedEndpoint
+{
+    fn into_into_dart(self) -> crate::api::cashu::CachedEndpoint {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::CashuDleq {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.e.into_into_dart().into_dart(),
+            self.s.into_into_dart().into_dart(),
+            self.r.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cashu::CashuDleq {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::CashuDleq>
+    for crate::api::cashu::CashuDleq
+{
+    fn into_into_dart(self) -> crate::api::cashu::CashuDleq {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::CashuProof {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.secret.into_into_dart().into_dart(),
+            self.c.into_into_dart().into_dart(),
+            self.witness.into_into_dart().into_dart(),
+            self.dleq.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.reserved.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cashu::CashuProof {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::CashuProof>
+    for crate::api::cashu::CashuProof
+{
+    fn into_into_dart(self) -> crate::api::cashu::CashuProof {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::CashuSecret {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.raw.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.nonce.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::CashuSecret
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::CashuSecret>
+    for crate::api::cashu::CashuSecret
+{
+    fn into_into_dart(self) -> crate::api::cashu::CashuSecret {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::CashuWitness {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.preimage.into_into_dart().into_dart(),
+            self.signatures.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::CashuWitness
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::CashuWitness>
+    for crate::api::cashu::CashuWitness
+{
+    fn into_into_dart(self) -> crate::api::cashu::CashuWitness {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::ContactInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.method.into_into_dart().into_dart(),
+            self.info.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::ContactInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::ContactInfo>
+    for crate::api::cashu::ContactInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::ContactInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::daemon::DaemonClient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.permissions.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::daemon::DaemonClient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::daemon::DaemonClient>
+    for crate::api::daemon::DaemonClient
+{
+    fn into_into_dart(self) -> crate::api::daemon::DaemonClient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logging::DiagnosticsBundle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.created_at.into_into_dart().into_dart(),
+            self.crate_version.into_into_dart().into_dart(),
+            self.os.into_into_dart().into_dart(),
+            self.arch.into_into_dart().into_dart(),
+            self.log_levels.into_into_dart().into_dart(),
+            self.records.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::logging::DiagnosticsBundle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logging::DiagnosticsBundle>
+    for crate::api::logging::DiagnosticsBundle
+{
+    fn into_into_dart(self) -> crate::api::logging::DiagnosticsBundle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::EncodedToken {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.token.into_into_dart().into_dart(),
+            self.encoding.into_into_dart().into_dart(),
+            self.byte_size.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.proofs_count.into_into_dart().into_dart(),
+            self.mint_urls.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::EncodedToken
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::EncodedToken>
+    for crate::api::cashu::EncodedToken
+{
+    fn into_into_dart(self) -> crate::api::cashu::EncodedToken {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::ImportReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format.into_into_dart().into_dart(),
+            self.mints.into_into_dart().into_dart(),
+            self.imported_amount.into_into_dart().into_dart(),
+            self.unassigned_proofs.into_into_dart().into_dart(),
+            self.has_mnemonic.into_into_dart().into_dart(),
+            self.keyset_counters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::ImportReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::ImportReport>
+    for crate::api::cashu::ImportReport
+{
+    fn into_into_dart(self) -> crate::api::cashu::ImportReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::ImportedMint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_url.into_into_dart().into_dart(),
+            self.proofs_found.into_into_dart().into_dart(),
+            self.amount_found.into_into_dart().into_dart(),
+            self.spent_amount.into_into_dart().into_dart(),
+            self.imported_amount.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::ImportedMint
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::ImportedMint>
+    for crate::api::cashu::ImportedMint
+{
+    fn into_into_dart(self) -> crate::api::cashu::ImportedMint {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::InterruptedOperation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.mint_url.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::InterruptedOperation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::InterruptedOperation>
+    for crate::api::cashu::InterruptedOperation
+{
+    fn into_into_dart(self) -> crate::api::cashu::InterruptedOperation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::KeysetCounter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.keyset_id.into_into_dart().into_dart(),
+            self.counter.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::KeysetCounter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::KeysetCounter>
+    for crate::api::cashu::KeysetCounter
+{
+    fn into_into_dart(self) -> crate::api::cashu::KeysetCounter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::LnurlPayInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.callback.into_into_dart().into_dart(),
+            self.min_sendable_sats.into_into_dart().into_dart(),
+            self.max_sendable_sats.into_into_dart().into_dart(),
+            self.comment_allowed.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.domain.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::LnurlPayInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::LnurlPayInfo>
+    for crate::api::cashu::LnurlPayInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::LnurlPayInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logging::LogRecord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.module.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.timestamp_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::logging::LogRecord
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logging::LogRecord>
+    for crate::api::logging::LogRecord
+{
+    fn into_into_dart(self) -> crate::api::logging::LogRecord {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::MintAuthSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.openid_discovery.into_into_dart().into_dart(),
+            self.client_id.into_into_dart().into_dart(),
+            self.clear_auth_endpoints.into_into_dart().into_dart(),
+            self.blind_auth_max_mint.into_into_dart().into_dart(),
+            self.blind_auth_endpoints.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::MintAuthSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::MintAuthSettings>
+    for crate::api::cashu::MintAuthSettings
+{
+    fn into_into_dart(self) -> crate::api::cashu::MintAuthSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::MintInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.description_long.into_into_dart().into_dart(),
+            self.contact.into_into_dart().into_dart(),
+            self.motd.into_into_dart().into_dart(),
+            self.icon_url.into_into_dart().into_dart(),
+            self.urls.into_into_dart().into_dart(),
+            self.nuts.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
+            self.additional_info.into_into_dart().into_dart(),
+            self.tos_url.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.settings.into_into_dart().into_dart(),
+            self.fetched_at.into_into_dart().into_dart(),
+            self.is_stale.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cashu::MintInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::MintInfo>
+    for crate::api::cashu::MintInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::MintInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::MintSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_methods.into_into_dart().into_dart(),
+            self.minting_disabled.into_into_dart().into_dart(),
+            self.melt_methods.into_into_dart().into_dart(),
+            self.melting_disabled.into_into_dart().into_dart(),
+            self.supported_nuts.into_into_dart().into_dart(),
+            self.mpp_methods.into_into_dart().into_dart(),
+            self.websocket.into_into_dart().into_dart(),
+            self.cache_ttl.into_into_dart().into_dart(),
+            self.cached_endpoints.into_into_dart().into_dart(),
+            self.auth.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::MintSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::MintSettings>
+    for crate::api::cashu::MintSettings
+{
+    fn into_into_dart(self) -> crate::api::cashu::MintSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nip60::Nip60SyncReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.imported_proofs.into_into_dart().into_dart(),
+            self.imported_amount.into_into_dart().into_dart(),
+            self.removed_proofs.into_into_dart().into_dart(),
+            self.mints_added.into_into_dart().into_dart(),
+            self.published_events.into_into_dart().into_dart(),
+            self.deleted_events.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+            self.synced_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nip60::Nip60SyncReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nip60::Nip60SyncReport>
+    for crate::api::nip60::Nip60SyncReport
+{
+    fn into_into_dart(self) -> crate::api::nip60::Nip60SyncReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nip60::Nip60SyncStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.relays.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
+            self.last_report.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nip60::Nip60SyncStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nip60::Nip60SyncStatus>
+    for crate::api::nip60::Nip60SyncStatus
+{
+    fn into_into_dart(self) -> crate::api::nip60::Nip60SyncStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nostr::NostrEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.sig.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nostr::NostrEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nostr::NostrEvent>
+    for crate::api::nostr::NostrEvent
+{
+    fn into_into_dart(self) -> crate::api::nostr::NostrEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nostr::NostrKeys {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.public_key.into_into_dart().into_dart(),
+            self.private_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nostr::NostrKeys {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nostr::NostrKeys>
+    for crate::api::nostr::NostrKeys
+{
+    fn into_into_dart(self) -> crate::api::nostr::NostrKeys {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nostr::NostrKeysWithBech32 {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.private_key.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
+            self.nsec.into_into_dart().into_dart(),
+            self.npub.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nostr::NostrKeysWithBech32
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nostr::NostrKeysWithBech32>
+    for crate::api::nostr::NostrKeysWithBech32
+{
+    fn into_into_dart(self) -> crate::api::nostr::NostrKeysWithBech32 {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::PaymentMethodSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.method.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.min_amount.into_into_dart().into_dart(),
+            self.max_amount.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.amountless.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::PaymentMethodSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::PaymentMethodSettings>
+    for crate::api::cashu::PaymentMethodSettings
+{
+    fn into_into_dart(self) -> crate::api::cashu::PaymentMethodSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::PaymentRequestInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payment_id.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.single_use.into_into_dart().into_dart(),
+            self.mints.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.transports.into_into_dart().into_dart(),
+            self.lock_kind.into_into_dart().into_dart(),
+            self.lock_data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::PaymentRequestInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::PaymentRequestInfo>
+    for crate::api::cashu::PaymentRequestInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::PaymentRequestInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::PaymentRequestTransport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.transport_type.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::PaymentRequestTransport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::PaymentRequestTransport>
+    for crate::api::cashu::PaymentRequestTransport
+{
+    fn into_into_dart(self) -> crate::api::cashu::PaymentRequestTransport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::ProofVerification {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.y.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.keyset_id.into_into_dart().into_dart(),
+            self.dleq_valid.into_into_dart().into_dart(),
+            self.secret_valid.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::ProofVerification
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::ProofVerification>
+    for crate::api::cashu::ProofVerification
+{
+    fn into_into_dart(self) -> crate::api::cashu::ProofVerification {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::network::ProxyConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.host.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::network::ProxyConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::network::ProxyConfig>
+    for crate::network::ProxyConfig
+{
+    fn into_into_dart(self) -> crate::network::ProxyConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::ProxyTestResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.success.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::ProxyTestResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::ProxyTestResult>
+    for crate::api::cashu::ProxyTestResult
+{
+    fn into_into_dart(self) -> crate::api::cashu::ProxyTestResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::qr::QrDecodeProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.complete.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+            self.received_frames.into_into_dart().into_dart(),
+            self.expected_frames.into_into_dart().into_dart(),
+            self.payload.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::qr::QrDecodeProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::qr::QrDecodeProgress>
+    for crate::api::qr::QrDecodeProgress
+{
+    fn into_into_dart(self) -> crate::api::qr::QrDecodeProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::RestoredMint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_url.into_into_dart().into_dart(),
+            self.restored_amount.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::RestoredMint
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::RestoredMint>
+    for crate::api::cashu::RestoredMint
+{
+    fn into_into_dart(self) -> crate::api::cashu::RestoredMint {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::RestoredProfile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.handle.into_into_dart().into_dart(),
+            self.mnemonic.into_into_dart().into_dart(),
+            self.mints.into_into_dart().into_dart(),
+            self.restored_amount.into_into_dart().into_dart(),
+            self.keyset_counters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::RestoredProfile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::RestoredProfile>
+    for crate::api::cashu::RestoredProfile
+{
+    fn into_into_dart(self) -> crate::api::cashu::RestoredProfile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::SelectedProofsSendOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.swap.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.memo.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::SelectedProofsSendOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::SelectedProofsSendOptions>
+    for crate::api::cashu::SelectedProofsSendOptions
+{
+    fn into_into_dart(self) -> crate::api::cashu::SelectedProofsSendOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::limits::SpendingAllowance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_url.into_into_dart().into_dart(),
+            self.per_transaction.into_into_dart().into_dart(),
+            self.daily_spent.into_into_dart().into_dart(),
+            self.daily_remaining.into_into_dart().into_dart(),
+            self.weekly_spent.into_into_dart().into_dart(),
+            self.weekly_remaining.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::limits::SpendingAllowance
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::limits::SpendingAllowance>
+    for crate::api::limits::SpendingAllowance
+{
+    fn into_into_dart(self) -> crate::api::limits::SpendingAllowance {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::limits::SpendingLimit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_url.into_into_dart().into_dart(),
+            self.per_transaction.into_into_dart().into_dart(),
+            self.daily.into_into_dart().into_dart(),
+            self.weekly.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::limits::SpendingLimit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::limits::SpendingLimit>
+    for crate::api::limits::SpendingLimit
+{
+    fn into_into_dart(self) -> crate::api::limits::SpendingLimit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::TokenVerification {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_url.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.keysets_valid.into_into_dart().into_dart(),
+            self.fetched_keys.into_into_dart().into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.proofs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::TokenVerification
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::TokenVerification>
+    for crate::api::cashu::TokenVerification
+{
+    fn into_into_dart(self) -> crate::api::cashu::TokenVerification {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::TorBootstrapStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.started.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+            self.ready.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for std::collections::HashMap<String, u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, u64)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::TorBootstrapStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::TorBootstrapStatus>
+    for crate::api::cashu::TorBootstrapStatus
+{
+    fn into_into_dart(self) -> crate::api::cashu::TorBootstrapStatus {
+        self
     }
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::TransactionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.memo.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.mint_url.into_into_dart().into_dart(),
+            self.transaction_type.into_into_dart().into_dart(),
+            self.lightning_invoice.into_into_dart().into_dart(),
+            self.ecash_token.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::TransactionInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::TransactionInfo>
+    for crate::api::cashu::TransactionInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::TransactionInfo {
+        self
     }
 }
-
-impl SseDecode for crate::api::cashu::CashuProof {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_secret = <String>::sse_decode(deserializer);
-        let mut var_c = <String>::sse_decode(deserializer);
-        return crate::api::cashu::CashuProof {
-            id: var_id,
-            amount: var_amount,
-            secret: var_secret,
-            c: var_c,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::UnsupportedByMint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_url.into_into_dart().into_dart(),
+            self.nut.into_into_dart().into_dart(),
+            self.capability.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::cashu::ContactInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_method = <String>::sse_decode(deserializer);
-        let mut var_info = <String>::sse_decode(deserializer);
-        return crate::api::cashu::ContactInfo {
-            method: var_method,
-            info: var_info,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::UnsupportedByMint
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::UnsupportedByMint>
+    for crate::api::cashu::UnsupportedByMint
+{
+    fn into_into_dart(self) -> crate::api::cashu::UnsupportedByMint {
+        self
     }
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::events::WalletEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.profile_id.into_into_dart().into_dart(),
+            self.mint_url.into_into_dart().into_dart(),
+            self.quote_id.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::cashu::CashuProof> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::cashu::CashuProof>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::events::WalletEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::events::WalletEvent>
+    for crate::api::events::WalletEvent
+{
+    fn into_into_dart(self) -> crate::api::events::WalletEvent {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::cashu::ContactInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::cashu::ContactInfo>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::WalletHandle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.profile_id.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for Vec<Vec<String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Vec<String>>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::WalletHandle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::WalletHandle>
+    for crate::api::cashu::WalletHandle
+{
+    fn into_into_dart(self) -> crate::api::cashu::WalletHandle {
+        self
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::WalletInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mint_url.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.balance.into_into_dart().into_dart(),
+            self.active_keyset_id.into_into_dart().into_dart(),
+            self.fetched_at.into_into_dart().into_dart(),
+            self.is_stale.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<(String, String)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cashu::WalletInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::WalletInfo>
+    for crate::api::cashu::WalletInfo
+{
+    fn into_into_dart(self) -> crate::api::cashu::WalletInfo {
+        self
     }
 }
-
-impl SseDecode for Vec<(String, u64)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, u64)>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::WalletProfile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.profile_id.into_into_dart().into_dart(),
+            self.profile_dir.into_into_dart().into_dart(),
+            self.is_open.into_into_dart().into_dart(),
+            self.is_encrypted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::cashu::TransactionInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::cashu::TransactionInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::WalletProfile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::WalletProfile>
+    for crate::api::cashu::WalletProfile
+{
+    fn into_into_dart(self) -> crate::api::cashu::WalletProfile {
+        self
     }
 }
-
-impl SseDecode for crate::api::cashu::MintInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <Option<String>>::sse_decode(deserializer);
-        let mut var_version = <Option<String>>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        let mut var_descriptionLong = <Option<String>>::sse_decode(deserializer);
-        let mut var_contact =
-            <Option<Vec<crate::api::cashu::ContactInfo>>>::sse_decode(deserializer);
-        let mut var_motd = <Option<String>>::sse_decode(deserializer);
-        let mut var_iconUrl = <Option<String>>::sse_decode(deserializer);
-        let mut var_urls = <Option<Vec<String>>>::sse_decode(deserializer);
-        let mut var_nuts = <Option<Vec<String>>>::sse_decode(deserializer);
-        let mut var_publicKey = <Option<String>>::sse_decode(deserializer);
-        let mut var_additionalInfo = <Option<String>>::sse_decode(deserializer);
-        return crate::api::cashu::MintInfo {
-            name: var_name,
-            version: var_version,
-            description: var_description,
-            description_long: var_descriptionLong,
-            contact: var_contact,
-            motd: var_motd,
-            icon_url: var_iconUrl,
-            urls: var_urls,
-            nuts: var_nuts,
-            public_key: var_publicKey,
-            additional_info: var_additionalInfo,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cashu::WebSocketSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.method.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.commands.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cashu::WebSocketSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::WebSocketSettings>
+    for crate::api::cashu::WebSocketSettings
+{
+    fn into_into_dart(self) -> crate::api::cashu::WebSocketSettings {
+        self
     }
 }
 
-impl SseDecode for crate::api::nostr::NostrEvent {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_pubkey = <String>::sse_decode(deserializer);
-        let mut var_createdAt = <u64>::sse_decode(deserializer);
-        let mut var_kind = <u64>::sse_decode(deserializer);
-        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_sig = <String>::sse_decode(deserializer);
-        return crate::api::nostr::NostrEvent {
-            id: var_id,
-            pubkey: var_pubkey,
-            created_at: var_createdAt,
-            kind: var_kind,
-            tags: var_tags,
-            content: var_content,
-            sig: var_sig,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for crate::api::nostr::NostrKeys {
+impl SseEncode for AnimatedQrDecoder {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_publicKey = <String>::sse_decode(deserializer);
-        let mut var_privateKey = <String>::sse_decode(deserializer);
-        return crate::api::nostr::NostrKeys {
-            public_key: var_publicKey,
-            private_key: var_privateKey,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for crate::api::nostr::NostrKeysWithBech32 {
+impl SseEncode for std::collections::BTreeMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_privateKey = <String>::sse_decode(deserializer);
-        let mut var_publicKey = <String>::sse_decode(deserializer);
-        let mut var_nsec = <String>::sse_decode(deserializer);
-        let mut var_npub = <String>::sse_decode(deserializer);
-        return crate::api::nostr::NostrKeysWithBech32 {
-            private_key: var_privateKey,
-            public_key: var_publicKey,
-            nsec: var_nsec,
-            npub: var_npub,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for std::collections::HashMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseEncode for std::collections::HashMap<String, u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, u64)>>::sse_encode(self.into_iter().collect(), serializer);
     }
 }
 
-impl SseDecode for Option<Vec<String>> {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<String>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for Option<Vec<crate::api::cashu::ContactInfo>> {
+impl SseEncode
+    for StreamSink<crate::api::logging::LogRecord, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<crate::api::cashu::ContactInfo>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for (String, String) {
+impl SseEncode
+    for StreamSink<
+        crate::api::cashu::TorBootstrapStatus,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for (String, u64) {
+impl SseEncode
+    for StreamSink<crate::api::events::WalletEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <u64>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::api::cashu::TransactionInfo {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_direction = <String>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_fee = <u64>::sse_decode(deserializer);
-        let mut var_memo = <Option<String>>::sse_decode(deserializer);
-        let mut var_timestamp = <u64>::sse_decode(deserializer);
-        let mut var_mintUrl = <String>::sse_decode(deserializer);
-        let mut var_transactionType = <Option<String>>::sse_decode(deserializer);
-        let mut var_lightningInvoice = <Option<String>>::sse_decode(deserializer);
-        let mut var_ecashToken = <Option<String>>::sse_decode(deserializer);
-        let mut var_metadata =
-            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-        return crate::api::cashu::TransactionInfo {
-            id: var_id,
-            direction: var_direction,
-            amount: var_amount,
-            fee: var_fee,
-            memo: var_memo,
-            timestamp: var_timestamp,
-            mint_url: var_mintUrl,
-            transaction_type: var_transactionType,
-            lightning_invoice: var_lightningInvoice,
-            ecash_token: var_ecashToken,
-            metadata: var_metadata,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for crate::api::qr::AnimatedQrFrames {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.frames, serializer);
+        <u32>::sse_encode(self.fragment_count, serializer);
+        <bool>::sse_encode(self.is_animated, serializer);
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for crate::api::cashu::Bolt12InvoiceRequestInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.payer_pubkey, serializer);
+        <Option<u64>>::sse_encode(self.amount_msat, serializer);
+        <Option<u64>>::sse_encode(self.quantity, serializer);
+        <Option<String>>::sse_encode(self.payer_note, serializer);
+        <String>::sse_encode(self.chain, serializer);
+        <Option<String>>::sse_encode(self.offer_description, serializer);
+        <Option<String>>::sse_encode(self.offer_issuer, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::cashu::Bolt12OfferInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.offer_id, serializer);
+        <Option<u64>>::sse_encode(self.amount_msat, serializer);
+        <Option<String>>::sse_encode(self.currency, serializer);
+        <Option<u64>>::sse_encode(self.currency_amount, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.issuer, serializer);
+        <Option<String>>::sse_encode(self.issuer_pubkey, serializer);
+        <Option<u64>>::sse_encode(self.absolute_expiry, serializer);
+        <Vec<String>>::sse_encode(self.chains, serializer);
+        <bool>::sse_encode(self.is_expired, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
 }
 
-impl SseDecode for crate::api::cashu::WalletInfo {
+impl SseEncode for crate::api::cashu::CachedEndpoint {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mintUrl = <String>::sse_decode(deserializer);
-        let mut var_unit = <String>::sse_decode(deserializer);
-        let mut var_balance = <u64>::sse_decode(deserializer);
-        let mut var_activeKeysetId = <String>::sse_decode(deserializer);
-        return crate::api::cashu::WalletInfo {
-            mint_url: var_mintUrl,
-            unit: var_unit,
-            balance: var_balance,
-            active_keyset_id: var_activeKeysetId,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.method, serializer);
+        <String>::sse_encode(self.path, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::cashu::CashuDleq {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.e, serializer);
+        <String>::sse_encode(self.s, serializer);
+        <String>::sse_encode(self.r, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__cashu__add_mint_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__cashu__check_all_melt_quotes_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__cashu__check_all_mint_quotes_impl(port, ptr, rust_vec_len, data_len),
-        4 => {
-            wire__crate__api__cashu__check_melt_quote_status_impl(port, ptr, rust_vec_len, data_len)
-        }
-        5 => {
-            wire__crate__api__cashu__check_mint_quote_status_impl(port, ptr, rust_vec_len, data_len)
-        }
-        6 => wire__crate__api__cashu__create_mint_quote_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__cashu__decode_bolt11_invoice_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__cashu__generate_mnemonic_phrase_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__cashu__get_all_balances_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__cashu__get_all_transactions_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__cashu__get_mint_info_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__cashu__get_tor_config_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__cashu__get_wallet_info_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__cashu__get_wallet_proofs_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__nostr__init_app_impl(port, ptr, rust_vec_len, data_len),
-        20 => {
-            wire__crate__api__cashu__init_multi_mint_wallet_impl(port, ptr, rust_vec_len, data_len)
-        }
-        21 => wire__crate__api__cashu__init_multi_mint_wallet_with_tor_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        22 => wire__crate__api__cashu__is_tor_enabled_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__cashu__is_tor_ready_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__cashu__list_mints_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__cashu__mnemonic_to_seed_hex_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__cashu__parse_cashu_token_impl(port, ptr, rust_vec_len, data_len),
-        33 => {
-            wire__crate__api__cashu__pay_invoice_for_wallet_impl(port, ptr, rust_vec_len, data_len)
-        }
-        35 => wire__crate__api__cashu__receive_tokens_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__cashu__reinitialize_with_tor_config_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        37 => wire__crate__api__cashu__remove_mint_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__cashu__restore_mint_impl(port, ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__cashu__seed_hex_to_mnemonic_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__cashu__send_p2pk_tokens_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__cashu__send_tokens_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__cashu__set_tor_config_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__cashu__validate_mnemonic_phrase_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        47 => wire__crate__api__cashu__verify_token_dleq_impl(port, ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__cashu__verify_token_p2pk_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__cashu__wallet_exists_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::cashu::CashuProof {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <crate::api::cashu::CashuSecret>::sse_encode(self.secret, serializer);
+        <String>::sse_encode(self.c, serializer);
+        <Option<crate::api::cashu::CashuWitness>>::sse_encode(self.witness, serializer);
+        <Option<crate::api::cashu::CashuDleq>>::sse_encode(self.dleq, serializer);
+        <String>::sse_encode(self.y, serializer);
+        <Option<String>>::sse_encode(self.state, serializer);
+        <bool>::sse_encode(self.reserved, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        8 => wire__crate__api__nostr__generate_keys_impl(ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__nostr__generate_keys_with_bech32_impl(ptr, rust_vec_len, data_len),
-        14 => {
-            wire__crate__api__nostr__get_public_key_from_private_impl(ptr, rust_vec_len, data_len)
-        }
-        18 => wire__crate__api__nostr__greet_impl(ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__nostr__nip04_decrypt_impl(ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__nostr__nip04_encrypt_impl(ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__nostr__nip44_decrypt_impl(ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__nostr__nip44_encrypt_impl(ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__nostr__npub_to_public_key_impl(ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__nostr__nsec_to_secret_key_impl(ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__nostr__public_key_to_npub_impl(ptr, rust_vec_len, data_len),
-        39 => wire__crate__api__nostr__secret_key_to_nsec_impl(ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__nostr__sign_event_impl(ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__nostr__verify_event_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::cashu::CashuSecret {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.raw, serializer);
+        <Option<String>>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.nonce, serializer);
+        <Option<String>>::sse_encode(self.data, serializer);
+        <Vec<Vec<String>>>::sse_encode(self.tags, serializer);
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::cashu::CashuProof {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.amount.into_into_dart().into_dart(),
-            self.secret.into_into_dart().into_dart(),
-            self.c.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::cashu::CashuWitness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.preimage, serializer);
+        <Option<Vec<String>>>::sse_encode(self.signatures, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cashu::CashuProof {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::CashuProof>
-    for crate::api::cashu::CashuProof
-{
-    fn into_into_dart(self) -> crate::api::cashu::CashuProof {
-        self
+
+impl SseEncode for crate::api::cashu::ContactInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.method, serializer);
+        <String>::sse_encode(self.info, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::cashu::ContactInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.method.into_into_dart().into_dart(),
-            self.info.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::daemon::DaemonClient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Vec<String>>::sse_encode(self.permissions, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::cashu::ContactInfo
-{
+
+impl SseEncode for crate::api::logging::DiagnosticsBundle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.created_at, serializer);
+        <String>::sse_encode(self.crate_version, serializer);
+        <String>::sse_encode(self.os, serializer);
+        <String>::sse_encode(self.arch, serializer);
+        <std::collections::BTreeMap<String, String>>::sse_encode(self.log_levels, serializer);
+        <Vec<crate::api::logging::LogRecord>>::sse_encode(self.records, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::ContactInfo>
-    for crate::api::cashu::ContactInfo
-{
-    fn into_into_dart(self) -> crate::api::cashu::ContactInfo {
-        self
+
+impl SseEncode for crate::api::cashu::EncodedToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.token, serializer);
+        <String>::sse_encode(self.encoding, serializer);
+        <u64>::sse_encode(self.byte_size, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.proofs_count, serializer);
+        <Vec<String>>::sse_encode(self.mint_urls, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::cashu::MintInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.version.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.description_long.into_into_dart().into_dart(),
-            self.contact.into_into_dart().into_dart(),
-            self.motd.into_into_dart().into_dart(),
-            self.icon_url.into_into_dart().into_dart(),
-            self.urls.into_into_dart().into_dart(),
-            self.nuts.into_into_dart().into_dart(),
-            self.public_key.into_into_dart().into_dart(),
-            self.additional_info.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cashu::MintInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::MintInfo>
-    for crate::api::cashu::MintInfo
-{
-    fn into_into_dart(self) -> crate::api::cashu::MintInfo {
-        self
+
+impl SseEncode for crate::api::cashu::ImportReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.format, serializer);
+        <Vec<crate::api::cashu::ImportedMint>>::sse_encode(self.mints, serializer);
+        <u64>::sse_encode(self.imported_amount, serializer);
+        <u64>::sse_encode(self.unassigned_proofs, serializer);
+        <bool>::sse_encode(self.has_mnemonic, serializer);
+        <Vec<crate::api::cashu::KeysetCounter>>::sse_encode(self.keyset_counters, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::nostr::NostrEvent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.pubkey.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.sig.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::cashu::ImportedMint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mint_url, serializer);
+        <u64>::sse_encode(self.proofs_found, serializer);
+        <u64>::sse_encode(self.amount_found, serializer);
+        <u64>::sse_encode(self.spent_amount, serializer);
+        <u64>::sse_encode(self.imported_amount, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nostr::NostrEvent {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::nostr::NostrEvent>
-    for crate::api::nostr::NostrEvent
-{
-    fn into_into_dart(self) -> crate::api::nostr::NostrEvent {
-        self
+
+impl SseEncode for crate::api::cashu::InterruptedOperation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.mint_url, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.started_at, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::nostr::NostrKeys {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.public_key.into_into_dart().into_dart(),
-            self.private_key.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::cashu::KeysetCounter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.keyset_id, serializer);
+        <u32>::sse_encode(self.counter, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nostr::NostrKeys {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::nostr::NostrKeys>
-    for crate::api::nostr::NostrKeys
-{
-    fn into_into_dart(self) -> crate::api::nostr::NostrKeys {
-        self
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::nostr::NostrKeysWithBech32 {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.private_key.into_into_dart().into_dart(),
-            self.public_key.into_into_dart().into_dart(),
-            self.nsec.into_into_dart().into_dart(),
-            self.npub.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::cashu::CachedEndpoint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::CachedEndpoint>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::nostr::NostrKeysWithBech32
-{
+
+impl SseEncode for Vec<crate::api::cashu::CashuProof> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::CashuProof>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::nostr::NostrKeysWithBech32>
-    for crate::api::nostr::NostrKeysWithBech32
-{
-    fn into_into_dart(self) -> crate::api::nostr::NostrKeysWithBech32 {
-        self
+
+impl SseEncode for Vec<crate::api::cashu::ContactInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::ContactInfo>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::cashu::TransactionInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.direction.into_into_dart().into_dart(),
-            self.amount.into_into_dart().into_dart(),
-            self.fee.into_into_dart().into_dart(),
-            self.memo.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-            self.mint_url.into_into_dart().into_dart(),
-            self.transaction_type.into_into_dart().into_dart(),
-            self.lightning_invoice.into_into_dart().into_dart(),
-            self.ecash_token.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::daemon::DaemonClient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::daemon::DaemonClient>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::cashu::TransactionInfo
-{
+
+impl SseEncode for Vec<crate::api::cashu::ImportedMint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::ImportedMint>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::TransactionInfo>
-    for crate::api::cashu::TransactionInfo
-{
-    fn into_into_dart(self) -> crate::api::cashu::TransactionInfo {
-        self
+
+impl SseEncode for Vec<crate::api::cashu::InterruptedOperation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::InterruptedOperation>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::cashu::WalletInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.mint_url.into_into_dart().into_dart(),
-            self.unit.into_into_dart().into_dart(),
-            self.balance.into_into_dart().into_dart(),
-            self.active_keyset_id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::cashu::KeysetCounter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::KeysetCounter>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cashu::WalletInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cashu::WalletInfo>
-    for crate::api::cashu::WalletInfo
-{
-    fn into_into_dart(self) -> crate::api::cashu::WalletInfo {
-        self
+
+impl SseEncode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<String>>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, String> {
+impl SseEncode for Vec<crate::api::logging::LogRecord> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::logging::LogRecord>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, u64> {
+impl SseEncode for Vec<crate::api::cashu::PaymentMethodSettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, u64)>>::sse_encode(self.into_iter().collect(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::PaymentMethodSettings>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::api::cashu::PaymentRequestTransport> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::PaymentRequestTransport>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::cashu::CashuProof {
+impl SseEncode for Vec<crate::api::cashu::ProofVerification> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <u64>::sse_encode(self.amount, serializer);
-        <String>::sse_encode(self.secret, serializer);
-        <String>::sse_encode(self.c, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cashu::ProofVerification>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::cashu::ContactInfo {
+impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.method, serializer);
-        <String>::sse_encode(self.info, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, String)>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Vec<(String, u64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <String>::sse_encode(item, serializer);
+            <(String, u64)>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::cashu::CashuProof> {
+impl SseEncode for Vec<crate::api::cashu::RestoredMint> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::cashu::CashuProof>::sse_encode(item, serializer);
+            <crate::api::cashu::RestoredMint>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::cashu::ContactInfo> {
+impl SseEncode for Vec<crate::api::limits::SpendingAllowance> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::cashu::ContactInfo>::sse_encode(item, serializer);
+            <crate::api::limits::SpendingAllowance>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<Vec<String>> {
+impl SseEncode for Vec<crate::api::limits::SpendingLimit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <Vec<String>>::sse_encode(item, serializer);
+            <crate::api::limits::SpendingLimit>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Vec<crate::api::cashu::TransactionInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <u8>::sse_encode(item, serializer);
+            <crate::api::cashu::TransactionInfo>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(String, String)> {
+impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <(String, String)>::sse_encode(item, serializer);
+            <u32>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(String, u64)> {
+impl SseEncode for Vec<crate::api::cashu::WalletProfile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <(String, u64)>::sse_encode(item, serializer);
+            <crate::api::cashu::WalletProfile>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::cashu::TransactionInfo> {
+impl SseEncode for Vec<crate::api::cashu::WebSocketSettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::cashu::TransactionInfo>::sse_encode(item, serializer);
+            <crate::api::cashu::WebSocketSettings>::sse_encode(item, serializer);
         }
     }
 }
 
+impl SseEncode for crate::api::cashu::LnurlPayInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.callback, serializer);
+        <u64>::sse_encode(self.min_sendable_sats, serializer);
+        <u64>::sse_encode(self.max_sendable_sats, serializer);
+        <u64>::sse_encode(self.comment_allowed, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <String>::sse_encode(self.metadata, serializer);
+        <String>::sse_encode(self.domain, serializer);
+    }
+}
+
+impl SseEncode for crate::api::logging::LogRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.module, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u64>::sse_encode(self.timestamp_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::MintAuthSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.openid_discovery, serializer);
+        <Option<String>>::sse_encode(self.client_id, serializer);
+        <Vec<String>>::sse_encode(self.clear_auth_endpoints, serializer);
+        <Option<u64>>::sse_encode(self.blind_auth_max_mint, serializer);
+        <Vec<String>>::sse_encode(self.blind_auth_endpoints, serializer);
+    }
+}
+
 impl SseEncode for crate::api::cashu::MintInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2628,6 +8084,52 @@ impl SseEncode for crate::api::cashu::MintInfo {
         <Option<Vec<String>>>::sse_encode(self.nuts, serializer);
         <Option<String>>::sse_encode(self.public_key, serializer);
         <Option<String>>::sse_encode(self.additional_info, serializer);
+        <Option<String>>::sse_encode(self.tos_url, serializer);
+        <Option<u64>>::sse_encode(self.time, serializer);
+        <crate::api::cashu::MintSettings>::sse_encode(self.settings, serializer);
+        <Option<u64>>::sse_encode(self.fetched_at, serializer);
+        <bool>::sse_encode(self.is_stale, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::MintSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::cashu::PaymentMethodSettings>>::sse_encode(self.mint_methods, serializer);
+        <bool>::sse_encode(self.minting_disabled, serializer);
+        <Vec<crate::api::cashu::PaymentMethodSettings>>::sse_encode(self.melt_methods, serializer);
+        <bool>::sse_encode(self.melting_disabled, serializer);
+        <Vec<u32>>::sse_encode(self.supported_nuts, serializer);
+        <Vec<crate::api::cashu::PaymentMethodSettings>>::sse_encode(self.mpp_methods, serializer);
+        <Vec<crate::api::cashu::WebSocketSettings>>::sse_encode(self.websocket, serializer);
+        <Option<u64>>::sse_encode(self.cache_ttl, serializer);
+        <Vec<crate::api::cashu::CachedEndpoint>>::sse_encode(self.cached_endpoints, serializer);
+        <Option<crate::api::cashu::MintAuthSettings>>::sse_encode(self.auth, serializer);
+    }
+}
+
+impl SseEncode for crate::api::nip60::Nip60SyncReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.imported_proofs, serializer);
+        <u64>::sse_encode(self.imported_amount, serializer);
+        <u64>::sse_encode(self.removed_proofs, serializer);
+        <Vec<String>>::sse_encode(self.mints_added, serializer);
+        <u64>::sse_encode(self.published_events, serializer);
+        <u64>::sse_encode(self.deleted_events, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+        <u64>::sse_encode(self.synced_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::nip60::Nip60SyncStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <Vec<String>>::sse_encode(self.relays, serializer);
+        <Option<String>>::sse_encode(self.public_key, serializer);
+        <Option<crate::api::nip60::Nip60SyncReport>>::sse_encode(self.last_report, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
     }
 }
 
@@ -2672,6 +8174,76 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::cashu::CashuDleq> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cashu::CashuDleq>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::cashu::CashuWitness> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cashu::CashuWitness>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::cashu::MintAuthSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cashu::MintAuthSettings>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::nip60::Nip60SyncReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::nip60::Nip60SyncReport>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::network::ProxyConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::network::ProxyConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2682,6 +8254,16 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<crate::api::cashu::UnsupportedByMint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cashu::UnsupportedByMint>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2702,6 +8284,85 @@ impl SseEncode for Option<Vec<crate::api::cashu::ContactInfo>> {
     }
 }
 
+impl SseEncode for crate::api::cashu::PaymentMethodSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.method, serializer);
+        <String>::sse_encode(self.unit, serializer);
+        <Option<u64>>::sse_encode(self.min_amount, serializer);
+        <Option<u64>>::sse_encode(self.max_amount, serializer);
+        <bool>::sse_encode(self.description, serializer);
+        <bool>::sse_encode(self.amountless, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::PaymentRequestInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.payment_id, serializer);
+        <Option<u64>>::sse_encode(self.amount, serializer);
+        <Option<String>>::sse_encode(self.unit, serializer);
+        <Option<bool>>::sse_encode(self.single_use, serializer);
+        <Vec<String>>::sse_encode(self.mints, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Vec<crate::api::cashu::PaymentRequestTransport>>::sse_encode(self.transports, serializer);
+        <Option<String>>::sse_encode(self.lock_kind, serializer);
+        <Option<String>>::sse_encode(self.lock_data, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::PaymentRequestTransport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.transport_type, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <Vec<Vec<String>>>::sse_encode(self.tags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::ProofVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.y, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <String>::sse_encode(self.keyset_id, serializer);
+        <Option<bool>>::sse_encode(self.dleq_valid, serializer);
+        <bool>::sse_encode(self.secret_valid, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::network::ProxyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.password, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::ProxyTestResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.success, serializer);
+        <u64>::sse_encode(self.latency_ms, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::qr::QrDecodeProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.complete, serializer);
+        <f64>::sse_encode(self.progress, serializer);
+        <u32>::sse_encode(self.received_frames, serializer);
+        <u32>::sse_encode(self.expected_frames, serializer);
+        <Option<String>>::sse_encode(self.payload, serializer);
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2718,6 +8379,82 @@ impl SseEncode for (String, u64) {
     }
 }
 
+impl SseEncode for crate::api::cashu::RestoredMint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mint_url, serializer);
+        <u64>::sse_encode(self.restored_amount, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::RestoredProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::cashu::WalletHandle>::sse_encode(self.handle, serializer);
+        <String>::sse_encode(self.mnemonic, serializer);
+        <Vec<crate::api::cashu::RestoredMint>>::sse_encode(self.mints, serializer);
+        <u64>::sse_encode(self.restored_amount, serializer);
+        <Vec<crate::api::cashu::KeysetCounter>>::sse_encode(self.keyset_counters, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::SelectedProofsSendOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.swap, serializer);
+        <Option<u64>>::sse_encode(self.amount, serializer);
+        <Option<String>>::sse_encode(self.memo, serializer);
+    }
+}
+
+impl SseEncode for crate::api::limits::SpendingAllowance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.mint_url, serializer);
+        <Option<u64>>::sse_encode(self.per_transaction, serializer);
+        <u64>::sse_encode(self.daily_spent, serializer);
+        <Option<u64>>::sse_encode(self.daily_remaining, serializer);
+        <u64>::sse_encode(self.weekly_spent, serializer);
+        <Option<u64>>::sse_encode(self.weekly_remaining, serializer);
+        <String>::sse_encode(self.action, serializer);
+    }
+}
+
+impl SseEncode for crate::api::limits::SpendingLimit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.mint_url, serializer);
+        <Option<u64>>::sse_encode(self.per_transaction, serializer);
+        <Option<u64>>::sse_encode(self.daily, serializer);
+        <Option<u64>>::sse_encode(self.weekly, serializer);
+        <String>::sse_encode(self.action, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::TokenVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mint_url, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <bool>::sse_encode(self.keysets_valid, serializer);
+        <bool>::sse_encode(self.fetched_keys, serializer);
+        <bool>::sse_encode(self.valid, serializer);
+        <Vec<crate::api::cashu::ProofVerification>>::sse_encode(self.proofs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::TorBootstrapStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.started, serializer);
+        <f64>::sse_encode(self.progress, serializer);
+        <bool>::sse_encode(self.ready, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
 impl SseEncode for crate::api::cashu::TransactionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2735,6 +8472,13 @@ impl SseEncode for crate::api::cashu::TransactionInfo {
     }
 }
 
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2761,6 +8505,46 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::cashu::UnsupportedByMint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mint_url, serializer);
+        <u32>::sse_encode(self.nut, serializer);
+        <String>::sse_encode(self.capability, serializer);
+    }
+}
+
+impl SseEncode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::api::events::WalletEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.profile_id, serializer);
+        <Option<String>>::sse_encode(self.mint_url, serializer);
+        <Option<String>>::sse_encode(self.quote_id, serializer);
+        <Option<u64>>::sse_encode(self.amount, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+        <u64>::sse_encode(self.timestamp, serializer);
+        <std::collections::HashMap<String, String>>::sse_encode(self.data, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::WalletHandle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.profile_id, serializer);
+    }
+}
+
 impl SseEncode for crate::api::cashu::WalletInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2768,6 +8552,27 @@ impl SseEncode for crate::api::cashu::WalletInfo {
         <String>::sse_encode(self.unit, serializer);
         <u64>::sse_encode(self.balance, serializer);
         <String>::sse_encode(self.active_keyset_id, serializer);
+        <Option<u64>>::sse_encode(self.fetched_at, serializer);
+        <bool>::sse_encode(self.is_stale, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::WalletProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.profile_id, serializer);
+        <String>::sse_encode(self.profile_dir, serializer);
+        <bool>::sse_encode(self.is_open, serializer);
+        <bool>::sse_encode(self.is_encrypted, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cashu::WebSocketSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.method, serializer);
+        <String>::sse_encode(self.unit, serializer);
+        <Vec<String>>::sse_encode(self.commands, serializer);
     }
 }
 
@@ -2786,6 +8591,7 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::api::qr::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -2795,6 +8601,24 @@ mod io {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_rust_plugin_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>::increment_strong_count(ptr as _);
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_rust_plugin_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>::decrement_strong_count(ptr as _);
+        }
+    }
 }
 #[cfg(not(target_family = "wasm"))]
 pub use io::*;
@@ -2808,6 +8632,7 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::api::qr::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -2819,6 +8644,24 @@ mod web {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>::increment_strong_count(ptr as _);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnimatedQrDecoder(
+        ptr: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnimatedQrDecoder>>::decrement_strong_count(ptr as _);
+        }
+    }
 }
 #[cfg(target_family = "wasm")]
 pub use web::*;
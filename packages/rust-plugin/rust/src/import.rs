@@ -0,0 +1,250 @@
+use cdk::nuts::{Proof, Token};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+/// Wallet data found in another wallet's backup
+#[derive(Debug, Default)]
+pub(crate) struct ParsedBackup {
+    // "tokens", "proofs", "nutshell" or "cashu_me"
+    pub format: &'static str,
+    pub mnemonic: Option<String>,
+    // Deterministic secret counters by keyset id, only useful with the backup's mnemonic
+    pub counters: BTreeMap<String, u32>,
+    // Proofs by mint URL
+    pub proofs: BTreeMap<String, Vec<Proof>>,
+    // Tokens by mint URL, V4 tokens need the mint's keysets before their proofs can be read
+    pub tokens: BTreeMap<String, Vec<Token>>,
+    // Proofs whose mint could not be worked out
+    pub unassigned: u64,
+    // Mints named by the backup's keyset and mint tables, including ones without proofs
+    pub mints: BTreeSet<String>,
+}
+
+impl ParsedBackup {
+    /// Every mint the backup mentions
+    pub fn all_mints(&self) -> BTreeSet<String> {
+        let mut mints = self.mints.clone();
+        mints.extend(self.proofs.keys().cloned());
+        mints.extend(self.tokens.keys().cloned());
+        mints
+    }
+}
+
+/// Read a backup from nutshell, cashu.me, a JSON proof dump or a list of tokens
+pub(crate) fn parse_backup(backup: &str) -> Result<ParsedBackup, String> {
+    let backup = backup.trim();
+    if backup.starts_with("cashu") {
+        return parse_tokens(backup);
+    }
+
+    let value: Value =
+        serde_json::from_str(backup).map_err(|e| format!("Unrecognized backup format: {}", e))?;
+    match value {
+        Value::Array(items) => {
+            let mut parsed = ParsedBackup {
+                format: "proofs",
+                ..Default::default()
+            };
+            // Either bare proofs or groups of {"mint": ..., "proofs": [...]}
+            let key = match items.first() {
+                Some(item) if item.get("proofs").is_some() => "token",
+                _ => "proofs",
+            };
+            let mut object = Map::new();
+            object.insert(key.to_string(), Value::Array(items));
+            read_object(&object, &mut parsed)?;
+            Ok(parsed)
+        }
+        Value::Object(object) => {
+            let object = unwrap_stringified(object);
+            let mut parsed = ParsedBackup {
+                format: detect_format(&object),
+                ..Default::default()
+            };
+            read_object(&object, &mut parsed)?;
+            Ok(parsed)
+        }
+        _ => Err("Unrecognized backup format: expected a JSON object or array".to_string()),
+    }
+}
+
+fn parse_tokens(backup: &str) -> Result<ParsedBackup, String> {
+    let mut parsed = ParsedBackup {
+        format: "tokens",
+        ..Default::default()
+    };
+    for encoded in backup.split_whitespace() {
+        let token =
+            Token::from_str(encoded).map_err(|e| format!("Failed to parse token: {}", e))?;
+        match token {
+            // V3 tokens may bundle several mints and carry their proofs in full
+            Token::TokenV3(token_v3) => {
+                for entry in token_v3.token {
+                    parsed
+                        .proofs
+                        .entry(entry.mint.to_string())
+                        .or_default()
+                        .extend(entry.proofs);
+                }
+            }
+            Token::TokenV4(token_v4) => {
+                let mint_url = token_v4.mint_url.to_string();
+                parsed
+                    .tokens
+                    .entry(mint_url)
+                    .or_default()
+                    .push(Token::TokenV4(token_v4));
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+/// cashu.me backups store localStorage entries, most of them JSON encoded as strings
+fn unwrap_stringified(object: Map<String, Value>) -> Map<String, Value> {
+    object
+        .into_iter()
+        .map(|(key, value)| {
+            let key = key.strip_prefix("cashu.").unwrap_or(&key).to_string();
+            let value = match value {
+                Value::String(s)
+                    if s.starts_with('[') || s.starts_with('{') || s.starts_with('"') =>
+                {
+                    serde_json::from_str(&s).unwrap_or(Value::String(s))
+                }
+                value => value,
+            };
+            (key, value)
+        })
+        .collect()
+}
+
+fn detect_format(object: &Map<String, Value>) -> &'static str {
+    let mints_with_keysets = object
+        .get("mints")
+        .and_then(Value::as_array)
+        .is_some_and(|mints| mints.iter().any(|mint| mint.get("keysets").is_some()));
+    let keysets_with_mints = object
+        .get("keysets")
+        .and_then(Value::as_array)
+        .is_some_and(|keysets| keysets.iter().any(|keyset| mint_url_of(keyset).is_some()));
+
+    if object.contains_key("keysetCounters") || mints_with_keysets {
+        "cashu_me"
+    } else if keysets_with_mints {
+        "nutshell"
+    } else {
+        "proofs"
+    }
+}
+
+fn mint_url_of(value: &Value) -> Option<String> {
+    ["mint_url", "mintUrl", "mint", "url"]
+        .iter()
+        .find_map(|key| value.get(*key)?.as_str())
+        .map(|url| url.trim_end_matches('/').to_string())
+}
+
+fn read_object(object: &Map<String, Value>, parsed: &mut ParsedBackup) -> Result<(), String> {
+    // Keysets tell which mint a proof without a mint URL belongs to
+    let mut keyset_mints = BTreeMap::new();
+    for keyset in object
+        .get("keysets")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let Some(id) = keyset.get("id").and_then(Value::as_str) else {
+            continue;
+        };
+        if let Some(mint_url) = mint_url_of(keyset) {
+            keyset_mints.insert(id.to_string(), mint_url);
+        }
+        if let Some(counter) = keyset.get("counter").and_then(Value::as_u64) {
+            parsed.counters.insert(id.to_string(), counter as u32);
+        }
+    }
+    for mint in object
+        .get("mints")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let Some(mint_url) = mint_url_of(mint) else {
+            continue;
+        };
+        parsed.mints.insert(mint_url.clone());
+        for keyset in mint
+            .get("keysets")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(id) = keyset.get("id").and_then(Value::as_str) {
+                keyset_mints.insert(id.to_string(), mint_url.clone());
+            }
+        }
+    }
+    for counter in object
+        .get("keysetCounters")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        if let (Some(id), Some(count)) = (
+            counter.get("id").and_then(Value::as_str),
+            counter.get("counter").and_then(Value::as_u64),
+        ) {
+            parsed.counters.insert(id.to_string(), count as u32);
+        }
+    }
+
+    parsed.mnemonic = ["mnemonic", "seed_phrase"]
+        .iter()
+        .find_map(|key| object.get(*key)?.as_str())
+        .map(|mnemonic| mnemonic.trim().to_string())
+        .filter(|mnemonic| !mnemonic.is_empty());
+
+    parsed.mints.extend(keyset_mints.values().cloned());
+
+    // A token in JSON form: {"token": [{"mint": ..., "proofs": [...]}]}
+    let default_mint = mint_url_of(&Value::Object(object.clone()));
+    let mut groups = vec![(default_mint, object.get("proofs"))];
+    for entry in object
+        .get("token")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        groups.push((mint_url_of(entry), entry.get("proofs")));
+    }
+
+    for (group_mint, proofs) in groups {
+        for item in proofs.and_then(Value::as_array).into_iter().flatten() {
+            if let Some(encoded) = item.as_str() {
+                parsed.format = "tokens";
+                let tokens = parse_tokens(encoded)?;
+                for (mint_url, proofs) in tokens.proofs {
+                    parsed.proofs.entry(mint_url).or_default().extend(proofs);
+                }
+                for (mint_url, tokens) in tokens.tokens {
+                    parsed.tokens.entry(mint_url).or_default().extend(tokens);
+                }
+                continue;
+            }
+
+            let proof: Proof = serde_json::from_value(item.clone())
+                .map_err(|e| format!("Invalid proof in backup: {}", e))?;
+            let mint_url = mint_url_of(item)
+                .or_else(|| keyset_mints.get(&proof.keyset_id.to_string()).cloned())
+                .or_else(|| group_mint.clone());
+            match mint_url {
+                Some(mint_url) => parsed.proofs.entry(mint_url).or_default().push(proof),
+                None => parsed.unassigned += 1,
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,9 +1,32 @@
 pub mod api;
+#[cfg(feature = "daemon")]
+mod daemon;
+#[cfg(feature = "sqlcipher")]
+mod db_encryption;
 mod frb_generated;
+mod import;
+mod lnurl;
+mod mint_cache;
+mod network;
+mod nip60;
+mod operations;
+mod relay;
+#[cfg(test)]
+mod test_mint;
+#[cfg(test)]
+mod test_relay;
+#[cfg(feature = "tor")]
+mod tor;
 
 // Re-export API functions
 pub use api::cashu::*;
+pub use api::daemon::*;
+pub use api::events::*;
+pub use api::limits::*;
+pub use api::logging::*;
+pub use api::nip60::*;
 pub use api::nostr::*;
+pub use api::qr::*;
 
 #[cfg(test)]
 mod tests {
@@ -54,29 +77,2123 @@ mod tests {
         println!("All Nostr tests passed!");
     }
 
-    #[test]
-    fn test_cashu_functions() {
+    #[tokio::test]
+    async fn test_cashu_functions() {
         println!("Testing Cashu Rust functions...");
 
-        // Test creating wallet with empty mint (no default)
-        let mint_url = "".to_string();
-        let unit = "sat".to_string();
-        // Note: This test will fail without a valid mint URL, which is expected
-        // Users need to add their own mints
+        // Seeds round-trip through their mnemonic
+        let mnemonic = generate_mnemonic_phrase(12).await.unwrap();
+        assert!(validate_mnemonic_phrase(mnemonic.clone()).await.unwrap());
+        let seed_hex = mnemonic_to_seed_hex(mnemonic).await.unwrap();
+        assert_eq!(seed_hex.len(), 128);
+        println!("✅ Mnemonic to seed successful");
+
+        assert!(!validate_mnemonic_phrase("not a mnemonic".to_string())
+            .await
+            .unwrap());
+        println!("✅ Rejected invalid mnemonic");
+
+        println!("All Cashu tests passed!");
+    }
+
+    /// Drives the wallet API against the in-process test mint, no network involved
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_wallet_against_test_mint() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_mint_wallet");
+        let seed_hex = random_seed_hex().await;
+
+        init_multi_mint_wallet(database_dir.clone(), seed_hex.clone())
+            .await
+            .unwrap();
+        let handle = WalletHandle {
+            profile_id: "default".to_string(),
+        };
+        add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+        println!("✅ Added test mint");
+
+        // The fake Lightning backend pays the quote on its own
+        mint.fund(&handle, 1000).await;
+        println!("✅ Minted 1000 sats");
+
+        // Sending to ourselves swaps the proofs back in
+        let token = send_tokens(handle.clone(), mint.url.clone(), 200, None)
+            .await
+            .unwrap();
+        let received = receive_tokens(handle.clone(), token).await.unwrap();
+        assert_eq!(received, 200);
+        println!("✅ Sent and received 200 sats");
 
-        // Test creating proof
-        let proof = create_cashu_proof(
-            "test_proof_id".to_string(),
-            1000,
-            "test_secret".to_string(),
-            "test_c".to_string(),
+        // The token comes back in the requested encoding, built from what was sent
+        let encoded =
+            send_tokens_with_encoding(handle.clone(), mint.url.clone(), 50, None, "v3".to_string())
+                .await
+                .unwrap();
+        assert!(encoded.token.starts_with("cashuA"));
+        assert_eq!(encoded.amount, 50);
+        assert!(encoded.proofs_count > 0);
+        assert_eq!(encoded.mint_urls.len(), 1);
+        assert_eq!(
+            receive_tokens(handle.clone(), encoded.token).await.unwrap(),
+            50
         );
-        println!("✅ Created Cashu proof successfully");
+        // An unknown encoding fails before anything is spent
+        assert!(send_tokens_with_encoding(
+            handle.clone(),
+            mint.url.clone(),
+            50,
+            None,
+            "v5".to_string(),
+        )
+        .await
+        .is_err());
+        let balance: u64 = get_all_balances(handle.clone())
+            .await
+            .unwrap()
+            .values()
+            .sum();
+        assert_eq!(balance, 1000);
+        println!("✅ Sent a V3 encoded token");
 
-        // Test validating proof
-        let is_valid = validate_cashu_proof(proof).unwrap();
-        println!("✅ Validated proof: {}", is_valid);
+        // Pay a Lightning invoice issued by the mint's fake backend
+        let invoice = create_mint_quote(handle.clone(), mint.url.clone(), 100, None)
+            .await
+            .unwrap()["request"]
+            .clone();
+        pay_invoice_for_wallet(handle.clone(), mint.url.clone(), invoice, None)
+            .await
+            .unwrap();
+        let balance: u64 = get_all_balances(handle.clone())
+            .await
+            .unwrap()
+            .values()
+            .sum();
+        // Unused fee reserve comes back as change
+        assert!((890..=900).contains(&balance));
+        println!("✅ Paid a 100 sat invoice");
 
-        println!("All Cashu tests passed!");
+        // A fresh profile with the same seed recovers the balance
+        close_wallet(handle).await.unwrap();
+        let restored_dir = format!("{}_restored", database_dir);
+        let restored = open_wallet("restored".to_string(), restored_dir.clone(), seed_hex)
+            .await
+            .unwrap();
+        add_mint(restored.clone(), mint.url.clone()).await.unwrap();
+        let restored_amount = restore_mint(restored.clone(), mint.url.clone())
+            .await
+            .unwrap();
+        assert_eq!(restored_amount, balance);
+        println!("✅ Restored {} sats from the seed", restored_amount);
+
+        close_wallet(restored).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+        let _ = std::fs::remove_dir_all(&restored_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_combine_multi_mint_tokens() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+
+        let mint_a = TestMint::start().await;
+        let mint_b = TestMint::start().await;
+        let database_dir = temp_database_dir("test_combine_tokens");
+        let handle = open_wallet(
+            "combine".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        for mint in [&mint_a, &mint_b] {
+            add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+            mint.fund(&handle, 100).await;
+        }
+
+        let token_a = send_tokens(handle.clone(), mint_a.url.clone(), 20, None)
+            .await
+            .unwrap();
+        let token_b = send_tokens(handle.clone(), mint_b.url.clone(), 30, None)
+            .await
+            .unwrap();
+
+        // Tokens from two mints only fit in a V3 token
+        assert!(combine_tokens(
+            handle.clone(),
+            vec![token_a.clone(), token_b.clone()],
+            None,
+            "v4".to_string(),
+        )
+        .await
+        .is_err());
+        let combined = combine_tokens(
+            handle.clone(),
+            vec![token_a, token_b],
+            None,
+            "v3".to_string(),
+        )
+        .await
+        .unwrap();
+        assert!(combined.token.starts_with("cashuA"));
+        assert_eq!(combined.amount, 50);
+        assert_eq!(combined.mint_urls.len(), 2);
+        println!("✅ Combined tokens from two mints");
+
+        // Re-encoding the multi-mint token keeps every mint's proofs
+        let reencoded = encode_token(handle.clone(), combined.token.clone(), "v3".to_string())
+            .await
+            .unwrap();
+        assert_eq!(reencoded.amount, combined.amount);
+        assert_eq!(reencoded.proofs_count, combined.proofs_count);
+        assert_eq!(reencoded.mint_urls.len(), 2);
+        assert!(
+            encode_token(handle.clone(), combined.token.clone(), "v4".to_string())
+                .await
+                .is_err()
+        );
+        let recombined = combine_tokens(
+            handle.clone(),
+            vec![reencoded.token],
+            None,
+            "v3".to_string(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(recombined.amount, 50);
+        assert_eq!(recombined.proofs_count, combined.proofs_count);
+        println!("✅ Re-encoded the multi-mint token");
+
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_bolt12_offers() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+        use std::time::Duration;
+
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_bolt12");
+        let handle = open_wallet(
+            "bolt12".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+        assert!(
+            check_mint_capability(handle.clone(), mint.url.clone(), "bolt12_mint".to_string())
+                .await
+                .is_ok()
+        );
+
+        // Receiving through an offer the mint issued
+        let quote = create_bolt12_mint_quote(handle.clone(), mint.url.clone(), Some(100), None)
+            .await
+            .unwrap();
+        assert_eq!(quote["payment_method"], "bolt12");
+        let offer = decode_bolt12_offer(quote["request"].clone()).await.unwrap();
+        assert_eq!(offer.amount_msat, Some(100_000));
+        assert!(!offer.is_expired);
+        println!("✅ Decoded the mint's BOLT12 offer");
+
+        let mut minted = 0;
+        for _ in 0..50 {
+            let result =
+                mint_bolt12_quote(handle.clone(), mint.url.clone(), quote["quote_id"].clone())
+                    .await
+                    .unwrap();
+            minted += result["minted"].parse::<u64>().unwrap();
+            if minted > 0 {
+                assert_eq!(result["amount_issued"], "100");
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+        assert_eq!(minted, 100);
+        // Nothing more was paid, so there is nothing more to mint
+        let result = mint_bolt12_quote(handle.clone(), mint.url.clone(), quote["quote_id"].clone())
+            .await
+            .unwrap();
+        assert_eq!(result["minted"], "0");
+        println!("✅ Minted 100 sats from a BOLT12 offer");
+
+        // Amountless offers need the amount from the caller
+        let amountless = create_bolt12_mint_quote(handle.clone(), mint.url.clone(), None, None)
+            .await
+            .unwrap();
+        let offer = decode_bolt12_offer(amountless["request"].clone())
+            .await
+            .unwrap();
+        assert!(offer.amount_msat.is_none());
+        let error = pay_bolt12_offer(
+            handle.clone(),
+            mint.url.clone(),
+            amountless["request"].clone(),
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.contains("amount is required"), "{}", error);
+        let error = pay_bolt12_offer(
+            handle.clone(),
+            mint.url.clone(),
+            amountless["request"].clone(),
+            Some(u64::MAX),
+        )
+        .await
+        .unwrap_err();
+        assert!(error.contains("too large"), "{}", error);
+        // Above the mint's NUT-05 maximum nothing is melted
+        assert!(pay_bolt12_offer(
+            handle.clone(),
+            mint.url.clone(),
+            amountless["request"].clone(),
+            Some(200_000),
+        )
+        .await
+        .is_err());
+
+        // Paying an offer through a melt
+        let payee = create_bolt12_mint_quote(handle.clone(), mint.url.clone(), Some(20), None)
+            .await
+            .unwrap();
+        let state = pay_bolt12_offer(
+            handle.clone(),
+            mint.url.clone(),
+            payee["request"].clone(),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(state.to_lowercase(), "paid");
+        let balance: u64 = get_all_balances(handle.clone())
+            .await
+            .unwrap()
+            .values()
+            .sum();
+        // Unused fee reserve comes back as change
+        assert!((70..=80).contains(&balance), "{}", balance);
+        println!("✅ Paid a BOLT12 offer");
+
+        assert!(decode_bolt12_offer("lno1notanoffer".to_string())
+            .await
+            .is_err());
+
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    /// Stand-in LNURL server answering from a fixed url -> body map
+    /// URLs with a query (callbacks) fall back to their entry without the query.
+    struct StubLnurlClient {
+        responses: std::collections::HashMap<String, String>,
+        requests: std::sync::Mutex<Vec<url::Url>>,
+    }
+
+    impl StubLnurlClient {
+        fn new(responses: std::collections::HashMap<String, String>) -> Self {
+            Self {
+                responses,
+                requests: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::lnurl::LnurlHttpClient for StubLnurlClient {
+        async fn get(&self, url: &url::Url) -> Result<String, String> {
+            self.requests.lock().unwrap().push(url.clone());
+            let mut without_query = url.clone();
+            without_query.set_query(None);
+            self.responses
+                .get(url.as_str())
+                .or_else(|| self.responses.get(without_query.as_str()))
+                .cloned()
+                .ok_or_else(|| format!("Unexpected request: {}", url))
+        }
+    }
+
+    /// The LNURL client override is global, tests that set it run one at a time
+    static LNURL_CLIENT_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_lnurl_pay_resolution() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir};
+
+        let _lock = LNURL_CLIENT_LOCK.lock().await;
+        let database_dir = temp_database_dir("test_lnurl_pay_resolution");
+        let handle = open_wallet(
+            "lnurl_pay_resolution".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        let mut responses = std::collections::HashMap::new();
+        responses.insert(
+            "https://example.com/.well-known/lnurlp/alice".to_string(),
+            r#"{"tag":"payRequest","callback":"https://example.com/lnurlp/alice/callback","minSendable":1000,"maxSendable":100000000,"metadata":"[[\"text/plain\",\"Pay alice\"]]","commentAllowed":140}"#.to_string(),
+        );
+        responses.insert(
+            "https://example.com/.well-known/lnurlp/bob".to_string(),
+            r#"{"tag":"withdrawRequest","callback":"https://example.com/withdraw","k1":"00","minWithdrawable":1000,"maxWithdrawable":1000,"defaultDescription":""}"#.to_string(),
+        );
+        crate::lnurl::set_http_client(Some(std::sync::Arc::new(StubLnurlClient::new(responses))));
+
+        let info = resolve_lnurl_pay(handle.clone(), "alice@example.com".to_string())
+            .await
+            .unwrap();
+        assert_eq!(info.min_sendable_sats, 1);
+        assert_eq!(info.max_sendable_sats, 100_000);
+        assert_eq!(info.comment_allowed, 140);
+        assert_eq!(info.description.as_deref(), Some("Pay alice"));
+        assert_eq!(info.domain, "example.com");
+        println!("✅ Lightning address resolved");
+
+        // Withdraw requests are not pay requests
+        assert!(
+            resolve_lnurl_pay(handle.clone(), "bob@example.com".to_string())
+                .await
+                .is_err()
+        );
+
+        crate::lnurl::set_http_client(None);
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+        println!("All LNURL tests passed!");
+    }
+
+    /// Invoice as an LNURL-pay service returns it, committing to the metadata
+    fn lnurl_invoice(metadata: &str, amount_msat: u64) -> String {
+        use cdk::bitcoin::hashes::{sha256, Hash};
+        use cdk::bitcoin::secp256k1::{Secp256k1, SecretKey};
+        use cdk::lightning_invoice::{Currency, InvoiceBuilder, PaymentSecret};
+
+        let node_key = SecretKey::from_slice(&[42; 32]).unwrap();
+        InvoiceBuilder::new(Currency::Bitcoin)
+            .description_hash(sha256::Hash::hash(metadata.as_bytes()))
+            .payment_hash(sha256::Hash::hash(&rand::random::<[u8; 32]>()))
+            .payment_secret(PaymentSecret(rand::random()))
+            .amount_milli_satoshis(amount_msat)
+            .current_timestamp()
+            .min_final_cltv_expiry_delta(144)
+            .build_signed(|hash| Secp256k1::new().sign_ecdsa_recoverable(hash, &node_key))
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_lnurl_request_invoice() {
+        use crate::lnurl::{request_invoice, PayRequest};
+
+        let metadata = r#"[["text/plain","Pay alice"]]"#;
+        let pay_request = PayRequest {
+            callback: "https://example.com/lnurlp/alice/callback".to_string(),
+            min_sendable: 1_000,
+            max_sendable: 100_000,
+            metadata: metadata.to_string(),
+            tag: "payRequest".to_string(),
+            comment_allowed: 10,
+        };
+        let invoice = lnurl_invoice(metadata, 21_000);
+        let mut responses = std::collections::HashMap::new();
+        responses.insert(
+            pay_request.callback.clone(),
+            serde_json::json!({ "pr": invoice, "routes": [] }).to_string(),
+        );
+        let client = StubLnurlClient::new(responses);
+
+        let received = request_invoice(&client, &pay_request, 21_000, Some("thanks".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(received.to_string(), invoice);
+        let callback = client.requests.lock().unwrap().last().cloned().unwrap();
+        let query: std::collections::HashMap<_, _> = callback.query_pairs().into_owned().collect();
+        assert_eq!(query["amount"], "21000");
+        assert_eq!(query["comment"], "thanks");
+        println!("✅ Requested an invoice from the callback");
+
+        // Amounts outside min/max sendable and long comments fail without a request
+        let requests_before = client.requests.lock().unwrap().len();
+        let error = request_invoice(&client, &pay_request, 500, None)
+            .await
+            .unwrap_err();
+        assert!(error.contains("between 1 and 100"), "{}", error);
+        assert!(request_invoice(&client, &pay_request, 101_000, None)
+            .await
+            .is_err());
+        let error = request_invoice(&client, &pay_request, 21_000, Some("a".repeat(11)))
+            .await
+            .unwrap_err();
+        assert!(error.contains("at most 10"), "{}", error);
+        assert_eq!(client.requests.lock().unwrap().len(), requests_before);
+        println!("✅ Checked min/max sendable and the comment length");
+
+        // The invoice has to commit to the metadata and the amount that were asked for
+        let other_metadata = PayRequest {
+            metadata: r#"[["text/plain","Pay mallory"]]"#.to_string(),
+            ..pay_request.clone()
+        };
+        let error = request_invoice(&client, &other_metadata, 21_000, None)
+            .await
+            .unwrap_err();
+        assert!(error.contains("description hash"), "{}", error);
+        let error = request_invoice(&client, &pay_request, 22_000, None)
+            .await
+            .unwrap_err();
+        assert!(error.contains("amount does not match"), "{}", error);
+        println!("✅ Rejected invoices not matching the pay request");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_pay_lnurl() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+        use std::sync::Arc;
+
+        let _lock = LNURL_CLIENT_LOCK.lock().await;
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_pay_lnurl");
+        let handle = open_wallet(
+            "pay_lnurl".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&handle, 1000).await;
+
+        // The test mint's fake backend pays whatever invoice the service hands out
+        let metadata = r#"[["text/plain","Pay alice"]]"#;
+        let mut responses = std::collections::HashMap::new();
+        responses.insert(
+            "https://example.com/.well-known/lnurlp/alice".to_string(),
+            serde_json::json!({
+                "tag": "payRequest",
+                "callback": "https://example.com/lnurlp/alice/callback",
+                "minSendable": 1_000,
+                "maxSendable": 1_000_000,
+                "metadata": metadata,
+            })
+            .to_string(),
+        );
+        responses.insert(
+            "https://example.com/lnurlp/alice/callback".to_string(),
+            serde_json::json!({ "pr": lnurl_invoice(metadata, 100_000) }).to_string(),
+        );
+        let client = Arc::new(StubLnurlClient::new(responses));
+        crate::lnurl::set_http_client(Some(client.clone()));
+
+        pay_lnurl(
+            handle.clone(),
+            mint.url.clone(),
+            "alice@example.com".to_string(),
+            100,
+            None,
+        )
+        .await
+        .unwrap();
+        let balance: u64 = get_all_balances(handle.clone())
+            .await
+            .unwrap()
+            .values()
+            .sum();
+        // Unused fee reserve comes back as change
+        assert!((890..=900).contains(&balance), "{}", balance);
+        println!("✅ Paid 100 sats to a lightning address");
+
+        // Amounts that overflow millisats are refused before anything is requested
+        let requests_before = client.requests.lock().unwrap().len();
+        let error = pay_lnurl(
+            handle.clone(),
+            mint.url.clone(),
+            "alice@example.com".to_string(),
+            u64::MAX,
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.contains("too large"), "{}", error);
+        assert_eq!(client.requests.lock().unwrap().len(), requests_before);
+
+        crate::lnurl::set_http_client(None);
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_lnurl_withdraw() {
+        use crate::api::events::subscribe;
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+        use std::sync::Arc;
+
+        let _lock = LNURL_CLIENT_LOCK.lock().await;
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_lnurl_withdraw");
+        let handle = open_wallet(
+            "lnurl_withdraw".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+
+        // The faucet accepts whatever invoice it is handed, the test mint pays it on its own
+        let mut responses = std::collections::HashMap::new();
+        responses.insert(
+            "https://faucet.example.com/withdraw".to_string(),
+            r#"{"tag":"withdrawRequest","callback":"https://faucet.example.com/withdraw/callback","k1":"k1-secret","minWithdrawable":10000,"maxWithdrawable":50000,"defaultDescription":"Faucet"}"#.to_string(),
+        );
+        responses.insert(
+            "https://faucet.example.com/withdraw/callback".to_string(),
+            r#"{"status":"OK"}"#.to_string(),
+        );
+        let client = Arc::new(StubLnurlClient::new(responses));
+        crate::lnurl::set_http_client(Some(client.clone()));
+
+        let mut events = subscribe();
+        let minted = redeem_lnurl_withdraw(
+            handle.clone(),
+            "lnurlw://faucet.example.com/withdraw".to_string(),
+            mint.url.clone(),
+            None,
+        )
+        .await
+        .unwrap();
+        // None asks for the maximum
+        assert_eq!(minted, 50);
+        let balance: u64 = get_all_balances(handle.clone())
+            .await
+            .unwrap()
+            .values()
+            .sum();
+        assert_eq!(balance, 50);
+
+        // The mint's invoice went to the callback together with k1
+        let callback = client.requests.lock().unwrap().last().cloned().unwrap();
+        let query: std::collections::HashMap<_, _> = callback.query_pairs().into_owned().collect();
+        assert_eq!(query["k1"], "k1-secret");
+        assert!(query["pr"].starts_with("lnbc"));
+        println!("✅ Redeemed an LNURL-withdraw code");
+
+        // Every stage shows up on the wallet event stream, in order
+        let mut kinds = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if event.profile_id.as_deref() == Some("lnurl_withdraw") {
+                kinds.push(event.kind);
+            }
+        }
+        assert_eq!(
+            kinds,
+            vec![
+                "lnurl_withdraw_resolved",
+                "lnurl_withdraw_quote_created",
+                "lnurl_withdraw_invoice_submitted",
+                "lnurl_withdraw_paid",
+                "lnurl_withdraw_minted",
+            ]
+        );
+        println!("✅ Reported each withdraw stage as an event");
+
+        // Amounts outside the service's range fail before a quote is created
+        let requests_before = client.requests.lock().unwrap().len();
+        let error = redeem_lnurl_withdraw(
+            handle.clone(),
+            "lnurlw://faucet.example.com/withdraw".to_string(),
+            mint.url.clone(),
+            Some(5),
+        )
+        .await
+        .unwrap_err();
+        assert!(error.contains("between 10 and 50"), "{}", error);
+        assert_eq!(client.requests.lock().unwrap().len(), requests_before + 1);
+        let failed = std::iter::from_fn(|| events.try_recv().ok())
+            .find(|event| event.profile_id.as_deref() == Some("lnurl_withdraw"))
+            .unwrap();
+        assert_eq!(failed.kind, "lnurl_withdraw_failed");
+        assert_eq!(failed.message, Some(error));
+        println!("✅ Reported a failed withdraw");
+
+        crate::lnurl::set_http_client(None);
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    #[tokio::test]
+    async fn test_payment_request_round_trip() {
+        let keys = generate_keys().unwrap();
+        let request = create_payment_request(
+            Some(21),
+            Some(vec!["https://mint.example.com".to_string()]),
+            Some("Coffee".to_string()),
+            Some(true),
+            Some(format!("02{}", keys.public_key)),
+            vec![PaymentRequestTransport {
+                transport_type: "post".to_string(),
+                target: "https://example.com/pay".to_string(),
+                tags: vec![],
+            }],
+        )
+        .await
+        .unwrap();
+        assert!(request.starts_with("creqA"));
+        println!("✅ Created payment request");
+
+        let info = decode_payment_request(request).await.unwrap();
+        assert_eq!(info.amount, Some(21));
+        assert_eq!(info.unit.as_deref(), Some("sat"));
+        assert_eq!(info.description.as_deref(), Some("Coffee"));
+        assert_eq!(info.mints.len(), 1);
+        assert_eq!(info.transports[0].transport_type, "post");
+        assert_eq!(info.lock_kind.as_deref(), Some("P2PK"));
+        println!("✅ Payment request round-trip test passed!");
+
+        // The lock's NUT-10 tags carry over into the token's spending conditions
+        use cdk::nuts::nut10::Kind;
+        use cdk::nuts::nut18::Nut10SecretRequest;
+        use cdk::nuts::{SigFlag, SpendingConditions};
+
+        let refund_key = generate_keys().unwrap().public_key;
+        let tags = vec![
+            vec!["locktime".to_string(), "1700000000".to_string()],
+            vec!["refund".to_string(), format!("03{}", refund_key)],
+            vec!["n_sigs".to_string(), "1".to_string()],
+            vec!["sigflag".to_string(), "SIG_ALL".to_string()],
+        ];
+        let nut10 =
+            Nut10SecretRequest::new(Kind::P2PK, format!("02{}", keys.public_key), Some(tags));
+        let SpendingConditions::P2PKConditions { data, conditions } =
+            payment_request_conditions(&nut10).unwrap()
+        else {
+            panic!("Expected a P2PK lock");
+        };
+        assert_eq!(data.to_hex(), format!("02{}", keys.public_key));
+        let conditions = conditions.unwrap();
+        assert_eq!(conditions.locktime, Some(1_700_000_000));
+        assert_eq!(conditions.refund_keys.unwrap().len(), 1);
+        assert_eq!(conditions.num_sigs, Some(1));
+        assert_eq!(conditions.sig_flag, SigFlag::SigAll);
+
+        // Without tags the lock is the bare pubkey
+        let bare = Nut10SecretRequest::new(Kind::P2PK, format!("02{}", keys.public_key), None);
+        assert!(matches!(
+            payment_request_conditions(&bare).unwrap(),
+            SpendingConditions::P2PKConditions {
+                conditions: None,
+                ..
+            }
+        ));
+        let htlc = Nut10SecretRequest::new(Kind::HTLC, "00".repeat(32), None);
+        assert!(payment_request_conditions(&htlc).is_err());
+        println!("✅ Payment request lock tags test passed!");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_pay_payment_request_transports() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_pay_payment_request");
+        let handle = open_wallet(
+            "pay_payment_request".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&handle, 100).await;
+
+        // The first transport points at a closed port, the second one takes the payment
+        let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let unreachable = format!("http://{}/pay", closed.local_addr().unwrap());
+        drop(closed);
+        let (sender, mut payments) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let receiver = axum::Router::new().route(
+            "/pay",
+            axum::routing::post(move |body: String| {
+                let sender = sender.clone();
+                async move {
+                    let _ = sender.send(body);
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let reachable = format!("http://{}/pay", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, receiver).await;
+        });
+
+        let request = create_payment_request(
+            Some(10),
+            Some(vec![mint.url.clone()]),
+            None,
+            None,
+            None,
+            [unreachable, reachable]
+                .into_iter()
+                .map(|target| PaymentRequestTransport {
+                    transport_type: "post".to_string(),
+                    target,
+                    tags: vec![],
+                })
+                .collect(),
+        )
+        .await
+        .unwrap();
+        let result = pay_payment_request(handle.clone(), request, None, None)
+            .await
+            .unwrap();
+        assert_eq!(result["delivered_via"], "post");
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&payments.recv().await.unwrap()).unwrap();
+        let paid: u64 = payload["proofs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|proof| proof["amount"].as_u64().unwrap())
+            .sum();
+        assert_eq!(paid, 10);
+        println!("✅ Fell through to the second payment request transport");
+
+        server.abort();
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    #[test]
+    fn test_animated_qr_round_trip() {
+        let payload = format!("cashuB{}", "o".repeat(2000));
+
+        let encoded = encode_animated_qr(payload.clone(), Some(100), None).unwrap();
+        assert!(encoded.is_animated);
+        assert!(encoded.frames.len() as u32 >= encoded.fragment_count);
+        println!("✅ Encoded {} frames", encoded.frames.len());
+
+        // Scan in reverse order, missing the first fragment entirely
+        let mut decoder = AnimatedQrDecoder::new();
+        let mut progress = decoder.progress();
+        for frame in encoded.frames.iter().skip(1).rev() {
+            progress = decoder.receive(frame.to_uppercase()).unwrap();
+            if progress.complete {
+                break;
+            }
+        }
+        assert!(progress.complete);
+        assert_eq!(progress.payload, Some(payload));
+        println!("✅ Animated QR round-trip test passed!");
+
+        // Small payloads stay a single static frame
+        let single = encode_animated_qr("cashuAshort".to_string(), None, None).unwrap();
+        assert!(!single.is_animated);
+        let progress = AnimatedQrDecoder::new()
+            .receive(single.frames[0].clone())
+            .unwrap();
+        assert_eq!(progress.payload.as_deref(), Some("cashuAshort"));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_profiles() {
+        let database_dir = std::env::temp_dir()
+            .join(format!("purrwallet-profiles-{}", std::process::id()))
+            .to_string_lossy()
+            .to_string();
+
+        // Profile ids become directory names
+        let result = open_wallet(
+            "../escape".to_string(),
+            database_dir.clone(),
+            "00".repeat(32),
+        )
+        .await;
+        assert!(result.is_err());
+
+        let profiles = list_profiles(database_dir.clone()).await.unwrap();
+        assert!(profiles.is_empty());
+
+        let handle = WalletHandle {
+            profile_id: "business".to_string(),
+        };
+        let result = get_all_balances(handle.clone()).await;
+        assert!(result.unwrap_err().contains("not open"));
+        assert!(close_wallet(handle).await.is_err());
+        println!("✅ Wallet profile test passed!");
+    }
+
+    #[tokio::test]
+    async fn test_operation_journal() {
+        use crate::operations::WalletOperations;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let profile_dir =
+            std::env::temp_dir().join(format!("purrwallet-journal-{}", std::process::id()));
+        std::fs::create_dir_all(&profile_dir).unwrap();
+
+        // A send still running at shutdown is reported and stays journaled
+        let operations = Arc::new(WalletOperations::open(&profile_dir).unwrap());
+        let mut send = operations.begin().await.unwrap();
+        send.journal("send", "https://mint.example.com", 21)
+            .unwrap();
+        let still_running = operations.shutdown(Duration::from_millis(50)).await;
+        assert_eq!(still_running.len(), 1);
+        assert!(operations.begin().await.is_err());
+        std::mem::forget(send);
+
+        // Deleting or rekeying the profile now fails unless forced
+        let error =
+            crate::api::cashu::ensure_no_running_operations(Some(still_running.clone()), false)
+                .unwrap_err();
+        assert!(error.contains("send of 21 sat at https://mint.example.com"));
+        assert!(crate::api::cashu::ensure_no_running_operations(Some(still_running), true).is_ok());
+        assert!(crate::api::cashu::ensure_no_running_operations(None, false).is_ok());
+
+        // The next session sees it as interrupted until cleared
+        let operations = WalletOperations::open(&profile_dir).unwrap();
+        let interrupted = operations.interrupted();
+        assert_eq!(interrupted.len(), 1);
+        assert_eq!(interrupted[0].amount, 21);
+        operations.clear_interrupted().unwrap();
+        assert!(WalletOperations::open(&profile_dir)
+            .unwrap()
+            .interrupted()
+            .is_empty());
+
+        // Finished operations leave nothing behind
+        let operations = Arc::new(WalletOperations::open(&profile_dir).unwrap());
+        let mut melt = operations.begin().await.unwrap();
+        melt.journal("melt", "https://mint.example.com", 100)
+            .unwrap();
+        drop(melt);
+        assert!(operations.shutdown(Duration::from_secs(1)).await.is_empty());
+
+        let _ = std::fs::remove_dir_all(&profile_dir);
+        println!("✅ Operation journal test passed!");
+    }
+
+    #[test]
+    fn test_mint_cache_freshness() {
+        use crate::mint_cache::MintCache;
+        use cdk::mint_url::MintUrl;
+        use std::str::FromStr;
+
+        let profile_dir =
+            std::env::temp_dir().join(format!("purrwallet-mint-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&profile_dir).unwrap();
+        let fast = MintUrl::from_str("https://fast.example.com").unwrap();
+        let slow = MintUrl::from_str("https://slow.example.com").unwrap();
+
+        let cache = MintCache::open(&profile_dir).unwrap();
+        assert!(cache.freshness(&fast).is_none());
+        // A zero NUT-19 TTL is stale straight away, no TTL falls back to the default
+        cache.record(&fast, Some(0)).unwrap();
+        cache.record(&slow, None).unwrap();
+        assert!(cache.freshness(&fast).unwrap().is_stale);
+        assert!(!cache.freshness(&slow).unwrap().is_stale);
+
+        // Only one background refresh per mint
+        assert!(cache.start_refresh(&fast));
+        assert!(!cache.start_refresh(&fast));
+        cache.finish_refresh(&fast);
+        assert!(cache.start_refresh(&fast));
+
+        // Fetch times survive a restart
+        cache.forget(&fast).unwrap();
+        let reopened = MintCache::open(&profile_dir).unwrap();
+        assert!(reopened.freshness(&fast).is_none());
+        assert!(reopened.freshness(&slow).is_some());
+
+        let _ = std::fs::remove_dir_all(&profile_dir);
+        println!("✅ Mint cache test passed!");
+    }
+
+    #[test]
+    fn test_mint_settings_from_info() {
+        let info: cdk::nuts::MintInfo = serde_json::from_value(serde_json::json!({
+            "name": "Test mint",
+            "pubkey": "0296d0aa13b6a31cf0cd974249f28c7b7176d7274712c95a41c7d8066d3f29d679",
+            "nuts": {
+                "4": {
+                    "methods": [{
+                        "method": "bolt11",
+                        "unit": "sat",
+                        "min_amount": 1,
+                        "max_amount": 500000,
+                        "options": { "description": true }
+                    }],
+                    "disabled": false
+                },
+                "5": {
+                    "methods": [{ "method": "bolt11", "unit": "sat", "min_amount": 10, "max_amount": 100000 }],
+                    "disabled": false
+                },
+                "7": { "supported": true },
+                "17": {
+                    "supported": [{
+                        "method": "bolt11",
+                        "unit": "sat",
+                        "commands": ["bolt11_mint_quote", "proof_state"]
+                    }]
+                },
+                "19": {
+                    "ttl": 300,
+                    "cached_endpoints": [{ "method": "POST", "path": "/v1/swap" }]
+                }
+            }
+        }))
+        .unwrap();
+
+        let settings = mint_settings(&info.nuts);
+        let mint_method = &settings.mint_methods[0];
+        assert_eq!(mint_method.method, "bolt11");
+        assert_eq!(mint_method.max_amount, Some(500000));
+        assert!(mint_method.description);
+        assert_eq!(settings.melt_methods[0].min_amount, Some(10));
+        assert!(settings.supported_nuts.contains(&7));
+        assert_eq!(
+            settings.websocket[0].commands,
+            vec!["bolt11_mint_quote", "proof_state"]
+        );
+        assert_eq!(settings.cache_ttl, Some(300));
+        assert_eq!(settings.cached_endpoints[0].path, "/v1/swap");
+        assert!(settings.auth.is_none());
+        println!("✅ Mint settings test passed!");
+    }
+
+    #[test]
+    fn test_mint_capabilities() {
+        use cdk::nuts::CurrencyUnit;
+
+        let info: cdk::nuts::MintInfo = serde_json::from_value(serde_json::json!({
+            "nuts": {
+                "4": { "methods": [{ "method": "bolt11", "unit": "sat" }], "disabled": false },
+                "5": { "methods": [{ "method": "bolt11", "unit": "sat" }], "disabled": false },
+                "7": { "supported": true },
+                "9": { "supported": true },
+                "10": { "supported": true },
+                "12": { "supported": true },
+                "15": { "methods": [{ "method": "bolt11", "unit": "sat" }] },
+                "17": {
+                    "supported": [{
+                        "method": "bolt11",
+                        "unit": "usd",
+                        "commands": ["bolt11_melt_quote"]
+                    }]
+                }
+            }
+        }))
+        .unwrap();
+
+        let unit = CurrencyUnit::Sat;
+        assert!(MintCapability::Bolt11Mint.is_supported(&info, &unit));
+        assert!(MintCapability::Restore.is_supported(&info, &unit));
+        assert!(MintCapability::Dleq.is_supported(&info, &unit));
+        // NUT-10 alone is not enough to lock to a pubkey
+        assert!(!MintCapability::P2pk.is_supported(&info, &unit));
+        assert!(!MintCapability::Bolt12Melt.is_supported(&info, &unit));
+        assert!(!MintCapability::Htlc.is_supported(&info, &unit));
+        assert!(MintCapability::Mpp.is_supported(&info, &unit));
+        // WebSocket subscriptions are only offered for the usd unit
+        assert!(!MintCapability::WebSocket.is_supported(&info, &unit));
+        assert!(MintCapability::WebSocket.is_supported(&info, &CurrencyUnit::Usd));
+        assert!(!MintCapability::Mpp.is_supported(&info, &CurrencyUnit::Usd));
+        assert_eq!(
+            MintCapability::from_name("websocket"),
+            Ok(MintCapability::WebSocket)
+        );
+
+        // The error survives being wrapped by another message
+        let error = UnsupportedByMint {
+            mint_url: "https://mint.example.com".to_string(),
+            nut: 11,
+            capability: "p2pk".to_string(),
+        };
+        let message = format!("Failed to send: {}", error);
+        let parsed = parse_unsupported_by_mint(message).unwrap();
+        assert_eq!(parsed.nut, 11);
+        assert_eq!(parsed.capability, "p2pk");
+        assert_eq!(parsed.mint_url, "https://mint.example.com");
+        assert!(parse_unsupported_by_mint("Mint not found in wallet".to_string()).is_none());
+        println!("✅ Mint capability test passed!");
+    }
+
+    #[test]
+    fn test_tor_policy_settings() {
+        use crate::network::{NetworkSettings, TorPolicy};
+        use cdk::mint_url::MintUrl;
+        use std::str::FromStr;
+
+        let profile_dir =
+            std::env::temp_dir().join(format!("tor_policy_{}", rand::random::<u64>()));
+
+        // Profiles without saved settings keep the old onion-only behaviour
+        let settings = NetworkSettings::load(&profile_dir).unwrap();
+        assert_eq!(settings.tor_policy, TorPolicy::OnionOnly);
+
+        let settings = NetworkSettings {
+            tor_policy: TorPolicy::parse("never").unwrap(),
+            ..Default::default()
+        };
+        settings.save(&profile_dir).unwrap();
+        let loaded = NetworkSettings::load(&profile_dir).unwrap();
+        assert_eq!(loaded.tor_policy.as_str(), "never");
+        assert!(TorPolicy::parse("sometimes").is_err());
+
+        let onion = MintUrl::from_str("http://mintabcdefghijklmnop.onion").unwrap();
+        let clearnet = MintUrl::from_str("https://mint.example.com").unwrap();
+        assert!(TorPolicy::Always.uses_tor(&clearnet));
+        assert!(TorPolicy::OnionOnly.uses_tor(&onion));
+        assert!(!TorPolicy::OnionOnly.uses_tor(&clearnet));
+        assert!(TorPolicy::Never.check_mint(&onion).is_err());
+        assert!(TorPolicy::Never.check_mint(&clearnet).is_ok());
+
+        std::fs::remove_dir_all(&profile_dir).unwrap();
+        println!("✅ Tor policy settings test passed!");
+    }
+
+    #[test]
+    fn test_proxy_settings() {
+        use crate::api::cashu::ProxyConfig;
+        use crate::network::{network_key, NetworkSettings, StoredProxy};
+        use cdk::mint_url::MintUrl;
+        use std::str::FromStr;
+
+        let socks = ProxyConfig {
+            kind: "socks5".to_string(),
+            host: "127.0.0.1".to_string(),
+            port: 9050,
+            username: Some("alice".to_string()),
+            password: Some("p@ss".to_string()),
+        };
+        // socks5h lets the proxy resolve .onion names
+        let url = socks.to_url().unwrap();
+        assert_eq!(url.scheme(), "socks5h");
+        assert_eq!(url.username(), "alice");
+        assert_eq!(url.password(), Some("p%40ss"));
+
+        let corporate = ProxyConfig {
+            kind: "http".to_string(),
+            host: "proxy.corp.example".to_string(),
+            port: 3128,
+            username: None,
+            password: None,
+        };
+        assert_eq!(
+            corporate.to_url().unwrap().as_str(),
+            "http://proxy.corp.example:3128/"
+        );
+        assert!(ProxyConfig {
+            kind: "socks4".to_string(),
+            ..corporate.clone()
+        }
+        .to_url()
+        .is_err());
+
+        // Passwords are only stored sealed with the profile's key
+        let key = network_key(&[7u8; 64]);
+        let sealed = StoredProxy::seal(socks.clone(), &key).unwrap();
+        let json = serde_json::to_string(&sealed).unwrap();
+        assert!(!json.contains("p@ss"));
+        assert!(!json.contains("\"password\""));
+        assert_eq!(sealed.open(&key).unwrap(), socks);
+        assert!(sealed.open(&network_key(&[8u8; 64])).is_err());
+
+        // A mint's own proxy wins over the wallet proxy
+        let mut settings = NetworkSettings {
+            proxy: Some(StoredProxy::seal(corporate.clone(), &key).unwrap()),
+            ..Default::default()
+        };
+        let onion = MintUrl::from_str("http://mintabcdefghijklmnop.onion").unwrap();
+        let clearnet = MintUrl::from_str("https://mint.example.com").unwrap();
+        settings.mint_proxies.insert(onion.to_string(), sealed);
+        assert_eq!(
+            settings.proxy_for(&onion).unwrap().open(&key).unwrap(),
+            socks
+        );
+        assert_eq!(
+            settings.proxy_for(&clearnet).unwrap().open(&key).unwrap(),
+            corporate
+        );
+
+        // Plaintext passwords from earlier releases get sealed
+        let mut legacy: NetworkSettings = serde_json::from_value(serde_json::json!({
+            "proxy": {
+                "kind": "socks5",
+                "host": "127.0.0.1",
+                "port": 9050,
+                "username": "alice",
+                "password": "p@ss"
+            }
+        }))
+        .unwrap();
+        assert!(legacy.seal_legacy_passwords(&key).unwrap());
+        assert!(!serde_json::to_string(&legacy).unwrap().contains("p@ss"));
+        assert_eq!(legacy.proxy.unwrap().open(&key).unwrap(), socks);
+        println!("✅ Proxy settings test passed!");
+    }
+
+    #[test]
+    fn test_tor_isolation_settings() {
+        use crate::network::{NetworkSettings, TorPolicy};
+
+        let profile_dir =
+            std::env::temp_dir().join(format!("tor_isolation_{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&profile_dir).unwrap();
+
+        // Settings saved before isolation existed keep shared circuits
+        std::fs::write(
+            profile_dir.join("network_settings.json"),
+            r#"{ "tor_policy": "always" }"#,
+        )
+        .unwrap();
+        let mut settings = NetworkSettings::load(&profile_dir).unwrap();
+        assert_eq!(settings.tor_policy, TorPolicy::Always);
+        assert!(!settings.tor_isolation);
+
+        settings.tor_isolation = true;
+        settings.save(&profile_dir).unwrap();
+        assert!(NetworkSettings::load(&profile_dir).unwrap().tor_isolation);
+
+        std::fs::remove_dir_all(&profile_dir).unwrap();
+        println!("✅ Tor isolation settings test passed!");
+    }
+
+    #[cfg(feature = "tor")]
+    #[tokio::test]
+    async fn test_tor_mint_isolation() {
+        use crate::tor::{isolation_key, mint_proxy_url, IsolationTokens};
+        use cdk::mint_url::MintUrl;
+        use std::str::FromStr;
+
+        let first = MintUrl::from_str("https://mint.example.com").unwrap();
+        let second = MintUrl::from_str("http://mintabcdefghijklmnop.onion").unwrap();
+
+        // Both mints go through the profile's one client, under different SOCKS users
+        let profile_id = format!("tor-isolation-{}", rand::random::<u32>());
+        let profile_dir = std::env::temp_dir().join(&profile_id);
+        let first_url = mint_proxy_url(&profile_id, &profile_dir, Some(&first)).unwrap();
+        let second_url = mint_proxy_url(&profile_id, &profile_dir, Some(&second)).unwrap();
+        let shared_url = mint_proxy_url(&profile_id, &profile_dir, None).unwrap();
+        assert_eq!(first_url.scheme(), "socks5h");
+        assert_eq!(first_url.port(), second_url.port());
+        assert_eq!(first_url.password(), second_url.password());
+        assert_ne!(first_url.username(), second_url.username());
+        assert_eq!(shared_url.username(), "shared");
+        assert_eq!(
+            mint_proxy_url(&profile_id, &profile_dir, Some(&first)).unwrap(),
+            first_url
+        );
+        crate::tor::stop(&profile_id);
+
+        // Different keys never share an isolation token, a new identity replaces them
+        let mut tokens = IsolationTokens::default();
+        let first_token = tokens.token(&isolation_key(Some(&first)));
+        assert_eq!(tokens.token(&isolation_key(Some(&first))), first_token);
+        assert_ne!(tokens.token(&isolation_key(Some(&second))), first_token);
+        tokens.reset();
+        assert_ne!(tokens.token(&isolation_key(Some(&first))), first_token);
+
+        let _ = std::fs::remove_dir_all(&profile_dir);
+        println!("✅ Tor mint isolation test passed!");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_spending_limits() {
+        use crate::api::limits::*;
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+        use cdk::mint_url::MintUrl;
+        use std::str::FromStr;
+
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_spending_limits");
+        let seed_hex = random_seed_hex().await;
+        let handle = open_wallet("limits".to_string(), database_dir.clone(), seed_hex.clone())
+            .await
+            .unwrap();
+        add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&handle, 200).await;
+
+        set_spending_limit(
+            handle.clone(),
+            SpendingLimit {
+                mint_url: None,
+                per_transaction: Some(50),
+                daily: Some(60),
+                weekly: None,
+                action: "block".to_string(),
+            },
+            None,
+        )
+        .await
+        .unwrap();
+        let error = send_tokens(handle.clone(), mint.url.clone(), 51, None)
+            .await
+            .unwrap_err();
+        assert!(error.contains("per-transaction limit"), "{}", error);
+
+        // A spend that passed the check counts until it is done
+        let multi_mint_wallet = get_multi_mint_wallet(&handle).await.unwrap();
+        let mint_url = MintUrl::from_str(&mint.url).unwrap();
+        let running = check_spend(&handle, &multi_mint_wallet, &mint_url, 40)
+            .await
+            .unwrap();
+        let error = check_spend(&handle, &multi_mint_wallet, &mint_url, 40)
+            .await
+            .err()
+            .unwrap();
+        assert!(error.contains("daily limit"), "{}", error);
+        assert_eq!(
+            get_spending_allowances(handle.clone()).await.unwrap()[0].daily_spent,
+            40
+        );
+        drop(running);
+        assert_eq!(
+            get_spending_allowances(handle.clone()).await.unwrap()[0].daily_spent,
+            0
+        );
+
+        // Concurrent sends cannot both get under the daily limit
+        let (first, second) = tokio::join!(
+            send_tokens(handle.clone(), mint.url.clone(), 40, None),
+            send_tokens(handle.clone(), mint.url.clone(), 40, None),
+        );
+        assert!(first.is_ok() != second.is_ok(), "{:?} {:?}", first, second);
+        let allowance = &get_spending_allowances(handle.clone()).await.unwrap()[0];
+        assert_eq!(allowance.daily_spent, 40);
+        assert_eq!(allowance.daily_remaining, Some(20));
+        println!("✅ Daily limit held against concurrent spends");
+
+        // An "unlock" limit lets a spend through after unlock_spending, once
+        set_spending_limit(
+            handle.clone(),
+            SpendingLimit {
+                mint_url: Some(mint.url.clone()),
+                per_transaction: None,
+                daily: Some(60),
+                weekly: Some(1000),
+                action: "unlock".to_string(),
+            },
+            None,
+        )
+        .await
+        .unwrap();
+        remove_spending_limit(handle.clone(), None, None)
+            .await
+            .unwrap();
+        let error = send_tokens(handle.clone(), mint.url.clone(), 30, None)
+            .await
+            .unwrap_err();
+        assert!(error.contains("Spending unlock required"), "{}", error);
+        assert!(
+            unlock_spending(handle.clone(), "1234".to_string(), None, 30)
+                .await
+                .is_err()
+        );
+
+        set_spending_pin(handle.clone(), None, "1234".to_string())
+            .await
+            .unwrap();
+        assert!(
+            unlock_spending(handle.clone(), "4321".to_string(), None, 30)
+                .await
+                .is_err()
+        );
+        // The unlock covers up to its amount and only at its mint
+        unlock_spending(
+            handle.clone(),
+            "1234".to_string(),
+            Some(mint.url.clone()),
+            30,
+        )
+        .await
+        .unwrap();
+        assert!(send_tokens(handle.clone(), mint.url.clone(), 31, None)
+            .await
+            .is_err());
+        // A spend the unlock does not cover leaves it in place
+        send_tokens(handle.clone(), mint.url.clone(), 30, None)
+            .await
+            .unwrap();
+        assert!(send_tokens(handle.clone(), mint.url.clone(), 30, None)
+            .await
+            .is_err());
+        println!("✅ Unlock allowed a single spend past the limit");
+
+        // With a PIN set, changing limits needs it
+        assert!(
+            remove_spending_limit(handle.clone(), Some(mint.url.clone()), None)
+                .await
+                .is_err()
+        );
+        remove_spending_limit(
+            handle.clone(),
+            Some(mint.url.clone()),
+            Some("1234".to_string()),
+        )
+        .await
+        .unwrap();
+        assert!(get_spending_limits(handle.clone())
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Too many wrong PINs lock PIN checks out, even for the right PIN
+        for _ in 0..5 {
+            let error = unlock_spending(handle.clone(), "0000".to_string(), None, 30)
+                .await
+                .unwrap_err();
+            assert_eq!(error, "Wrong spending PIN");
+        }
+        let error = unlock_spending(handle.clone(), "1234".to_string(), None, 30)
+            .await
+            .unwrap_err();
+        assert!(error.contains("Too many wrong spending PINs"), "{}", error);
+
+        // Reopening the profile does not reset the lockout
+        close_wallet(handle).await.unwrap();
+        let handle = open_wallet("limits".to_string(), database_dir.clone(), seed_hex)
+            .await
+            .unwrap();
+        let error = set_spending_pin(handle.clone(), Some("1234".to_string()), String::new())
+            .await
+            .unwrap_err();
+        assert!(error.contains("Too many wrong spending PINs"), "{}", error);
+        println!("✅ Locked out PIN checks after too many wrong PINs");
+
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    #[cfg(feature = "sqlcipher")]
+    #[test]
+    fn test_database_encryption() {
+        use crate::db_encryption::{is_plaintext_database, prepare_encrypted_database, rotate_key};
+        use rusqlite::Connection;
+
+        let dir = std::env::temp_dir().join(format!("db_encryption_{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("multi_mint_wallet.db");
+        let salt_path = dir.join("multi_mint_wallet.salt");
+        let pending_salt_path = dir.join("multi_mint_wallet.salt.new");
+        let read_row = |key: &str| -> rusqlite::Result<String> {
+            let conn = Connection::open(&db_path)?;
+            conn.pragma_update(None, "key", key)?;
+            conn.query_row("SELECT note FROM notes", [], |row| row.get(0))
+        };
+
+        // An existing plaintext database is encrypted in place on first use
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute("CREATE TABLE notes (note TEXT)", []).unwrap();
+            conn.execute("INSERT INTO notes VALUES ('kept')", [])
+                .unwrap();
+        }
+        assert!(is_plaintext_database(&db_path).unwrap());
+        let key = prepare_encrypted_database(&db_path, "first pin").unwrap();
+        assert!(!is_plaintext_database(&db_path).unwrap());
+        assert_eq!(read_row(&key).unwrap(), "kept");
+        assert!(read_row("").is_err());
+        println!("✅ Migrated a plaintext database");
+
+        assert_eq!(
+            prepare_encrypted_database(&db_path, "wrong pin").unwrap_err(),
+            "Wrong wallet database key"
+        );
+        assert!(prepare_encrypted_database(&db_path, "").is_err());
+
+        // A rotation that died before the rekey leaves the old key working
+        std::fs::write(&pending_salt_path, "00".repeat(16)).unwrap();
+        assert_eq!(
+            prepare_encrypted_database(&db_path, "first pin").unwrap(),
+            key
+        );
+        assert!(!pending_salt_path.exists());
+
+        let old_salt = std::fs::read(&salt_path).unwrap();
+        rotate_key(&db_path, "first pin", "second pin").unwrap();
+        assert!(prepare_encrypted_database(&db_path, "first pin").is_err());
+        let new_key = prepare_encrypted_database(&db_path, "second pin").unwrap();
+        assert_ne!(new_key, key);
+        assert_eq!(read_row(&new_key).unwrap(), "kept");
+        assert!(rotate_key(&db_path, "first pin", "third pin").is_err());
+        println!("✅ Rotated the database key");
+
+        // A rotation that died after the rekey, before the new salt was promoted
+        std::fs::rename(&salt_path, &pending_salt_path).unwrap();
+        std::fs::write(&salt_path, &old_salt).unwrap();
+        assert_eq!(
+            prepare_encrypted_database(&db_path, "second pin").unwrap(),
+            new_key
+        );
+        assert!(!pending_salt_path.exists());
+        assert!(prepare_encrypted_database(&db_path, "first pin").is_err());
+        println!("✅ Recovered from an interrupted key rotation");
+
+        // Files shorter than the header are not plaintext databases
+        let short = dir.join("short.db");
+        std::fs::write(&short, b"SQLite").unwrap();
+        assert!(!is_plaintext_database(&short).unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_event() {
+        let keys = generate_keys().unwrap();
+        let unsigned = serde_json::json!({
+            "pubkey": keys.public_key,
+            "created_at": 1_700_000_000u64,
+            "kind": 1,
+            "tags": [],
+            "content": "purrwallet-cli",
+        });
+        let signed = sign_event(unsigned.to_string(), keys.private_key.clone()).unwrap();
+
+        let event: NostrEvent = serde_json::from_str(&signed).unwrap();
+        assert!(verify_event(event).unwrap());
+
+        // A changed event no longer matches its id and signature
+        let mut tampered: NostrEvent = serde_json::from_str(&signed).unwrap();
+        tampered.content = "tampered".to_string();
+        assert!(!verify_event(tampered).unwrap());
+        println!("✅ Event verification test passed!");
+    }
+
+    #[test]
+    fn test_daemon_client_auth() {
+        use crate::api::daemon::{authorize, create_client, Permission};
+
+        let profile_dir =
+            std::env::temp_dir().join(format!("daemon_clients_{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&profile_dir).unwrap();
+
+        let token = create_client(
+            &profile_dir,
+            "pos-terminal",
+            &["read".to_string(), "receive".to_string()],
+        )
+        .unwrap();
+        assert_eq!(
+            authorize(&profile_dir, &token, Permission::Receive).unwrap(),
+            "pos-terminal"
+        );
+        // Receiving does not allow paying
+        assert!(authorize(&profile_dir, &token, Permission::Pay).is_err());
+        assert!(authorize(&profile_dir, "not-a-token", Permission::Read).is_err());
+
+        // Only the hash of the token is written to disk
+        let stored = std::fs::read_to_string(profile_dir.join("daemon_clients.json")).unwrap();
+        assert!(!stored.contains(&token));
+
+        assert!(create_client(&profile_dir, "pos-terminal", &[]).is_err());
+        assert!(create_client(&profile_dir, "script", &["admin".to_string()]).is_err());
+
+        std::fs::remove_dir_all(&profile_dir).unwrap();
+        println!("✅ Daemon client auth test passed!");
+    }
+
+    #[cfg(feature = "daemon")]
+    #[tokio::test]
+    async fn test_daemon_rpc() {
+        use crate::api::daemon::{add_daemon_client, start_daemon, stop_daemon};
+        use crate::test_mint::{random_seed_hex, temp_database_dir};
+
+        let database_dir = temp_database_dir("daemon_rpc");
+        let handle = open_wallet(
+            "daemon".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        let token = add_daemon_client(
+            handle.clone(),
+            "dashboard".to_string(),
+            vec!["read".to_string()],
+        )
+        .await
+        .unwrap();
+        let address = start_daemon(handle.clone(), "127.0.0.1:0".to_string())
+            .await
+            .unwrap();
+
+        let client = reqwest::Client::new();
+        let call = |method: &str, token: Option<&str>| {
+            let mut request = client
+                .post(format!("http://{}/rpc", address))
+                .header("Content-Type", "application/json")
+                .body(
+                    serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": method }).to_string(),
+                );
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+            async move {
+                let response = request.send().await.unwrap();
+                let status = response.status().as_u16();
+                let body = response.text().await.unwrap();
+                (
+                    status,
+                    serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+                )
+            }
+        };
+
+        let (status, body) = call("get_balances", Some(&token)).await;
+        assert_eq!(status, 200);
+        assert!(body["result"].is_object());
+
+        // No token, a wrong token and a missing permission are all refused
+        let (status, body) = call("get_balances", None).await;
+        assert_eq!(status, 401);
+        assert_eq!(body["error"]["code"], -32001);
+        let (status, _) = call("get_balances", Some("not-a-token")).await;
+        assert_eq!(status, 401);
+        let (status, body) = call("send_tokens", Some(&token)).await;
+        assert_eq!(status, 401);
+        assert!(body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("'send'"));
+
+        let (status, body) = call("drain_wallet", Some(&token)).await;
+        assert_eq!(status, 200);
+        assert_eq!(body["error"]["code"], -32601);
+
+        stop_daemon(handle.clone()).await.unwrap();
+
+        // Anything at a socket path that is not a socket is left alone
+        #[cfg(unix)]
+        {
+            let path = std::path::Path::new(&database_dir).join("not-a-socket");
+            std::fs::write(&path, "keep me").unwrap();
+            let result = start_daemon(handle.clone(), format!("unix:{}", path.display())).await;
+            assert!(result.unwrap_err().contains("not a socket"));
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep me");
+        }
+
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+        println!("✅ Daemon RPC test passed!");
+    }
+
+    #[test]
+    fn test_log_redaction() {
+        use crate::api::logging::redact;
+
+        let line = "Failed to receive cashuBo2FteCJodHRwczovL21pbnQuZXhhbXBsZS5jb20 from https://mint.example.com";
+        let redacted = redact(line);
+        assert!(!redacted.contains("cashuBo2Fte"));
+        assert!(redacted.contains("https://mint.example.com"));
+
+        let proof = r#"{"amount":8,"id":"009a1f293253e41e","secret":"my-proof-secret","C":"02698c4e2b5f9534cd0687d87513c759790cf829aa5739184a3e3735471fbda904"}"#;
+        let redacted = redact(proof);
+        assert!(!redacted.contains("proof-secret"));
+        assert!(redacted.contains(r#""secret":"[redacted]""#));
+        assert!(!redacted.contains("02698c4e"));
+        // Keyset ids and amounts are useful for debugging and stay
+        assert!(redacted.contains("009a1f293253e41e"));
+        assert!(redacted.contains("\"amount\":8"));
+
+        // The whole value goes, up to the next quote, comma or end of line
+        assert_eq!(
+            redact("mnemonic abandon ability able, profile default"),
+            "mnemonic [redacted], profile default"
+        );
+        assert_eq!(
+            redact("proxy password=hunter2 hunter3\nnext line"),
+            "proxy password=[redacted]\nnext line"
+        );
+        assert_eq!(
+            redact(r#"{"kind":"token","amount":8}"#),
+            r#"{"kind":"token","amount":8}"#
+        );
+
+        // Base64 tokens are not split at '+', '/' or '='
+        let redacted = redact("Sending cashuAeyJ0b2tlbiI6W3sibWludCI6+/abcd== now");
+        assert_eq!(redacted, "Sending [redacted] now");
+        println!("✅ Log redaction test passed!");
+    }
+
+    #[test]
+    fn test_cashu_proof_round_trip() {
+        use cdk::nuts::{Id, P2PKWitness, Proof, ProofDleq, SecretKey, Witness};
+        use cdk::secret::Secret;
+        use std::str::FromStr;
+
+        let key = SecretKey::generate();
+        let raw_secret = format!(
+            r#"["P2PK",{{"nonce":"5d11913ee0f92fefdc82a6764fd2457a","data":"{}","tags":[["sigflag","SIG_INPUTS"]]}}]"#,
+            key.public_key().to_hex()
+        );
+        let proof = Proof {
+            keyset_id: Id::from_str("009a1f293253e41e").unwrap(),
+            amount: 8.into(),
+            secret: Secret::new(raw_secret.clone()),
+            c: SecretKey::generate().public_key(),
+            witness: Some(Witness::P2PKWitness(P2PKWitness {
+                signatures: vec!["a".repeat(128)],
+            })),
+            dleq: Some(ProofDleq::new(
+                SecretKey::generate(),
+                SecretKey::generate(),
+                SecretKey::generate(),
+            )),
+        };
+
+        let cashu_proof = CashuProof::from(proof.clone());
+        assert_eq!(cashu_proof.secret.kind.as_deref(), Some("P2PK"));
+        assert_eq!(cashu_proof.secret.data, Some(key.public_key().to_hex()));
+        assert_eq!(cashu_proof.secret.tags, vec![vec!["sigflag", "SIG_INPUTS"]]);
+        assert_eq!(cashu_proof.y, proof.y().unwrap().to_hex());
+        assert!(cashu_proof.dleq.is_some());
+
+        let round_trip = Proof::try_from(cashu_proof).unwrap();
+        assert_eq!(
+            serde_json::to_value(&round_trip).unwrap(),
+            serde_json::to_value(&proof).unwrap()
+        );
+        assert_eq!(round_trip.secret.to_string(), raw_secret);
+
+        // Plain secrets have no NUT-10 structure
+        let plain = CashuProof::from(Proof {
+            secret: Secret::generate(),
+            witness: None,
+            dleq: None,
+            ..proof
+        });
+        assert!(plain.secret.kind.is_none());
+        assert!(plain.witness.is_none());
+        println!("✅ Cashu proof round trip test passed!");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_offline_token_verification() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_offline_verify");
+
+        let sender = open_wallet(
+            "sender".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(sender.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&sender, 100).await;
+        let token = send_tokens(sender.clone(), mint.url.clone(), 50, None)
+            .await
+            .unwrap();
+
+        // The sender has the mint's keys cached
+        let verification = verify_token_offline(sender.clone(), token.clone(), false)
+            .await
+            .unwrap();
+        assert!(verification.valid, "{:?}", verification);
+        assert!(verification.keysets_valid);
+        assert!(!verification.fetched_keys);
+        assert_eq!(verification.amount, 50);
+        println!("✅ Verified a token against cached keys");
+
+        // A wallet without the mint needs permission to fetch its keys
+        let verifier = open_wallet(
+            "verifier".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        assert!(verify_token_offline(verifier.clone(), token.clone(), false)
+            .await
+            .is_err());
+        let verification = verify_token_offline(verifier.clone(), token, true)
+            .await
+            .unwrap();
+        assert!(verification.valid, "{:?}", verification);
+        assert!(verification.fetched_keys);
+        println!("✅ Verified a token from a mint that is not in the wallet");
+
+        // A DLEQ proof from another key is caught
+        let mut proofs = get_wallet_proofs(sender.clone(), mint.url.clone())
+            .await
+            .unwrap();
+        assert!(!proofs.is_empty());
+        let dleq = proofs[0].dleq.as_mut().expect("Mint returned no DLEQ");
+        dleq.e = cdk::nuts::SecretKey::generate().to_secret_hex();
+        let verification = verify_proofs_offline(sender.clone(), mint.url.clone(), proofs, false)
+            .await
+            .unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.proofs[0].dleq_valid, Some(false));
+        println!("✅ Rejected a tampered DLEQ proof");
+
+        close_wallet(sender).await.unwrap();
+        close_wallet(verifier).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_import_wallet_backup() {
+        use crate::import::parse_backup;
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+        use cdk::mint_url::MintUrl;
+        use cdk::nuts::nut00::token::TokenV3Token;
+        use cdk::nuts::{Proof, TokenV3};
+        use std::str::FromStr;
+
+        // cashu.me keeps JSON encoded localStorage entries
+        let proof = serde_json::json!({
+            "id": "009a1f293253e41e",
+            "amount": 2,
+            "secret": "407915bc212be61a77e3e6d2aeb4c727980bda51cd06a6afc29e2861768a7837",
+            "C": "02bc9097997d81afb2cc7346b5e4345a9346bd2a506eb7958598a72f0cf85163ea",
+            "reserved": false
+        });
+        let cashu_me = serde_json::json!({
+            "cashu.mints": serde_json::json!([{
+                "url": "https://mint.example.com/",
+                "keysets": [{ "id": "009a1f293253e41e", "unit": "sat" }]
+            }])
+            .to_string(),
+            "cashu.proofs": serde_json::json!([proof]).to_string(),
+            "cashu.keysetCounters": r#"[{"id":"009a1f293253e41e","counter":12}]"#,
+            "cashu.mnemonic": "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        });
+        let parsed = parse_backup(&cashu_me.to_string()).unwrap();
+        assert_eq!(parsed.format, "cashu_me");
+        assert_eq!(parsed.proofs["https://mint.example.com"].len(), 1);
+        assert_eq!(parsed.counters["009a1f293253e41e"], 12);
+        assert!(parsed.mnemonic.is_some());
+
+        // Nutshell ties proofs to mints through its keysets table
+        let nutshell = serde_json::json!({
+            "keysets": [{ "id": "009a1f293253e41e", "mint_url": "https://mint.example.com", "counter": 3 }],
+            "proofs": [proof, { "id": "00ffffffffffffff", "amount": 1, "secret": "abc", "C": proof["C"] }]
+        });
+        let parsed = parse_backup(&nutshell.to_string()).unwrap();
+        assert_eq!(parsed.format, "nutshell");
+        assert_eq!(parsed.proofs["https://mint.example.com"].len(), 1);
+        assert_eq!(parsed.unassigned, 1);
+
+        // A V3 token may bundle proofs from several mints
+        let v3_proof: Proof = serde_json::from_value(proof.clone()).unwrap();
+        let bundle = TokenV3 {
+            token: ["https://mint.example.com", "https://other.example.com"]
+                .into_iter()
+                .map(|url| {
+                    TokenV3Token::new(MintUrl::from_str(url).unwrap(), vec![v3_proof.clone()])
+                })
+                .collect(),
+            memo: None,
+            unit: None,
+        }
+        .to_string();
+        let parsed = parse_backup(&bundle).unwrap();
+        assert_eq!(parsed.proofs.len(), 2);
+        assert!(parsed.tokens.is_empty());
+        println!("✅ Parsed cashu.me and nutshell backups");
+
+        // Proofs dumped from one wallet are swapped into another
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_import");
+        let old = open_wallet(
+            "old".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(old.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&old, 64).await;
+        let proofs: Vec<Proof> = get_wallet_proofs(old.clone(), mint.url.clone())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| Proof::try_from(p).unwrap())
+            .collect();
+        let dump = serde_json::json!([{ "mint": mint.url, "proofs": proofs }]).to_string();
+
+        let new = open_wallet(
+            "new".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        let report = import_wallet_backup(new.clone(), dump.clone())
+            .await
+            .unwrap();
+        assert_eq!(report.imported_amount, 64, "{:?}", report);
+        assert_eq!(report.mints[0].spent_amount, 0);
+
+        // Importing again finds the proofs spent by the first import
+        let report = import_wallet_backup(new.clone(), dump).await.unwrap();
+        assert_eq!(report.imported_amount, 0);
+        assert_eq!(report.mints[0].spent_amount, 64);
+        println!("✅ Imported a proof dump and skipped spent proofs");
+
+        // The backup's mnemonic is restored into its own profile, not put in the report
+        let mnemonic = generate_mnemonic_phrase(12).await.unwrap();
+        let seeded = open_wallet(
+            "seeded".to_string(),
+            database_dir.clone(),
+            mnemonic_to_seed_hex(mnemonic.clone()).await.unwrap(),
+        )
+        .await
+        .unwrap();
+        add_mint(seeded.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&seeded, 32).await;
+        close_wallet(seeded).await.unwrap();
+
+        let backup = serde_json::json!({
+            "mnemonic": mnemonic,
+            "mints": [{ "url": mint.url }]
+        })
+        .to_string();
+        let report = import_wallet_backup(new.clone(), backup.clone())
+            .await
+            .unwrap();
+        assert!(report.has_mnemonic);
+        let restored = restore_backup_profile(
+            "from_backup".to_string(),
+            database_dir.clone(),
+            backup.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(restored.restored_amount, 32, "{:?}", restored.mints);
+        assert_eq!(restored.mnemonic, mnemonic);
+        // An existing profile is never overwritten
+        assert!(
+            restore_backup_profile("from_backup".to_string(), database_dir.clone(), backup)
+                .await
+                .is_err()
+        );
+        close_wallet(restored.handle).await.unwrap();
+        println!("✅ Restored the backup's seed into its own profile");
+
+        close_wallet(old).await.unwrap();
+        close_wallet(new).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_send_selected_proofs() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+
+        let mint = TestMint::start().await;
+        let database_dir = temp_database_dir("test_coin_control");
+        let handle = open_wallet(
+            "coin_control".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(handle.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&handle, 96).await;
+
+        // 96 is minted as a 64 and a 32 proof
+        let proofs = get_wallet_proofs(handle.clone(), mint.url.clone())
+            .await
+            .unwrap();
+        let proof_64 = proofs.iter().find(|p| p.amount == 64).unwrap().clone();
+        let proof_32 = proofs.iter().find(|p| p.amount == 32).unwrap().clone();
+
+        // Without a swap the token carries that exact proof
+        let token = send_selected_proofs(
+            handle.clone(),
+            mint.url.clone(),
+            vec![proof_64.y.clone()],
+            SelectedProofsSendOptions::default(),
+        )
+        .await
+        .unwrap();
+        let sent: cdk::nuts::Token = token.parse().unwrap();
+        assert_eq!(u64::from(sent.value().unwrap()), 64);
+        let proofs = get_wallet_proofs(handle.clone(), mint.url.clone())
+            .await
+            .unwrap();
+        let sent_proof = proofs.iter().find(|p| p.y == proof_64.y).unwrap();
+        assert_eq!(sent_proof.state.as_deref(), Some("pending_spent"));
+        println!("✅ Sent a chosen proof without a swap");
+
+        // A proof that is already out cannot be picked again
+        assert!(send_selected_proofs(
+            handle.clone(),
+            mint.url.clone(),
+            vec![proof_64.y.clone()],
+            SelectedProofsSendOptions::default(),
+        )
+        .await
+        .is_err());
+
+        // A send that fails after reserving the proof gives it back
+        assert!(send_selected_proofs(
+            handle.clone(),
+            mint.url.clone(),
+            vec![proof_32.y.clone()],
+            SelectedProofsSendOptions {
+                amount: Some(5),
+                ..Default::default()
+            },
+        )
+        .await
+        .is_err());
+        // An amount that overflows with the fee is refused the same way
+        assert!(send_selected_proofs(
+            handle.clone(),
+            mint.url.clone(),
+            vec![proof_32.y.clone()],
+            SelectedProofsSendOptions {
+                amount: Some(u64::MAX),
+                ..Default::default()
+            },
+        )
+        .await
+        .is_err());
+        let proofs = get_wallet_proofs(handle.clone(), mint.url.clone())
+            .await
+            .unwrap();
+        let released = proofs.iter().find(|p| p.y == proof_32.y).unwrap();
+        assert_eq!(released.state.as_deref(), Some("unspent"));
+        println!("✅ Released the proof of a failed send");
+
+        // With a swap only part of the proof is sent and the rest is change
+        let token = send_selected_proofs(
+            handle.clone(),
+            mint.url.clone(),
+            vec![proof_32.y.clone()],
+            SelectedProofsSendOptions {
+                swap: true,
+                amount: Some(10),
+                memo: Some("coin control".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+        let sent: cdk::nuts::Token = token.parse().unwrap();
+        assert_eq!(u64::from(sent.value().unwrap()), 10);
+        let proofs = get_wallet_proofs(handle.clone(), mint.url.clone())
+            .await
+            .unwrap();
+        assert!(proofs.iter().all(|p| p.y != proof_32.y));
+        let change: u64 = proofs
+            .iter()
+            .filter(|p| p.state.as_deref() == Some("unspent"))
+            .map(|p| p.amount)
+            .sum();
+        assert_eq!(change, 22);
+        println!("✅ Sent part of a chosen proof with a swap");
+
+        close_wallet(handle).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
+    }
+
+    /// Two devices share one NIP-60 wallet through a local relay
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_nip60_sync() {
+        use crate::test_mint::{random_seed_hex, temp_database_dir, TestMint};
+        use crate::test_relay::TestRelay;
+
+        let mint = TestMint::start().await;
+        let relay = TestRelay::start().await;
+        let database_dir = temp_database_dir("test_nip60");
+        let nostr_key = generate_keys().unwrap().private_key;
+        let balance = |handle: WalletHandle| async move {
+            get_all_balances(handle)
+                .await
+                .unwrap()
+                .values()
+                .sum::<u64>()
+        };
+
+        let phone = open_wallet(
+            "phone".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        add_mint(phone.clone(), mint.url.clone()).await.unwrap();
+        mint.fund(&phone, 64).await;
+
+        // A relay that does not answer may hold the wallet event, none is created
+        let tablet = open_wallet(
+            "tablet".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        enable_nip60_sync(
+            tablet.clone(),
+            nostr_key.clone(),
+            Some(vec![relay.url.clone(), "ws://127.0.0.1:9".to_string()]),
+        )
+        .await
+        .unwrap();
+        let report = sync_nip60_wallet(tablet.clone()).await.unwrap();
+        assert!(!report.errors.is_empty());
+        assert_eq!(relay.count(17375), 0);
+        disable_nip60_sync(tablet.clone()).await.unwrap();
+        close_wallet(tablet).await.unwrap();
+        println!("✅ No wallet event created while a relay was unreachable");
+
+        enable_nip60_sync(
+            phone.clone(),
+            nostr_key.clone(),
+            Some(vec![relay.url.clone()]),
+        )
+        .await
+        .unwrap();
+        sync_nip60_wallet(phone.clone()).await.unwrap();
+        assert_eq!(relay.count(17375), 1);
+        assert!(relay.count(7375) >= 1);
+        println!("✅ Published the wallet and token events");
+
+        // A second device with its own seed picks up the mint and the proofs
+        let laptop = open_wallet(
+            "laptop".to_string(),
+            database_dir.clone(),
+            random_seed_hex().await,
+        )
+        .await
+        .unwrap();
+        enable_nip60_sync(laptop.clone(), nostr_key, Some(vec![relay.url.clone()]))
+            .await
+            .unwrap();
+        sync_nip60_wallet(laptop.clone()).await.unwrap();
+        assert_eq!(list_mints(laptop.clone()).await.unwrap().len(), 1);
+        assert_eq!(balance(laptop.clone()).await, 64);
+        println!("✅ Second device imported the synced proofs");
+
+        // Spending on one device rolls the token event over with the change
+        send_tokens(laptop.clone(), mint.url.clone(), 10, None)
+            .await
+            .unwrap();
+        sync_nip60_wallet(laptop.clone()).await.unwrap();
+        assert!(relay.count(5) >= 1);
+
+        // The other device drops the spent proof and takes the change
+        sync_nip60_wallet(phone.clone()).await.unwrap();
+        assert_eq!(balance(phone.clone()).await, 54);
+        assert_eq!(balance(laptop.clone()).await, 54);
+        println!("✅ Merged a spend from the other device");
+
+        let status = get_nip60_sync_status(phone.clone()).await.unwrap();
+        assert!(status.enabled);
+        assert!(status.last_error.is_none());
+
+        close_wallet(phone).await.unwrap();
+        close_wallet(laptop).await.unwrap();
+        let _ = std::fs::remove_dir_all(&database_dir);
     }
 }
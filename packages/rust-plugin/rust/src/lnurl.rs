@@ -0,0 +1,317 @@
+use crate::api::cashu::{service_http_client, WalletHandle};
+use async_trait::async_trait;
+use cdk::bitcoin::hashes::{sha256, Hash};
+use cdk::lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescriptionRef};
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use url::Url;
+
+/// HTTP client used for LNURL requests
+/// Swappable so tests can answer requests from a local stand-in server.
+#[async_trait]
+pub trait LnurlHttpClient: Send + Sync {
+    /// GET the url and return the response body
+    async fn get(&self, url: &Url) -> Result<String, String>;
+}
+
+/// Default client backed by reqwest, routed like the profile's mint connections
+/// The transport is picked per request, the callback may live on another host than the
+/// LNURL itself.
+pub struct ReqwestLnurlClient {
+    handle: WalletHandle,
+}
+
+impl ReqwestLnurlClient {
+    pub fn new(handle: WalletHandle) -> Self {
+        Self { handle }
+    }
+}
+
+#[async_trait]
+impl LnurlHttpClient for ReqwestLnurlClient {
+    async fn get(&self, url: &Url) -> Result<String, String> {
+        let response = service_http_client(&self.handle, url)
+            .await?
+            .get(url.clone())
+            .send()
+            .await
+            .map_err(|e| format!("LNURL request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("LNURL server returned {}", response.status()));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read LNURL response: {}", e))
+    }
+}
+
+/// Client override, None means use the default reqwest client
+static LNURL_HTTP_CLIENT: RwLock<Option<Arc<dyn LnurlHttpClient>>> = RwLock::new(None);
+
+/// Replace the HTTP client used for LNURL requests (None restores the default)
+pub(crate) fn set_http_client(client: Option<Arc<dyn LnurlHttpClient>>) {
+    let mut guard = LNURL_HTTP_CLIENT.write().unwrap_or_else(|e| e.into_inner());
+    *guard = client;
+}
+
+pub(crate) fn http_client(handle: &WalletHandle) -> Arc<dyn LnurlHttpClient> {
+    let guard = LNURL_HTTP_CLIENT.read().unwrap_or_else(|e| e.into_inner());
+    match guard.as_ref() {
+        Some(client) => client.clone(),
+        None => Arc::new(ReqwestLnurlClient::new(handle.clone())),
+    }
+}
+
+/// LUD-06 pay request returned by the first LNURL-pay call
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PayRequest {
+    pub callback: String,
+    pub min_sendable: u64,
+    pub max_sendable: u64,
+    pub metadata: String,
+    pub tag: String,
+    // LUD-12
+    #[serde(default)]
+    pub comment_allowed: u64,
+}
+
+/// Response of the pay request callback
+#[derive(Debug, Clone, Deserialize)]
+struct InvoiceResponse {
+    pr: String,
+}
+
+/// LNURL error response, returned instead of the expected payload
+#[derive(Debug, Clone, Deserialize)]
+struct ErrorResponse {
+    status: String,
+    reason: Option<String>,
+}
+
+/// Convert a lightning address, bech32 LNURL or lnurlp:// link into the URL to fetch
+pub(crate) fn lnurl_to_url(input: &str) -> Result<Url, String> {
+    let trimmed = input.trim();
+    let trimmed = trimmed
+        .strip_prefix("lightning:")
+        .or_else(|| trimmed.strip_prefix("LIGHTNING:"))
+        .unwrap_or(trimmed);
+
+    // Lightning address (LUD-16)
+    if let Some((name, domain)) = trimmed.split_once('@') {
+        if name.is_empty() || domain.is_empty() || domain.contains('/') {
+            return Err(format!("Invalid lightning address '{}'", trimmed));
+        }
+        // .onion services are only reachable over plain HTTP
+        let scheme = if domain.ends_with(".onion") {
+            "http"
+        } else {
+            "https"
+        };
+        let url = format!(
+            "{}://{}/.well-known/lnurlp/{}",
+            scheme,
+            domain,
+            name.to_lowercase()
+        );
+        return Url::parse(&url).map_err(|e| format!("Invalid lightning address: {}", e));
+    }
+
+    // LUD-17 schemes
+    for (prefix, scheme) in [
+        ("lnurlp://", "https://"),
+        ("lnurlw://", "https://"),
+        ("keyauth://", "https://"),
+    ] {
+        if let Some(rest) = trimmed.strip_prefix(prefix) {
+            let scheme = if rest.split('/').next().unwrap_or("").ends_with(".onion") {
+                "http://"
+            } else {
+                scheme
+            };
+            return Url::parse(&format!("{}{}", scheme, rest))
+                .map_err(|e| format!("Invalid LNURL: {}", e));
+        }
+    }
+
+    // Bech32 encoded LNURL (LUD-01)
+    if trimmed.to_lowercase().starts_with("lnurl1") {
+        let (hrp, data) =
+            bech32::decode(trimmed).map_err(|e| format!("Invalid LNURL encoding: {}", e))?;
+        if hrp.to_lowercase() != "lnurl" {
+            return Err(format!("Unexpected LNURL prefix '{}'", hrp));
+        }
+        let decoded =
+            String::from_utf8(data).map_err(|e| format!("Invalid LNURL contents: {}", e))?;
+        return Url::parse(&decoded).map_err(|e| format!("Invalid LNURL: {}", e));
+    }
+
+    Err("Not a lightning address or LNURL".to_string())
+}
+
+/// Parse an LNURL response body, surfacing {"status": "ERROR"} replies as errors
+pub(crate) fn parse_response<T: for<'de> Deserialize<'de>>(body: &str) -> Result<T, String> {
+    if let Ok(error) = serde_json::from_str::<ErrorResponse>(body) {
+        if error.status.eq_ignore_ascii_case("ERROR") {
+            return Err(format!(
+                "LNURL service error: {}",
+                error.reason.unwrap_or_else(|| "unknown reason".to_string())
+            ));
+        }
+    }
+
+    serde_json::from_str(body).map_err(|e| format!("Invalid LNURL response: {}", e))
+}
+
+/// Fetch and validate the pay request behind a lightning address or LNURL
+pub(crate) async fn fetch_pay_request(
+    client: &dyn LnurlHttpClient,
+    lnurl: &str,
+) -> Result<PayRequest, String> {
+    let url = lnurl_to_url(lnurl)?;
+    let body = client.get(&url).await?;
+    let pay_request: PayRequest = parse_response(&body)?;
+
+    if pay_request.tag != "payRequest" {
+        return Err(format!(
+            "Expected an LNURL-pay request, got '{}'",
+            pay_request.tag
+        ));
+    }
+    if pay_request.min_sendable > pay_request.max_sendable {
+        return Err("LNURL-pay min sendable is above max sendable".to_string());
+    }
+
+    Ok(pay_request)
+}
+
+/// Extract the text/plain description from LUD-06 metadata
+pub(crate) fn metadata_description(metadata: &str) -> Option<String> {
+    let entries: Vec<Vec<serde_json::Value>> = serde_json::from_str(metadata).ok()?;
+    entries.into_iter().find_map(|entry| {
+        match (
+            entry.first().and_then(|v| v.as_str()),
+            entry.get(1).and_then(|v| v.as_str()),
+        ) {
+            (Some("text/plain"), Some(text)) => Some(text.to_string()),
+            _ => None,
+        }
+    })
+}
+
+/// Request an invoice from the pay request callback and check it matches what we asked for
+pub(crate) async fn request_invoice(
+    client: &dyn LnurlHttpClient,
+    pay_request: &PayRequest,
+    amount_msat: u64,
+    comment: Option<String>,
+) -> Result<Bolt11Invoice, String> {
+    if amount_msat < pay_request.min_sendable || amount_msat > pay_request.max_sendable {
+        return Err(format!(
+            "Amount must be between {} and {} sats",
+            pay_request.min_sendable.div_ceil(1000),
+            pay_request.max_sendable / 1000
+        ));
+    }
+
+    let mut callback =
+        Url::parse(&pay_request.callback).map_err(|e| format!("Invalid callback URL: {}", e))?;
+    callback
+        .query_pairs_mut()
+        .append_pair("amount", &amount_msat.to_string());
+
+    if let Some(comment) = comment.filter(|c| !c.is_empty()) {
+        if comment.chars().count() as u64 > pay_request.comment_allowed {
+            return Err(format!(
+                "Comment is too long, at most {} characters are allowed",
+                pay_request.comment_allowed
+            ));
+        }
+        callback.query_pairs_mut().append_pair("comment", &comment);
+    }
+
+    let body = client.get(&callback).await?;
+    let response: InvoiceResponse = parse_response(&body)?;
+    let invoice = Bolt11Invoice::from_str(&response.pr)
+        .map_err(|e| format!("Failed to parse invoice from LNURL service: {}", e))?;
+
+    if invoice.amount_milli_satoshis() != Some(amount_msat) {
+        return Err("Invoice amount does not match the requested amount".to_string());
+    }
+
+    // LUD-06: the invoice must commit to the metadata we were shown
+    let metadata_hash = sha256::Hash::hash(pay_request.metadata.as_bytes());
+    match invoice.description() {
+        Bolt11InvoiceDescriptionRef::Hash(hash) if hash.0 == metadata_hash => {}
+        _ => return Err("Invoice description hash does not match LNURL metadata".to_string()),
+    }
+
+    Ok(invoice)
+}
+
+/// LUD-03 withdraw request returned by the first LNURL-withdraw call
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WithdrawRequest {
+    pub callback: String,
+    pub k1: String,
+    pub min_withdrawable: u64,
+    pub max_withdrawable: u64,
+    #[serde(default)]
+    pub default_description: String,
+    pub tag: String,
+}
+
+/// Plain {"status": "OK"} acknowledgement
+#[derive(Debug, Clone, Deserialize)]
+struct StatusResponse {
+    status: String,
+}
+
+/// Fetch and validate the withdraw request behind an LNURL
+pub(crate) async fn fetch_withdraw_request(
+    client: &dyn LnurlHttpClient,
+    lnurl: &str,
+) -> Result<WithdrawRequest, String> {
+    let url = lnurl_to_url(lnurl)?;
+    let body = client.get(&url).await?;
+    let withdraw_request: WithdrawRequest = parse_response(&body)?;
+
+    if withdraw_request.tag != "withdrawRequest" {
+        return Err(format!(
+            "Expected an LNURL-withdraw request, got '{}'",
+            withdraw_request.tag
+        ));
+    }
+    if withdraw_request.min_withdrawable > withdraw_request.max_withdrawable {
+        return Err("LNURL-withdraw min withdrawable is above max withdrawable".to_string());
+    }
+
+    Ok(withdraw_request)
+}
+
+/// Hand our invoice to the withdraw callback so the service pays it
+pub(crate) async fn submit_withdraw_invoice(
+    client: &dyn LnurlHttpClient,
+    withdraw_request: &WithdrawRequest,
+    invoice: &str,
+) -> Result<(), String> {
+    let mut callback = Url::parse(&withdraw_request.callback)
+        .map_err(|e| format!("Invalid callback URL: {}", e))?;
+    callback
+        .query_pairs_mut()
+        .append_pair("k1", &withdraw_request.k1)
+        .append_pair("pr", invoice);
+
+    let body = client.get(&callback).await?;
+    let response: StatusResponse = parse_response(&body)?;
+    if !response.status.eq_ignore_ascii_case("OK") {
+        return Err(format!("LNURL-withdraw failed: {}", response.status));
+    }
+
+    Ok(())
+}
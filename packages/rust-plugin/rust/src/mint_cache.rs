@@ -0,0 +1,114 @@
+use cdk::mint_url::MintUrl;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File the fetch times are stored in, inside the profile directory
+const MINT_CACHE_FILE_NAME: &str = "mint_cache.json";
+
+/// How long cached mint info stays fresh when the mint does not advertise a NUT-19 TTL
+const DEFAULT_MINT_CACHE_TTL: u64 = 60 * 60;
+
+/// When a mint's info and keysets were last fetched
+/// The data itself lives in the wallet database, this only tracks its age.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: u64,
+    // NUT-19 ttl advertised by the mint, in seconds
+    ttl: Option<u64>,
+}
+
+/// Age of the cached data for one mint
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Freshness {
+    pub fetched_at: u64,
+    pub is_stale: bool,
+}
+
+pub(crate) struct MintCache {
+    path: PathBuf,
+    entries: Mutex<BTreeMap<String, CacheEntry>>,
+    // Mints with a background refresh running
+    refreshing: Mutex<HashSet<String>>,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+impl MintCache {
+    pub(crate) fn open(profile_dir: &Path) -> Result<Self, String> {
+        let path = profile_dir.join(MINT_CACHE_FILE_NAME);
+        let entries = if path.exists() {
+            let json = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read mint cache: {}", e))?;
+            // A corrupt cache only costs a refetch
+            serde_json::from_str(&json).unwrap_or_default()
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+            refreshing: Mutex::new(HashSet::new()),
+        })
+    }
+
+    fn save(&self, entries: &BTreeMap<String, CacheEntry>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(entries)
+            .map_err(|e| format!("Failed to serialize mint cache: {}", e))?;
+        std::fs::write(&self.path, json).map_err(|e| format!("Failed to save mint cache: {}", e))
+    }
+
+    /// Record a successful fetch of the mint's info and keysets
+    pub(crate) fn record(&self, mint_url: &MintUrl, ttl: Option<u64>) -> Result<(), String> {
+        let mut entries = self.entries.lock().map_err(|e| e.to_string())?;
+        entries.insert(
+            mint_url.to_string(),
+            CacheEntry {
+                fetched_at: now_secs(),
+                ttl,
+            },
+        );
+        self.save(&entries)
+    }
+
+    pub(crate) fn forget(&self, mint_url: &MintUrl) -> Result<(), String> {
+        let mut entries = self.entries.lock().map_err(|e| e.to_string())?;
+        if entries.remove(&mint_url.to_string()).is_some() {
+            self.save(&entries)?;
+        }
+        Ok(())
+    }
+
+    /// None when the fetch time is unknown, e.g. for mints added before caching
+    pub(crate) fn freshness(&self, mint_url: &MintUrl) -> Option<Freshness> {
+        let entries = self.entries.lock().ok()?;
+        let entry = entries.get(&mint_url.to_string())?;
+        let ttl = entry.ttl.unwrap_or(DEFAULT_MINT_CACHE_TTL);
+        Some(Freshness {
+            fetched_at: entry.fetched_at,
+            is_stale: now_secs() >= entry.fetched_at.saturating_add(ttl),
+        })
+    }
+
+    /// Claim the background refresh for a mint, false if one is already running
+    pub(crate) fn start_refresh(&self, mint_url: &MintUrl) -> bool {
+        self.refreshing
+            .lock()
+            .map(|mut refreshing| refreshing.insert(mint_url.to_string()))
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn finish_refresh(&self, mint_url: &MintUrl) {
+        if let Ok(mut refreshing) = self.refreshing.lock() {
+            refreshing.remove(&mint_url.to_string());
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use cdk::mint_url::MintUrl;
+use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+use url::Url;
+
+/// File the network settings are stored in, inside the profile directory
+const NETWORK_SETTINGS_FILE_NAME: &str = "network_settings.json";
+
+/// Domain separation for the key protecting secrets in the network settings
+const NETWORK_KEY_CONTEXT: &[u8] = b"purrwallet network settings v1";
+
+/// Proxy for mint connections
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    // "socks5" or "http" (HTTP CONNECT)
+    pub kind: String,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// Proxy as saved in the settings file, the password sealed with the profile's network key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct StoredProxy {
+    pub kind: String,
+    pub host: String,
+    pub port: u16,
+    #[serde(default)]
+    pub username: Option<String>,
+    // Hex of nonce, ciphertext and tag (AES-256-GCM)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_password: Option<String>,
+    // Written in plaintext by earlier releases, sealed on the next open
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+/// Key for the secrets in a profile's network settings, derived from the wallet seed
+pub(crate) fn network_key(seed: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(NETWORK_KEY_CONTEXT);
+    hasher.update(seed);
+    hasher.finalize().into()
+}
+
+impl StoredProxy {
+    pub(crate) fn seal(proxy: ProxyConfig, key: &[u8; 32]) -> Result<Self, String> {
+        let encrypted_password = match proxy.password {
+            Some(password) => {
+                let nonce: [u8; 12] = rand::random();
+                let mut tag = [0u8; 16];
+                let ciphertext = encrypt_aead(
+                    Cipher::aes_256_gcm(),
+                    key,
+                    Some(&nonce),
+                    &[],
+                    password.as_bytes(),
+                    &mut tag,
+                )
+                .map_err(|e| format!("Failed to encrypt proxy password: {}", e))?;
+                Some(hex::encode(
+                    [&nonce[..], &ciphertext[..], &tag[..]].concat(),
+                ))
+            }
+            None => None,
+        };
+        Ok(Self {
+            kind: proxy.kind,
+            host: proxy.host,
+            port: proxy.port,
+            username: proxy.username,
+            encrypted_password,
+            password: None,
+        })
+    }
+
+    pub(crate) fn open(&self, key: &[u8; 32]) -> Result<ProxyConfig, String> {
+        let password = match (&self.encrypted_password, &self.password) {
+            (Some(sealed), _) => {
+                let sealed = hex::decode(sealed)
+                    .map_err(|_| "Invalid encrypted proxy password".to_string())?;
+                if sealed.len() < 28 {
+                    return Err("Invalid encrypted proxy password".to_string());
+                }
+                let (nonce, rest) = sealed.split_at(12);
+                let (ciphertext, tag) = rest.split_at(rest.len() - 16);
+                let password = decrypt_aead(
+                    Cipher::aes_256_gcm(),
+                    key,
+                    Some(nonce),
+                    &[],
+                    ciphertext,
+                    tag,
+                )
+                .map_err(|_| "Failed to decrypt proxy password".to_string())?;
+                Some(
+                    String::from_utf8(password)
+                        .map_err(|_| "Invalid encrypted proxy password".to_string())?,
+                )
+            }
+            (None, password) => password.clone(),
+        };
+        Ok(ProxyConfig {
+            kind: self.kind.clone(),
+            host: self.host.clone(),
+            port: self.port,
+            username: self.username.clone(),
+            password,
+        })
+    }
+
+    /// Proxy URL as understood by reqwest, with the password decrypted
+    pub(crate) fn to_url(&self, key: &[u8; 32]) -> Result<Url, String> {
+        self.open(key)?.to_url()
+    }
+}
+
+/// When mint connections go through Tor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TorPolicy {
+    /// Every mint connection goes through Tor
+    Always,
+    /// Only .onion mints use Tor, the rest connect directly
+    #[default]
+    OnionOnly,
+    /// Never use Tor, .onion mints are refused
+    Never,
+}
+
+impl TorPolicy {
+    pub(crate) fn parse(policy: &str) -> Result<Self, String> {
+        match policy {
+            "always" => Ok(Self::Always),
+            "onion_only" => Ok(Self::OnionOnly),
+            "never" => Ok(Self::Never),
+            _ => Err(format!(
+                "Unknown Tor policy '{}', expected always, onion_only or never",
+                policy
+            )),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::OnionOnly => "onion_only",
+            Self::Never => "never",
+        }
+    }
+
+    /// Whether a connection to this mint goes through Tor
+    pub(crate) fn uses_tor(&self, mint_url: &MintUrl) -> bool {
+        match self {
+            Self::Always => true,
+            Self::OnionOnly => is_onion(mint_url),
+            Self::Never => false,
+        }
+    }
+
+    /// Refuse mints the policy cannot reach
+    pub(crate) fn check_mint(&self, mint_url: &MintUrl) -> Result<(), String> {
+        if *self == Self::Never && is_onion(mint_url) {
+            return Err(format!(
+                "Tor is disabled for this wallet, cannot connect to {}",
+                mint_url
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Per profile network settings, applied when the wallet is opened
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct NetworkSettings {
+    #[serde(default)]
+    pub tor_policy: TorPolicy,
+    // Proxy for every mint without its own entry
+    #[serde(default)]
+    pub proxy: Option<StoredProxy>,
+    // Per mint overrides, keyed by mint URL
+    #[serde(default)]
+    pub mint_proxies: BTreeMap<String, StoredProxy>,
+    // Give every mint reached over Tor its own circuits
+    #[serde(default)]
+    pub tor_isolation: bool,
+}
+
+impl NetworkSettings {
+    pub(crate) fn load(profile_dir: &Path) -> Result<Self, String> {
+        let path = profile_dir.join(NETWORK_SETTINGS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read network settings: {}", e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Invalid network settings: {}", e))
+    }
+
+    pub(crate) fn save(&self, profile_dir: &Path) -> Result<(), String> {
+        std::fs::create_dir_all(profile_dir)
+            .map_err(|e| format!("Failed to create profile directory: {}", e))?;
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize network settings: {}", e))?;
+        std::fs::write(profile_dir.join(NETWORK_SETTINGS_FILE_NAME), json)
+            .map_err(|e| format!("Failed to save network settings: {}", e))
+    }
+
+    /// Proxy used for a mint, its own entry wins over the wallet wide one
+    pub(crate) fn proxy_for(&self, mint_url: &MintUrl) -> Option<&StoredProxy> {
+        self.mint_proxies
+            .get(&mint_url.to_string())
+            .or(self.proxy.as_ref())
+    }
+
+    /// Seal proxy passwords that earlier releases saved in plaintext
+    /// Returns whether anything changed and the settings need saving.
+    pub(crate) fn seal_legacy_passwords(&mut self, key: &[u8; 32]) -> Result<bool, String> {
+        let mut changed = false;
+        for proxy in self.proxy.iter_mut().chain(self.mint_proxies.values_mut()) {
+            if proxy.password.is_some() {
+                *proxy = StoredProxy::seal(proxy.open(key)?, key)?;
+                changed = true;
+            }
+        }
+        Ok(changed)
+    }
+}
+
+impl ProxyConfig {
+    /// Proxy URL as understood by reqwest
+    /// SOCKS5 uses socks5h so the proxy resolves host names, which .onion mints need.
+    pub(crate) fn to_url(&self) -> Result<Url, String> {
+        let scheme = match self.kind.as_str() {
+            "socks5" => "socks5h",
+            "http" => "http",
+            _ => {
+                return Err(format!(
+                    "Unknown proxy type '{}', expected socks5 or http",
+                    self.kind
+                ))
+            }
+        };
+        if self.host.is_empty() || self.port == 0 {
+            return Err("Proxy host and port are required".to_string());
+        }
+
+        let mut url = Url::parse(&format!("{}://{}:{}", scheme, self.host, self.port))
+            .map_err(|e| format!("Invalid proxy address: {}", e))?;
+        if let Some(username) = &self.username {
+            url.set_username(username)
+                .map_err(|_| "Invalid proxy username".to_string())?;
+            url.set_password(self.password.as_deref())
+                .map_err(|_| "Invalid proxy password".to_string())?;
+        }
+        Ok(url)
+    }
+}
+
+pub(crate) fn is_onion(mint_url: &MintUrl) -> bool {
+    mint_url
+        .to_string()
+        .parse::<Url>()
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host.ends_with(".onion")))
+        .unwrap_or(false)
+}
@@ -0,0 +1,590 @@
+use cdk::mint_url::MintUrl;
+use cdk::nuts::{Proof, PublicKey, State};
+use cdk::types::ProofInfo;
+use cdk::wallet::Wallet;
+use nostr::event::{Event, EventBuilder, Kind, Tag};
+use nostr::key::{Keys, SecretKey};
+use nostr::nips::nip44;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+
+use crate::api::cashu::{self, WalletHandle};
+use crate::api::events::{emit_wallet_event, WalletEvent};
+use crate::api::nip60::{Nip60SyncReport, Nip60SyncStatus};
+
+// NIP-60 event kinds
+const WALLET_KIND: u16 = 17375;
+const TOKEN_KIND: u16 = 7375;
+// NIP-09 deletion request
+const DELETION_KIND: u16 = 5;
+
+/// How often the local proofs are compared with what was last synced
+const LOCAL_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the relays are read for changes from other devices
+const REMOTE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Proofs the wallet holds, proofs given away in a token no longer count
+const HELD_STATES: [State; 3] = [State::Unspent, State::Pending, State::Reserved];
+
+/// Decrypted content of a kind 7375 token event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TokenContent {
+    mint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unit: Option<String>,
+    proofs: Vec<Proof>,
+    // Token events this one replaces
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    del: Vec<String>,
+}
+
+struct TokenEvent {
+    id: String,
+    created_at: u64,
+    // Normalized, so it compares equal to the wallet's mint URLs
+    mint_url: String,
+    content: TokenContent,
+}
+
+/// Background sync of one wallet profile
+struct RunningSync {
+    keys: Keys,
+    relays: Vec<String>,
+    task: JoinHandle<()>,
+    last_report: Option<Nip60SyncReport>,
+    last_error: Option<String>,
+}
+
+/// Running syncs by profile id
+static SYNCS: Mutex<BTreeMap<String, RunningSync>> = Mutex::new(BTreeMap::new());
+
+/// One sync at a time per profile, background rounds and sync_now would publish the
+/// same proofs
+static SYNC_LOCKS: Mutex<BTreeMap<String, Arc<tokio::sync::Mutex<()>>>> =
+    Mutex::new(BTreeMap::new());
+
+async fn lock_sync(profile_id: &str) -> tokio::sync::OwnedMutexGuard<()> {
+    let lock = SYNC_LOCKS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(profile_id.to_string())
+        .or_default()
+        .clone();
+    lock.lock_owned().await
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn encrypt(keys: &Keys, plaintext: &str) -> Result<String, String> {
+    nip44::encrypt(
+        keys.secret_key(),
+        &keys.public_key(),
+        plaintext,
+        nip44::Version::V2,
+    )
+    .map_err(|e| format!("NIP-44 encryption failed: {}", e))
+}
+
+fn decrypt(keys: &Keys, ciphertext: &str) -> Result<String, String> {
+    nip44::decrypt(keys.secret_key(), &keys.public_key(), ciphertext)
+        .map_err(|e| format!("NIP-44 decryption failed: {}", e))
+}
+
+async fn publish(
+    keys: &Keys,
+    relays: &[String],
+    kind: u16,
+    content: String,
+    tags: Vec<Vec<String>>,
+) -> Result<(), String> {
+    let tags = tags
+        .iter()
+        .map(|tag| Tag::parse(tag))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Invalid tags: {}", e))?;
+    let event = EventBuilder::new(Kind::from(kind), content)
+        .tags(tags)
+        .sign_with_keys(keys)
+        .map_err(|e| format!("Failed to sign event: {}", e))?;
+    crate::relay::publish_event(relays, &event)
+        .await
+        .map(|_| ())
+}
+
+fn normalize_mint_url(mint_url: &str) -> Result<String, String> {
+    MintUrl::from_str(mint_url)
+        .map(|url| url.to_string())
+        .map_err(|e| format!("Invalid mint URL: {}", e))
+}
+
+/// Content of a kind 17375 wallet event
+#[derive(Default)]
+struct WalletContent {
+    // For receiving P2PK locked ecash, kept as the first device created it
+    privkey: Option<String>,
+    mints: BTreeSet<String>,
+    // Tags other clients added, published again unchanged
+    other_tags: Vec<Vec<String>>,
+}
+
+fn parse_wallet_content(json: &str) -> Result<WalletContent, String> {
+    let tags: Vec<Vec<String>> =
+        serde_json::from_str(json).map_err(|e| format!("Invalid wallet event: {}", e))?;
+    let mut content = WalletContent::default();
+    for tag in tags {
+        match tag.as_slice() {
+            [name, value, ..] if name == "privkey" => content.privkey = Some(value.clone()),
+            [name, value, ..] if name == "mint" => {
+                if let Ok(mint_url) = normalize_mint_url(value) {
+                    content.mints.insert(mint_url);
+                }
+            }
+            _ => content.other_tags.push(tag),
+        }
+    }
+    Ok(content)
+}
+
+async fn held_proofs(wallet: &Wallet) -> Result<Vec<ProofInfo>, String> {
+    wallet
+        .localstore
+        .get_proofs(
+            Some(wallet.mint_url.clone()),
+            Some(wallet.unit.clone()),
+            Some(HELD_STATES.to_vec()),
+            None,
+        )
+        .await
+        .map_err(|e| format!("Failed to get proofs: {}", e))
+}
+
+/// Sorted Ys of every held proof, changes whenever the wallet's proofs do
+async fn held_fingerprint(handle: &WalletHandle) -> Result<Vec<String>, String> {
+    let multi_mint_wallet = cashu::get_multi_mint_wallet(handle).await?;
+    let mut ys = Vec::new();
+    for wallet in multi_mint_wallet.get_wallets().await {
+        ys.extend(
+            held_proofs(&wallet)
+                .await?
+                .into_iter()
+                .map(|info| info.y.to_hex()),
+        );
+    }
+    ys.sort();
+    Ok(ys)
+}
+
+/// Bring the wallet and the relays in line with each other
+async fn sync(
+    handle: &WalletHandle,
+    keys: &Keys,
+    relays: &[String],
+) -> Result<Nip60SyncReport, String> {
+    let _sync = lock_sync(&handle.profile_id).await;
+    // Shutdown waits for the round to finish
+    let (multi_mint_wallet, _operation) = cashu::begin_wallet_operation(handle).await?;
+    let mut report = Nip60SyncReport::default();
+    let public_key = keys.public_key();
+
+    let filter = serde_json::json!({
+        "authors": [public_key.to_hex()],
+        "kinds": [WALLET_KIND, TOKEN_KIND, DELETION_KIND],
+    });
+    let fetched = crate::relay::fetch_events(relays, &filter).await?;
+    // A relay that did not answer may hold a wallet event this round cannot see
+    let all_relays_answered = fetched.errors.is_empty();
+    report.errors.extend(fetched.errors);
+    let events: Vec<Event> = fetched
+        .events
+        .into_iter()
+        .filter(|event| event.pubkey == public_key && event.verify().is_ok())
+        .collect();
+
+    // Token events are gone once a deletion request or a newer token event names them
+    let mut deleted = HashSet::new();
+    for event in events
+        .iter()
+        .filter(|event| event.kind == Kind::from(DELETION_KIND))
+    {
+        for tag in event.tags.iter() {
+            if let [name, id, ..] = tag.as_slice() {
+                if name == "e" {
+                    deleted.insert(id.clone());
+                }
+            }
+        }
+    }
+    let mut token_events = Vec::new();
+    for event in events
+        .iter()
+        .filter(|event| event.kind == Kind::from(TOKEN_KIND))
+    {
+        let parsed = decrypt(keys, &event.content).and_then(|json| {
+            let content: TokenContent =
+                serde_json::from_str(&json).map_err(|e| format!("Invalid token event: {}", e))?;
+            Ok((normalize_mint_url(&content.mint)?, content))
+        });
+        match parsed {
+            Ok((mint_url, content)) => {
+                deleted.extend(content.del.iter().cloned());
+                token_events.push(TokenEvent {
+                    id: event.id.to_hex(),
+                    created_at: event.created_at.as_u64(),
+                    mint_url,
+                    content,
+                });
+            }
+            Err(e) => report
+                .errors
+                .push(format!("Skipped token event {}: {}", event.id, e)),
+        }
+    }
+    token_events.retain(|event| !deleted.contains(&event.id));
+    // Newest first, so of two events holding the same proof the older one is rolled over
+    token_events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let wallet_event = events
+        .iter()
+        .filter(|event| event.kind == Kind::from(WALLET_KIND))
+        .max_by_key(|event| event.created_at);
+    let remote_wallet = match wallet_event {
+        // Overwriting a wallet event we cannot read would lose its privkey
+        Some(event) => parse_wallet_content(&decrypt(keys, &event.content)?)?,
+        None => WalletContent::default(),
+    };
+
+    // Mints any device uses end up in every device's wallet
+    let mut local_mints: BTreeSet<String> = multi_mint_wallet
+        .get_wallets()
+        .await
+        .iter()
+        .map(|wallet| wallet.mint_url.to_string())
+        .collect();
+    let missing: BTreeSet<String> = remote_wallet
+        .mints
+        .iter()
+        .chain(token_events.iter().map(|event| &event.mint_url))
+        .filter(|mint_url| !local_mints.contains(*mint_url))
+        .cloned()
+        .collect();
+    for mint_url in missing {
+        let added = match MintUrl::from_str(&mint_url) {
+            Ok(url) => cashu::ensure_mint(handle, &url).await,
+            Err(e) => Err(e.to_string()),
+        };
+        match added {
+            Ok(_) => {
+                local_mints.insert(mint_url.clone());
+                report.mints_added.push(mint_url);
+            }
+            Err(e) => report.errors.push(format!("{}: {}", mint_url, e)),
+        }
+    }
+
+    for wallet in multi_mint_wallet.get_wallets().await {
+        let mint_url = wallet.mint_url.to_string();
+        let unit = wallet.unit.to_string();
+        let mint_events: Vec<&TokenEvent> = token_events
+            .iter()
+            .filter(|event| event.mint_url == mint_url)
+            .filter(|event| event.content.unit.as_deref().is_none_or(|u| u == unit))
+            .collect();
+        if let Err(e) = sync_mint(keys, relays, &wallet, &mint_events, &mut report).await {
+            report.errors.push(format!("{}: {}", mint_url, e));
+        }
+    }
+
+    // A mint this device could not add stays listed for the devices that can
+    let mints: BTreeSet<String> = remote_wallet.mints.union(&local_mints).cloned().collect();
+    if wallet_event.is_none() || mints != remote_wallet.mints {
+        if all_relays_answered {
+            // Only the first device creates the privkey, later ones keep it
+            let privkey = match wallet_event {
+                Some(_) => remote_wallet.privkey,
+                None => Some(SecretKey::generate().to_secret_hex()),
+            };
+            let tags: Vec<Vec<String>> = privkey
+                .map(|privkey| vec!["privkey".to_string(), privkey])
+                .into_iter()
+                .chain(
+                    mints
+                        .iter()
+                        .map(|mint_url| vec!["mint".to_string(), mint_url.clone()]),
+                )
+                .chain(remote_wallet.other_tags)
+                .collect();
+            let content = serde_json::to_string(&tags)
+                .map_err(|e| format!("Failed to serialize wallet event: {}", e))?;
+            publish(
+                keys,
+                relays,
+                WALLET_KIND,
+                encrypt(keys, &content)?,
+                Vec::new(),
+            )
+            .await?;
+            report.published_events += 1;
+        } else {
+            report
+                .errors
+                .push("Wallet event not updated, not every relay answered".to_string());
+        }
+    }
+
+    report.synced_at = now_secs();
+    Ok(report)
+}
+
+/// Merge one mint's token events with its local proofs
+async fn sync_mint(
+    keys: &Keys,
+    relays: &[String],
+    wallet: &Wallet,
+    events: &[&TokenEvent],
+    report: &mut Nip60SyncReport,
+) -> Result<(), String> {
+    let known: HashSet<PublicKey> = wallet
+        .localstore
+        .get_proofs(
+            Some(wallet.mint_url.clone()),
+            Some(wallet.unit.clone()),
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| format!("Failed to get proofs: {}", e))?
+        .into_iter()
+        .map(|info| info.y)
+        .collect();
+
+    // Remote proofs this device has never seen come from another device
+    let mut incoming: HashMap<PublicKey, Proof> = HashMap::new();
+    for proof in events.iter().flat_map(|event| event.content.proofs.iter()) {
+        if let Ok(y) = proof.y() {
+            if !known.contains(&y) {
+                incoming.insert(y, proof.clone());
+            }
+        }
+    }
+    if !incoming.is_empty() {
+        let incoming: Vec<Proof> = incoming.into_values().collect();
+        let unspent: HashSet<PublicKey> = wallet
+            .check_proofs_spent(incoming.clone())
+            .await
+            .map_err(|e| format!("Failed to check proof states: {}", e))?
+            .into_iter()
+            .filter(|state| state.state == State::Unspent)
+            .map(|state| state.y)
+            .collect();
+        let new_proofs = incoming
+            .into_iter()
+            .filter(|proof| proof.y().is_ok_and(|y| unspent.contains(&y)))
+            .map(|proof| {
+                ProofInfo::new(
+                    proof,
+                    wallet.mint_url.clone(),
+                    State::Unspent,
+                    wallet.unit.clone(),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to import proofs: {}", e))?;
+        report.imported_proofs += new_proofs.len() as u64;
+        report.imported_amount += new_proofs
+            .iter()
+            .map(|info| u64::from(info.proof.amount))
+            .sum::<u64>();
+        wallet
+            .localstore
+            .update_proofs(new_proofs, Vec::new())
+            .await
+            .map_err(|e| format!("Failed to import proofs: {}", e))?;
+    }
+
+    // Events are kept while every proof in them is still held and in no newer event
+    let held = held_proofs(wallet).await?;
+    let held_ys: HashSet<PublicKey> = held.iter().map(|info| info.y).collect();
+    let mut covered = HashSet::new();
+    let mut stale = Vec::new();
+    for event in events {
+        let ys: Vec<PublicKey> = event
+            .content
+            .proofs
+            .iter()
+            .filter_map(|proof| proof.y().ok())
+            .collect();
+        let keep = ys.len() == event.content.proofs.len()
+            && ys
+                .iter()
+                .all(|y| held_ys.contains(y) && !covered.contains(y));
+        if keep {
+            covered.extend(ys);
+        } else {
+            stale.push(event.id.clone());
+        }
+    }
+
+    // Proofs no event covers may have been spent by another device meanwhile
+    let uncovered: Vec<Proof> = held
+        .into_iter()
+        .filter(|info| !covered.contains(&info.y))
+        .map(|info| info.proof)
+        .collect();
+    let mut to_publish = Vec::new();
+    if !uncovered.is_empty() {
+        let spent: Vec<PublicKey> = wallet
+            .check_proofs_spent(uncovered.clone())
+            .await
+            .map_err(|e| format!("Failed to check proof states: {}", e))?
+            .into_iter()
+            .filter(|state| state.state == State::Spent)
+            .map(|state| state.y)
+            .collect();
+        if !spent.is_empty() {
+            report.removed_proofs += spent.len() as u64;
+            wallet
+                .localstore
+                .update_proofs(Vec::new(), spent.clone())
+                .await
+                .map_err(|e| format!("Failed to remove spent proofs: {}", e))?;
+        }
+        to_publish = uncovered
+            .into_iter()
+            .filter(|proof| proof.y().is_ok_and(|y| !spent.contains(&y)))
+            .collect();
+    }
+
+    if !to_publish.is_empty() {
+        let content = TokenContent {
+            mint: wallet.mint_url.to_string(),
+            unit: Some(wallet.unit.to_string()),
+            proofs: to_publish,
+            del: stale.clone(),
+        };
+        let json = serde_json::to_string(&content)
+            .map_err(|e| format!("Failed to serialize token event: {}", e))?;
+        publish(keys, relays, TOKEN_KIND, encrypt(keys, &json)?, Vec::new()).await?;
+        report.published_events += 1;
+    }
+    if !stale.is_empty() {
+        let tags = stale
+            .iter()
+            .map(|id| vec!["e".to_string(), id.clone()])
+            .chain(std::iter::once(vec![
+                "k".to_string(),
+                TOKEN_KIND.to_string(),
+            ]))
+            .collect();
+        publish(keys, relays, DELETION_KIND, String::new(), tags).await?;
+        report.deleted_events += stale.len() as u64;
+    }
+
+    Ok(())
+}
+
+/// Keep the last outcome for the status call and tell Dart about it
+fn record(profile_id: &str, result: &Result<Nip60SyncReport, String>) {
+    if let Ok(mut syncs) = SYNCS.lock() {
+        if let Some(running) = syncs.get_mut(profile_id) {
+            match result {
+                Ok(report) => {
+                    running.last_report = Some(report.clone());
+                    running.last_error = None;
+                }
+                Err(e) => running.last_error = Some(e.clone()),
+            }
+        }
+    }
+
+    let event = match result {
+        Ok(report) => WalletEvent::new("nip60_sync_completed")
+            .amount(report.imported_amount)
+            .data("published_events", report.published_events)
+            .data("deleted_events", report.deleted_events)
+            .data("removed_proofs", report.removed_proofs),
+        Err(e) => WalletEvent::new("nip60_sync_failed").message(e),
+    };
+    emit_wallet_event(event.profile_id(profile_id));
+}
+
+/// Sync when the wallet's proofs change and every REMOTE_SYNC_INTERVAL, until stopped
+async fn run(handle: WalletHandle, keys: Keys, relays: Vec<String>) {
+    let mut last_fingerprint = None;
+    let mut last_sync: Option<Instant> = None;
+    loop {
+        // Fails once the wallet is closed
+        let Ok(fingerprint) = held_fingerprint(&handle).await else {
+            break;
+        };
+        let due = last_sync.is_none_or(|at| at.elapsed() >= REMOTE_SYNC_INTERVAL);
+        let offline = cashu::is_offline_mode(handle.clone()).await.unwrap_or(true);
+        if !offline && (due || last_fingerprint.as_ref() != Some(&fingerprint)) {
+            let result = sync(&handle, &keys, &relays).await;
+            record(&handle.profile_id, &result);
+            last_sync = Some(Instant::now());
+            last_fingerprint = held_fingerprint(&handle).await.ok();
+        }
+        tokio::time::sleep(LOCAL_CHECK_INTERVAL).await;
+    }
+}
+
+/// Start syncing a profile, replacing a sync that is already running
+pub(crate) fn start(handle: WalletHandle, keys: Keys, relays: Vec<String>) -> Result<(), String> {
+    stop(&handle.profile_id);
+    let profile_id = handle.profile_id.clone();
+    let task = tokio::spawn(run(handle, keys.clone(), relays.clone()));
+    SYNCS.lock().map_err(|e| e.to_string())?.insert(
+        profile_id,
+        RunningSync {
+            keys,
+            relays,
+            task,
+            last_report: None,
+            last_error: None,
+        },
+    );
+    Ok(())
+}
+
+pub(crate) fn stop(profile_id: &str) {
+    let Ok(mut syncs) = SYNCS.lock() else {
+        return;
+    };
+    if let Some(running) = syncs.remove(profile_id) {
+        running.task.abort();
+    }
+}
+
+pub(crate) async fn sync_now(handle: &WalletHandle) -> Result<Nip60SyncReport, String> {
+    let (keys, relays) = SYNCS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get(&handle.profile_id)
+        .map(|running| (running.keys.clone(), running.relays.clone()))
+        .ok_or("NIP-60 sync is not enabled for this wallet")?;
+    let result = sync(handle, &keys, &relays).await;
+    record(&handle.profile_id, &result);
+    result
+}
+
+pub(crate) fn status(profile_id: &str) -> Option<Nip60SyncStatus> {
+    let syncs = SYNCS.lock().ok()?;
+    let running = syncs.get(profile_id)?;
+    Some(Nip60SyncStatus {
+        enabled: true,
+        relays: running.relays.clone(),
+        public_key: Some(running.keys.public_key().to_hex()),
+        last_report: running.last_report.clone(),
+        last_error: running.last_error.clone(),
+    })
+}
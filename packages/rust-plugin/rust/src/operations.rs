@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{watch, OwnedRwLockReadGuard, RwLock};
+
+use crate::api::cashu::InterruptedOperation;
+
+/// File the operation journal is stored in, inside the profile directory
+const JOURNAL_FILE_NAME: &str = "operation_journal.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Journal {
+    // Operations running right now, left behind if the process dies
+    in_flight: BTreeMap<String, InterruptedOperation>,
+    // Operations from earlier sessions that never finished, kept until the app clears them
+    interrupted: Vec<InterruptedOperation>,
+}
+
+/// Tracks the operations running against one open wallet so shutdown can wait for them
+pub(crate) struct WalletOperations {
+    // Every operation holds a read guard, shutdown takes the write lock
+    gate: Arc<RwLock<()>>,
+    shutdown: watch::Sender<bool>,
+    journal_path: PathBuf,
+    journal: Mutex<Journal>,
+}
+
+/// Registration of a running operation, dropping it marks the operation finished
+pub(crate) struct OperationGuard {
+    operations: Arc<WalletOperations>,
+    journal_id: Option<String>,
+    _permit: OwnedRwLockReadGuard<()>,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+impl WalletOperations {
+    /// Load the profile's journal, operations left in flight by the last session
+    /// become interrupted
+    pub(crate) fn open(profile_dir: &Path) -> Result<Self, String> {
+        let journal_path = profile_dir.join(JOURNAL_FILE_NAME);
+        let mut journal: Journal = if journal_path.exists() {
+            let json = std::fs::read_to_string(&journal_path)
+                .map_err(|e| format!("Failed to read operation journal: {}", e))?;
+            serde_json::from_str(&json).map_err(|e| format!("Invalid operation journal: {}", e))?
+        } else {
+            Journal::default()
+        };
+
+        let left_behind = std::mem::take(&mut journal.in_flight);
+        journal.interrupted.extend(left_behind.into_values());
+
+        let operations = Self {
+            gate: Arc::new(RwLock::new(())),
+            shutdown: watch::channel(false).0,
+            journal_path,
+            journal: Mutex::new(journal),
+        };
+        operations.save()?;
+        Ok(operations)
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let journal = self.journal.lock().map_err(|e| e.to_string())?;
+        let json = serde_json::to_string_pretty(&*journal)
+            .map_err(|e| format!("Failed to serialize operation journal: {}", e))?;
+        std::fs::write(&self.journal_path, json)
+            .map_err(|e| format!("Failed to save operation journal: {}", e))
+    }
+
+    /// Register a new operation, fails once shutdown has started
+    pub(crate) async fn begin(self: &Arc<Self>) -> Result<OperationGuard, String> {
+        if self.is_shutting_down() {
+            return Err("Wallet is shutting down".to_string());
+        }
+        let permit = self.gate.clone().read_owned().await;
+        Ok(OperationGuard {
+            operations: self.clone(),
+            journal_id: None,
+            _permit: permit,
+        })
+    }
+
+    pub(crate) fn is_shutting_down(&self) -> bool {
+        *self.shutdown.borrow()
+    }
+
+    /// Resolves once shutdown has started, for background loops to stop on
+    pub(crate) async fn cancelled(&self) {
+        let mut receiver = self.shutdown.subscribe();
+        let _ = receiver.wait_for(|shutting_down| *shutting_down).await;
+    }
+
+    /// Stop accepting operations and wait for the running ones
+    /// Returns the journaled operations still running when the timeout hit.
+    pub(crate) async fn shutdown(&self, timeout: Duration) -> Vec<InterruptedOperation> {
+        self.shutdown.send_replace(true);
+        if tokio::time::timeout(timeout, self.gate.write())
+            .await
+            .is_ok()
+        {
+            return Vec::new();
+        }
+
+        self.journal
+            .lock()
+            .map(|journal| journal.in_flight.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn interrupted(&self) -> Vec<InterruptedOperation> {
+        self.journal
+            .lock()
+            .map(|journal| journal.interrupted.clone())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn clear_interrupted(&self) -> Result<(), String> {
+        self.journal
+            .lock()
+            .map_err(|e| e.to_string())?
+            .interrupted
+            .clear();
+        self.save()
+    }
+}
+
+impl OperationGuard {
+    /// Record the operation in the journal until it finishes, so a crash or a
+    /// shutdown timeout leaves a trace the app can recover from
+    pub(crate) fn journal(
+        &mut self,
+        kind: &str,
+        mint_url: &str,
+        amount: u64,
+    ) -> Result<(), String> {
+        let id = hex::encode(rand::random::<[u8; 8]>());
+        self.operations
+            .journal
+            .lock()
+            .map_err(|e| e.to_string())?
+            .in_flight
+            .insert(
+                id.clone(),
+                InterruptedOperation {
+                    id: id.clone(),
+                    kind: kind.to_string(),
+                    mint_url: mint_url.to_string(),
+                    amount,
+                    started_at: now_secs(),
+                },
+            );
+        self.operations.save()?;
+        self.journal_id = Some(id);
+        Ok(())
+    }
+
+    /// Resolves once the wallet starts shutting down
+    pub(crate) async fn cancelled(&self) {
+        self.operations.cancelled().await
+    }
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        let Some(id) = self.journal_id.take() else {
+            return;
+        };
+        if let Ok(mut journal) = self.operations.journal.lock() {
+            journal.in_flight.remove(&id);
+        }
+        let _ = self.operations.save();
+    }
+}
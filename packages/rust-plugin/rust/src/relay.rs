@@ -0,0 +1,191 @@
+use futures::{SinkExt, StreamExt};
+use nostr::event::Event;
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
+
+/// How long to wait for a relay to accept an event
+const RELAY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Publish an event to a single relay and wait for its ["OK", ...] reply
+async fn publish_to_relay(relay_url: &str, event_json: &str, event_id: &str) -> Result<(), String> {
+    let (mut stream, _) = tokio_tungstenite::connect_async(relay_url)
+        .await
+        .map_err(|e| format!("Failed to connect to {}: {}", relay_url, e))?;
+
+    let message = format!(r#"["EVENT",{}]"#, event_json);
+    stream
+        .send(Message::Text(message.into()))
+        .await
+        .map_err(|e| format!("Failed to send event to {}: {}", relay_url, e))?;
+
+    let result = loop {
+        let message = match stream.next().await {
+            Some(Ok(message)) => message,
+            Some(Err(e)) => break Err(format!("Relay {} error: {}", relay_url, e)),
+            None => break Err(format!("Relay {} closed the connection", relay_url)),
+        };
+
+        let Message::Text(text) = message else {
+            continue;
+        };
+        let Ok(reply) = serde_json::from_str::<Vec<serde_json::Value>>(text.as_str()) else {
+            continue;
+        };
+
+        // ["OK", <event id>, <accepted>, <message>]
+        if reply.first().and_then(|v| v.as_str()) == Some("OK")
+            && reply.get(1).and_then(|v| v.as_str()) == Some(event_id)
+        {
+            let accepted = reply.get(2).and_then(|v| v.as_bool()).unwrap_or(false);
+            let reason = reply.get(3).and_then(|v| v.as_str()).unwrap_or("");
+            break if accepted {
+                Ok(())
+            } else {
+                Err(format!("Relay {} rejected event: {}", relay_url, reason))
+            };
+        }
+    };
+
+    let _ = stream.close(None).await;
+    result
+}
+
+/// Publish an event to every relay, returning the relays that accepted it
+/// Fails only if no relay accepted the event.
+pub(crate) async fn publish_event(relays: &[String], event: &Event) -> Result<Vec<String>, String> {
+    let event_json =
+        serde_json::to_string(event).map_err(|e| format!("Failed to serialize event: {}", e))?;
+    let event_id = event.id.to_hex();
+
+    let mut accepted = Vec::new();
+    let mut errors = Vec::new();
+    for relay_url in relays {
+        match tokio::time::timeout(
+            RELAY_TIMEOUT,
+            publish_to_relay(relay_url, &event_json, &event_id),
+        )
+        .await
+        {
+            Ok(Ok(())) => accepted.push(relay_url.clone()),
+            Ok(Err(e)) => errors.push(e),
+            Err(_) => errors.push(format!("Relay {} timed out", relay_url)),
+        }
+    }
+
+    if accepted.is_empty() {
+        return Err(format!(
+            "No relay accepted the event: {}",
+            if errors.is_empty() {
+                "no relays given".to_string()
+            } else {
+                errors.join("; ")
+            }
+        ));
+    }
+
+    Ok(accepted)
+}
+
+/// Read the events matching a filter from a single relay, until it sends EOSE
+async fn fetch_from_relay(
+    relay_url: &str,
+    filter: &serde_json::Value,
+) -> Result<Vec<Event>, String> {
+    let (mut stream, _) = tokio_tungstenite::connect_async(relay_url)
+        .await
+        .map_err(|e| format!("Failed to connect to {}: {}", relay_url, e))?;
+
+    let subscription_id = hex::encode(rand::random::<[u8; 8]>());
+    let message = serde_json::json!(["REQ", subscription_id, filter]).to_string();
+    stream
+        .send(Message::Text(message.into()))
+        .await
+        .map_err(|e| format!("Failed to send request to {}: {}", relay_url, e))?;
+
+    let mut events = Vec::new();
+    let result = loop {
+        let message = match stream.next().await {
+            Some(Ok(message)) => message,
+            Some(Err(e)) => break Err(format!("Relay {} error: {}", relay_url, e)),
+            None => break Err(format!("Relay {} closed the connection", relay_url)),
+        };
+
+        let Message::Text(text) = message else {
+            continue;
+        };
+        let Ok(reply) = serde_json::from_str::<Vec<serde_json::Value>>(text.as_str()) else {
+            continue;
+        };
+        if reply.get(1).and_then(|v| v.as_str()) != Some(subscription_id.as_str()) {
+            continue;
+        }
+
+        // ["EVENT", <sub id>, <event>], ["EOSE", <sub id>] or ["CLOSED", <sub id>, <message>]
+        match reply.first().and_then(|v| v.as_str()) {
+            Some("EVENT") => {
+                if let Some(Ok(event)) = reply.get(2).cloned().map(serde_json::from_value::<Event>)
+                {
+                    events.push(event);
+                }
+            }
+            Some("EOSE") => break Ok(()),
+            Some("CLOSED") => {
+                let reason = reply.get(2).and_then(|v| v.as_str()).unwrap_or("");
+                break Err(format!(
+                    "Relay {} refused the request: {}",
+                    relay_url, reason
+                ));
+            }
+            _ => {}
+        }
+    };
+
+    let close = serde_json::json!(["CLOSE", subscription_id]).to_string();
+    let _ = stream.send(Message::Text(close.into())).await;
+    let _ = stream.close(None).await;
+    result.map(|_| events)
+}
+
+/// Events read from the relays, with the relays that did not answer
+pub(crate) struct FetchedEvents {
+    pub events: Vec<Event>,
+    pub errors: Vec<String>,
+}
+
+/// Read the events matching a filter from every relay, without duplicates
+/// Fails only if no relay answered.
+pub(crate) async fn fetch_events(
+    relays: &[String],
+    filter: &serde_json::Value,
+) -> Result<FetchedEvents, String> {
+    let mut events: Vec<Event> = Vec::new();
+    let mut answered = false;
+    let mut errors = Vec::new();
+    for relay_url in relays {
+        match tokio::time::timeout(RELAY_TIMEOUT, fetch_from_relay(relay_url, filter)).await {
+            Ok(Ok(relay_events)) => {
+                answered = true;
+                for event in relay_events {
+                    if !events.iter().any(|known| known.id == event.id) {
+                        events.push(event);
+                    }
+                }
+            }
+            Ok(Err(e)) => errors.push(e),
+            Err(_) => errors.push(format!("Relay {} timed out", relay_url)),
+        }
+    }
+
+    if !answered {
+        return Err(format!(
+            "No relay answered: {}",
+            if errors.is_empty() {
+                "no relays given".to_string()
+            } else {
+                errors.join("; ")
+            }
+        ));
+    }
+
+    Ok(FetchedEvents { events, errors })
+}
@@ -0,0 +1,123 @@
+use bip39::Mnemonic;
+use cdk::mint::{MintBuilder, MintMeltLimits};
+use cdk::nuts::{CurrencyUnit, PaymentMethod};
+use cdk::types::FeeReserve;
+use cdk_fake_wallet::FakeWallet;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use crate::api::cashu::{
+    check_mint_quote_status, create_mint_quote, generate_mnemonic_phrase, mnemonic_to_seed_hex,
+    WalletHandle,
+};
+
+/// Largest amount the test mint accepts for a mint or melt
+const TEST_MINT_MAX_AMOUNT: u64 = 100_000;
+
+/// CDK mint with a fake Lightning backend, served over HTTP on localhost
+/// BOLT11 and BOLT12 mint quotes are paid as soon as they are created and every melt succeeds.
+pub(crate) struct TestMint {
+    pub url: String,
+    server: JoinHandle<()>,
+}
+
+impl TestMint {
+    pub(crate) async fn start() -> Self {
+        // The mint has to know its own URL, so bind before building it
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind test mint");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let localstore = Arc::new(
+            cdk_sqlite::mint::memory::empty()
+                .await
+                .expect("Failed to create test mint database"),
+        );
+        let fake_lightning = Arc::new(FakeWallet::new(
+            FeeReserve {
+                min_fee_reserve: 1.into(),
+                percent_fee_reserve: 0.02,
+            },
+            HashMap::new(),
+            HashSet::new(),
+            0,
+            CurrencyUnit::Sat,
+        ));
+
+        let mut builder = MintBuilder::new(localstore.clone())
+            .with_name("purrwallet test mint".to_string())
+            .with_description("In-process mint for end-to-end tests".to_string())
+            .with_urls(vec![url.clone()]);
+        for method in [PaymentMethod::Bolt11, PaymentMethod::Bolt12] {
+            builder
+                .add_payment_processor(
+                    CurrencyUnit::Sat,
+                    method,
+                    MintMeltLimits::new(1, TEST_MINT_MAX_AMOUNT),
+                    fake_lightning.clone(),
+                )
+                .await
+                .expect("Failed to add fake Lightning backend");
+        }
+
+        let mnemonic = Mnemonic::generate(12).unwrap();
+        let mint = builder
+            .build_with_seed(localstore, &mnemonic.to_seed_normalized(""))
+            .await
+            .expect("Failed to build test mint");
+        mint.start().await.expect("Failed to start test mint");
+
+        let router = cdk_axum::create_mint_router(Arc::new(mint), false)
+            .await
+            .expect("Failed to create test mint router");
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+
+        Self { url, server }
+    }
+
+    /// Mint amount sats into a wallet that has this mint added
+    /// The fake backend pays the quote on its own, this waits until the proofs are in.
+    pub(crate) async fn fund(&self, handle: &WalletHandle, amount: u64) {
+        create_mint_quote(handle.clone(), self.url.clone(), amount, None)
+            .await
+            .unwrap();
+        for _ in 0..50 {
+            let minted = check_mint_quote_status(handle.clone(), self.url.clone())
+                .await
+                .unwrap();
+            if minted != "0" {
+                assert_eq!(minted, amount.to_string());
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+        panic!("Test mint did not pay the {} sat quote", amount);
+    }
+}
+
+impl Drop for TestMint {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Seed for a test wallet, different on every call
+pub(crate) async fn random_seed_hex() -> String {
+    mnemonic_to_seed_hex(generate_mnemonic_phrase(12).await.unwrap())
+        .await
+        .unwrap()
+}
+
+/// Wallet directory under the system temp dir that no other test run uses
+pub(crate) fn temp_database_dir(prefix: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("{}_{}", prefix, rand::random::<u64>()))
+        .to_string_lossy()
+        .to_string()
+}
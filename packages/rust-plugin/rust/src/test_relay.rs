@@ -0,0 +1,110 @@
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Nostr relay on localhost that stores every event it gets
+/// Answers REQ with the stored events matching the filter's authors and kinds.
+/// Signatures are not checked, the code under test verifies what it reads.
+pub(crate) struct TestRelay {
+    pub url: String,
+    events: Arc<Mutex<Vec<Value>>>,
+    server: JoinHandle<()>,
+}
+
+fn matches(filter: &Value, event: &Value) -> bool {
+    let contains = |field: &str, value: &Value| {
+        filter
+            .get(field)
+            .and_then(Value::as_array)
+            .is_none_or(|allowed| allowed.contains(value))
+    };
+    contains("authors", &event["pubkey"]) && contains("kinds", &event["kind"])
+}
+
+async fn serve_connection(stream: tokio::net::TcpStream, events: Arc<Mutex<Vec<Value>>>) {
+    let Ok(mut socket) = tokio_tungstenite::accept_async(stream).await else {
+        return;
+    };
+    while let Some(Ok(message)) = socket.next().await {
+        let Message::Text(text) = message else {
+            continue;
+        };
+        let Ok(request) = serde_json::from_str::<Vec<Value>>(text.as_str()) else {
+            continue;
+        };
+
+        let replies = match request.first().and_then(Value::as_str) {
+            Some("EVENT") => {
+                let event = request.get(1).cloned().unwrap_or(Value::Null);
+                let reply = json!(["OK", event["id"], true, ""]);
+                events.lock().unwrap().push(event);
+                vec![reply]
+            }
+            Some("REQ") => {
+                let subscription_id = request.get(1).cloned().unwrap_or(Value::Null);
+                let filter = request.get(2).cloned().unwrap_or(Value::Null);
+                let mut replies: Vec<Value> = events
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .filter(|event| matches(&filter, event))
+                    .map(|event| json!(["EVENT", subscription_id, event]))
+                    .collect();
+                replies.push(json!(["EOSE", subscription_id]));
+                replies
+            }
+            _ => Vec::new(),
+        };
+        for reply in replies {
+            if socket
+                .send(Message::Text(reply.to_string().into()))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+impl TestRelay {
+    pub(crate) async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind test relay");
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let stored = events.clone();
+        let server = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve_connection(stream, stored.clone()));
+            }
+        });
+
+        Self {
+            url,
+            events,
+            server,
+        }
+    }
+
+    /// Number of stored events of a kind
+    pub(crate) fn count(&self, kind: u64) -> usize {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event["kind"] == kind)
+            .count()
+    }
+}
+
+impl Drop for TestRelay {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
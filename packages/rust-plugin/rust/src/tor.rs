@@ -0,0 +1,385 @@
+use arti_client::config::{BoolOrAuto, TorClientConfigBuilder};
+use arti_client::{BootstrapBehavior, IsolationToken, StreamPrefs, TorClient};
+use cdk::mint_url::MintUrl;
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::{JoinHandle, JoinSet};
+use tor_rtcompat::PreferredRuntime;
+use url::Url;
+
+use crate::api::cashu::TorBootstrapStatus;
+
+/// Tor for one profile
+/// A single Arti client in the profile's Tor directories bootstraps, reports progress
+/// and carries every mint connection. Mint transports reach it through a SOCKS5 bridge
+/// on localhost, the SOCKS username picks the circuits a connection may share.
+struct TorProfile {
+    client: TorClient<PreferredRuntime>,
+    status: watch::Receiver<TorBootstrapStatus>,
+    bootstrap: JoinHandle<()>,
+    proxy: SocketAddr,
+    // Other local processes must not be able to use the bridge
+    secret: String,
+    bridge: JoinHandle<()>,
+    isolation: Arc<Mutex<IsolationTokens>>,
+}
+
+/// Running Tor clients by profile id
+static PROFILES: Mutex<BTreeMap<String, TorProfile>> = Mutex::new(BTreeMap::new());
+
+/// Isolation token per SOCKS username, streams with different tokens never share a circuit
+#[derive(Default)]
+pub(crate) struct IsolationTokens(HashMap<String, IsolationToken>);
+
+impl IsolationTokens {
+    pub(crate) fn token(&mut self, key: &str) -> IsolationToken {
+        *self
+            .0
+            .entry(key.to_string())
+            .or_insert_with(IsolationToken::new)
+    }
+
+    /// Forget every token, later streams are built on new circuits
+    pub(crate) fn reset(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// SOCKS username for a mint, None for connections that share circuits
+pub(crate) fn isolation_key(mint_url: Option<&MintUrl>) -> String {
+    match mint_url {
+        Some(mint_url) => hex::encode(&Sha256::digest(mint_url.to_string().as_bytes())[..16]),
+        None => "shared".to_string(),
+    }
+}
+
+fn not_started() -> TorBootstrapStatus {
+    TorBootstrapStatus {
+        started: false,
+        progress: 0.0,
+        ready: false,
+        message: "Tor has not been started".to_string(),
+        error: None,
+    }
+}
+
+/// Start the profile's Tor client and bootstrap it, unless it already runs
+pub(crate) fn start(profile_id: &str, profile_dir: &Path) -> Result<(), String> {
+    let mut profiles = PROFILES
+        .lock()
+        .map_err(|_| "Tor state is unavailable".to_string())?;
+    if let Some(profile) = profiles.get_mut(profile_id) {
+        // Retry a failed bootstrap, leave a running or finished one alone
+        if profile.status.borrow().error.is_some() {
+            profile.bootstrap.abort();
+            (profile.status, profile.bootstrap) = spawn_bootstrap(profile.client.clone());
+        }
+        return Ok(());
+    }
+
+    let state_dir = profile_dir.join("tor_data");
+    let cache_dir = profile_dir.join("tor_cache");
+    std::fs::create_dir_all(&state_dir)
+        .map_err(|e| format!("Failed to create Tor data directory: {}", e))?;
+    std::fs::create_dir_all(&cache_dir)
+        .map_err(|e| format!("Failed to create Tor cache directory: {}", e))?;
+
+    let config = TorClientConfigBuilder::from_directories(state_dir, cache_dir)
+        .build()
+        .map_err(|e| format!("Invalid Tor configuration: {}", e))?;
+    let client = TorClient::builder()
+        .config(config)
+        .bootstrap_behavior(BootstrapBehavior::OnDemand)
+        .create_unbootstrapped()
+        .map_err(|e| format!("Failed to create Tor client: {}", e))?;
+
+    let listener = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .and_then(|listener| {
+            listener.set_nonblocking(true)?;
+            Ok(listener)
+        })
+        .map_err(|e| format!("Failed to start Tor bridge: {}", e))?;
+    let proxy = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to start Tor bridge: {}", e))?;
+    let listener = TcpListener::from_std(listener)
+        .map_err(|e| format!("Failed to start Tor bridge: {}", e))?;
+
+    let secret = hex::encode(rand::random::<[u8; 16]>());
+    let isolation = Arc::new(Mutex::new(IsolationTokens::default()));
+    let bridge = tokio::spawn(run_bridge(
+        listener,
+        client.clone(),
+        secret.clone(),
+        isolation.clone(),
+    ));
+    let (status, bootstrap) = spawn_bootstrap(client.clone());
+
+    profiles.insert(
+        profile_id.to_string(),
+        TorProfile {
+            client,
+            status,
+            bootstrap,
+            proxy,
+            secret,
+            bridge,
+            isolation,
+        },
+    );
+    Ok(())
+}
+
+/// Bootstrap the client, reporting its own progress events
+fn spawn_bootstrap(
+    client: TorClient<PreferredRuntime>,
+) -> (watch::Receiver<TorBootstrapStatus>, JoinHandle<()>) {
+    let (sender, receiver) = watch::channel(TorBootstrapStatus {
+        started: true,
+        message: "Starting Tor".to_string(),
+        ..not_started()
+    });
+
+    let task = tokio::spawn(async move {
+        let mut events = client.bootstrap_events();
+        let progress = async {
+            while let Some(event) = events.next().await {
+                sender.send_modify(|status| {
+                    status.progress = event.as_frac() as f64;
+                    status.ready = event.ready_for_traffic();
+                    status.message = event.to_string();
+                });
+            }
+        };
+
+        tokio::select! {
+            result = client.bootstrap() => match result {
+                Ok(()) => sender.send_modify(|status| {
+                    status.progress = 1.0;
+                    status.ready = true;
+                    status.message = "Tor is ready".to_string();
+                }),
+                Err(e) => sender.send_modify(|status| {
+                    status.error = Some(format!("Failed to bootstrap Tor: {}", e))
+                }),
+            },
+            _ = progress => {}
+        }
+    });
+
+    (receiver, task)
+}
+
+/// Proxy URL that sends a mint's requests through the profile's Tor client
+/// Starts Tor if needed. Mints passed as None share circuits with each other.
+pub(crate) fn mint_proxy_url(
+    profile_id: &str,
+    profile_dir: &Path,
+    mint_url: Option<&MintUrl>,
+) -> Result<Url, String> {
+    start(profile_id, profile_dir)?;
+    let profiles = PROFILES
+        .lock()
+        .map_err(|_| "Tor state is unavailable".to_string())?;
+    let profile = profiles
+        .get(profile_id)
+        .ok_or_else(|| "Tor is not running".to_string())?;
+
+    // socks5h leaves name resolution to Tor, which .onion mints need
+    let mut url = Url::parse(&format!("socks5h://{}", profile.proxy))
+        .map_err(|e| format!("Invalid Tor bridge address: {}", e))?;
+    url.set_username(&isolation_key(mint_url))
+        .map_err(|_| "Invalid Tor isolation key".to_string())?;
+    url.set_password(Some(&profile.secret))
+        .map_err(|_| "Invalid Tor bridge secret".to_string())?;
+    Ok(url)
+}
+
+/// Move later connections onto new circuits
+/// Open connections keep theirs, mint transports are replaced to drop them.
+pub(crate) fn new_identity(profile_id: &str) {
+    if let Ok(profiles) = PROFILES.lock() {
+        if let Some(profile) = profiles.get(profile_id) {
+            if let Ok(mut isolation) = profile.isolation.lock() {
+                isolation.reset();
+            }
+        }
+    }
+}
+
+pub(crate) fn status(profile_id: &str) -> TorBootstrapStatus {
+    PROFILES
+        .lock()
+        .ok()
+        .and_then(|profiles| {
+            profiles
+                .get(profile_id)
+                .map(|profile| profile.status.borrow().clone())
+        })
+        .unwrap_or_else(not_started)
+}
+
+/// Receiver for status updates, None when Tor was never started
+pub(crate) fn subscribe(profile_id: &str) -> Option<watch::Receiver<TorBootstrapStatus>> {
+    PROFILES
+        .lock()
+        .ok()?
+        .get(profile_id)
+        .map(|profile| profile.status.clone())
+}
+
+/// Stop the profile's Tor client when the profile is closed
+/// Aborting the bridge also closes every connection running through it.
+pub(crate) fn stop(profile_id: &str) {
+    if let Ok(mut profiles) = PROFILES.lock() {
+        if let Some(profile) = profiles.remove(profile_id) {
+            profile.bootstrap.abort();
+            profile.bridge.abort();
+        }
+    }
+}
+
+/// Accept SOCKS5 connections from mint transports until aborted
+async fn run_bridge(
+    listener: TcpListener,
+    client: TorClient<PreferredRuntime>,
+    secret: String,
+    isolation: Arc<Mutex<IsolationTokens>>,
+) {
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let Ok((socket, _)) = accepted else {
+                    continue;
+                };
+                let (client, secret, isolation) =
+                    (client.clone(), secret.clone(), isolation.clone());
+                connections.spawn(async move {
+                    if let Err(e) = bridge_connection(socket, client, &secret, &isolation).await {
+                        log::debug!("Tor bridge connection failed: {}", e);
+                    }
+                });
+            }
+            Some(_) = connections.join_next(), if !connections.is_empty() => {}
+        }
+    }
+}
+
+/// Serve one SOCKS5 CONNECT (RFC 1928) with username/password authentication (RFC 1929)
+async fn bridge_connection(
+    mut socket: TcpStream,
+    client: TorClient<PreferredRuntime>,
+    secret: &str,
+    isolation: &Mutex<IsolationTokens>,
+) -> io::Result<()> {
+    let key = socks_authenticate(&mut socket, secret).await?;
+    let (host, port) = socks_connect_request(&mut socket).await?;
+
+    let token = isolation
+        .lock()
+        .map_err(|_| io::Error::other("Tor isolation state is unavailable"))?
+        .token(&key);
+    let mut prefs = StreamPrefs::new();
+    prefs.set_isolation(token);
+    prefs.connect_to_onion_services(BoolOrAuto::Explicit(true));
+
+    let mut stream = match client
+        .connect_with_prefs((host.as_str(), port), &prefs)
+        .await
+    {
+        Ok(stream) => stream,
+        Err(e) => {
+            // Host unreachable
+            socket.write_all(&socks_reply(4)).await?;
+            return Err(io::Error::other(e.to_string()));
+        }
+    };
+    socket.write_all(&socks_reply(0)).await?;
+    tokio::io::copy_bidirectional(&mut socket, &mut stream).await?;
+    Ok(())
+}
+
+/// Negotiate username/password authentication, returns the username
+async fn socks_authenticate(socket: &mut TcpStream, secret: &str) -> io::Result<String> {
+    let mut greeting = [0u8; 2];
+    socket.read_exact(&mut greeting).await?;
+    let mut methods = vec![0u8; greeting[1] as usize];
+    socket.read_exact(&mut methods).await?;
+    if greeting[0] != 5 || !methods.contains(&2) {
+        socket.write_all(&[5, 0xff]).await?;
+        return Err(io::Error::other(
+            "SOCKS client offered no password authentication",
+        ));
+    }
+    socket.write_all(&[5, 2]).await?;
+
+    let mut header = [0u8; 2];
+    socket.read_exact(&mut header).await?;
+    let mut username = vec![0u8; header[1] as usize];
+    socket.read_exact(&mut username).await?;
+    let mut password_len = [0u8; 1];
+    socket.read_exact(&mut password_len).await?;
+    let mut password = vec![0u8; password_len[0] as usize];
+    socket.read_exact(&mut password).await?;
+    if password != secret.as_bytes() {
+        socket.write_all(&[1, 1]).await?;
+        return Err(io::Error::other("Wrong Tor bridge secret"));
+    }
+    socket.write_all(&[1, 0]).await?;
+
+    String::from_utf8(username).map_err(|_| io::Error::other("SOCKS username is not UTF-8"))
+}
+
+/// Read the CONNECT request, returns the target host and port
+async fn socks_connect_request(socket: &mut TcpStream) -> io::Result<(String, u16)> {
+    let mut header = [0u8; 4];
+    socket.read_exact(&mut header).await?;
+    if header[0] != 5 || header[1] != 1 {
+        // Command not supported
+        socket.write_all(&socks_reply(7)).await?;
+        return Err(io::Error::other("Only SOCKS CONNECT is supported"));
+    }
+
+    let host = match header[3] {
+        1 => {
+            let mut ip = [0u8; 4];
+            socket.read_exact(&mut ip).await?;
+            Ipv4Addr::from(ip).to_string()
+        }
+        3 => {
+            let mut len = [0u8; 1];
+            socket.read_exact(&mut len).await?;
+            let mut name = vec![0u8; len[0] as usize];
+            socket.read_exact(&mut name).await?;
+            String::from_utf8(name).map_err(|_| io::Error::other("Host name is not UTF-8"))?
+        }
+        4 => {
+            let mut ip = [0u8; 16];
+            socket.read_exact(&mut ip).await?;
+            Ipv6Addr::from(ip).to_string()
+        }
+        _ => {
+            // Address type not supported
+            socket.write_all(&socks_reply(8)).await?;
+            return Err(io::Error::other("Unknown SOCKS address type"));
+        }
+    };
+
+    let mut port = [0u8; 2];
+    socket.read_exact(&mut port).await?;
+    Ok((host, u16::from_be_bytes(port)))
+}
+
+/// CONNECT reply, the bound address is not meaningful for a bridge
+fn socks_reply(code: u8) -> [u8; 10] {
+    [5, code, 0, 1, 0, 0, 0, 0, 0, 0]
+}